@@ -18,7 +18,12 @@ CREATE TABLE routing_events_queue
     `created_at` DateTime64(9),
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
-    `routing_approach` Nullable(String)
+    `routing_approach` Nullable(String),
+    `shadow_algorithm_id` Nullable(String),
+    `shadow_connector` Nullable(String),
+    `is_shadow_agreement` Nullable(Bool),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String)
 )
 ENGINE = Kafka
 SETTINGS kafka_broker_list = 'kafka0:29092', kafka_topic_list = 'hyperswitch-routing-api-events', kafka_group_name = 'hyper', kafka_format = 'JSONEachRow', kafka_handle_error_mode = 'stream';
@@ -63,6 +68,11 @@ CREATE TABLE routing_events (
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
     `routing_approach` Nullable(String),
+    `shadow_algorithm_id` Nullable(String),
+    `shadow_connector` Nullable(String),
+    `is_shadow_agreement` Nullable(Bool),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String),
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX profileIndex profile_id TYPE bloom_filter GRANULARITY 1
 ) ENGINE = MergeTree
@@ -90,6 +100,11 @@ CREATE TABLE routing_events_audit (
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
     `routing_approach` Nullable(String),
+    `shadow_algorithm_id` Nullable(String),
+    `shadow_connector` Nullable(String),
+    `is_shadow_agreement` Nullable(Bool),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String),
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX profileIndex profile_id TYPE bloom_filter GRANULARITY 1
 ) ENGINE = MergeTree 
@@ -117,7 +132,12 @@ CREATE MATERIALIZED VIEW routing_events_mv TO routing_events (
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
-    `routing_approach` Nullable(String)
+    `routing_approach` Nullable(String),
+    `shadow_algorithm_id` Nullable(String),
+    `shadow_connector` Nullable(String),
+    `is_shadow_agreement` Nullable(Bool),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String)
 ) AS
 SELECT
     merchant_id,
@@ -138,7 +158,12 @@ SELECT
     now() AS inserted_at,
     method,
     routing_engine,
-    routing_approach
+    routing_approach,
+    shadow_algorithm_id,
+    shadow_connector,
+    is_shadow_agreement,
+    routing_algorithm_id,
+    rule_name
 FROM
     routing_events_queue
 WHERE
@@ -163,7 +188,12 @@ CREATE MATERIALIZED VIEW routing_events_audit_mv TO routing_events_audit (
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `method` LowCardinality(String),
     `routing_engine` LowCardinality(String),
-    `routing_approach` Nullable(String)
+    `routing_approach` Nullable(String),
+    `shadow_algorithm_id` Nullable(String),
+    `shadow_connector` Nullable(String),
+    `is_shadow_agreement` Nullable(Bool),
+    `routing_algorithm_id` Nullable(String),
+    `rule_name` Nullable(String)
 ) AS
 SELECT
     merchant_id,
@@ -184,7 +214,12 @@ SELECT
     now() AS inserted_at,
     method,
     routing_engine,
-    routing_approach
+    routing_approach,
+    shadow_algorithm_id,
+    shadow_connector,
+    is_shadow_agreement,
+    routing_algorithm_id,
+    rule_name
 FROM
     routing_events_queue
 WHERE
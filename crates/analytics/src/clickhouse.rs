@@ -29,10 +29,11 @@ use crate::{
         metrics::{latency::LatencyAvg, ApiEventMetricRow},
     },
     auth_events::filters::AuthEventFilterRow,
+    connector_errors::ConnectorErrorRow,
     connector_events::events::ConnectorEventsResult,
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
-    routing_events::events::RoutingEventsResult,
+    routing_events::{events::RoutingEventsResult, metrics::ShadowRoutingMetricRow},
     sdk_events::events::SdkEventsResult,
     types::TableEngine,
 };
@@ -189,7 +190,9 @@ impl super::api_event::events::ApiLogsFilterAnalytics for ClickhouseClient {}
 impl super::api_event::filters::ApiEventFilterAnalytics for ClickhouseClient {}
 impl super::api_event::metrics::ApiEventMetricAnalytics for ClickhouseClient {}
 impl super::connector_events::events::ConnectorEventLogAnalytics for ClickhouseClient {}
+impl super::connector_errors::ConnectorErrorAnalytics for ClickhouseClient {}
 impl super::routing_events::events::RoutingEventLogAnalytics for ClickhouseClient {}
+impl super::routing_events::metrics::ShadowRoutingMetricAnalytics for ClickhouseClient {}
 impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
     for ClickhouseClient
 {
@@ -249,6 +252,26 @@ impl TryInto<RoutingEventsResult> for serde_json::Value {
     }
 }
 
+impl TryInto<ShadowRoutingMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ShadowRoutingMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ShadowRoutingMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<ConnectorErrorRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorErrorRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorErrorRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<PaymentMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -0,0 +1,108 @@
+//! Errors returned by the connectors on failed payment attempts, used to find the errors that are
+//! not mapped by any GSM rule
+
+use api_models::analytics::{Granularity, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    errors::AnalyticsResult,
+    query::{
+        Aggregate, FilterTypes, GroupByClause, Order, QueryBuilder, QueryFilter, ToSql, Window,
+    },
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+    AnalyticsProvider,
+};
+
+pub trait ConnectorErrorAnalytics: LoadRow<ConnectorErrorRow> {}
+
+/// Counts the failed payment attempts of all merchants by connector, error code and error
+/// message, keeping the `limit_per_connector` most frequent errors of each connector
+pub async fn get_connector_errors<T>(
+    time_range: &TimeRange,
+    limit_per_connector: u64,
+    pool: &T,
+) -> FiltersResult<Vec<ConnectorErrorRow>>
+where
+    T: AnalyticsDataSource + ConnectorErrorAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+    for column in ["connector", "error_code", "error_message"] {
+        query_builder.add_select_column(column).switch()?;
+    }
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .switch()?;
+
+    query_builder
+        .add_filter_clause("status", storage_enums::AttemptStatus::Failure)
+        .switch()?;
+    for column in ["connector", "error_code"] {
+        query_builder
+            .add_custom_filter_clause(column, "", FilterTypes::IsNotNull)
+            .switch()?;
+    }
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    for column in ["connector", "error_code", "error_message"] {
+        query_builder.add_group_by_clause(column).switch()?;
+        query_builder.add_outer_select_column(column).switch()?;
+    }
+    query_builder.add_outer_select_column("count").switch()?;
+    query_builder
+        .add_top_n_clause(
+            &["connector"],
+            limit_per_connector,
+            "count",
+            Order::Descending,
+        )
+        .switch()?;
+
+    query_builder
+        .execute_query::<ConnectorErrorRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+pub async fn connector_errors_core(
+    pool: &AnalyticsProvider,
+    time_range: &TimeRange,
+    limit_per_connector: u64,
+) -> AnalyticsResult<Vec<ConnectorErrorRow>> {
+    let data = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Connector Errors not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Connector Errors"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_connector_errors(time_range, limit_per_connector, ckh_pool).await
+        }
+    }
+    .switch()?;
+    Ok(data)
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorErrorRow {
+    pub connector: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub count: Option<i64>,
+}
@@ -2,6 +2,7 @@ pub mod active_payments;
 pub mod api_event;
 pub mod auth_events;
 mod clickhouse;
+pub mod connector_errors;
 pub mod connector_events;
 pub mod core;
 pub mod disputes;
@@ -1160,6 +1161,7 @@ pub enum AnalyticsFlow {
     GetDisputeMetrics,
     GetSankey,
     GetRoutingEvents,
+    GetShadowRoutingMetrics,
 }
 
 impl FlowMetric for AnalyticsFlow {}
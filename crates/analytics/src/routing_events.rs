@@ -1,5 +1,6 @@
 mod core;
 pub mod events;
+pub mod metrics;
 pub trait RoutingEventAnalytics: events::RoutingEventLogAnalytics {}
 
-pub use self::core::routing_events_core;
+pub use self::core::{routing_events_core, shadow_routing_metrics_core};
@@ -1,8 +1,16 @@
-use api_models::analytics::routing_events::RoutingEventsRequest;
+use std::collections::BTreeMap;
+
+use api_models::analytics::routing_events::{
+    RoutingEventsRequest, ShadowRoutingMetricsBucket, ShadowRoutingMetricsRequest,
+    ShadowRoutingMetricsResponse,
+};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
 
-use super::events::{get_routing_events, RoutingEventsResult};
+use super::{
+    events::{get_routing_events, RoutingEventsResult},
+    metrics::{get_shadow_routing_metrics, ShadowRoutingMetricRow},
+};
 use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
 
 pub async fn routing_events_core(
@@ -24,3 +32,119 @@ pub async fn routing_events_core(
     .switch()?;
     Ok(data)
 }
+
+pub async fn shadow_routing_metrics_core(
+    pool: &AnalyticsProvider,
+    req: ShadowRoutingMetricsRequest,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> AnalyticsResult<ShadowRoutingMetricsResponse> {
+    let rows = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Shadow Routing Metrics not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Shadow Routing Metrics"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_shadow_routing_metrics(merchant_id, profile_id, &req, ckh_pool).await
+        }
+    }
+    .switch()?;
+
+    Ok(ShadowRoutingMetricsResponse {
+        data: get_shadow_routing_metrics_buckets(rows),
+    })
+}
+
+/// Sums the counts of the shadow routing events of each shadow algorithm
+fn get_shadow_routing_metrics_buckets(
+    rows: Vec<ShadowRoutingMetricRow>,
+) -> Vec<ShadowRoutingMetricsBucket> {
+    let mut buckets = BTreeMap::<String, ShadowRoutingMetricsBucket>::new();
+    for row in rows {
+        let Some(shadow_algorithm_id) = row.shadow_algorithm_id else {
+            continue;
+        };
+        let count = row
+            .count
+            .and_then(|count| u64::try_from(count).ok())
+            .unwrap_or_default();
+        let bucket = buckets
+            .entry(shadow_algorithm_id.clone())
+            .or_insert_with(|| ShadowRoutingMetricsBucket {
+                shadow_algorithm_id,
+                ..Default::default()
+            });
+        bucket.total_count += count;
+        if row.is_shadow_agreement == Some(true) {
+            bucket.agreement_count += count;
+        } else {
+            bucket.divergence_count += count;
+        }
+    }
+
+    buckets
+        .into_values()
+        .map(|mut bucket| {
+            bucket.agreement_rate = (bucket.total_count > 0)
+                .then(|| {
+                    Some(
+                        f64::from(u32::try_from(bucket.agreement_count).ok()?) * 100.0
+                            / f64::from(u32::try_from(bucket.total_count).ok()?),
+                    )
+                })
+                .flatten();
+            bucket
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric_row(
+        shadow_algorithm_id: Option<&str>,
+        is_shadow_agreement: Option<bool>,
+        count: i64,
+    ) -> ShadowRoutingMetricRow {
+        ShadowRoutingMetricRow {
+            shadow_algorithm_id: shadow_algorithm_id.map(str::to_string),
+            is_shadow_agreement,
+            count: Some(count),
+        }
+    }
+
+    #[test]
+    fn test_get_shadow_routing_metrics_buckets() {
+        let buckets = get_shadow_routing_metrics_buckets(vec![
+            metric_row(Some("routing_2"), Some(false), 5),
+            metric_row(Some("routing_1"), Some(true), 3),
+            metric_row(Some("routing_1"), Some(false), 1),
+            metric_row(Some("routing_2"), None, 5),
+            metric_row(None, Some(true), 7),
+        ]);
+
+        let summary = buckets
+            .iter()
+            .map(|bucket| {
+                (
+                    bucket.shadow_algorithm_id.as_str(),
+                    bucket.total_count,
+                    bucket.agreement_count,
+                    bucket.divergence_count,
+                    bucket.agreement_rate,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("routing_1", 4, 3, 1, Some(75.0)),
+                // Events without an agreement are counted as divergences
+                ("routing_2", 10, 0, 10, Some(0.0)),
+            ]
+        );
+    }
+}
@@ -71,4 +71,9 @@ pub struct RoutingEventsResult {
     pub method: String,
     pub routing_engine: String,
     pub routing_approach: Option<String>,
+    pub shadow_algorithm_id: Option<String>,
+    pub shadow_connector: Option<String>,
+    pub is_shadow_agreement: Option<bool>,
+    pub routing_algorithm_id: Option<String>,
+    pub rule_name: Option<String>,
 }
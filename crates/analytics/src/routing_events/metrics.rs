@@ -0,0 +1,79 @@
+use api_models::analytics::{routing_events::ShadowRoutingMetricsRequest, Granularity};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use hyperswitch_interfaces::events::routing_api_logs::SHADOW_ROUTING_FLOW;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+};
+
+pub trait ShadowRoutingMetricAnalytics: LoadRow<ShadowRoutingMetricRow> {}
+
+/// Counts the shadow routing events of a profile, grouped by shadow algorithm and by whether the
+/// shadow decision agreed with the live one
+pub async fn get_shadow_routing_metrics<T>(
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+    query_param: &ShadowRoutingMetricsRequest,
+    pool: &T,
+) -> FiltersResult<Vec<ShadowRoutingMetricRow>>
+where
+    T: AnalyticsDataSource + ShadowRoutingMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::RoutingEvents);
+
+    query_builder
+        .add_select_column("shadow_algorithm_id")
+        .switch()?;
+    query_builder
+        .add_select_column("is_shadow_agreement")
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+    query_builder
+        .add_filter_clause("profile_id", profile_id.clone())
+        .switch()?;
+    query_builder
+        .add_filter_clause("flow", SHADOW_ROUTING_FLOW)
+        .switch()?;
+    query_param
+        .time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    query_builder
+        .add_group_by_clause("shadow_algorithm_id")
+        .switch()?;
+    query_builder
+        .add_group_by_clause("is_shadow_agreement")
+        .switch()?;
+
+    query_builder
+        .execute_query::<ShadowRoutingMetricRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ShadowRoutingMetricRow {
+    pub shadow_algorithm_id: Option<String>,
+    pub is_shadow_agreement: Option<bool>,
+    pub count: Option<i64>,
+}
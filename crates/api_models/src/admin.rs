@@ -266,6 +266,40 @@ pub struct CardTestingGuardConfig {
     pub customer_id_blocking_threshold: i32,
     /// Determines Redis Expiry for Card Testing Guard for profile
     pub card_testing_guard_expiry: i32,
+    /// Velocity rules evaluated on confirming the payments of the profile, in addition to the blockings above
+    #[serde(default)]
+    #[schema(value_type = Vec<VelocityRule>)]
+    pub velocity_rules: Vec<common_types::domain::VelocityRule>,
+}
+
+impl CardTestingGuardConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let mut rule_names = HashSet::new();
+        for rule in &self.velocity_rules {
+            if rule.name.is_empty() {
+                return Err("The name of a velocity rule must not be empty".to_string());
+            }
+            if common_types::domain::VelocityRule::is_reserved_name(&rule.name) {
+                return Err(format!(
+                    "The velocity rule name `{}` is reserved for the fixed blockings",
+                    rule.name
+                ));
+            }
+            if !rule_names.insert(rule.name.as_str()) {
+                return Err(format!("Duplicate velocity rule name `{}`", rule.name));
+            }
+            if rule.keys.is_empty() {
+                return Err(format!("The velocity rule `{}` must have a key", rule.name));
+            }
+            if rule.threshold < 0 || rule.window_in_seconds == 0 {
+                return Err(format!(
+                    "The velocity rule `{}` must have a non negative threshold and a non zero window",
+                    rule.name
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -1918,6 +1952,39 @@ pub enum AcceptedCountries {
     AllAccepted,
 }
 
+/// State of the circuit breaker of a merchant connector account
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CircuitBreakerState {
+    /// Requests are sent to the connector
+    #[default]
+    Closed,
+    /// Requests are not sent to the connector until the open duration elapses
+    Open,
+    /// A limited number of requests are sent to the connector to check if it has recovered
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConnectorCircuitBreakerResponse {
+    /// Unique ID of the connector
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR", value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// Current state of the circuit breaker
+    pub state: CircuitBreakerState,
+    /// Time at which the circuit breaker last changed its state
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub state_changed_at: Option<time::PrimitiveDateTime>,
+    /// Number of connector calls in the current evaluation window
+    pub window_request_count: u64,
+    /// Number of failed or slow connector calls in the current evaluation window
+    pub window_failure_count: u64,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MerchantConnectorDeleteResponse {
@@ -2203,6 +2270,14 @@ pub struct ProfileCreate {
     /// Bool indicating if overcapture  must be requested for all payments
     #[schema(value_type = Option<bool>)]
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+
+    /// UTC offset, written as +HH:MM or -HH:MM, at which the time based conditions of routing rules (hour of day, day of week and calendar date) are evaluated. Defaults to UTC
+    #[schema(value_type = Option<String>, example = "+05:30")]
+    pub routing_timezone: Option<String>,
+
+    /// Currency to which the payment amount is converted for the `amount_in_base_currency` conditions of routing, surcharge and 3DS decision rules
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub routing_base_currency: Option<api_enums::Currency>,
 }
 
 #[nutype::nutype(
@@ -2553,6 +2628,14 @@ pub struct ProfileResponse {
     /// Bool indicating if overcapture  must be requested for all payments
     #[schema(value_type = Option<bool>)]
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+
+    /// UTC offset, written as +HH:MM or -HH:MM, at which the time based conditions of routing rules (hour of day, day of week and calendar date) are evaluated. Defaults to UTC
+    #[schema(value_type = Option<String>, example = "+05:30")]
+    pub routing_timezone: Option<String>,
+
+    /// Currency to which the payment amount is converted for the `amount_in_base_currency` conditions of routing, surcharge and 3DS decision rules
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub routing_base_currency: Option<api_enums::Currency>,
 }
 
 #[cfg(feature = "v2")]
@@ -2906,6 +2989,14 @@ pub struct ProfileUpdate {
     /// Bool indicating if overcapture  must be requested for all payments
     #[schema(value_type = Option<bool>)]
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+
+    /// UTC offset, written as +HH:MM or -HH:MM, at which the time based conditions of routing rules (hour of day, day of week and calendar date) are evaluated. Defaults to UTC
+    #[schema(value_type = Option<String>, example = "+05:30")]
+    pub routing_timezone: Option<String>,
+
+    /// Currency to which the payment amount is converted for the `amount_in_base_currency` conditions of routing, surcharge and 3DS decision rules
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub routing_base_currency: Option<api_enums::Currency>,
 }
 
 #[cfg(feature = "v2")]
@@ -3382,6 +3473,42 @@ pub struct ConnectorAgnosticMitChoice {
 
 impl common_utils::events::ApiEventMetric for ConnectorAgnosticMitChoice {}
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSigningSecretRotateRequest {
+    /// The duration (in seconds) for which webhooks continue to be signed with the previous
+    /// secret, in addition to the new secret. The previous secret is discarded immediately if set
+    /// to 0.
+    #[schema(default = 86400, maximum = 604800, example = 3600)]
+    pub grace_period_in_seconds: Option<u32>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookSigningSecretRotateRequest {}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct WebhookSigningSecretRotateResponse {
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The new secret with which outgoing webhooks are signed.
+    #[schema(value_type = String)]
+    pub payment_response_hash_key: Secret<String>,
+
+    /// Time until which outgoing webhooks are also signed with the previous secret.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_secret_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookSigningSecretRotateResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
 impl common_utils::events::ApiEventMetric for payment_methods::PaymentMethodMigrate {}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
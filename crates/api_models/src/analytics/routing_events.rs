@@ -4,3 +4,27 @@ pub struct RoutingEventsRequest {
     pub refund_id: Option<String>,
     pub dispute_id: Option<String>,
 }
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ShadowRoutingMetricsRequest {
+    pub time_range: super::TimeRange,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ShadowRoutingMetricsResponse {
+    pub data: Vec<ShadowRoutingMetricsBucket>,
+}
+
+/// Agreement between the live and the shadow routing decisions, for one shadow algorithm
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct ShadowRoutingMetricsBucket {
+    pub shadow_algorithm_id: String,
+    /// Number of payments for which the shadow algorithm was evaluated
+    pub total_count: u64,
+    /// Number of payments for which both algorithms picked the same connector
+    pub agreement_count: u64,
+    /// Number of payments for which the algorithms picked different connectors
+    pub divergence_count: u64,
+    /// Percentage of payments for which both algorithms picked the same connector
+    pub agreement_rate: Option<f64>,
+}
@@ -0,0 +1,95 @@
+use common_enums::{AuditLogAction, AuditLogActorType, AuditLogResourceType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The query parameters for listing audit log entries.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuditLogListConstraints {
+    /// Only return entries of changes made by the user or API key with the specified identifier.
+    pub actor_id: Option<String>,
+
+    /// Only return entries of the specified action.
+    pub action: Option<AuditLogAction>,
+
+    /// Only return entries of changes made to the specified type of resource.
+    pub resource_type: Option<AuditLogResourceType>,
+
+    /// Only return entries of changes made to the resource with the specified identifier.
+    pub resource_id: Option<String>,
+
+    /// Only return entries created after the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Only return entries created before the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// The maximum number of entries to return, defaults to 100.
+    #[schema(minimum = 1, maximum = 1000, example = 100)]
+    pub limit: Option<u16>,
+
+    /// The number of entries to skip.
+    #[schema(example = 0)]
+    pub offset: Option<u32>,
+}
+
+/// An entry in the audit log.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// The identifier for the audit log entry.
+    #[schema(max_length = 64, example = "audit_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub audit_log_id: String,
+
+    /// The identifier for the Merchant Account the changed resource belongs to. Not present for
+    /// resources that are not owned by a merchant, such as GSM rules.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = Option<String>)]
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+
+    /// The type of the actor that made the change.
+    pub actor_type: AuditLogActorType,
+
+    /// The identifier of the user or API key that made the change.
+    #[schema(example = "dev_9fBs8SyZTBP8XPUhKvNs")]
+    pub actor_id: String,
+
+    /// The action performed on the resource.
+    pub action: AuditLogAction,
+
+    /// The type of the changed resource.
+    pub resource_type: AuditLogResourceType,
+
+    /// The identifier of the changed resource.
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub resource_id: String,
+
+    /// The fields of the resource that were changed, with their values before and after the
+    /// change. Sensitive values are masked.
+    #[schema(value_type = Option<Object>, example = json!({
+        "before": { "disabled": false },
+        "after": { "disabled": true }
+    }))]
+    pub diff: Option<serde_json::Value>,
+
+    /// Time at which the change was made.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditLogListRequestInternal {
+    /// The merchant whose entries are listed, entries of all merchants are listed if not provided
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub constraints: AuditLogListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for AuditLogListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ResourceListAPI)
+    }
+}
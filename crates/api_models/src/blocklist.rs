@@ -9,6 +9,17 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    /// Email of the customer, matched case insensitively
+    CustomerEmail(String),
+    /// IP address, or a range of IP addresses in the CIDR notation
+    IpAddress(String),
+    /// Phone number in the E.164 format, including the country code
+    PhoneNumber(String),
+    /// Device id sent in the browser information of the payment
+    DeviceFingerprint(String),
+    /// Country of the billing address
+    BillingCountry(enums::CountryAlpha2),
+    CustomerId(String),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -20,9 +31,26 @@ pub struct GenerateFingerprintRequest {
 pub struct Card {
     pub card_number: StrongSecret<String>,
 }
-pub type AddToBlocklistRequest = BlocklistRequest;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AddToBlocklistRequest {
+    #[serde(flatten)]
+    pub data: BlocklistRequest,
+    /// Time after which the entry expires, in seconds. The entry never expires if not provided
+    pub ttl_in_seconds: Option<u32>,
+    /// Reason for blocking the data, reported on the payments blocked by the entry
+    #[schema(max_length = 255)]
+    pub reason: Option<String>,
+}
+
 pub type DeleteFromBlocklistRequest = BlocklistRequest;
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkAddToBlocklistRequest {
+    /// The entries to be added to the blocklist. Each entry is added independently of the others
+    pub entries: Vec<AddToBlocklistRequest>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct BlocklistResponse {
     pub fingerprint_id: String,
@@ -30,6 +58,25 @@ pub struct BlocklistResponse {
     pub data_kind: enums::BlocklistDataKind,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: time::PrimitiveDateTime,
+    pub reason: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkAddToBlocklistResponse {
+    /// The entries added to the blocklist
+    pub added: Vec<BlocklistResponse>,
+    /// The entries which could not be added to the blocklist
+    pub failed: Vec<BulkBlocklistEntryError>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BulkBlocklistEntryError {
+    /// Position of the entry in the request
+    pub index: usize,
+    /// Reason the entry could not be added
+    pub message: String,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -66,7 +113,10 @@ pub struct ToggleBlocklistQuery {
 }
 
 impl ApiEventMetric for BlocklistRequest {}
+impl ApiEventMetric for AddToBlocklistRequest {}
+impl ApiEventMetric for BulkAddToBlocklistRequest {}
 impl ApiEventMetric for BlocklistResponse {}
+impl ApiEventMetric for BulkAddToBlocklistResponse {}
 impl ApiEventMetric for ToggleBlocklistResponse {}
 impl ApiEventMetric for ListBlocklistQuery {}
 impl ApiEventMetric for GenerateFingerprintRequest {}
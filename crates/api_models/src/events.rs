@@ -30,8 +30,8 @@ use crate::{
     admin::*,
     analytics::{
         api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
-        outgoing_webhook_event::OutgoingWebhookLogsRequest, routing_events::RoutingEventsRequest,
-        sdk_events::*, search::*, *,
+        outgoing_webhook_event::OutgoingWebhookLogsRequest, routing_events::*, sdk_events::*,
+        search::*, *,
     },
     api_keys::*,
     cards_info::*,
@@ -98,6 +98,7 @@ impl_api_event_type!(
         CreateApiKeyRequest,
         ListApiKeyConstraints,
         MerchantConnectorDeleteResponse,
+        ConnectorCircuitBreakerResponse,
         MerchantConnectorUpdate,
         MerchantConnectorCreate,
         MerchantId,
@@ -145,7 +146,9 @@ impl_api_event_type!(
         OrganizationUpdateRequest,
         OrganizationId,
         CustomerListRequest,
-        RoutingEventsRequest
+        RoutingEventsRequest,
+        ShadowRoutingMetricsRequest,
+        ShadowRoutingMetricsResponse
     )
 );
 
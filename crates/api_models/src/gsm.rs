@@ -11,10 +11,17 @@ pub struct GsmCreateRequest {
     pub flow: String,
     /// The sub_flow in which the code and message occurred  for a connector
     pub sub_flow: String,
-    /// code received from the connector
+    /// code received from the connector, ignored by `message_contains`, `message_regex` and
+    /// `connector_default` rules
     pub code: String,
-    /// message received from the connector
+    /// message received from the connector, or the pattern matched against it by
+    /// `message_contains` and `message_regex` rules. Ignored by `code` and `connector_default`
+    /// rules
     pub message: String,
+    /// How the rule is matched against the error of the connector. Fields ignored by the match
+    /// type are stored as `*`. Defaults to `exact`
+    #[schema(value_type = Option<GsmMatchType>)]
+    pub match_type: Option<api_enums::GsmMatchType>,
     /// status provided by the router
     pub status: String,
     /// optional error provided by the router
@@ -151,6 +158,9 @@ pub struct GsmResponse {
     pub code: String,
     /// message received from the connector
     pub message: String,
+    /// How the rule is matched against the error of the connector
+    #[schema(value_type = GsmMatchType)]
+    pub match_type: api_enums::GsmMatchType,
     /// status provided by the router
     pub status: String,
     /// optional error provided by the router
@@ -185,3 +195,88 @@ pub struct GsmResponse {
     #[schema(value_type = GsmFeatureData)]
     pub feature_data: Option<common_types::domain::GsmFeatureData>,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmImportRequest {
+    /// The rules to be imported. Either all of them are imported or, if any of them is invalid or
+    /// already exists, none of them
+    pub rules: Vec<GsmCreateRequest>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmImportResponse {
+    /// The number of rules imported
+    pub imported_count: usize,
+}
+
+/// A GSM rule in the flat format of the CSV files used for importing and exporting GSM rules
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct GsmCsvRecord {
+    pub connector: String,
+    pub flow: String,
+    pub sub_flow: String,
+    pub code: String,
+    pub message: String,
+    pub match_type: Option<api_enums::GsmMatchType>,
+    pub status: String,
+    pub router_error: Option<String>,
+    pub decision: api_enums::GsmDecision,
+    pub step_up_possible: bool,
+    pub clear_pan_possible: bool,
+    pub alternate_network_possible: bool,
+    pub retry_later_initial_delay_in_seconds: Option<u32>,
+    pub retry_later_max_retries: Option<u8>,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    pub error_category: Option<api_enums::ErrorCategory>,
+}
+
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GsmExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmExportQuery {
+    /// The format of the exported rules, a JSON list of rules or a CSV file with one rule per row
+    #[serde(default)]
+    pub format: GsmExportFormat,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmUnmappedErrorsRequest {
+    /// The time range of the failed payment attempts looked at
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of unmapped errors returned, the most frequent ones first. Defaults to
+    /// 100
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmUnmappedErrorsResponse {
+    /// The unmapped errors, the most frequent ones first
+    pub data: Vec<GsmUnmappedError>,
+}
+
+/// An error returned by a connector during payments, which isn't matched by any GSM rule
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmUnmappedError {
+    /// The connector which returned the error
+    pub connector: String,
+    /// The flow in which the error occurred
+    pub flow: String,
+    /// code received from the connector
+    pub code: String,
+    /// message received from the connector
+    pub message: Option<String>,
+    /// The number of failed payment attempts with this error
+    pub occurrences: u64,
+    /// The unified code of the existing GSM rule whose message is the most similar to this error
+    pub suggested_unified_code: Option<String>,
+    /// The unified message of the existing GSM rule whose message is the most similar to this
+    /// error
+    pub suggested_unified_message: Option<String>,
+}
@@ -2,6 +2,7 @@ pub mod admin;
 pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
+pub mod audit_log;
 pub mod authentication;
 pub mod blocklist;
 pub mod cards_info;
@@ -42,6 +43,8 @@ pub mod recon;
 pub mod refunds;
 pub mod relay;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscription;
 pub mod surcharge_decision_configs;
 pub mod three_ds_decision_rule;
 #[cfg(feature = "tokenization_v2")]
@@ -50,6 +53,7 @@ pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -1544,6 +1544,9 @@ pub struct BrowserInformation {
 
     /// The device model of the client
     pub device_model: Option<String>,
+
+    /// An identifier of the client device, stable across the payments made from it
+    pub device_id: Option<String>,
 }
 
 impl RequestSurchargeDetails {
@@ -5583,6 +5586,13 @@ pub struct PaymentsResponse {
     /// Contains card network response details (e.g., Visa/Mastercard advice codes).
     #[schema(value_type = Option<NetworkDetails>)]
     pub network_details: Option<NetworkDetails>,
+
+    /// Names of the velocity rules that flagged this payment for review
+    pub flagged_velocity_rules: Option<Vec<String>>,
+
+    /// Blocklist entry which blocked this payment
+    #[schema(value_type = Option<BlocklistMatchDetails>)]
+    pub blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
 }
 
 #[cfg(feature = "v2")]
@@ -6936,7 +6946,7 @@ pub struct PgRedirectResponse {
 }
 
 #[cfg(feature = "v1")]
-#[derive(Debug, serde::Serialize, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, PartialEq, Eq, serde::Deserialize)]
 pub struct RedirectionResponse {
     pub return_url: String,
     pub params: Vec<(String, String)>,
@@ -6946,7 +6956,7 @@ pub struct RedirectionResponse {
 }
 
 #[cfg(feature = "v2")]
-#[derive(Debug, serde::Serialize, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, PartialEq, Eq, serde::Deserialize)]
 pub struct RedirectionResponse {
     pub return_url_with_query_params: String,
 }
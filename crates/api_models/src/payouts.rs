@@ -892,6 +892,138 @@ pub struct PayoutLinkStatusDetails {
     pub test_mode: bool,
 }
 
+/// A single payout of a batch of payouts. Batches are disbursed to saved payout methods, so each
+/// payout references either a `payout_method_id` or a `payout_token` of the customer.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchRow {
+    /// Unique identifier for the payout, generated if not provided
+    #[schema(value_type = Option<String>, max_length = 30)]
+    pub payout_id: Option<id_type::PayoutId>,
+
+    /// The merchant's own reference for the payout
+    #[schema(max_length = 255, example = "seller_disbursal_2025_10_01")]
+    pub merchant_order_reference_id: Option<String>,
+
+    /// The payout amount, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1000)]
+    pub amount: common_utils::types::MinorUnit,
+
+    /// The currency of the payout
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The customer receiving the payout
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The saved payout method of the customer to disburse to
+    pub payout_method_id: Option<String>,
+
+    /// The payout token of the customer to disburse to
+    pub payout_token: Option<String>,
+
+    /// The type of the payout method
+    #[schema(value_type = Option<PayoutType>, example = "bank")]
+    pub payout_type: Option<api_enums::PayoutType>,
+
+    /// The payout connector to use, the connector is chosen by routing if not provided
+    #[schema(value_type = Option<PayoutConnectors>, example = "wise")]
+    pub connector: Option<api_enums::PayoutConnectors>,
+
+    /// Whether the payout should be fulfilled right after it is created
+    #[schema(value_type = Option<bool>, default = false)]
+    pub auto_fulfill: Option<bool>,
+
+    /// The send priority of the payout
+    #[schema(value_type = Option<PayoutSendPriority>, example = "instant")]
+    pub priority: Option<api_enums::PayoutSendPriority>,
+
+    /// A description of the payout
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The profile the payouts are created for, the default profile of the merchant is used if
+    /// not provided
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The maximum number of payouts of the batch processed at the same time
+    #[schema(value_type = Option<u16>, default = 10, maximum = 50)]
+    pub max_concurrency: Option<u16>,
+
+    /// The payouts of the batch
+    pub payouts: Vec<PayoutBatchRow>,
+}
+
+/// Query parameters of a batch of payouts uploaded as a CSV file
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayoutBatchUploadQuery {
+    pub profile_id: Option<id_type::ProfileId>,
+    pub max_concurrency: Option<u16>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PayoutBatchId {
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// The identifier of the batch
+    pub batch_id: String,
+
+    /// The identifier of the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The profile the payouts of the batch are created for
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The aggregate status of the batch
+    #[schema(value_type = PayoutBatchStatus, example = "processing")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// The number of payouts in the batch
+    pub total_count: i32,
+
+    /// The number of payouts created and accepted by the payout connector
+    pub succeeded_count: i32,
+
+    /// The number of payouts that failed
+    pub failed_count: i32,
+
+    /// The maximum number of payouts of the batch processed at the same time
+    pub max_concurrency: i32,
+
+    /// Time at which the batch was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the batch was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The outcome of a single payout of a batch of payouts, as included in the results file of the
+/// batch
+#[derive(Clone, Debug, Serialize)]
+pub struct PayoutBatchItemResponse {
+    pub row_number: i32,
+    pub payout_id: id_type::PayoutId,
+    pub merchant_order_reference_id: Option<String>,
+    pub status: api_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<api_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
 impl From<Bank> for payout_method_utils::BankAdditionalData {
     fn from(bank_data: Bank) -> Self {
         match bank_data {
@@ -6,7 +6,9 @@ use common_utils::{
     ext_traits::ValueExt,
     pii,
 };
-use euclid::frontend::ast::Program;
+#[cfg(feature = "routing_dsl")]
+use euclid::frontend::ast::parser::{EuclidParsable, ParseResult};
+use euclid::frontend::ast::{printer::EuclidPrintable, Program};
 pub use euclid::{
     dssa::types::EuclidAnalysable,
     frontend::{
@@ -29,7 +31,7 @@ const DEFAULT_HEDGING_PERCENT: f64 = 5.0;
 const DEFAULT_ELIMINATION_THRESHOLD: f64 = 0.35;
 const DEFAULT_PAYMENT_METHOD: &str = "CARD";
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum ConnectorSelection {
     Priority(Vec<RoutableConnectorChoice>),
@@ -46,6 +48,58 @@ impl ConnectorSelection {
         }
     }
 }
+
+impl From<ast::ConnectorSelection> for ConnectorSelection {
+    fn from(value: ast::ConnectorSelection) -> Self {
+        match value {
+            ast::ConnectorSelection::Priority(list) => {
+                Self::Priority(list.into_iter().map(Into::into).collect())
+            }
+            ast::ConnectorSelection::VolumeSplit(splits) => Self::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<ConnectorSelection> for ast::ConnectorSelection {
+    fn from(value: ConnectorSelection) -> Self {
+        match value {
+            ConnectorSelection::Priority(list) => {
+                Self::Priority(list.into_iter().map(Into::into).collect())
+            }
+            ConnectorSelection::VolumeSplit(splits) => Self::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ast::ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "routing_dsl")]
+impl EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        ast::ConnectorSelection::parse_output(input)
+            .map(|(remaining, selection)| (remaining, selection.into()))
+    }
+}
+
+impl EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> String {
+        ast::ConnectorSelection::from(self.clone()).print_output()
+    }
+}
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct RoutingConfigRequest {
@@ -139,6 +193,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CryptoType,
         DirKeyKind::MetaData,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::AmountInBaseCurrency,
         DirKeyKind::PaymentCurrency,
         DirKeyKind::AuthenticationType,
         DirKeyKind::MandateAcceptanceType,
@@ -155,6 +210,9 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::CalendarDate,
     ];
 }
 
@@ -228,6 +286,32 @@ impl std::fmt::Display for RoutableConnectorChoice {
     }
 }
 
+impl From<ast::RoutableConnectorChoice> for RoutableConnectorChoice {
+    fn from(value: ast::RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: match value.choice_kind {
+                ast::RoutableChoiceKind::OnlyConnector => RoutableChoiceKind::OnlyConnector,
+                ast::RoutableChoiceKind::FullStruct => RoutableChoiceKind::FullStruct,
+            },
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
+impl From<RoutableConnectorChoice> for ast::RoutableConnectorChoice {
+    fn from(value: RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: match value.choice_kind {
+                RoutableChoiceKind::OnlyConnector => ast::RoutableChoiceKind::OnlyConnector,
+                RoutableChoiceKind::FullStruct => ast::RoutableChoiceKind::FullStruct,
+            },
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
 impl From<RoutableConnectorChoice> for ast::ConnectorChoice {
     fn from(value: RoutableConnectorChoice) -> Self {
         Self {
@@ -518,14 +602,26 @@ pub struct RoutingAlgorithmRef {
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    /// Algorithm evaluated alongside the active one without affecting the connector choice
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shadow_algorithm_id: Option<common_utils::id_type::RoutingId>,
 }
 
 impl RoutingAlgorithmRef {
     pub fn update_algorithm_id(&mut self, new_id: common_utils::id_type::RoutingId) {
+        // Activating the shadow algorithm ends its shadow evaluation
+        if self.shadow_algorithm_id.as_ref() == Some(&new_id) {
+            self.shadow_algorithm_id = None;
+        }
         self.algorithm_id = Some(new_id);
         self.timestamp = common_utils::date_time::now_unix_timestamp();
     }
 
+    pub fn update_shadow_algorithm_id(&mut self, new_id: Option<common_utils::id_type::RoutingId>) {
+        self.shadow_algorithm_id = new_id;
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+    }
+
     pub fn update_conditional_config_id(&mut self, ids: String) {
         self.config_algo_id = Some(ids);
         self.timestamp = common_utils::date_time::now_unix_timestamp();
@@ -558,6 +654,10 @@ pub struct RoutingDictionaryRecord {
     pub modified_at: i64,
     pub algorithm_for: Option<TransactionType>,
     pub decision_engine_routing_id: Option<String>,
+    /// Rules of the algorithm that can never be selected, returned when the algorithm is created
+    #[schema(value_type = Option<Vec<Object>>)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<euclid::dssa::types::RuleAnalysisWarning>>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -587,6 +687,228 @@ pub struct RoutingLinkWrapper {
     pub algorithm_id: RoutingAlgorithmId,
 }
 
+#[derive(Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingSimulationReportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationRequest {
+    /// The time range of the payment attempts the algorithm is replayed against
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of payment attempts to replay. The default limit is 1000 and max limit is 10000
+    pub limit: Option<u32>,
+    /// `csv` returns the attempts whose routing decision would change as a downloadable file
+    #[serde(default)]
+    pub report_format: RoutingSimulationReportFormat,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingSimulationWrapper {
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub request: RoutingSimulationRequest,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationConnectorVolume {
+    pub connector: String,
+    /// Number of attempts that were actually routed to the connector
+    pub actual_count: u64,
+    /// Number of attempts the simulated algorithm would have routed to the connector
+    pub simulated_count: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationBreakdown {
+    #[schema(value_type = Option<PaymentMethod>)]
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<common_enums::Currency>,
+    #[schema(value_type = Option<CardNetwork>)]
+    pub card_network: Option<common_enums::CardNetwork>,
+    pub connector_volumes: Vec<RoutingSimulationConnectorVolume>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationAttempt {
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    #[schema(value_type = Option<PaymentMethod>)]
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    #[schema(value_type = Option<PaymentMethodType>)]
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<common_enums::Currency>,
+    #[schema(value_type = Option<CardNetwork>)]
+    pub card_network: Option<common_enums::CardNetwork>,
+    pub amount: i64,
+    pub actual_connector: String,
+    /// The connector the simulated algorithm would have picked, if any connector was eligible
+    pub simulated_connector: Option<String>,
+    /// Name of the rule that produced the simulated decision, for advanced algorithms
+    pub matched_rule: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Number of payment attempts the algorithm was replayed against
+    pub total_count: u64,
+    /// Number of payment attempts whose connector would change
+    pub changed_count: u64,
+    /// Number of payment attempts that could not be replayed
+    pub skipped_count: u64,
+    pub connector_volumes: Vec<RoutingSimulationConnectorVolume>,
+    /// Connector volumes grouped by payment method, currency and card network
+    pub breakdown: Vec<RoutingSimulationBreakdown>,
+    pub changed_attempts: Vec<RoutingSimulationAttempt>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDslImportRequest {
+    pub name: String,
+    pub description: String,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The advanced routing program written in the routing DSL
+    #[schema(
+        example = "default: [stripe]\n\ncard_rule: [adyen 70%, stripe 30%]\n{\n    payment_method = card\n}\n"
+    )]
+    pub dsl: String,
+    pub transaction_type: Option<TransactionType>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDslExportResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub description: String,
+    /// The advanced routing program in its canonical DSL form
+    pub dsl: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingEligibilityExplainRequest {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Explain the eligibility of connectors for an existing payment of the profile
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    /// Explain the eligibility of connectors for a hypothetical payment, used when `payment_id`
+    /// is not provided
+    pub context: Option<RoutingEligibilityContext>,
+}
+
+/// The details of a hypothetical payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingEligibilityContext {
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency)]
+    pub currency: common_enums::Currency,
+    #[schema(value_type = Option<PaymentMethod>)]
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    #[schema(value_type = Option<PaymentMethodType>)]
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    #[schema(value_type = Option<CardNetwork>)]
+    pub card_network: Option<common_enums::CardNetwork>,
+    #[schema(value_type = Option<CountryAlpha2>)]
+    pub billing_country: Option<common_enums::CountryAlpha2>,
+    #[schema(value_type = Option<CaptureMethod>)]
+    pub capture_method: Option<common_enums::CaptureMethod>,
+    #[schema(value_type = Option<AuthenticationType>)]
+    pub authentication_type: Option<common_enums::AuthenticationType>,
+    #[schema(value_type = Option<FutureUsage>)]
+    pub setup_future_usage: Option<common_enums::FutureUsage>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingEligibilityExplainResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub results: Vec<ConnectorEligibilityExplanation>,
+}
+
+/// The eligibility of a connector for a payment method type
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorEligibilityExplanation {
+    pub connector: String,
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    #[schema(value_type = PaymentMethod)]
+    pub payment_method: common_enums::PaymentMethod,
+    #[schema(value_type = PaymentMethodType)]
+    pub payment_method_type: common_enums::PaymentMethodType,
+    /// Whether the connector passes both the routing and the payment method list checks
+    pub eligible: bool,
+    /// Outcome of the connector filtering performed during routing
+    pub routing: EligibilityCheckResult,
+    /// Outcome of the payment method filters applied while listing payment methods
+    pub payment_method_list: EligibilityCheckResult,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EligibilityCheckResult {
+    pub eligible: bool,
+    /// The constraints that were not satisfied, each followed by the constraints it depends on,
+    /// indented one level deeper
+    pub failed_constraints: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingRuleHitsQuery {
+    /// Hits are counted per hour, so the start of the time range is rounded down to the hour
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+    /// Defaults to the current time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingRuleHitsWrapper {
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub query: RoutingRuleHitsQuery,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingRuleHitsResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Every rule of the algorithm in program order, including the rules without any hits
+    pub rules: Vec<RoutingRuleHits>,
+    /// Number of decisions made by the default selection, when none of the rules matched
+    pub default_selection_hit_count: u64,
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub default_selection_last_hit_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingRuleHits {
+    pub rule_name: String,
+    /// Number of decisions made by the rule within the time range
+    pub hit_count: u64,
+    /// The last time the rule made a decision, regardless of the time range
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_hit_at: Option<time::PrimitiveDateTime>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DynamicAlgorithmWithTimestamp<T> {
     pub algorithm_id: Option<T>,
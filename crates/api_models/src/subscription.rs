@@ -0,0 +1,420 @@
+use common_enums::{
+    Currency, SubscriptionInvoiceStatus, SubscriptionPlanInterval, SubscriptionStatus,
+};
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+    types::MinorUnit,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a subscription plan.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanCreateRequest {
+    /// The identifier for the Business Profile the plan belongs to. Defaults to the Business
+    /// Profile of the API key, if any.
+    #[schema(max_length = 64, value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The name of the plan.
+    #[schema(max_length = 255, example = "Pro (monthly)")]
+    pub name: String,
+
+    /// A description of the plan.
+    #[schema(max_length = 255, example = "Unlimited projects, billed monthly")]
+    pub description: Option<String>,
+
+    /// The amount charged for each billing interval, in the lowest denomination of the currency.
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+
+    /// The currency in which the plan is billed.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The unit of the billing interval.
+    #[schema(example = "month")]
+    pub interval: SubscriptionPlanInterval,
+
+    /// The number of `interval` units in a billing period, defaults to 1. For example, an
+    /// `interval` of `month` and an `interval_count` of 3 bills the subscription quarterly.
+    #[schema(minimum = 1, example = 1)]
+    pub interval_count: Option<u16>,
+
+    /// The number of days subscriptions to the plan are in their trial period before they are
+    /// charged for the first time.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// Metadata is useful for storing additional, unstructured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "pro" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// The request body for updating a subscription plan. The price and the billing interval of a
+/// plan cannot be changed, a new plan must be created instead.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanUpdateRequest {
+    /// The name of the plan.
+    #[schema(max_length = 255, example = "Pro (monthly)")]
+    pub name: Option<String>,
+
+    /// A description of the plan.
+    #[schema(max_length = 255, example = "Unlimited projects, billed monthly")]
+    pub description: Option<String>,
+
+    /// Whether new subscriptions can be created for the plan. Existing subscriptions to an
+    /// inactive plan continue to be renewed.
+    pub is_active: Option<bool>,
+
+    /// Metadata is useful for storing additional, unstructured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "pro" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// The query parameters for listing subscription plans.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanListConstraints {
+    /// Only return plans of the specified Business Profile. Defaults to the Business Profile of
+    /// the API key, if any.
+    #[schema(max_length = 64, value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+/// The response body for subscription plan APIs.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubscriptionPlanResponse {
+    /// The identifier for the plan.
+    #[schema(max_length = 64, example = "plan_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub plan_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The name of the plan.
+    pub name: String,
+
+    /// A description of the plan.
+    pub description: Option<String>,
+
+    /// The amount charged for each billing interval, in the lowest denomination of the currency.
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+
+    /// The currency in which the plan is billed.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The unit of the billing interval.
+    pub interval: SubscriptionPlanInterval,
+
+    /// The number of `interval` units in a billing period.
+    pub interval_count: u16,
+
+    /// The number of days subscriptions to the plan are in their trial period.
+    pub trial_period_days: Option<u16>,
+
+    /// Whether new subscriptions can be created for the plan.
+    pub is_active: bool,
+
+    /// Metadata is useful for storing additional, unstructured information on an object.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+
+    /// Time at which the plan was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for SubscriptionPlanResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionPlanUpdateRequestInternal {
+    pub plan_id: String,
+    pub request: SubscriptionPlanUpdateRequest,
+}
+
+impl ApiEventMetric for SubscriptionPlanUpdateRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionPlanId {
+    pub plan_id: String,
+}
+
+impl ApiEventMetric for SubscriptionPlanId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+/// The request body for creating a subscription.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the plan the customer subscribes to.
+    #[schema(max_length = 64, example = "plan_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub plan_id: String,
+
+    /// The identifier for the customer.
+    #[schema(max_length = 64, value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The saved payment method of the customer, which is charged off-session for each billing
+    /// period. The payment method must have been saved for off-session payments.
+    #[schema(max_length = 64, example = "pm_Jf0jVnJCWCvxHVtdbEbU")]
+    pub payment_method_id: String,
+
+    /// The mandate to be used to charge the customer. When provided, the mandate is used instead
+    /// of the payment method.
+    #[schema(max_length = 64, example = "man_pdB6vRbBKmDXZT4sxNLn")]
+    pub mandate_id: Option<String>,
+
+    /// The number of days the subscription is in its trial period, overrides the trial period
+    /// of the plan. Use 0 to skip the trial period of the plan.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// Metadata is useful for storing additional, unstructured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "seat_count": 5 }"#)]
+    pub metadata: Option<common_utils::pii::SecretSerdeValue>,
+}
+
+/// The request body for updating a subscription.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionUpdateRequest {
+    /// The plan the subscription is changed to. The new plan must have the same currency and
+    /// billing interval as the current plan, and is charged from the next billing period.
+    #[schema(max_length = 64, example = "plan_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub plan_id: Option<String>,
+
+    /// Whether the difference between the prices of the plans for the remainder of the current
+    /// billing period is added to the next invoice, defaults to `true`.
+    pub prorate: Option<bool>,
+
+    /// The saved payment method of the customer to be charged from the next billing period.
+    #[schema(max_length = 64, example = "pm_Jf0jVnJCWCvxHVtdbEbU")]
+    pub payment_method_id: Option<String>,
+}
+
+/// The request body for cancelling a subscription.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCancelRequest {
+    /// Cancel the subscription at the end of the current billing period instead of immediately,
+    /// defaults to `false`.
+    pub cancel_at_period_end: Option<bool>,
+}
+
+/// The response body for subscription APIs.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier for the subscription.
+    #[schema(max_length = 128, example = "sub_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub subscription_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The identifier for the customer.
+    #[schema(max_length = 64, value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The status of the subscription.
+    pub status: SubscriptionStatus,
+
+    /// The identifier for the plan the subscription is billed for.
+    #[schema(max_length = 64, example = "plan_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub plan_id: String,
+
+    /// The saved payment method charged for each billing period.
+    pub payment_method_id: Option<String>,
+
+    /// The mandate used to charge the customer, if any.
+    pub mandate_id: Option<String>,
+
+    /// The start of the current billing period.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub current_period_start: Option<PrimitiveDateTime>,
+
+    /// The end of the current billing period, at which the subscription is renewed.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub current_period_end: Option<PrimitiveDateTime>,
+
+    /// The end of the trial period, if the subscription has one.
+    #[schema(example = "2022-09-24T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+
+    /// Whether the subscription is cancelled at the end of the current billing period.
+    pub cancel_at_period_end: bool,
+
+    /// Time at which the subscription was last paused.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub paused_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the subscription was cancelled.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+
+    /// The prorated amount added to the next invoice because of plan changes. A negative amount
+    /// is a credit.
+    #[schema(value_type = i64, example = 500)]
+    pub pending_proration_amount: MinorUnit,
+
+    /// Metadata is useful for storing additional, unstructured information on an object.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+
+    /// Time at which the subscription was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for SubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionUpdateRequestInternal {
+    pub subscription_id: String,
+    pub request: SubscriptionUpdateRequest,
+}
+
+impl ApiEventMetric for SubscriptionUpdateRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionCancelRequestInternal {
+    pub subscription_id: String,
+    pub request: SubscriptionCancelRequest,
+}
+
+impl ApiEventMetric for SubscriptionCancelRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionId {
+    pub subscription_id: String,
+}
+
+impl ApiEventMetric for SubscriptionId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+/// An invoice generated for a billing period of a subscription.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubscriptionInvoiceResponse {
+    /// The identifier for the invoice.
+    #[schema(max_length = 64, example = "inv_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub invoice_id: String,
+
+    /// The identifier for the subscription.
+    #[schema(max_length = 128, example = "sub_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub subscription_id: String,
+
+    /// The amount of the invoice, including prorations, in the lowest denomination of the
+    /// currency.
+    #[schema(value_type = i64, example = 1999)]
+    pub amount: MinorUnit,
+
+    /// The currency of the invoice.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The status of the invoice.
+    pub status: SubscriptionInvoiceStatus,
+
+    /// The start of the billing period the invoice is for.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+
+    /// The end of the billing period the invoice is for.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+
+    /// The payment created for the latest attempt to charge the invoice.
+    #[schema(max_length = 64, value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<id_type::PaymentId>,
+
+    /// The number of attempts made to charge the invoice.
+    pub attempt_count: i32,
+
+    /// Time at which the invoice was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
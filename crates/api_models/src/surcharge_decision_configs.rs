@@ -32,6 +32,7 @@ impl EuclidDirFilter for SurchargeDecisionConfigs {
         DirKeyKind::PaymentMethod,
         DirKeyKind::MetaData,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::AmountInBaseCurrency,
         DirKeyKind::PaymentCurrency,
         DirKeyKind::BillingCountry,
         DirKeyKind::CardNetwork,
@@ -0,0 +1,166 @@
+use common_enums::{EventType, WebhookEndpointStatus};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a webhook endpoint.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which webhooks are delivered.
+    #[schema(value_type = String, example = "https://erp.example.com/webhooks")]
+    pub url: url::Url,
+
+    /// The event types for which webhooks are delivered to the endpoint. Webhooks for all event
+    /// types are delivered if empty.
+    #[serde(default)]
+    pub enabled_events: Vec<EventType>,
+
+    /// The status of the endpoint, defaults to `active`.
+    pub status: Option<WebhookEndpointStatus>,
+
+    /// A description of the endpoint.
+    #[schema(max_length = 255, example = "ERP payment and refund notifications")]
+    pub description: Option<String>,
+}
+
+/// The request body for updating a webhook endpoint.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which webhooks are delivered.
+    #[schema(value_type = Option<String>, example = "https://erp.example.com/webhooks")]
+    pub url: Option<url::Url>,
+
+    /// The event types for which webhooks are delivered to the endpoint. Webhooks for all event
+    /// types are delivered if empty.
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// The status of the endpoint.
+    pub status: Option<WebhookEndpointStatus>,
+
+    /// A description of the endpoint.
+    #[schema(max_length = 255, example = "ERP payment and refund notifications")]
+    pub description: Option<String>,
+}
+
+/// The response body for webhook endpoint APIs.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whep_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The URL to which webhooks are delivered.
+    #[schema(example = "https://erp.example.com/webhooks")]
+    pub url: String,
+
+    /// The event types for which webhooks are delivered to the endpoint. Webhooks for all event
+    /// types are delivered if empty.
+    pub enabled_events: Vec<EventType>,
+
+    /// The status of the endpoint.
+    pub status: WebhookEndpointStatus,
+
+    /// A description of the endpoint.
+    pub description: Option<String>,
+
+    /// The secret used to sign the webhooks delivered to the endpoint. This is returned only when
+    /// the endpoint is created.
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signing_secret: Option<Secret<String>>,
+
+    /// Time at which the endpoint was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the endpoint was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookEndpointCreateRequestInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub request: WebhookEndpointCreateRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointCreateRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookEndpointUpdateRequestInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub endpoint_id: String,
+    pub request: WebhookEndpointUpdateRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointUpdateRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookEndpointIdInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub endpoint_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointIdInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+/// The response body of the delete webhook endpoint API.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whep_sn8p4Q1S7cqvMFbGNTzJ")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Indicates whether the webhook endpoint was deleted.
+    pub deleted: bool,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointDeleteResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -5,6 +5,8 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
+#[cfg(feature = "v1")]
+use crate::subscription;
 use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
@@ -431,6 +433,11 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = SubscriptionResponse, title = "SubscriptionResponse")]
+    SubscriptionDetails(Box<subscription::SubscriptionResponse>),
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = PayoutBatchResponse, title = "PayoutBatchResponse")]
+    PayoutBatchDetails(Box<payouts::PayoutBatchResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
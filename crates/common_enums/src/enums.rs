@@ -294,6 +294,7 @@ pub enum GsmDecision {
     Retry,
     #[default]
     DoDefault,
+    RetryLater,
 }
 
 #[derive(
@@ -315,6 +316,38 @@ pub enum GsmFeature {
     Retry,
 }
 
+/// Specifies how a GSM rule is matched against the error of a connector. When several rules of
+/// a connector and flow match an error, the rule is picked in the order of the variants below.
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum GsmMatchType {
+    /// Matches the error code and the error message exactly
+    #[default]
+    Exact,
+    /// Matches the error code with any error message
+    Code,
+    /// Matches error messages that contain the `message` of the rule, ignoring case
+    MessageContains,
+    /// Matches error messages against the regular expression in the `message` of the rule
+    MessageRegex,
+    /// Matches any error of the connector
+    ConnectorDefault,
+}
+
 /// Specifies the type of cardholder authentication to be applied for a payment.
 ///
 /// - `ThreeDs`: Requests 3D Secure (3DS) authentication. If the card is enrolled, 3DS authentication will be activated, potentially shifting chargeback liability to the issuer.
@@ -472,6 +505,93 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    CustomerEmail,
+    IpAddress,
+    PhoneNumber,
+    DeviceFingerprint,
+    BillingCountry,
+    CustomerId,
+}
+
+/// Attribute of a payment by which the payments of a velocity rule are grouped
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleKey {
+    /// Fingerprint of the card number
+    CardFingerprint,
+    /// Bank identification number of the card
+    CardBin,
+    /// Ip address of the client, as sent in the browser information
+    IpAddress,
+    /// Email of the customer
+    Email,
+    /// Id of the customer
+    CustomerId,
+    /// Id of the device of the client, as sent in the browser information
+    DeviceId,
+}
+
+/// Quantity measured by a velocity rule over its window
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityMeasure {
+    /// Number of confirmed payments
+    AttemptCount,
+    /// Number of failed payments
+    FailedCount,
+    /// Sum of the amounts of the confirmed payments, in minor units of the currency of the payment
+    AmountSum,
+}
+
+/// Action taken on a payment which exceeds the threshold of a velocity rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityAction {
+    /// Reject the payment
+    Block,
+    /// Authenticate the payment with 3DS
+    ForceThreeDs,
+    /// Let the payment through, but report it for a manual review
+    FlagForReview,
 }
 
 /// Specifies how the payment is captured.
@@ -1520,6 +1640,9 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    Subscriptions,
+    #[cfg(feature = "payouts")]
+    PayoutBatches,
 }
 
 impl EventClass {
@@ -1558,6 +1681,15 @@ impl EventClass {
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
             ]),
+            Self::Subscriptions => HashSet::from([
+                EventType::SubscriptionActive,
+                EventType::SubscriptionTrialing,
+                EventType::SubscriptionPaused,
+                EventType::SubscriptionPastDue,
+                EventType::SubscriptionCancelled,
+            ]),
+            #[cfg(feature = "payouts")]
+            Self::PayoutBatches => HashSet::from([EventType::PayoutBatchCompleted]),
         }
     }
 }
@@ -1617,6 +1749,13 @@ pub enum EventType {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    SubscriptionActive,
+    SubscriptionTrialing,
+    SubscriptionPaused,
+    SubscriptionPastDue,
+    SubscriptionCancelled,
+    #[cfg(feature = "payouts")]
+    PayoutBatchCompleted,
 }
 
 #[derive(
@@ -1640,6 +1779,250 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// The status of a webhook endpoint. Webhooks are not delivered to disabled endpoints.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookEndpointStatus {
+    #[default]
+    Active,
+    Disabled,
+}
+
+/// The type of actor that performed an action recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogActorType {
+    User,
+    ApiKey,
+    AdminApiKey,
+    System,
+}
+
+/// The action recorded in an audit log entry
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogAction {
+    Create,
+    Update,
+    Delete,
+    Activate,
+    Deactivate,
+}
+
+/// The type of resource changed by an action recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogResourceType {
+    MerchantConnectorAccount,
+    RoutingAlgorithm,
+    BusinessProfile,
+    ApiKey,
+    UserRole,
+    GsmRule,
+    BlocklistEntry,
+}
+
+/// The status of a subscription billed using a subscription plan
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The subscription has been created, and its first invoice has not been paid yet
+    Created,
+    /// The subscription is in its trial period, and will be charged when the trial ends
+    Trialing,
+    /// The latest invoice of the subscription has been paid
+    Active,
+    /// The subscription has been paused, and will not be renewed until it is resumed
+    Paused,
+    /// The latest invoice of the subscription could not be paid, and is being retried
+    PastDue,
+    /// The subscription has been cancelled, and will not be renewed
+    Cancelled,
+}
+
+impl SubscriptionStatus {
+    /// Whether the subscription has been cancelled and can no longer be modified
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Cancelled)
+    }
+}
+
+/// The unit of the billing interval of a subscription plan
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionPlanInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// The status of an invoice generated for a billing period of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInvoiceStatus {
+    /// The invoice has not been charged yet, or the payment of the invoice is being processed
+    Pending,
+    /// The invoice has been paid
+    Paid,
+    /// The latest attempt to charge the invoice failed, the invoice will be retried
+    Failed,
+    /// The invoice could not be paid after all retries, and will not be charged again
+    Void,
+}
+
+/// The aggregate status of a batch of payouts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The batch has been created, and none of its payouts have been processed yet
+    Pending,
+    /// The payouts of the batch are being processed
+    Processing,
+    /// All payouts of the batch have been processed, and one or more of them failed
+    PartiallyFailed,
+    /// All payouts of the batch have been processed successfully
+    Completed,
+}
+
+impl PayoutBatchStatus {
+    /// Whether all payouts of the batch have been processed
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::PartiallyFailed | Self::Completed)
+    }
+}
+
+/// The status of a single payout of a batch of payouts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The payout has not been created yet
+    Pending,
+    /// The payout was created and accepted by the payout connector
+    Succeeded,
+    /// The payout could not be created, or was rejected by the payout connector
+    Failed,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -7753,6 +8136,7 @@ pub enum PermissionGroup {
     InternalManage,
     ThemeView,
     ThemeManage,
+    AuditLogView,
 }
 
 #[derive(
@@ -7769,9 +8153,12 @@ pub enum ParentGroup {
     Account,
     Internal,
     Theme,
+    AuditLog,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize, ToSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum Resource {
     Payment,
@@ -7800,10 +8187,21 @@ pub enum Resource {
     RevenueRecovery,
     InternalConnector,
     Theme,
+    AuditLog,
 }
 
 #[derive(
-    Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize, Hash,
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    Hash,
+    ToSchema,
 )]
 #[serde(rename_all = "snake_case")]
 pub enum PermissionScope {
@@ -8754,6 +9152,10 @@ pub enum ProcessTrackerRunner {
     PassiveRecoveryWorkflow,
     ProcessDisputeWorkflow,
     DisputeListWorkflow,
+    WebhookSigningSecretExpiryWorkflow,
+    SubscriptionRenewalWorkflow,
+    PayoutBatchWorkflow,
+    PaymentRetryLaterWorkflow,
 }
 
 #[derive(Debug)]
@@ -2,12 +2,12 @@ use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "payouts")]
-use crate::enums::PayoutStatus;
 use crate::enums::{
     AttemptStatus, Country, CountryAlpha2, CountryAlpha3, DisputeStatus, EventType, IntentStatus,
-    MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
+    MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus, SubscriptionStatus,
 };
+#[cfg(feature = "payouts")]
+use crate::enums::{PayoutBatchStatus, PayoutStatus};
 
 impl Display for NumericCountryCodeParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -2188,6 +2188,18 @@ impl From<PayoutStatus> for Option<EventType> {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl From<PayoutBatchStatus> for Option<EventType> {
+    fn from(value: PayoutBatchStatus) -> Self {
+        match value {
+            PayoutBatchStatus::PartiallyFailed | PayoutBatchStatus::Completed => {
+                Some(EventType::PayoutBatchCompleted)
+            }
+            PayoutBatchStatus::Pending | PayoutBatchStatus::Processing => None,
+        }
+    }
+}
+
 impl From<DisputeStatus> for EventType {
     fn from(value: DisputeStatus) -> Self {
         match value {
@@ -2212,6 +2224,19 @@ impl From<MandateStatus> for Option<EventType> {
     }
 }
 
+impl From<SubscriptionStatus> for Option<EventType> {
+    fn from(value: SubscriptionStatus) -> Self {
+        match value {
+            SubscriptionStatus::Active => Some(EventType::SubscriptionActive),
+            SubscriptionStatus::Trialing => Some(EventType::SubscriptionTrialing),
+            SubscriptionStatus::Paused => Some(EventType::SubscriptionPaused),
+            SubscriptionStatus::PastDue => Some(EventType::SubscriptionPastDue),
+            SubscriptionStatus::Cancelled => Some(EventType::SubscriptionCancelled),
+            SubscriptionStatus::Created => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -0,0 +1,314 @@
+//! API key related types
+
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
+use common_enums::{PermissionScope, Resource};
+use common_utils::{id_type, impl_to_sql_from_sql_json};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Restrictions applied to an API key. An API key without restrictions has access to all the
+/// resources of the merchant account.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyRestrictions {
+    /// The permissions granted to the API key. The API key can access all resources if not
+    /// provided.
+    pub permissions: Option<Vec<ApiKeyPermission>>,
+
+    /// The business profiles the API key can access. The API key can access all business
+    /// profiles if not provided.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["pro_abcdefghijklmnop"]))]
+    pub profile_ids: Option<Vec<id_type::ProfileId>>,
+
+    /// The IP addresses or CIDR ranges from which the API key can be used. The API key can be
+    /// used from any IP address if not provided.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["10.0.0.0/8", "203.0.113.7"]))]
+    pub allowed_ip_ranges: Option<Vec<IpRange>>,
+}
+impl_to_sql_from_sql_json!(ApiKeyRestrictions);
+
+impl ApiKeyRestrictions {
+    /// Checks whether the API key is permitted to perform an action of `scope` on `resource`.
+    /// Write access to a resource implies read access to it.
+    pub fn is_permitted(&self, resource: Resource, scope: PermissionScope) -> bool {
+        self.permissions.as_ref().map_or(true, |permissions| {
+            permissions
+                .iter()
+                .any(|permission| permission.resource == resource && scope <= permission.scope)
+        })
+    }
+
+    /// Checks whether the API key can access the specified business profile.
+    pub fn is_profile_allowed(&self, profile_id: &id_type::ProfileId) -> bool {
+        self.profile_ids
+            .as_ref()
+            .map_or(true, |profile_ids| profile_ids.contains(profile_id))
+    }
+
+    /// Checks whether the API key can be used from the specified IP address. A key with an IP
+    /// allow-list cannot be used if the source IP address is not known.
+    pub fn is_ip_allowed(&self, ip_address: Option<IpAddr>) -> bool {
+        self.allowed_ip_ranges.as_ref().map_or(true, |ip_ranges| {
+            ip_address.is_some_and(|ip_address| {
+                ip_ranges
+                    .iter()
+                    .any(|ip_range| ip_range.contains(&ip_address))
+            })
+        })
+    }
+
+    /// Returns the business profile to use when a request does not specify one, which is the
+    /// only profile the API key is restricted to, if any.
+    pub fn get_default_profile_id(&self) -> Option<&id_type::ProfileId> {
+        match self.profile_ids.as_deref() {
+            Some([profile_id]) => Some(profile_id),
+            _ => None,
+        }
+    }
+}
+
+/// A permission granted to an API key
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyPermission {
+    /// The resource the permission applies to
+    #[schema(example = "payment")]
+    pub resource: Resource,
+
+    /// The access granted on the resource, `write` implies `read`
+    #[schema(example = "read")]
+    pub scope: PermissionScope,
+}
+
+/// An IPv4 or IPv6 address range in CIDR notation. A single IP address is treated as a range
+/// containing only that address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IpRange {
+    network: IpAddr,
+    prefix_length: u8,
+}
+
+impl IpRange {
+    /// Checks whether the range contains the specified IP address. IPv4-mapped IPv6 addresses
+    /// are matched against IPv4 ranges.
+    pub fn contains(&self, ip_address: &IpAddr) -> bool {
+        let ip_address = get_canonical_ip_address(*ip_address);
+        match (self.network, ip_address) {
+            (IpAddr::V4(_), IpAddr::V4(_)) | (IpAddr::V6(_), IpAddr::V6(_)) => {
+                get_network_address(self.network, self.prefix_length)
+                    == get_network_address(ip_address, self.prefix_length)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the range with the host bits of its network cleared. IPv4-mapped IPv6 ranges are
+    /// converted to the equivalent IPv4 ranges.
+    pub fn normalize(self) -> Self {
+        let (network, prefix_length) = match self.network {
+            IpAddr::V6(network) => {
+                match (network.to_ipv4_mapped(), self.prefix_length.checked_sub(96)) {
+                    (Some(ipv4_network), Some(prefix_length)) => {
+                        (IpAddr::V4(ipv4_network), prefix_length)
+                    }
+                    _ => (self.network, self.prefix_length),
+                }
+            }
+            IpAddr::V4(_) => (self.network, self.prefix_length),
+        };
+
+        Self {
+            network: get_network_address(network, prefix_length),
+            prefix_length,
+        }
+    }
+
+    /// Returns the normalized ranges containing the IP address, from the widest range to the
+    /// range of the address alone. IPv4-mapped IPv6 addresses are treated as IPv4 addresses.
+    pub fn get_ranges_containing(ip_address: IpAddr) -> Vec<Self> {
+        let ip_address = get_canonical_ip_address(ip_address);
+        (0..=get_max_prefix_length(ip_address))
+            .map(|prefix_length| Self {
+                network: get_network_address(ip_address, prefix_length),
+                prefix_length,
+            })
+            .collect()
+    }
+}
+
+fn get_canonical_ip_address(ip_address: IpAddr) -> IpAddr {
+    match ip_address {
+        IpAddr::V6(ipv6_address) => ipv6_address
+            .to_ipv4_mapped()
+            .map(IpAddr::V4)
+            .unwrap_or(ip_address),
+        IpAddr::V4(_) => ip_address,
+    }
+}
+
+fn get_max_prefix_length(ip_address: IpAddr) -> u8 {
+    match ip_address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Clears the bits of the IP address beyond the prefix length
+fn get_network_address(ip_address: IpAddr, prefix_length: u8) -> IpAddr {
+    let host_bits = get_max_prefix_length(ip_address).saturating_sub(prefix_length);
+    match ip_address {
+        IpAddr::V4(ip_address) => {
+            let mask = u32::MAX.checked_shl(u32::from(host_bits)).unwrap_or(0);
+            IpAddr::V4(Ipv4Addr::from(u32::from(ip_address) & mask))
+        }
+        IpAddr::V6(ip_address) => {
+            let mask = u128::MAX.checked_shl(u32::from(host_bits)).unwrap_or(0);
+            IpAddr::V6(Ipv6Addr::from(u128::from(ip_address) & mask))
+        }
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid_range = || format!("`{value}` is not a valid IP address or CIDR range");
+
+        let (network, prefix_length) = match value.split_once('/') {
+            Some((network, prefix_length)) => (
+                IpAddr::from_str(network).map_err(|_| invalid_range())?,
+                Some(u8::from_str(prefix_length).map_err(|_| invalid_range())?),
+            ),
+            None => (IpAddr::from_str(value).map_err(|_| invalid_range())?, None),
+        };
+
+        let max_prefix_length = get_max_prefix_length(network);
+        let prefix_length = prefix_length.unwrap_or(max_prefix_length);
+
+        if prefix_length > max_prefix_length {
+            return Err(invalid_range());
+        }
+
+        Ok(Self {
+            network,
+            prefix_length,
+        })
+    }
+}
+
+impl TryFrom<String> for IpRange {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl fmt::Display for IpRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.network, self.prefix_length)
+    }
+}
+
+impl From<IpRange> for String {
+    fn from(ip_range: IpRange) -> Self {
+        ip_range.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        IpAddr::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_ip_range_contains() {
+        let ip_range = IpRange::from_str("10.1.0.0/16").unwrap();
+        assert!(ip_range.contains(&ip("10.1.0.1")));
+        assert!(ip_range.contains(&ip("10.1.255.255")));
+        assert!(ip_range.contains(&ip("::ffff:10.1.2.3")));
+        assert!(!ip_range.contains(&ip("10.2.0.1")));
+        assert!(!ip_range.contains(&ip("2001:db8::1")));
+
+        let ip_range = IpRange::from_str("2001:db8::/32").unwrap();
+        assert!(ip_range.contains(&ip("2001:db8:ffff::1")));
+        assert!(!ip_range.contains(&ip("2001:db9::1")));
+
+        let ip_range = IpRange::from_str("203.0.113.7").unwrap();
+        assert!(ip_range.contains(&ip("203.0.113.7")));
+        assert!(!ip_range.contains(&ip("203.0.113.8")));
+
+        let ip_range = IpRange::from_str("0.0.0.0/0").unwrap();
+        assert!(ip_range.contains(&ip("198.51.100.1")));
+    }
+
+    #[test]
+    fn test_ip_range_parsing() {
+        assert!(IpRange::from_str("10.0.0.0/33").is_err());
+        assert!(IpRange::from_str("10.0.0/8").is_err());
+        assert!(IpRange::from_str("2001:db8::/129").is_err());
+        assert!(IpRange::from_str("not-an-ip").is_err());
+        assert_eq!(
+            IpRange::from_str("203.0.113.7").unwrap().to_string(),
+            "203.0.113.7/32"
+        );
+    }
+
+    #[test]
+    fn test_ip_range_normalization() {
+        let normalize = |value: &str| IpRange::from_str(value).unwrap().normalize().to_string();
+        assert_eq!(normalize("192.168.1.17/24"), "192.168.1.0/24");
+        assert_eq!(normalize("2001:db8::1/32"), "2001:db8::/32");
+        assert_eq!(normalize("::ffff:10.1.2.3/112"), "10.1.0.0/16");
+        assert_eq!(normalize("::ffff:10.1.2.3/64"), "::/64");
+    }
+
+    #[test]
+    fn test_ranges_containing_ip_address() {
+        let ranges = IpRange::get_ranges_containing(ip("::ffff:10.2.3.4"));
+        assert_eq!(ranges.len(), 33);
+        assert!(ranges.contains(&IpRange::from_str("10.2.0.0/16").unwrap()));
+        assert!(ranges.contains(&IpRange::from_str("10.2.3.4").unwrap()));
+        assert!(ranges.iter().all(|range| range.contains(&ip("10.2.3.4"))));
+    }
+
+    #[test]
+    fn test_api_key_restrictions() {
+        let restrictions: ApiKeyRestrictions = serde_json::from_value(serde_json::json!({
+            "permissions": [
+                { "resource": "payment", "scope": "read" },
+                { "resource": "refund", "scope": "write" }
+            ],
+            "allowed_ip_ranges": ["10.0.0.0/8"]
+        }))
+        .unwrap();
+
+        assert!(restrictions.is_permitted(Resource::Payment, PermissionScope::Read));
+        assert!(!restrictions.is_permitted(Resource::Payment, PermissionScope::Write));
+        assert!(restrictions.is_permitted(Resource::Refund, PermissionScope::Read));
+        assert!(!restrictions.is_permitted(Resource::Payout, PermissionScope::Write));
+
+        assert!(restrictions.is_ip_allowed(Some(ip("10.20.30.40"))));
+        assert!(!restrictions.is_ip_allowed(Some(ip("192.168.1.1"))));
+        assert!(!restrictions.is_ip_allowed(None));
+
+        let unrestricted = ApiKeyRestrictions::default();
+        assert!(unrestricted.is_permitted(Resource::Payout, PermissionScope::Write));
+        assert!(unrestricted.is_ip_allowed(None));
+    }
+}
@@ -138,6 +138,29 @@ pub struct RetryFeatureData {
     /// decision to be taken for auto retries flow
     #[schema(value_type = GsmDecision)]
     pub decision: common_enums::GsmDecision,
+    /// backoff of the deferred retries scheduled by the `retry_later` decision, the default
+    /// backoff is used if not provided
+    #[serde(default)]
+    pub retry_later_config: Option<RetryLaterConfig>,
+}
+
+/// Represents the backoff of the deferred retries scheduled by the `retry_later` GSM decision.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct RetryLaterConfig {
+    /// delay before the first deferred retry, in seconds. Every further retry waits twice as long
+    /// as the previous one
+    pub initial_delay_in_seconds: u32,
+    /// maximum number of deferred retries of a payment
+    pub max_retries: u8,
+}
+
+impl Default for RetryLaterConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay_in_seconds: 60 * 60,
+            max_retries: 3,
+        }
+    }
 }
 
 impl_to_sql_from_sql_json!(GsmFeatureData);
@@ -180,4 +203,87 @@ impl RetryFeatureData {
     pub fn get_decision(&self) -> common_enums::GsmDecision {
         self.decision
     }
+
+    /// Retrieves the backoff of the deferred retries, falling back to the default backoff.
+    pub fn get_retry_later_config(&self) -> RetryLaterConfig {
+        self.retry_later_config.unwrap_or_default()
+    }
+}
+
+/// Implementation of methods for `RetryLaterConfig`
+impl RetryLaterConfig {
+    /// Retrieves the delay before the deferred retry with the given zero based index, in seconds.
+    /// Returns `None` once the deferred retries are exhausted.
+    pub fn get_delay_in_seconds(&self, retry_count: i32) -> Option<i64> {
+        u32::try_from(retry_count)
+            .ok()
+            .filter(|retry_count| *retry_count < u32::from(self.max_retries))
+            .map(|retry_count| {
+                i64::from(self.initial_delay_in_seconds)
+                    .saturating_mul(2_i64.saturating_pow(retry_count))
+            })
+    }
+}
+
+/// A velocity rule of the card testing guard. The payments sharing the values of the keys of the
+/// rule are measured over a sliding window, and the action of the rule is taken on a payment
+/// once the measure, including the payment itself, exceeds the threshold.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+pub struct VelocityRule {
+    /// name of the rule, unique within the profile
+    pub name: String,
+    /// attributes by which the payments are grouped. The rule is skipped for a payment if any of
+    /// the attributes is not available on it
+    #[schema(value_type = Vec<VelocityRuleKey>)]
+    pub keys: Vec<common_enums::VelocityRuleKey>,
+    /// quantity measured over the window
+    #[schema(value_type = VelocityMeasure)]
+    pub measure: common_enums::VelocityMeasure,
+    /// highest allowed value of the measure within the window
+    pub threshold: i64,
+    /// length of the sliding window, in seconds
+    pub window_in_seconds: u32,
+    /// action taken on a payment exceeding the threshold
+    #[schema(value_type = VelocityAction)]
+    pub action: common_enums::VelocityAction,
+    /// evaluate the rule only for payments made without a customer
+    #[serde(default)]
+    pub guest_customers_only: bool,
+}
+
+impl VelocityRule {
+    /// Name of the rule of the card IP blocking of the card testing guard
+    pub const CARD_IP_BLOCKING_RULE: &'static str = "card_ip_blocking";
+    /// Name of the rule of the guest user card blocking of the card testing guard
+    pub const GUEST_USER_CARD_BLOCKING_RULE: &'static str = "guest_user_card_blocking";
+    /// Name of the rule of the customer id blocking of the card testing guard
+    pub const CUSTOMER_ID_BLOCKING_RULE: &'static str = "customer_id_blocking";
+
+    /// Checks whether the name is taken by one of the fixed blockings of the card testing guard
+    pub fn is_reserved_name(name: &str) -> bool {
+        [
+            Self::CARD_IP_BLOCKING_RULE,
+            Self::GUEST_USER_CARD_BLOCKING_RULE,
+            Self::CUSTOMER_ID_BLOCKING_RULE,
+        ]
+        .contains(&name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_later_delays_double_until_exhausted() {
+        let retry_later_config = RetryLaterConfig {
+            initial_delay_in_seconds: 900,
+            max_retries: 3,
+        };
+        let delays = (0..4)
+            .map(|retry_count| retry_later_config.get_delay_in_seconds(retry_count))
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![Some(900), Some(1800), Some(3600), None]);
+        assert_eq!(retry_later_config.get_delay_in_seconds(-1), None);
+    }
 }
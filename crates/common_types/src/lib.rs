@@ -2,6 +2,7 @@
 
 #![warn(missing_docs, missing_debug_implementations)]
 
+pub mod api_keys;
 pub mod consts;
 pub mod customers;
 pub mod domain;
@@ -164,6 +164,7 @@ impl EuclidDirFilter for ConditionalConfigs {
         DirKeyKind::CardNetwork,
         DirKeyKind::MetaData,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::AmountInBaseCurrency,
         DirKeyKind::PaymentCurrency,
         DirKeyKind::CaptureMethod,
         DirKeyKind::BillingCountry,
@@ -322,6 +323,25 @@ pub enum ConnectorChargeResponseData {
 
 impl_to_sql_from_sql_json!(ConnectorChargeResponseData);
 
+/// Blocklist entry which blocked the payment, reported to the merchant only
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct BlocklistMatchDetails {
+    /// Fingerprint id identifying the blocklist entry
+    pub fingerprint_id: String,
+
+    /// Kind of data the blocklist entry blocks
+    #[schema(value_type = BlocklistDataKind)]
+    pub data_kind: enums::BlocklistDataKind,
+
+    /// Reason provided by the merchant for blocking the data
+    pub reason: Option<String>,
+}
+
+impl_to_sql_from_sql_json!(BlocklistMatchDetails);
+
 /// Fee information to be charged on the payment being collected via xendit
 #[derive(
     Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
@@ -67,6 +67,7 @@ impl EuclidDirFilter for ThreeDSDecisionRule {
     const ALLOWED: &'static [DirKeyKind] = &[
         DirKeyKind::CardNetwork,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::AmountInBaseCurrency,
         DirKeyKind::PaymentCurrency,
         DirKeyKind::IssuerName,
         DirKeyKind::IssuerCountry,
@@ -138,6 +138,15 @@ pub enum ApiEventsType {
     },
     ThreeDsDecisionRule,
     Chat,
+    Subscription {
+        subscription_id: String,
+    },
+    SubscriptionPlan {
+        plan_id: String,
+    },
+    PayoutBatch {
+        batch_id: String,
+    },
 }
 
 impl ApiEventMetric for serde_json::Value {}
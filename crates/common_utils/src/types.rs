@@ -1230,6 +1230,9 @@ pub struct BrowserInformation {
     /// The device model of the client
     pub device_model: Option<String>,
 
+    /// An identifier of the client device, stable across the payments made from it
+    pub device_id: Option<String>,
+
     /// Accept-language of the browser
     pub accept_language: Option<String>,
 }
@@ -0,0 +1,46 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::audit_log};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLogNew {
+    pub audit_log_id: String,
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub actor_type: storage_enums::AuditLogActorType,
+    pub actor_id: String,
+    pub action: storage_enums::AuditLogAction,
+    pub resource_type: storage_enums::AuditLogResourceType,
+    pub resource_id: String,
+    pub diff: Option<serde_json::Value>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = audit_log, primary_key(audit_log_id), check_for_backend(diesel::pg::Pg))]
+pub struct AuditLog {
+    pub audit_log_id: String,
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub actor_type: storage_enums::AuditLogActorType,
+    pub actor_id: String,
+    pub action: storage_enums::AuditLogAction,
+    pub resource_type: storage_enums::AuditLogResourceType,
+    pub resource_id: String,
+    pub diff: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Filters applicable when listing audit log entries
+#[derive(Clone, Debug, Default)]
+pub struct AuditLogFilters {
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub actor_id: Option<String>,
+    pub action: Option<storage_enums::AuditLogAction>,
+    pub resource_type: Option<storage_enums::AuditLogResourceType>,
+    pub resource_id: Option<String>,
+    pub created_after: Option<PrimitiveDateTime>,
+    pub created_before: Option<PrimitiveDateTime>,
+}
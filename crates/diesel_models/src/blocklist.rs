@@ -11,6 +11,8 @@ pub struct BlocklistNew {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub reason: Option<String>,
+    pub expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(
@@ -23,4 +25,14 @@ pub struct Blocklist {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub reason: Option<String>,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl Blocklist {
+    /// Checks whether the entry has expired, entries without an expiry never expire
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= common_utils::date_time::now())
+    }
 }
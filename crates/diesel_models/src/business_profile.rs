@@ -80,6 +80,10 @@ pub struct Profile {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub routing_timezone: Option<String>,
+    pub routing_base_currency: Option<common_enums::Currency>,
+    pub previous_payment_response_hash_key: Option<Encryption>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -139,6 +143,10 @@ pub struct ProfileNew {
     pub merchant_country_code: Option<common_types::payments::MerchantCountryCode>,
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
+    pub routing_timezone: Option<String>,
+    pub routing_base_currency: Option<common_enums::Currency>,
+    pub previous_payment_response_hash_key: Option<Encryption>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -199,6 +207,10 @@ pub struct ProfileUpdateInternal {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub routing_timezone: Option<String>,
+    pub routing_base_currency: Option<common_enums::Currency>,
+    pub previous_payment_response_hash_key: Option<Option<Encryption>>,
+    pub previous_payment_response_hash_key_expires_at: Option<Option<time::PrimitiveDateTime>>,
 }
 
 #[cfg(feature = "v1")]
@@ -256,6 +268,10 @@ impl ProfileUpdateInternal {
             dispute_polling_interval,
             is_manual_retry_enabled,
             always_enable_overcapture,
+            routing_timezone,
+            routing_base_currency,
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -345,6 +361,13 @@ impl ProfileUpdateInternal {
             is_manual_retry_enabled: is_manual_retry_enabled.or(source.is_manual_retry_enabled),
             always_enable_overcapture: always_enable_overcapture
                 .or(source.always_enable_overcapture),
+            routing_timezone: routing_timezone.or(source.routing_timezone),
+            routing_base_currency: routing_base_currency.or(source.routing_base_currency),
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .unwrap_or(source.previous_payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at:
+                previous_payment_response_hash_key_expires_at
+                    .unwrap_or(source.previous_payment_response_hash_key_expires_at),
         }
     }
 }
@@ -411,6 +434,10 @@ pub struct Profile {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub routing_timezone: Option<String>,
+    pub routing_base_currency: Option<common_enums::Currency>,
+    pub previous_payment_response_hash_key: Option<Encryption>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -717,6 +744,11 @@ impl ProfileUpdateInternal {
             split_txns_enabled: split_txns_enabled.or(source.split_txns_enabled),
             is_manual_retry_enabled: None,
             always_enable_overcapture: None,
+            routing_timezone: None,
+            routing_base_currency: None,
+            previous_payment_response_hash_key: source.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: source
+                .previous_payment_response_hash_key_expires_at,
         }
     }
 }
@@ -750,10 +782,69 @@ pub struct CardTestingGuardConfig {
     pub is_customer_id_blocking_enabled: bool,
     pub customer_id_blocking_threshold: i32,
     pub card_testing_guard_expiry: i32,
+    #[serde(default)]
+    pub velocity_rules: Vec<common_types::domain::VelocityRule>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(CardTestingGuardConfig);
 
+impl CardTestingGuardConfig {
+    /// Velocity rules to be evaluated for the profile. The enabled fixed blockings are expressed
+    /// as rules blocking on the failed payments within `card_testing_guard_expiry`, and precede
+    /// the configured rules.
+    pub fn get_velocity_rules(&self) -> Vec<common_types::domain::VelocityRule> {
+        let window_in_seconds = u32::try_from(self.card_testing_guard_expiry).unwrap_or_default();
+        let fixed_blocking_rule = |name: &str,
+                                   keys: Vec<common_enums::VelocityRuleKey>,
+                                   threshold: i32,
+                                   guest_customers_only: bool| {
+            common_types::domain::VelocityRule {
+                name: name.to_string(),
+                keys,
+                measure: common_enums::VelocityMeasure::FailedCount,
+                threshold: threshold.into(),
+                window_in_seconds,
+                action: common_enums::VelocityAction::Block,
+                guest_customers_only,
+            }
+        };
+
+        [
+            self.is_card_ip_blocking_enabled.then(|| {
+                fixed_blocking_rule(
+                    common_types::domain::VelocityRule::CARD_IP_BLOCKING_RULE,
+                    vec![
+                        common_enums::VelocityRuleKey::CardFingerprint,
+                        common_enums::VelocityRuleKey::IpAddress,
+                    ],
+                    self.card_ip_blocking_threshold,
+                    false,
+                )
+            }),
+            self.is_guest_user_card_blocking_enabled.then(|| {
+                fixed_blocking_rule(
+                    common_types::domain::VelocityRule::GUEST_USER_CARD_BLOCKING_RULE,
+                    vec![common_enums::VelocityRuleKey::CardFingerprint],
+                    self.guest_user_card_blocking_threshold,
+                    true,
+                )
+            }),
+            self.is_customer_id_blocking_enabled.then(|| {
+                fixed_blocking_rule(
+                    common_types::domain::VelocityRule::CUSTOMER_ID_BLOCKING_RULE,
+                    vec![common_enums::VelocityRuleKey::CustomerId],
+                    self.customer_id_blocking_threshold,
+                    false,
+                )
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.velocity_rules.iter().cloned())
+        .collect()
+    }
+}
+
 impl Default for CardTestingGuardConfig {
     fn default() -> Self {
         Self {
@@ -769,6 +860,7 @@ impl Default for CardTestingGuardConfig {
                 common_utils::consts::DEFAULT_CUSTOMER_ID_BLOCKING_THRESHOLD,
             card_testing_guard_expiry:
                 common_utils::consts::DEFAULT_CARD_TESTING_GUARD_EXPIRY_IN_SECS,
+            velocity_rules: Vec::new(),
         }
     }
 }
@@ -81,6 +81,8 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    SubscriptionDetails,
+    PayoutBatchDetails,
 }
 
 // Refund
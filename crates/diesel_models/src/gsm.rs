@@ -41,6 +41,7 @@ pub struct GatewayStatusMap {
     pub clear_pan_possible: bool,
     pub feature_data: Option<common_types::domain::GsmFeatureData>,
     pub feature: Option<common_enums::GsmFeature>,
+    pub match_type: Option<common_enums::GsmMatchType>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable)]
@@ -61,6 +62,7 @@ pub struct GatewayStatusMappingNew {
     pub clear_pan_possible: bool,
     pub feature_data: Option<common_types::domain::GsmFeatureData>,
     pub feature: Option<common_enums::GsmFeature>,
+    pub match_type: Option<common_enums::GsmMatchType>,
 }
 
 #[derive(
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
@@ -36,6 +37,8 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
+pub mod payout_batch_item;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
@@ -45,6 +48,8 @@ pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
 pub mod subscription;
+pub mod subscription_invoice;
+pub mod subscription_plan;
 pub mod types;
 pub mod unified_translations;
 
@@ -60,6 +65,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 #[cfg(feature = "v2")]
@@ -224,6 +224,8 @@ pub struct PaymentAttempt {
     pub network_transaction_id: Option<String>,
     pub is_overcapture_enabled: Option<OvercaptureEnabledBool>,
     pub network_details: Option<NetworkDetails>,
+    pub flagged_velocity_rules: Option<Vec<String>>,
+    pub blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -449,6 +451,8 @@ pub struct PaymentAttemptNew {
     pub connector_request_reference_id: Option<String>,
     pub network_transaction_id: Option<String>,
     pub network_details: Option<NetworkDetails>,
+    pub flagged_velocity_rules: Option<Vec<String>>,
+    pub blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -526,6 +530,7 @@ pub enum PaymentAttemptUpdate {
         routing_approach: Option<storage_enums::RoutingApproach>,
         connector_request_reference_id: Option<String>,
         network_transaction_id: Option<String>,
+        flagged_velocity_rules: Option<Vec<String>>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -545,6 +550,7 @@ pub enum PaymentAttemptUpdate {
         error_code: Option<Option<String>>,
         error_message: Option<Option<String>>,
         updated_by: String,
+        blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -1080,6 +1086,8 @@ pub struct PaymentAttemptUpdateInternal {
     pub network_transaction_id: Option<String>,
     pub is_overcapture_enabled: Option<OvercaptureEnabledBool>,
     pub network_details: Option<NetworkDetails>,
+    pub flagged_velocity_rules: Option<Vec<String>>,
+    pub blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -1274,6 +1282,8 @@ impl PaymentAttemptUpdate {
             network_transaction_id,
             is_overcapture_enabled,
             network_details,
+            flagged_velocity_rules,
+            blocklist_match_details,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1346,6 +1356,8 @@ impl PaymentAttemptUpdate {
             network_transaction_id: network_transaction_id.or(source.network_transaction_id),
             is_overcapture_enabled: is_overcapture_enabled.or(source.is_overcapture_enabled),
             network_details: network_details.or(source.network_details),
+            flagged_velocity_rules: flagged_velocity_rules.or(source.flagged_velocity_rules),
+            blocklist_match_details: blocklist_match_details.or(source.blocklist_match_details),
             ..source
         }
     }
@@ -2680,6 +2692,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2747,6 +2761,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2785,6 +2801,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 routing_approach,
                 connector_request_reference_id,
                 network_transaction_id,
+                flagged_velocity_rules,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2848,6 +2865,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2916,6 +2935,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2985,12 +3006,15 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
                 error_code,
                 error_message,
                 updated_by,
+                blocklist_match_details,
             } => Self {
                 status: Some(status),
                 modified_at: common_utils::date_time::now(),
@@ -3054,6 +3078,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details,
             },
             PaymentAttemptUpdate::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -3121,6 +3147,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -3188,6 +3216,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -3285,6 +3315,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id,
                     is_overcapture_enabled,
                     network_details: None,
+                    flagged_velocity_rules: None,
+                    blocklist_match_details: None,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -3372,6 +3404,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details,
+                    flagged_velocity_rules: None,
+                    blocklist_match_details: None,
                 }
             }
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -3437,6 +3471,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -3511,6 +3547,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -3591,6 +3629,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details: None,
+                    flagged_velocity_rules: None,
+                    blocklist_match_details: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -3670,6 +3710,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details: None,
+                    flagged_velocity_rules: None,
+                    blocklist_match_details: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
@@ -3739,6 +3781,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -3807,6 +3851,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3884,6 +3930,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details: None,
+                    flagged_velocity_rules: None,
+                    blocklist_match_details: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -3952,6 +4000,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -4022,6 +4072,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -4101,6 +4153,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     network_transaction_id: None,
                     is_overcapture_enabled: None,
                     network_details: None,
+                    flagged_velocity_rules: None,
+                    blocklist_match_details: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -4169,6 +4223,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 network_transaction_id: None,
                 is_overcapture_enabled: None,
                 network_details: None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
         }
     }
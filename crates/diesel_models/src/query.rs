@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
@@ -32,6 +33,8 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
+pub mod payout_batch_item;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
@@ -40,6 +43,8 @@ pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
 pub mod subscription;
+pub mod subscription_invoice;
+pub mod subscription_plan;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod unified_translations;
@@ -48,3 +53,4 @@ pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
 mod utils;
+pub mod webhook_endpoint;
@@ -0,0 +1,73 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    audit_log::{AuditLog, AuditLogFilters, AuditLogNew},
+    schema::audit_log::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AuditLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AuditLog {
+    pub async fn list_by_filters(
+        conn: &PgPooledConn,
+        filters: AuditLogFilters,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = <Self as HasTable>::table().into_boxed();
+
+        if let Some(merchant_id) = filters.merchant_id {
+            query = query.filter(dsl::merchant_id.eq(merchant_id));
+        }
+
+        if let Some(actor_id) = filters.actor_id {
+            query = query.filter(dsl::actor_id.eq(actor_id));
+        }
+
+        if let Some(action) = filters.action {
+            query = query.filter(dsl::action.eq(action));
+        }
+
+        if let Some(resource_type) = filters.resource_type {
+            query = query.filter(dsl::resource_type.eq(resource_type));
+        }
+
+        if let Some(resource_id) = filters.resource_id {
+            query = query.filter(dsl::resource_id.eq(resource_id));
+        }
+
+        if let Some(created_after) = filters.created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = filters.created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        query = query
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .offset(offset);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering audit log entries by filters")
+    }
+}
@@ -28,6 +28,29 @@ impl Blocklist {
         .await
     }
 
+    /// Finds the entries with any of the given fingerprint ids which have not expired
+    pub async fn find_active_by_merchant_id_fingerprint_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::fingerprint_id.eq_any(fingerprint_ids))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
     pub async fn list_by_merchant_id_data_kind(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -39,7 +62,12 @@ impl Blocklist {
             conn,
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
-                .and(dsl::data_kind.eq(data_kind.to_owned())),
+                .and(dsl::data_kind.eq(data_kind.to_owned()))
+                .and(
+                    dsl::expires_at
+                        .is_null()
+                        .or(dsl::expires_at.gt(common_utils::date_time::now())),
+                ),
             Some(limit),
             Some(offset),
             Some(dsl::created_at.desc()),
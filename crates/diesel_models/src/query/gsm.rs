@@ -9,6 +9,12 @@ impl GatewayStatusMappingNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<GatewayStatusMap> {
         generics::generic_insert(conn, self).await
     }
+
+    /// Inserts the rules in a single statement, so that either all of them are inserted or none
+    pub async fn batch_insert(gsm_rules: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, GatewayStatusMap>(conn, gsm_rules).await?;
+        Ok(())
+    }
 }
 
 impl GatewayStatusMap {
@@ -32,6 +38,36 @@ impl GatewayStatusMap {
         .await
     }
 
+    pub async fn find_all(conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::connector.is_not_null(),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_all_by_connector_flow(
+        conn: &PgPooledConn,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::connector
+                .eq(connector)
+                .and(dsl::flow.eq(flow))
+                .and(dsl::sub_flow.eq(sub_flow)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     pub async fn retrieve_decision(
         conn: &PgPooledConn,
         connector: String,
@@ -0,0 +1,90 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    payout_batch_item::{
+        PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate, PayoutBatchItemUpdateInternal,
+    },
+    schema::payout_batch_item::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchItemNew {
+    pub async fn batch_insert(
+        payout_batch_items: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PayoutBatchItem>(conn, payout_batch_items).await?;
+        Ok(())
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn list_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+            None,
+            None,
+            Some(dsl::row_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_batch_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        status: storage_enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned()))
+                .and(dsl::status.eq(status)),
+            Some(limit),
+            None,
+            Some(dsl::row_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_batch_id_row_number(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<
+            <Self as HasTable>::Table,
+            PayoutBatchItemUpdateInternal,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned()))
+                .and(dsl::row_number.eq(row_number)),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while updating payout batch item")
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
     associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
-    JoinOnDsl, QueryDsl,
+    JoinOnDsl, QueryDsl, Table,
 };
 use error_stack::{report, ResultExt};
 
@@ -93,6 +93,29 @@ impl Payouts {
         .await
     }
 
+    /// Finds the payouts of the merchant with any of the given payout ids
+    pub async fn find_by_merchant_id_payout_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_ids: Vec<common_utils::id_type::PayoutId>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_id.eq_any(payout_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     pub async fn get_total_count_of_payouts(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
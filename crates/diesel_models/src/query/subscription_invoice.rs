@@ -0,0 +1,82 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    errors,
+    schema::subscription_invoice::dsl,
+    subscription_invoice::{
+        SubscriptionInvoice, SubscriptionInvoiceNew, SubscriptionInvoiceUpdate,
+        SubscriptionInvoiceUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl SubscriptionInvoiceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionInvoice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionInvoice {
+    pub async fn find_by_merchant_id_subscription_id_period_start(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        period_start: PrimitiveDateTime,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned()))
+                .and(dsl::period_start.eq(period_start)),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+            None,
+            None,
+            Some(dsl::period_start.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_invoice_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: SubscriptionInvoiceUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<
+            <Self as HasTable>::Table,
+            SubscriptionInvoiceUpdateInternal,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::invoice_id.eq(invoice_id.to_owned())),
+            SubscriptionInvoiceUpdateInternal::from(subscription_invoice_update),
+        )
+        .await?
+        .first()
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Error while updating subscription invoice")
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use common_utils::pii::SecretSerdeValue;
+use common_utils::{pii::SecretSerdeValue, types::MinorUnit};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +20,11 @@ pub struct SubscriptionNew {
     created_at: time::PrimitiveDateTime,
     modified_at: time::PrimitiveDateTime,
     profile_id: common_utils::id_type::ProfileId,
+    plan_id: Option<String>,
+    mandate_id: Option<String>,
+    current_period_start: Option<time::PrimitiveDateTime>,
+    current_period_end: Option<time::PrimitiveDateTime>,
+    trial_end: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(
@@ -40,6 +45,15 @@ pub struct Subscription {
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
     pub profile_id: common_utils::id_type::ProfileId,
+    pub plan_id: Option<String>,
+    pub mandate_id: Option<String>,
+    pub current_period_start: Option<time::PrimitiveDateTime>,
+    pub current_period_end: Option<time::PrimitiveDateTime>,
+    pub trial_end: Option<time::PrimitiveDateTime>,
+    pub cancel_at_period_end: Option<bool>,
+    pub paused_at: Option<time::PrimitiveDateTime>,
+    pub cancelled_at: Option<time::PrimitiveDateTime>,
+    pub pending_proration_amount: Option<MinorUnit>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, AsChangeset, router_derive::DebugAsDisplay, Deserialize)]
@@ -48,6 +62,14 @@ pub struct SubscriptionUpdate {
     pub payment_method_id: Option<String>,
     pub status: Option<String>,
     pub modified_at: time::PrimitiveDateTime,
+    pub plan_id: Option<String>,
+    pub mandate_id: Option<Option<String>>,
+    pub current_period_start: Option<time::PrimitiveDateTime>,
+    pub current_period_end: Option<time::PrimitiveDateTime>,
+    pub cancel_at_period_end: Option<bool>,
+    pub paused_at: Option<time::PrimitiveDateTime>,
+    pub cancelled_at: Option<time::PrimitiveDateTime>,
+    pub pending_proration_amount: Option<MinorUnit>,
 }
 
 impl SubscriptionNew {
@@ -80,8 +102,31 @@ impl SubscriptionNew {
             created_at: now,
             modified_at: now,
             profile_id,
+            plan_id: None,
+            mandate_id: None,
+            current_period_start: None,
+            current_period_end: None,
+            trial_end: None,
         }
     }
+
+    /// Sets the plan the subscription is billed for and its first billing period, for
+    /// subscriptions billed by the application instead of an external billing processor.
+    pub fn with_plan(
+        mut self,
+        plan_id: String,
+        mandate_id: Option<String>,
+        current_period_start: time::PrimitiveDateTime,
+        current_period_end: time::PrimitiveDateTime,
+        trial_end: Option<time::PrimitiveDateTime>,
+    ) -> Self {
+        self.plan_id = Some(plan_id);
+        self.mandate_id = mandate_id;
+        self.current_period_start = Some(current_period_start);
+        self.current_period_end = Some(current_period_end);
+        self.trial_end = trial_end;
+        self
+    }
 }
 
 impl SubscriptionUpdate {
@@ -90,6 +135,14 @@ impl SubscriptionUpdate {
             payment_method_id,
             status,
             modified_at: common_utils::date_time::now(),
+            plan_id: None,
+            mandate_id: None,
+            current_period_start: None,
+            current_period_end: None,
+            cancel_at_period_end: None,
+            paused_at: None,
+            cancelled_at: None,
+            pending_proration_amount: None,
         }
     }
 }
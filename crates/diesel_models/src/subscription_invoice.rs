@@ -0,0 +1,89 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::subscription_invoice};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceNew {
+    pub invoice_id: String,
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::SubscriptionInvoiceStatus,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = subscription_invoice, primary_key(invoice_id, merchant_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionInvoice {
+    pub invoice_id: String,
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::SubscriptionInvoiceStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionInvoiceUpdate {
+    /// Records the outcome of an attempt to charge the invoice
+    PaymentAttemptUpdate {
+        status: storage_enums::SubscriptionInvoiceStatus,
+        payment_id: Option<common_utils::id_type::PaymentId>,
+        attempt_count: i32,
+    },
+    StatusUpdate {
+        status: storage_enums::SubscriptionInvoiceStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceUpdateInternal {
+    pub status: Option<storage_enums::SubscriptionInvoiceStatus>,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_count: Option<i32>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionInvoiceUpdate> for SubscriptionInvoiceUpdateInternal {
+    fn from(subscription_invoice_update: SubscriptionInvoiceUpdate) -> Self {
+        match subscription_invoice_update {
+            SubscriptionInvoiceUpdate::PaymentAttemptUpdate {
+                status,
+                payment_id,
+                attempt_count,
+            } => Self {
+                status: Some(status),
+                payment_id,
+                attempt_count: Some(attempt_count),
+                modified_at: common_utils::date_time::now(),
+            },
+            SubscriptionInvoiceUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                payment_id: None,
+                attempt_count: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -220,6 +220,8 @@ pub struct PaymentAttemptBatchNew {
     pub connector_request_reference_id: Option<String>,
     pub network_transaction_id: Option<String>,
     pub network_details: Option<NetworkDetails>,
+    pub flagged_velocity_rules: Option<Vec<String>>,
+    pub blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -309,6 +311,8 @@ impl PaymentAttemptBatchNew {
             connector_request_reference_id: self.connector_request_reference_id,
             network_transaction_id: self.network_transaction_id,
             network_details: self.network_details,
+            flagged_velocity_rules: self.flagged_velocity_rules,
+            blocklist_match_details: self.blocklist_match_details,
         }
     }
 }
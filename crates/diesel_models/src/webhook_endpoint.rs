@@ -0,0 +1,96 @@
+use common_utils::encryption::Encryption;
+use diesel::{
+    expression::AsExpression, AsChangeset, Identifiable, Insertable, Queryable, Selectable,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_endpoint};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: String,
+    pub signing_secret: Encryption,
+    pub enabled_events: WebhookEndpointEventTypes,
+    pub status: storage_enums::WebhookEndpointStatus,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = webhook_endpoint, primary_key(merchant_id, endpoint_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: String,
+    pub signing_secret: Encryption,
+    pub enabled_events: WebhookEndpointEventTypes,
+    pub status: storage_enums::WebhookEndpointStatus,
+    pub description: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl WebhookEndpoint {
+    /// An endpoint without any enabled event types receives all events
+    pub fn is_event_type_enabled(&self, event_type: storage_enums::EventType) -> bool {
+        self.status == storage_enums::WebhookEndpointStatus::Active
+            && (self.enabled_events.0.is_empty() || self.enabled_events.0.contains(&event_type))
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<String>,
+        enabled_events: Option<WebhookEndpointEventTypes>,
+        status: Option<storage_enums::WebhookEndpointStatus>,
+        description: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    pub url: Option<String>,
+    pub enabled_events: Option<WebhookEndpointEventTypes>,
+    pub status: Option<storage_enums::WebhookEndpointStatus>,
+    pub description: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(webhook_endpoint_update: WebhookEndpointUpdate) -> Self {
+        match webhook_endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                enabled_events,
+                status,
+                description,
+            } => Self {
+                url,
+                enabled_events,
+                status,
+                description,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+/// The event types for which webhooks are delivered to an endpoint
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize, AsExpression, diesel::FromSqlRow,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct WebhookEndpointEventTypes(pub Vec<storage_enums::EventType>);
+
+common_utils::impl_to_sql_from_sql_json!(WebhookEndpointEventTypes);
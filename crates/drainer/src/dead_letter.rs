@@ -0,0 +1,375 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::DeadLetterError, metrics, query::ExecuteQuery, services::Store, types::DeadLetterEntry,
+};
+
+/// Number of entries returned when listing the dead letter stream, if not specified
+const DEFAULT_LIST_LIMIT: u64 = 20;
+
+/// Header carrying the admin API key
+const API_KEY_HEADER: &str = "api-key";
+
+/// The admin API key the dead letter stream APIs are authenticated with
+pub struct AdminApiKey(Secret<String>);
+
+pub struct DeadLetter;
+
+impl DeadLetter {
+    pub fn server(
+        stores: HashMap<id_type::TenantId, Arc<Store>>,
+        admin_api_key: Secret<String>,
+    ) -> Scope {
+        web::scope("dead_letter")
+            .app_data(web::Data::new(stores))
+            .app_data(web::Data::new(AdminApiKey(admin_api_key)))
+            .service(web::resource("/{tenant_id}/{stream_index}").route(web::get().to(list)))
+            .service(
+                web::resource("/{tenant_id}/{stream_index}/{entry_id}")
+                    .route(web::get().to(retrieve))
+                    .route(web::delete().to(discard)),
+            )
+            .service(
+                web::resource("/{tenant_id}/{stream_index}/{entry_id}/replay")
+                    .route(web::post().to(replay)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeadLetterPath {
+    tenant_id: String,
+    stream_index: u8,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeadLetterEntryPath {
+    tenant_id: String,
+    stream_index: u8,
+    entry_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeadLetterListQuery {
+    /// Entry id to start listing from (inclusive)
+    from: Option<String>,
+    limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeadLetterEntrySummary {
+    pub entry_id: String,
+    pub original_entry_id: String,
+    pub error: String,
+    pub retry_count: i64,
+    pub dead_lettered_at: i64,
+    pub operation: Option<String>,
+    pub table: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeadLetterEntryDetails {
+    #[serde(flatten)]
+    pub summary: DeadLetterEntrySummary,
+    pub request_id: Option<String>,
+    pub global_id: Option<String>,
+    pub pushed_at: Option<i64>,
+    /// The database operation with sensitive fields masked,
+    /// absent if the original entry could not be parsed
+    pub typed_sql: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeadLetterActionResponse {
+    pub entry_id: String,
+    pub status: DeadLetterActionStatus,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadLetterActionStatus {
+    Replayed,
+    Discarded,
+}
+
+fn get_store(
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: String,
+) -> Result<Arc<Store>, error_stack::Report<DeadLetterError>> {
+    id_type::TenantId::try_from_string(tenant_id.clone())
+        .ok()
+        .and_then(|tenant_id| stores.get(&tenant_id).cloned())
+        .ok_or_else(|| report!(DeadLetterError::TenantNotFound { tenant_id }))
+}
+
+fn validate_stream_index(
+    store: &Store,
+    stream_index: u8,
+) -> Result<String, error_stack::Report<DeadLetterError>> {
+    if stream_index >= store.config.drainer_num_partitions {
+        return Err(report!(DeadLetterError::InvalidStreamIndex {
+            stream_index
+        }));
+    }
+    Ok(store.get_drainer_stream_name(stream_index))
+}
+
+fn to_summary(entry_id: String, entry: &DeadLetterEntry) -> DeadLetterEntrySummary {
+    let stream_data = entry.get_stream_data().ok();
+    DeadLetterEntrySummary {
+        entry_id,
+        original_entry_id: entry.original_entry_id.clone(),
+        error: entry.error.clone(),
+        retry_count: entry.retry_count,
+        dead_lettered_at: entry.dead_lettered_at,
+        operation: stream_data
+            .as_ref()
+            .map(|data| data.typed_sql.operation().to_string()),
+        table: stream_data
+            .as_ref()
+            .map(|data| data.typed_sql.table().to_string()),
+    }
+}
+
+async fn find_entry(
+    store: &Store,
+    stream_name: &str,
+    entry_id: &str,
+) -> Result<DeadLetterEntry, error_stack::Report<DeadLetterError>> {
+    let (_, entry) = store
+        .read_from_dead_letter_stream(stream_name, entry_id, entry_id, Some(1))
+        .await
+        .change_context(DeadLetterError::StreamOperationFailed)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            report!(DeadLetterError::EntryNotFound {
+                entry_id: entry_id.to_owned(),
+            })
+        })?;
+
+    DeadLetterEntry::from_hashmap(entry).change_context(DeadLetterError::EntryParsingFailed)
+}
+
+/// Checks the admin API key of the request. All requests are rejected if no admin API key is
+/// configured.
+fn authenticate(
+    req: &HttpRequest,
+    admin_api_key: &AdminApiKey,
+) -> Result<(), error_stack::Report<DeadLetterError>> {
+    let admin_api_key = admin_api_key.0.peek();
+    let is_authenticated = !admin_api_key.is_empty()
+        && req
+            .headers()
+            .get(API_KEY_HEADER)
+            .and_then(|header_value| header_value.to_str().ok())
+            .is_some_and(|request_api_key| request_api_key == admin_api_key);
+
+    if is_authenticated {
+        Ok(())
+    } else {
+        Err(report!(DeadLetterError::Unauthorized))
+    }
+}
+
+fn error_response(error: error_stack::Report<DeadLetterError>) -> HttpResponse {
+    logger::error!(?error);
+    actix_web::ResponseError::error_response(error.current_context())
+}
+
+#[instrument(skip_all)]
+pub async fn list(
+    req: HttpRequest,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    admin_api_key: web::Data<AdminApiKey>,
+    path: web::Path<DeadLetterPath>,
+    query: web::Query<DeadLetterListQuery>,
+) -> HttpResponse {
+    let DeadLetterPath {
+        tenant_id,
+        stream_index,
+    } = path.into_inner();
+    let DeadLetterListQuery { from, limit } = query.into_inner();
+
+    let result = async {
+        authenticate(&req, &admin_api_key)?;
+        let store = get_store(&stores, tenant_id)?;
+        let stream_name = validate_stream_index(&store, stream_index)?;
+
+        let entries = store
+            .read_from_dead_letter_stream(
+                &stream_name,
+                from.as_deref().unwrap_or("-"),
+                "+",
+                Some(limit.unwrap_or(DEFAULT_LIST_LIMIT)),
+            )
+            .await
+            .change_context(DeadLetterError::StreamOperationFailed)?;
+
+        Ok::<_, error_stack::Report<DeadLetterError>>(
+            entries
+                .into_iter()
+                .filter_map(|(entry_id, entry)| {
+                    DeadLetterEntry::from_hashmap(entry)
+                        .map_err(|error| logger::error!(?error, entry_id = %entry_id))
+                        .ok()
+                        .map(|entry| to_summary(entry_id, &entry))
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+    .await;
+
+    match result {
+        Ok(summaries) => HttpResponse::Ok().json(summaries),
+        Err(error) => error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve(
+    req: HttpRequest,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    admin_api_key: web::Data<AdminApiKey>,
+    path: web::Path<DeadLetterEntryPath>,
+) -> HttpResponse {
+    let DeadLetterEntryPath {
+        tenant_id,
+        stream_index,
+        entry_id,
+    } = path.into_inner();
+
+    let result = async {
+        authenticate(&req, &admin_api_key)?;
+        let store = get_store(&stores, tenant_id)?;
+        let stream_name = validate_stream_index(&store, stream_index)?;
+        let entry = find_entry(&store, &stream_name, &entry_id).await?;
+
+        let stream_data = entry.get_stream_data().ok();
+        let typed_sql = stream_data
+            .as_ref()
+            .map(|data| masking::masked_serialize(&data.typed_sql))
+            .transpose()
+            .change_context(DeadLetterError::EntryParsingFailed)?;
+
+        Ok::<_, error_stack::Report<DeadLetterError>>(DeadLetterEntryDetails {
+            summary: to_summary(entry_id, &entry),
+            request_id: entry.original_entry.get("request_id").cloned(),
+            global_id: entry.original_entry.get("global_id").cloned(),
+            pushed_at: stream_data.as_ref().map(|data| data.pushed_at),
+            typed_sql,
+        })
+    }
+    .await;
+
+    match result {
+        Ok(details) => HttpResponse::Ok().json(details),
+        Err(error) => error_response(error),
+    }
+}
+
+/// Applies the database operation of the entry again and removes it from the dead letter stream
+/// if it succeeds
+#[instrument(skip_all)]
+pub async fn replay(
+    req: HttpRequest,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    admin_api_key: web::Data<AdminApiKey>,
+    path: web::Path<DeadLetterEntryPath>,
+) -> HttpResponse {
+    let DeadLetterEntryPath {
+        tenant_id,
+        stream_index,
+        entry_id,
+    } = path.into_inner();
+
+    let result = async {
+        authenticate(&req, &admin_api_key)?;
+        let store = get_store(&stores, tenant_id)?;
+        let stream_name = validate_stream_index(&store, stream_index)?;
+        let entry = find_entry(&store, &stream_name, &entry_id).await?;
+
+        let stream_data = entry
+            .get_stream_data()
+            .change_context(DeadLetterError::EntryParsingFailed)?;
+
+        stream_data
+            .typed_sql
+            .execute_query(&store, stream_data.pushed_at)
+            .await
+            .map_err(|error| {
+                report!(DeadLetterError::ReplayFailed {
+                    message: format!("{error:?}"),
+                })
+            })?;
+
+        store
+            .delete_from_dead_letter_stream(&stream_name, &entry_id)
+            .await
+            .change_context(DeadLetterError::StreamOperationFailed)?;
+        metrics::DEAD_LETTER_ENTRIES_REPLAYED
+            .add(1, router_env::metric_attributes!(("stream", stream_name)));
+
+        Ok::<_, error_stack::Report<DeadLetterError>>(DeadLetterActionResponse {
+            entry_id,
+            status: DeadLetterActionStatus::Replayed,
+        })
+    }
+    .await;
+
+    match result {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(error) => error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn discard(
+    req: HttpRequest,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    admin_api_key: web::Data<AdminApiKey>,
+    path: web::Path<DeadLetterEntryPath>,
+) -> HttpResponse {
+    let DeadLetterEntryPath {
+        tenant_id,
+        stream_index,
+        entry_id,
+    } = path.into_inner();
+
+    let result = async {
+        authenticate(&req, &admin_api_key)?;
+        let store = get_store(&stores, tenant_id)?;
+        let stream_name = validate_stream_index(&store, stream_index)?;
+
+        let deleted_entries = store
+            .delete_from_dead_letter_stream(&stream_name, &entry_id)
+            .await
+            .change_context(DeadLetterError::StreamOperationFailed)?;
+
+        if deleted_entries == 0 {
+            return Err(report!(DeadLetterError::EntryNotFound { entry_id }));
+        }
+
+        logger::info!(entry_id = %entry_id, "Discarded entry from the dead letter stream");
+        metrics::DEAD_LETTER_ENTRIES_DISCARDED
+            .add(1, router_env::metric_attributes!(("stream", stream_name)));
+
+        Ok::<_, error_stack::Report<DeadLetterError>>(DeadLetterActionResponse {
+            entry_id,
+            status: DeadLetterActionStatus::Discarded,
+        })
+    }
+    .await;
+
+    match result {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(error) => error_response(error),
+    }
+}
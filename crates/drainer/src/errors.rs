@@ -27,6 +27,24 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DeadLetterError {
+    #[error("Invalid or missing admin API key")]
+    Unauthorized,
+    #[error("Tenant {tenant_id} not found")]
+    TenantNotFound { tenant_id: String },
+    #[error("Stream index {stream_index} is out of range")]
+    InvalidStreamIndex { stream_index: u8 },
+    #[error("Entry {entry_id} not found in the dead letter stream")]
+    EntryNotFound { entry_id: String },
+    #[error("Failed to parse the dead letter entry")]
+    EntryParsingFailed,
+    #[error("Failed to replay the dead letter entry: {message}")]
+    ReplayFailed { message: String },
+    #[error("Failed to perform the operation on the dead letter stream")]
+    StreamOperationFailed,
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +74,25 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DeadLetterError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound { .. } | Self::EntryNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::InvalidStreamIndex { .. } => StatusCode::BAD_REQUEST,
+            Self::ReplayFailed { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::EntryParsingFailed | Self::StreamOperationFailed => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::build(self.status_code()).json(serde_json::json!({
+            "message": self.to_string()
+        }))
+    }
+}
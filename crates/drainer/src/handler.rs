@@ -82,6 +82,7 @@ impl Handler {
                             store.clone(),
                             stream_index,
                             self.conf.max_read_count,
+                            self.conf.max_retry_count,
                             self.active_tasks.clone(),
                             jobs_picked.clone(),
                         )
@@ -174,6 +175,7 @@ async fn drainer_handler(
     store: Arc<Store>,
     stream_index: u8,
     max_read_count: u64,
+    max_retry_count: u32,
     active_tasks: Arc<atomic::AtomicU64>,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -184,6 +186,7 @@ async fn drainer_handler(
     let drainer_result = Box::pin(drainer(
         store.clone(),
         max_read_count,
+        max_retry_count,
         stream_name.as_str(),
         jobs_picked,
     ))
@@ -204,6 +207,7 @@ async fn drainer_handler(
 async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
+    max_retry_count: u32,
     stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -243,9 +247,11 @@ async fn drainer(
     let session_id = common_utils::generate_id_with_default_len("drainer_session");
 
     let mut last_processed_id = String::new();
+    // Entries drained or dead lettered in this session, whose retry counts are no longer needed
+    let mut processed_entry_ids = Vec::new();
 
     for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
+        let data = match StreamData::from_hashmap(entry.clone()) {
             Ok(data) => data,
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
@@ -254,8 +260,24 @@ async fn drainer(
                     router_env::metric_attributes!(("operation", "deserialization")),
                 );
 
-                // break from the loop in case of a deser error
-                break;
+                // Retrying a malformed entry can never succeed, move it out of the way right away
+                store
+                    .move_to_dead_letter_stream(
+                        stream_name,
+                        &entry_id,
+                        entry,
+                        format!("{err:?}"),
+                        0,
+                    )
+                    .await?;
+                last_processed_id = entry_id;
+
+                if store.use_legacy_version() {
+                    store
+                        .delete_from_stream(stream_name, &last_processed_id)
+                        .await?;
+                }
+                continue;
             }
         };
 
@@ -265,16 +287,46 @@ async fn drainer(
 
         match data.typed_sql.execute_query(&store, data.pushed_at).await {
             Ok(_) => {
+                processed_entry_ids.push(entry_id.clone());
                 last_processed_id = entry_id;
             }
             Err(err) => match err.current_context() {
                 // In case of Uniqueviolation we can't really do anything to fix it so just clear
                 // it from the stream
                 diesel_models::errors::DatabaseError::UniqueViolation => {
+                    processed_entry_ids.push(entry_id.clone());
+                    last_processed_id = entry_id;
+                }
+                _ => {
+                    let retry_count = store.increment_retry_count(stream_name, &entry_id).await?;
+                    metrics::QUERY_EXECUTION_RETRIES.add(
+                        1,
+                        router_env::metric_attributes!(("stream", stream_name.to_owned())),
+                    );
+
+                    // break from the loop in case of an error in query, the entry will be
+                    // retried in the next cycle until the retry budget is exhausted
+                    if retry_count < max_retry_count {
+                        break;
+                    }
+
+                    logger::error!(
+                        entry_id = %entry_id,
+                        retry_count,
+                        "Moving entry to the dead letter stream after exhausting retries"
+                    );
+                    store
+                        .move_to_dead_letter_stream(
+                            stream_name,
+                            &entry_id,
+                            entry,
+                            format!("{err:?}"),
+                            retry_count,
+                        )
+                        .await?;
+                    processed_entry_ids.push(entry_id.clone());
                     last_processed_id = entry_id;
                 }
-                // break from the loop in case of an error in query
-                _ => break,
             },
         }
 
@@ -285,6 +337,12 @@ async fn drainer(
         }
     }
 
+    if !processed_entry_ids.is_empty() {
+        store
+            .clear_retry_counts(stream_name, processed_entry_ids)
+            .await;
+    }
+
     if !(last_processed_id.is_empty() || store.use_legacy_version()) {
         let entries_trimmed = store
             .trim_from_stream(stream_name, &last_processed_id)
@@ -3,7 +3,11 @@ use std::collections::HashMap;
 use redis_interface as redis;
 use router_env::{logger, tracing};
 
-use crate::{errors, metrics, Store};
+use crate::{errors, metrics, types::DeadLetterEntry, Store};
+
+/// Retry counts are kept only until the entry is drained or dead lettered,
+/// the expiry makes sure the hash doesn't grow unbounded
+const RETRY_COUNT_EXPIRY_IN_SECONDS: i64 = 24 * 60 * 60;
 
 pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
@@ -25,6 +29,17 @@ impl Store {
         self.drainer_stream(format!("shard_{stream_index}").as_str())
     }
 
+    /// Example: {shard_5}_drainer_stream_dead_letter
+    #[inline(always)]
+    pub(crate) fn get_dead_letter_stream_name(&self, stream_name: &str) -> String {
+        format!("{stream_name}_dead_letter")
+    }
+
+    #[inline(always)]
+    fn get_retry_count_key(&self, stream_name: &str) -> String {
+        format!("{stream_name}_retry_count")
+    }
+
     #[router_env::instrument(skip_all)]
     pub async fn is_stream_available(&self, stream_index: u8) -> bool {
         let stream_key_flag = self.get_stream_key_flag(stream_index);
@@ -133,4 +148,128 @@ impl Store {
 
         Ok(())
     }
+
+    /// Increments the number of failed attempts of an entry and returns the updated count
+    pub async fn increment_retry_count(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<u32> {
+        let retry_count_key = self.get_retry_count_key(stream_name);
+        let retry_count = self
+            .redis_conn
+            .increment_fields_in_hash(&retry_count_key.as_str().into(), &[(entry_id, 1)])
+            .await
+            .map_err(errors::DrainerError::from)?
+            .first()
+            .copied()
+            .unwrap_or_default();
+
+        self.redis_conn
+            .set_expiry(
+                &retry_count_key.as_str().into(),
+                RETRY_COUNT_EXPIRY_IN_SECONDS,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(u32::try_from(retry_count).unwrap_or(u32::MAX))
+    }
+
+    /// Removes the retry counts of the entries which have left the stream. Failures are only
+    /// logged, the counts expire along with the hash otherwise.
+    pub async fn clear_retry_counts(&self, stream_name: &str, entry_ids: Vec<String>) {
+        let retry_count_key = self.get_retry_count_key(stream_name);
+        let _ = self
+            .redis_conn
+            .delete_fields_from_hash(&retry_count_key.as_str().into(), entry_ids)
+            .await
+            .map_err(|error| logger::error!(operation = "clear_retry_counts", err = ?error));
+    }
+
+    /// Appends the entry along with the reason of failure to the dead letter stream of the shard
+    pub async fn move_to_dead_letter_stream(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, String>,
+        error: String,
+        retry_count: u32,
+    ) -> errors::DrainerResult<()> {
+        let dead_letter_stream_name = self.get_dead_letter_stream_name(stream_name);
+        let fields = DeadLetterEntry::to_field_value_pairs(entry, entry_id, error, retry_count);
+
+        self.redis_conn
+            .stream_append_entry(
+                &dead_letter_stream_name.as_str().into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        metrics::ENTRIES_MOVED_TO_DEAD_LETTER_STREAM.add(
+            1,
+            router_env::metric_attributes!(("stream", stream_name.to_owned())),
+        );
+        self.record_dead_letter_stream_length(stream_name).await;
+
+        Ok(())
+    }
+
+    pub async fn record_dead_letter_stream_length(&self, stream_name: &str) {
+        let dead_letter_stream_name = self.get_dead_letter_stream_name(stream_name);
+
+        match self
+            .redis_conn
+            .stream_get_length(&dead_letter_stream_name.as_str().into())
+            .await
+        {
+            Ok(length) => metrics::DEAD_LETTER_STREAM_LENGTH.record(
+                u64::try_from(length).unwrap_or(u64::MAX),
+                router_env::metric_attributes!(("stream", stream_name.to_owned())),
+            ),
+            Err(error) => {
+                logger::error!(operation = "dead_letter_stream_length", err = ?error)
+            }
+        }
+    }
+
+    pub async fn read_from_dead_letter_stream(
+        &self,
+        stream_name: &str,
+        start_entry_id: &str,
+        end_entry_id: &str,
+        count: Option<u64>,
+    ) -> errors::DrainerResult<StreamEntries> {
+        let dead_letter_stream_name = self.get_dead_letter_stream_name(stream_name);
+
+        Ok(self
+            .redis_conn
+            .stream_read_entries_in_range(
+                &dead_letter_stream_name.as_str().into(),
+                start_entry_id,
+                end_entry_id,
+                count,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?)
+    }
+
+    pub async fn delete_from_dead_letter_stream(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<usize> {
+        let dead_letter_stream_name = self.get_dead_letter_stream_name(stream_name);
+
+        let deleted_entries = self
+            .redis_conn
+            .stream_delete_entries(&dead_letter_stream_name.as_str().into(), entry_id)
+            .await
+            .map_err(errors::DrainerError::from)?;
+        self.record_dead_letter_stream_length(stream_name).await;
+
+        Ok(deleted_entries)
+    }
 }
@@ -33,3 +33,60 @@ impl StreamData {
             .change_context(errors::ParsingError::StructParseFailure("StreamData"))
     }
 }
+
+/// An entry of the dead letter stream, holds the fields of the original drainer stream entry
+/// along with the details of why it couldn't be applied
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DeadLetterEntry {
+    pub original_entry_id: String,
+    pub error: String,
+    #[serde(deserialize_with = "deserialize_i64")]
+    pub retry_count: i64,
+    #[serde(deserialize_with = "deserialize_i64")]
+    pub dead_lettered_at: i64,
+    #[serde(flatten)]
+    pub original_entry: HashMap<String, String>,
+}
+
+impl DeadLetterEntry {
+    pub fn to_field_value_pairs(
+        original_entry: HashMap<String, String>,
+        original_entry_id: &str,
+        error: String,
+        retry_count: u32,
+    ) -> Vec<(String, String)> {
+        original_entry
+            .into_iter()
+            .chain([
+                (
+                    "original_entry_id".to_string(),
+                    original_entry_id.to_string(),
+                ),
+                ("error".to_string(), error),
+                ("retry_count".to_string(), retry_count.to_string()),
+                (
+                    "dead_lettered_at".to_string(),
+                    common_utils::date_time::now_unix_timestamp().to_string(),
+                ),
+            ])
+            .collect()
+    }
+
+    pub fn from_hashmap(
+        hashmap: HashMap<String, String>,
+    ) -> errors::CustomResult<Self, errors::ParsingError> {
+        let iter = MapDeserializer::<
+            '_,
+            std::collections::hash_map::IntoIter<String, String>,
+            serde_json::error::Error,
+        >::new(hashmap.into_iter());
+
+        Self::deserialize(iter)
+            .change_context(errors::ParsingError::StructParseFailure("DeadLetterEntry"))
+    }
+
+    /// Parses the original drainer stream entry, so that it can be applied again
+    pub fn get_stream_data(&self) -> errors::CustomResult<StreamData, errors::ParsingError> {
+        StreamData::from_hashmap(self.original_entry.clone())
+    }
+}
@@ -3,7 +3,9 @@
 use common_utils::types::MinorUnit;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use euclid::{
-    backend::{inputs, EuclidBackend, InterpreterBackend, VirInterpreterBackend},
+    backend::{
+        inputs, DecisionTableBackend, EuclidBackend, InterpreterBackend, VirInterpreterBackend,
+    },
     enums,
     frontend::ast::{self, parser},
     types::DummyOutput,
@@ -39,6 +41,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
         metadata: None,
         payment: inputs::PaymentInput {
             amount: MinorUnit::new(32),
+            amount_in_base_currency: None,
             card_bin: None,
             currency: enums::Currency::USD,
             authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -59,6 +62,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             payment_type: None,
         },
         issuer_data: None,
+        time: None,
         acquirer_data: None,
         customer_device_data: None,
     };
@@ -68,13 +72,51 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
     (program, inp)
 }
 
+/// A rule set with one rule per amount band and currency, of which the input only matches the
+/// last rule, so that the interpreters have to go through every rule
+fn get_large_program_data(band_count: i64) -> (ast::Program<DummyOutput>, inputs::BackendInput) {
+    let currencies = ["USD", "EUR", "GBP", "INR"];
+    let mut code = String::from("default: [\"stripe\"]\n");
+
+    for band in 0..band_count {
+        for currency in currencies {
+            code.push_str(&format!(
+                r#"
+        band_{band}_{currency}: ["adyen", "checkout"]
+        {{
+            currency = {currency} & amount >= {} & amount < {} {{
+                payment_method = (card, wallet)
+                capture_method = manual
+            }}
+        }}
+"#,
+                band * 100,
+                (band + 1) * 100
+            ));
+        }
+    }
+
+    let (_, mut inp) = get_program_data();
+    inp.payment.amount = MinorUnit::new(band_count * 100 - 1);
+    inp.payment.currency = enums::Currency::INR;
+    inp.payment.capture_method = Some(enums::CaptureMethod::Manual);
+    inp.payment_method.payment_method = Some(enums::PaymentMethod::Card);
+
+    let (_, program) = parser::program(&code).expect("Parser");
+
+    (program, inp)
+}
+
 fn interpreter_vs_jit_vs_vir_interpreter(c: &mut Criterion) {
     let (program, binputs) = get_program_data();
 
     let interp_b = InterpreterBackend::with_program(program.clone()).expect("Interpreter backend");
 
     let vir_interp_b =
-        VirInterpreterBackend::with_program(program).expect("Vir Interpreter Backend");
+        VirInterpreterBackend::with_program(program.clone()).expect("Vir Interpreter Backend");
+
+    let decision_table_b =
+        DecisionTableBackend::with_program(program).expect("Decision Table Backend");
 
     c.bench_function("Raw Interpreter Backend", |b| {
         b.iter(|| {
@@ -91,7 +133,64 @@ fn interpreter_vs_jit_vs_vir_interpreter(c: &mut Criterion) {
                 .expect("Vir Interpreter execution");
         })
     });
+
+    c.bench_function("Decision Table Backend", |b| {
+        b.iter(|| {
+            decision_table_b
+                .execute(binputs.clone())
+                .expect("Decision Table execution");
+        })
+    });
+}
+
+fn large_rule_sets(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Large rule sets");
+
+    for band_count in [25, 125] {
+        let (program, binputs) = get_large_program_data(band_count);
+        let rule_count = program.rules.len();
+
+        let interp_b =
+            InterpreterBackend::with_program(program.clone()).expect("Interpreter backend");
+        let vir_interp_b =
+            VirInterpreterBackend::with_program(program.clone()).expect("Vir Interpreter Backend");
+        let decision_table_b =
+            DecisionTableBackend::with_program(program).expect("Decision Table Backend");
+
+        group.bench_function(format!("Raw Interpreter Backend/{rule_count} rules"), |b| {
+            b.iter(|| {
+                interp_b
+                    .execute(black_box(binputs.clone()))
+                    .expect("Interpreter EXECUTION");
+            })
+        });
+
+        group.bench_function(
+            format!("Valued Interpreter Backend/{rule_count} rules"),
+            |b| {
+                b.iter(|| {
+                    vir_interp_b
+                        .execute(black_box(binputs.clone()))
+                        .expect("Vir Interpreter execution");
+                })
+            },
+        );
+
+        group.bench_function(format!("Decision Table Backend/{rule_count} rules"), |b| {
+            b.iter(|| {
+                decision_table_b
+                    .execute(black_box(binputs.clone()))
+                    .expect("Decision Table execution");
+            })
+        });
+    }
+
+    group.finish();
 }
 
-criterion_group!(benches, interpreter_vs_jit_vs_vir_interpreter);
+criterion_group!(
+    benches,
+    interpreter_vs_jit_vs_vir_interpreter,
+    large_rule_sets
+);
 criterion_main!(benches);
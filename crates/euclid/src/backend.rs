@@ -1,8 +1,12 @@
+#[cfg(feature = "valued_jit")]
+pub mod decision_table;
 pub mod inputs;
 pub mod interpreter;
 #[cfg(feature = "valued_jit")]
 pub mod vir_interpreter;
 
+#[cfg(feature = "valued_jit")]
+pub use decision_table::DecisionTableBackend;
 pub use inputs::BackendInput;
 pub use interpreter::InterpreterBackend;
 #[cfg(feature = "valued_jit")]
@@ -0,0 +1,668 @@
+//! A backend that compiles a program into indexes over its comparisons, for rule sets too large
+//! to be evaluated rule by rule on every payment.
+//!
+//! Every root to leaf path of IF statements in a rule is a conjunction of comparisons, and a rule
+//! matches when any of its paths does. At execution, the values of the context are looked up in
+//! per key indexes (hash maps for enum, string and metadata values, sorted thresholds for numbers)
+//! to find the comparisons that hold, and the paths requiring a comparison that does not hold are
+//! removed from the set of candidate paths. The first remaining path decides the rule, so the
+//! result is the same as evaluating the rules in order.
+
+pub mod types;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    backend::{self, inputs, vir_interpreter, EuclidBackend},
+    frontend::{
+        ast,
+        dir::{self, EuclidDirFilter},
+        vir,
+    },
+    types::{DataType, EuclidKey, EuclidValue, NumValueRefinement},
+};
+
+/// The comparisons of a numeric key that hold for a number, indexed by their thresholds
+#[derive(Debug, Clone, Default)]
+struct NumericIndex {
+    equal: FxHashMap<i64, Vec<usize>>,
+    not_equal: Vec<(i64, usize)>,
+    greater_than: Vec<(i64, usize)>,
+    greater_than_equal: Vec<(i64, usize)>,
+    less_than: Vec<(i64, usize)>,
+    less_than_equal: Vec<(i64, usize)>,
+}
+
+impl NumericIndex {
+    fn sort(&mut self) {
+        self.not_equal.sort_unstable();
+        self.greater_than.sort_unstable();
+        self.greater_than_equal.sort_unstable();
+        self.less_than.sort_unstable();
+        self.less_than_equal.sort_unstable();
+    }
+
+    /// Marks the comparisons that are satisfied by `number`
+    fn mark_present(&self, number: i64, present: &mut types::BitSet) {
+        if let Some(comparisons) = self.equal.get(&number) {
+            comparisons
+                .iter()
+                .for_each(|comparison| present.insert(*comparison));
+        }
+
+        let below = self.not_equal.partition_point(|(t, _)| *t < number);
+        let above = self.not_equal.partition_point(|(t, _)| *t <= number);
+        Self::mark(self.not_equal.get(..below), present);
+        Self::mark(self.not_equal.get(above..), present);
+
+        let split = self.greater_than.partition_point(|(t, _)| *t < number);
+        Self::mark(self.greater_than.get(..split), present);
+
+        let split = self
+            .greater_than_equal
+            .partition_point(|(t, _)| *t <= number);
+        Self::mark(self.greater_than_equal.get(..split), present);
+
+        let split = self.less_than.partition_point(|(t, _)| *t <= number);
+        Self::mark(self.less_than.get(split..), present);
+
+        let split = self.less_than_equal.partition_point(|(t, _)| *t < number);
+        Self::mark(self.less_than_equal.get(split..), present);
+    }
+
+    fn mark(entries: Option<&[(i64, usize)]>, present: &mut types::BitSet) {
+        entries
+            .into_iter()
+            .flatten()
+            .for_each(|(_, comparison)| present.insert(*comparison));
+    }
+}
+
+/// Deduplicates the comparisons of the program while it is being compiled
+#[derive(Default)]
+struct ComparisonInterner {
+    ids: FxHashMap<(bool, Vec<EuclidValue>), usize>,
+    comparisons: Vec<vir::ValuedComparison>,
+}
+
+impl ComparisonInterner {
+    fn intern(&mut self, comparison: &vir::ValuedComparison) -> usize {
+        let is_negative = matches!(
+            comparison.logic,
+            vir::ValuedComparisonLogic::NegativeConjunction
+        );
+        let next_id = self.comparisons.len();
+        *self
+            .ids
+            .entry((is_negative, comparison.values.clone()))
+            .or_insert_with(|| {
+                self.comparisons.push(comparison.clone());
+                next_id
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DecisionTableBackend<O> {
+    default_selection: O,
+    rules: Vec<vir::ValuedRule<O>>,
+    /// The index of the rule each path belongs to, paths being ordered by rule
+    path_rules: Vec<usize>,
+    comparison_count: usize,
+    /// The comparisons that hold when none of their values are present
+    negative_comparisons: types::BitSet,
+    /// The comparisons each enum, string and metadata value appears in
+    atomic_index: FxHashMap<EuclidValue, Vec<usize>>,
+    numeric_indexes: FxHashMap<EuclidKey, NumericIndex>,
+    /// The paths requiring each comparison to hold
+    comparison_paths: Vec<types::BitSet>,
+}
+
+impl<O> DecisionTableBackend<O>
+where
+    O: Clone,
+{
+    fn collect_paths(
+        statements: &[vir::ValuedIfStatement],
+        prefix: &mut Vec<usize>,
+        paths: &mut Vec<Vec<usize>>,
+        interner: &mut ComparisonInterner,
+    ) {
+        for statement in statements {
+            let prefix_len = prefix.len();
+            prefix.extend(
+                statement
+                    .condition
+                    .iter()
+                    .map(|comparison| interner.intern(comparison)),
+            );
+
+            match &statement.nested {
+                Some(nested) => Self::collect_paths(nested, prefix, paths, interner),
+                None => paths.push(prefix.clone()),
+            }
+
+            prefix.truncate(prefix_len);
+        }
+    }
+
+    fn compile(program: vir::ValuedProgram<O>) -> Self {
+        let mut interner = ComparisonInterner::default();
+        let mut paths = Vec::new();
+        let mut path_rules = Vec::new();
+
+        for (rule_index, rule) in program.rules.iter().enumerate() {
+            let mut rule_paths = Vec::new();
+            Self::collect_paths(
+                &rule.statements,
+                &mut Vec::new(),
+                &mut rule_paths,
+                &mut interner,
+            );
+            path_rules.extend(std::iter::repeat(rule_index).take(rule_paths.len()));
+            paths.extend(rule_paths);
+        }
+
+        let comparison_count = interner.comparisons.len();
+        let mut negative_comparisons = types::BitSet::new(comparison_count);
+        let mut atomic_index = FxHashMap::<EuclidValue, Vec<usize>>::default();
+        let mut numeric_indexes = FxHashMap::<EuclidKey, NumericIndex>::default();
+
+        for (comparison_id, comparison) in interner.comparisons.iter().enumerate() {
+            if matches!(
+                comparison.logic,
+                vir::ValuedComparisonLogic::NegativeConjunction
+            ) {
+                negative_comparisons.insert(comparison_id);
+            }
+
+            for value in &comparison.values {
+                let key = value.get_key();
+                if !matches!(key.key_type(), DataType::Number) {
+                    atomic_index
+                        .entry(value.clone())
+                        .or_default()
+                        .push(comparison_id);
+                    continue;
+                }
+
+                // Numeric values of other kinds never match, as in the interpreter
+                let Some(num_value) = value.get_num_value() else {
+                    continue;
+                };
+                let index = numeric_indexes.entry(key).or_default();
+                let entry = (num_value.number.get_amount_as_i64(), comparison_id);
+                match num_value.refinement {
+                    None => index.equal.entry(entry.0).or_default().push(comparison_id),
+                    Some(NumValueRefinement::NotEqual) => index.not_equal.push(entry),
+                    Some(NumValueRefinement::GreaterThan) => index.greater_than.push(entry),
+                    Some(NumValueRefinement::GreaterThanEqual) => {
+                        index.greater_than_equal.push(entry)
+                    }
+                    Some(NumValueRefinement::LessThan) => index.less_than.push(entry),
+                    Some(NumValueRefinement::LessThanEqual) => index.less_than_equal.push(entry),
+                }
+            }
+        }
+        numeric_indexes.values_mut().for_each(NumericIndex::sort);
+
+        let mut comparison_paths = vec![types::BitSet::new(paths.len()); comparison_count];
+        for (path_id, path) in paths.iter().enumerate() {
+            for comparison_id in path {
+                if let Some(required_by) = comparison_paths.get_mut(*comparison_id) {
+                    required_by.insert(path_id);
+                }
+            }
+        }
+
+        Self {
+            default_selection: program.default_selection,
+            rules: program.rules,
+            path_rules,
+            comparison_count,
+            negative_comparisons,
+            atomic_index,
+            numeric_indexes,
+            comparison_paths,
+        }
+    }
+
+    fn eval(&self, ctx: &vir_interpreter::types::Context) -> backend::BackendOutput<O> {
+        // Comparisons with at least one of their values present in the context
+        let mut present = types::BitSet::new(self.comparison_count);
+        for value in ctx.atomic_values() {
+            if let Some(comparisons) = self.atomic_index.get(value) {
+                comparisons
+                    .iter()
+                    .for_each(|comparison| present.insert(*comparison));
+            }
+        }
+        for (key, index) in &self.numeric_indexes {
+            if let Some(num_value) = ctx.get_num_value(key) {
+                index.mark_present(num_value.number.get_amount_as_i64(), &mut present);
+            }
+        }
+
+        // A positive comparison fails when none of its values are present and a negative one
+        // fails when any of them is
+        let mut failed = present;
+        failed.symmetric_difference_with(&self.negative_comparisons);
+        failed.complement();
+
+        let mut candidate_paths = types::BitSet::full(self.path_rules.len());
+        for comparison_id in failed.iter() {
+            if let Some(required_by) = self.comparison_paths.get(comparison_id) {
+                candidate_paths.difference_with(required_by);
+            }
+        }
+
+        candidate_paths
+            .first()
+            .and_then(|path_id| self.path_rules.get(path_id))
+            .and_then(|rule_index| self.rules.get(*rule_index))
+            .map_or_else(
+                || backend::BackendOutput {
+                    connector_selection: self.default_selection.clone(),
+                    rule_name: None,
+                },
+                |rule| backend::BackendOutput {
+                    connector_selection: rule.connector_selection.clone(),
+                    rule_name: Some(rule.name.clone()),
+                },
+            )
+    }
+}
+
+impl<O> EuclidBackend<O> for DecisionTableBackend<O>
+where
+    O: Clone + EuclidDirFilter,
+{
+    type Error = types::DecisionTableError;
+
+    fn with_program(program: ast::Program<O>) -> Result<Self, Self::Error> {
+        let dir_program = ast::lowering::lower_program(program)
+            .map_err(types::DecisionTableError::LoweringError)?;
+
+        let vir_program = dir::lowering::lower_program(dir_program)
+            .map_err(types::DecisionTableError::LoweringError)?;
+
+        Ok(Self::compile(vir_program))
+    }
+
+    fn execute(
+        &self,
+        input: inputs::BackendInput,
+    ) -> Result<backend::BackendOutput<O>, Self::Error> {
+        let ctx = vir_interpreter::types::Context::from_input(input);
+        Ok(self.eval(&ctx))
+    }
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod test {
+    #![allow(clippy::expect_used)]
+
+    use common_utils::types::MinorUnit;
+    use rustc_hash::FxHashMap;
+
+    use super::*;
+    use crate::{
+        backend::{InterpreterBackend, VirInterpreterBackend},
+        enums,
+        types::DummyOutput,
+    };
+
+    /// A xorshift generator, so that the generated programs are the same on every run
+    struct Generator(u64);
+
+    impl Generator {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: usize) -> usize {
+            let bound = u64::try_from(bound).expect("Bound");
+            usize::try_from(self.next_u64() % bound).expect("Index")
+        }
+
+        fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+            items.get(self.below(items.len())).expect("Item")
+        }
+
+        fn chance(&mut self) -> bool {
+            self.next_u64() % 2 == 0
+        }
+    }
+
+    const PAYMENT_METHODS: &[&str] = &["card", "pay_later", "wallet", "bank_redirect"];
+    const CURRENCIES: &[&str] = &["USD", "EUR", "GBP", "INR"];
+    const CAPTURE_METHODS: &[&str] = &["automatic", "manual"];
+    const AUTHENTICATION_TYPES: &[&str] = &["three_ds", "no_three_ds"];
+    const AMOUNTS: &[i64] = &[10, 100, 500, 1000, 5000];
+
+    fn enum_comparison(generator: &mut Generator, key: &str, variants: &[&str]) -> String {
+        let operator = if generator.chance() { "=" } else { "/=" };
+        if generator.chance() {
+            format!("{key} {operator} {}", generator.pick(variants))
+        } else {
+            format!(
+                "{key} {operator} ({}, {})",
+                generator.pick(variants),
+                generator.pick(variants)
+            )
+        }
+    }
+
+    /// `/=` is left out of the comparisons of the raw interpreter, as the lowering turns it
+    /// into a negated `!=` which only holds for the given number
+    fn number_comparison(generator: &mut Generator, key: &str, not_equal: bool) -> String {
+        let number = generator.pick(AMOUNTS);
+        match generator.below(8) {
+            0 => format!("{key} = {number}"),
+            1 if not_equal => format!("{key} /= {number}"),
+            1 => format!("{key} <= {number}"),
+            2 => format!("{key} > {number}"),
+            3 => format!("{key} >= {number}"),
+            4 => format!("{key} < {number}"),
+            5 => format!("{key} <= {number}"),
+            6 => format!("{key} = ({number}, {})", generator.pick(AMOUNTS)),
+            _ => format!("{key} = (> {number}, < {})", generator.pick(AMOUNTS)),
+        }
+    }
+
+    /// A comparison on a key the raw interpreter understands
+    fn basic_comparison(generator: &mut Generator, key_index: usize) -> String {
+        match key_index {
+            0 => enum_comparison(generator, "payment_method", PAYMENT_METHODS),
+            1 => enum_comparison(generator, "currency", CURRENCIES),
+            2 => enum_comparison(generator, "capture_method", CAPTURE_METHODS),
+            3 => enum_comparison(generator, "authentication_type", AUTHENTICATION_TYPES),
+            _ => number_comparison(generator, "amount", false),
+        }
+    }
+
+    fn extended_comparison(generator: &mut Generator, key_index: usize) -> String {
+        match key_index {
+            0..=3 => basic_comparison(generator, key_index),
+            4 => number_comparison(generator, "amount", true),
+            5 => number_comparison(generator, "amount_in_base_currency", true),
+            6 => format!(
+                "\"tier\" = \"{}\"",
+                generator.pick(&["gold", "silver", "bronze"])
+            ),
+            7 => format!("card_bin = \"{}\"", generator.pick(&["424242", "510510"])),
+            8 => enum_comparison(generator, "pay_later", &["klarna", "affirm"]),
+            9 => enum_comparison(
+                generator,
+                "setup_future_usage",
+                &["off_session", "on_session"],
+            ),
+            10 => format!(
+                "any({}, {})",
+                basic_comparison(generator, 0),
+                number_comparison(generator, "amount", true)
+            ),
+            _ => format!(
+                "not({})",
+                enum_comparison(generator, "currency", CURRENCIES)
+            ),
+        }
+    }
+
+    fn condition(generator: &mut Generator, extended: bool) -> String {
+        let key_count = if extended { 12 } else { 5 };
+        let mut keys = Vec::new();
+        for _ in 0..=generator.below(2) {
+            let key_index = generator.below(key_count);
+            if !keys.contains(&key_index) {
+                keys.push(key_index);
+            }
+        }
+
+        keys.into_iter()
+            .map(|key_index| {
+                if extended {
+                    extended_comparison(generator, key_index)
+                } else {
+                    basic_comparison(generator, key_index)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" & ")
+    }
+
+    fn statements(generator: &mut Generator, depth: usize, extended: bool) -> Vec<String> {
+        let indent = "    ".repeat(depth);
+        let mut lines = Vec::new();
+        for _ in 0..=generator.below(2) {
+            let condition = condition(generator, extended);
+            if depth < 2 && generator.below(3) == 0 {
+                lines.push(format!("{indent}{condition} {{"));
+                lines.extend(statements(generator, depth + 1, extended));
+                lines.push(format!("{indent}}}"));
+            } else {
+                lines.push(format!("{indent}{condition}"));
+            }
+        }
+        lines
+    }
+
+    fn generate_program(generator: &mut Generator, extended: bool) -> ast::Program<DummyOutput> {
+        let mut lines = vec!["default: [\"default\"]".to_string()];
+        for rule_index in 0..=generator.below(8) {
+            lines.push(String::new());
+            lines.push(format!("rule_{rule_index}: [\"connector_{rule_index}\"]"));
+            lines.push("{".to_string());
+            lines.extend(statements(generator, 1, extended));
+            lines.push("}".to_string());
+        }
+        lines.push(String::new());
+
+        let program_str = lines.join("\n");
+        let (_, program) = ast::parser::program::<DummyOutput>(&program_str).expect("Program");
+        program
+    }
+
+    fn optional(generator: &mut Generator, complete: bool) -> bool {
+        complete || generator.chance()
+    }
+
+    fn generate_input(generator: &mut Generator, complete: bool) -> inputs::BackendInput {
+        let amount = MinorUnit::new(*generator.pick(&[5, 10, 50, 100, 500, 1000, 5000, 10000]));
+
+        inputs::BackendInput {
+            metadata: optional(generator, complete).then(|| {
+                FxHashMap::from_iter([(
+                    "tier".to_string(),
+                    generator.pick(&["gold", "silver"]).to_string(),
+                )])
+            }),
+            payment: inputs::PaymentInput {
+                amount,
+                amount_in_base_currency: optional(generator, complete)
+                    .then(|| MinorUnit::new(*generator.pick(&[10, 100, 1000]))),
+                currency: *generator.pick(&[
+                    enums::Currency::USD,
+                    enums::Currency::EUR,
+                    enums::Currency::GBP,
+                    enums::Currency::INR,
+                ]),
+                authentication_type: optional(generator, complete).then(|| {
+                    *generator.pick(&[
+                        enums::AuthenticationType::ThreeDs,
+                        enums::AuthenticationType::NoThreeDs,
+                    ])
+                }),
+                card_bin: optional(generator, complete)
+                    .then(|| generator.pick(&["424242", "510510"]).to_string()),
+                capture_method: optional(generator, complete).then(|| {
+                    *generator.pick(&[
+                        enums::CaptureMethod::Automatic,
+                        enums::CaptureMethod::Manual,
+                    ])
+                }),
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: optional(generator, complete)
+                    .then_some(enums::SetupFutureUsage::OffSession),
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: optional(generator, complete).then(|| {
+                    *generator.pick(&[
+                        enums::PaymentMethod::Card,
+                        enums::PaymentMethod::PayLater,
+                        enums::PaymentMethod::Wallet,
+                        enums::PaymentMethod::BankRedirect,
+                    ])
+                }),
+                payment_method_type: optional(generator, complete).then(|| {
+                    *generator.pick(&[
+                        enums::PaymentMethodType::Klarna,
+                        enums::PaymentMethodType::Affirm,
+                    ])
+                }),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            time: None,
+        }
+    }
+
+    fn assert_same_output(
+        expected: &backend::BackendOutput<DummyOutput>,
+        actual: &backend::BackendOutput<DummyOutput>,
+        program: &ast::Program<DummyOutput>,
+        input: &inputs::BackendInput,
+    ) {
+        assert_eq!(
+            (&expected.rule_name, &expected.connector_selection),
+            (&actual.rule_name, &actual.connector_selection),
+            "Program: {program:?}\nInput: {input:?}"
+        );
+    }
+
+    #[test]
+    fn test_matches_vir_interpreter() {
+        let mut generator = Generator(0x5eed_1234_abcd_0001);
+        let mut compiled_programs = 0;
+
+        for _ in 0..200 {
+            let program = generate_program(&mut generator, true);
+            let Ok(vir_backend) = VirInterpreterBackend::with_program(program.clone()) else {
+                assert!(DecisionTableBackend::with_program(program).is_err());
+                continue;
+            };
+            let decision_table =
+                DecisionTableBackend::with_program(program.clone()).expect("Decision table");
+            compiled_programs += 1;
+
+            for _ in 0..50 {
+                let input = generate_input(&mut generator, false);
+                let expected = vir_backend.execute(input.clone()).expect("Vir execution");
+                let actual = decision_table
+                    .execute(input.clone())
+                    .expect("Decision table execution");
+                assert_same_output(&expected, &actual, &program, &input);
+            }
+        }
+
+        assert!(compiled_programs > 100);
+    }
+
+    #[test]
+    fn test_matches_interpreter() {
+        let mut generator = Generator(0x0bad_cafe_f00d_0002);
+        let mut compiled_programs = 0;
+
+        for _ in 0..200 {
+            let program = generate_program(&mut generator, false);
+            let Ok(decision_table) = DecisionTableBackend::with_program(program.clone()) else {
+                continue;
+            };
+            let interpreter =
+                InterpreterBackend::with_program(program.clone()).expect("Interpreter");
+            compiled_programs += 1;
+
+            // The raw interpreter treats a missing value as failing every comparison, so only
+            // complete inputs are compared
+            for _ in 0..50 {
+                let input = generate_input(&mut generator, true);
+                let expected = interpreter
+                    .execute(input.clone())
+                    .expect("Interpreter execution");
+                let actual = decision_table
+                    .execute(input.clone())
+                    .expect("Decision table execution");
+                assert_same_output(&expected, &actual, &program, &input);
+            }
+        }
+
+        assert!(compiled_programs > 100);
+    }
+
+    #[test]
+    fn test_rule_order_and_nesting() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+            payment_method = card {
+                amount > 1000
+                currency = EUR
+            }
+        }
+
+        rule_2: ["checkout"]
+        {
+            amount >= 500
+        }
+
+        rule_3: ["braintree"]
+        {
+            card_bin /= "424242"
+        }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = DecisionTableBackend::with_program(program).expect("Decision table");
+
+        let mut generator = Generator(1);
+        let mut input = generate_input(&mut generator, true);
+        input.payment_method.payment_method = Some(enums::PaymentMethod::Card);
+        input.payment.currency = enums::Currency::USD;
+        input.payment.amount = MinorUnit::new(5000);
+        input.payment.card_bin = None;
+
+        let result = backend.execute(input.clone()).expect("Execution");
+        assert_eq!(result.rule_name.as_deref(), Some("rule_1"));
+
+        input.payment.amount = MinorUnit::new(500);
+        let result = backend.execute(input.clone()).expect("Execution");
+        assert_eq!(result.rule_name.as_deref(), Some("rule_2"));
+
+        // Negative comparisons hold when the value is absent
+        input.payment.amount = MinorUnit::new(10);
+        let result = backend.execute(input.clone()).expect("Execution");
+        assert_eq!(result.rule_name.as_deref(), Some("rule_3"));
+
+        input.payment.card_bin = Some("424242".to_string());
+        let result = backend.execute(input).expect("Execution");
+        assert_eq!(result.rule_name, None);
+        assert_eq!(
+            result.connector_selection.outputs,
+            vec!["stripe".to_string()]
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::dssa;
+
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+pub enum DecisionTableError {
+    #[error("Error when lowering the program: {0:?}")]
+    LoweringError(dssa::types::AnalysisError),
+}
+
+const WORD_BITS: usize = 64;
+
+/// A fixed size set of indices
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    pub fn full(len: usize) -> Self {
+        let mut set = Self {
+            words: vec![u64::MAX; len.div_ceil(WORD_BITS)],
+            len,
+        };
+        set.clear_excess_bits();
+        set
+    }
+
+    pub fn insert(&mut self, index: usize) {
+        if let Some(word) = self.words.get_mut(index / WORD_BITS) {
+            *word |= 1 << (index % WORD_BITS);
+        }
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.words
+            .get(index / WORD_BITS)
+            .is_some_and(|word| word & (1 << (index % WORD_BITS)) != 0)
+    }
+
+    pub fn difference_with(&mut self, other: &Self) {
+        self.words
+            .iter_mut()
+            .zip(other.words.iter())
+            .for_each(|(word, other_word)| *word &= !other_word);
+    }
+
+    /// Flips every index of the set
+    pub fn complement(&mut self) {
+        self.words.iter_mut().for_each(|word| *word = !*word);
+        self.clear_excess_bits();
+    }
+
+    /// Flips the indices that are present in `other`
+    pub fn symmetric_difference_with(&mut self, other: &Self) {
+        self.words
+            .iter_mut()
+            .zip(other.words.iter())
+            .for_each(|(word, other_word)| *word ^= other_word);
+    }
+
+    pub fn first(&self) -> Option<usize> {
+        self.iter().next()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, word)| {
+                let mut remaining = *word;
+                std::iter::from_fn(move || {
+                    if remaining == 0 {
+                        return None;
+                    }
+                    let bit = usize::try_from(remaining.trailing_zeros()).ok()?;
+                    remaining &= remaining - 1;
+                    Some(word_index * WORD_BITS + bit)
+                })
+            })
+    }
+
+    fn clear_excess_bits(&mut self) {
+        let excess = self.len % WORD_BITS;
+        if excess != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << excess) - 1;
+            }
+        }
+    }
+}
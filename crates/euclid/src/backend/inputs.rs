@@ -1,9 +1,12 @@
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
+pub use time::UtcOffset;
 
 use crate::{
     enums,
-    frontend::dir::enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+    frontend::dir::enums::{
+        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +26,9 @@ pub struct PaymentMethodInput {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentInput {
     pub amount: common_utils::types::MinorUnit,
+    /// The amount converted to the base currency configured on the profile, if it could be computed
+    #[serde(default)]
+    pub amount_in_base_currency: Option<common_utils::types::MinorUnit>,
     pub currency: enums::Currency,
     pub authentication_type: Option<enums::AuthenticationType>,
     pub card_bin: Option<String>,
@@ -52,6 +58,61 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeInput {
+    pub hour_of_day: u8,
+    pub day_of_week: DayOfWeek,
+    /// The calendar date written as YYYYMMDD
+    pub calendar_date: u32,
+}
+
+impl TimeInput {
+    /// Builds the time input for the given unix timestamp as seen at the provided UTC offset
+    pub fn from_unix_timestamp(timestamp: i64, utc_offset: UtcOffset) -> Option<Self> {
+        let local_time = time::OffsetDateTime::from_unix_timestamp(timestamp)
+            .ok()?
+            .to_offset(utc_offset);
+
+        let day_of_week = match local_time.weekday() {
+            time::Weekday::Monday => DayOfWeek::Monday,
+            time::Weekday::Tuesday => DayOfWeek::Tuesday,
+            time::Weekday::Wednesday => DayOfWeek::Wednesday,
+            time::Weekday::Thursday => DayOfWeek::Thursday,
+            time::Weekday::Friday => DayOfWeek::Friday,
+            time::Weekday::Saturday => DayOfWeek::Saturday,
+            time::Weekday::Sunday => DayOfWeek::Sunday,
+        };
+
+        Some(Self {
+            hour_of_day: local_time.hour(),
+            day_of_week,
+            calendar_date: u32::try_from(local_time.year()).ok()? * 10000
+                + u32::from(u8::from(local_time.month())) * 100
+                + u32::from(local_time.day()),
+        })
+    }
+}
+
+/// Parses a UTC offset written as `+HH:MM` or `-HH:MM`, for example `+05:30`
+pub fn parse_utc_offset(utc_offset: &str) -> Option<UtcOffset> {
+    let (sign, utc_offset) = match utc_offset.strip_prefix('+') {
+        Some(utc_offset) => (1, utc_offset),
+        None => (-1, utc_offset.strip_prefix('-')?),
+    };
+    let (hours, minutes) = utc_offset.split_once(':')?;
+    let parse_component = |component: &str| {
+        (component.len() == 2 && component.bytes().all(|byte| byte.is_ascii_digit()))
+            .then(|| component.parse::<i8>().ok())
+            .flatten()
+    };
+    let (hours, minutes) = (parse_component(hours)?, parse_component(minutes)?);
+
+    if hours > 14 || minutes > 59 {
+        return None;
+    }
+    UtcOffset::from_hms(sign * hours, sign * minutes, 0).ok()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -61,4 +122,46 @@ pub struct BackendInput {
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub time: Option<TimeInput>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_utc_offset() {
+        assert_eq!(
+            parse_utc_offset("+05:30"),
+            Some(UtcOffset::from_hms(5, 30, 0).expect("UTC offset"))
+        );
+        assert_eq!(
+            parse_utc_offset("-03:00"),
+            Some(UtcOffset::from_hms(-3, 0, 0).expect("UTC offset"))
+        );
+        assert_eq!(parse_utc_offset("+00:00"), Some(UtcOffset::UTC));
+
+        for invalid_offset in [
+            "05:30", "+5:30", "+05:3", "+0530", "+15:00", "+05:60", "+-5:00",
+        ] {
+            assert_eq!(parse_utc_offset(invalid_offset), None, "{invalid_offset}");
+        }
+    }
+
+    #[test]
+    fn test_time_input_from_unix_timestamp() {
+        // 2025-12-06T22:30:00Z is Sunday 04:00 at +05:30
+        let time_input = TimeInput::from_unix_timestamp(
+            1_765_060_200,
+            parse_utc_offset("+05:30").expect("UTC offset"),
+        )
+        .expect("Time input");
+
+        assert_eq!(time_input.hour_of_day, 4);
+        assert_eq!(time_input.day_of_week, DayOfWeek::Sunday);
+        assert_eq!(time_input.calendar_date, 20251207);
+    }
 }
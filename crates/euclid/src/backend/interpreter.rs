@@ -80,12 +80,38 @@ where
         }
     }
 
+    fn eval_condition_expression(
+        expression: &ast::ConditionExpression,
+        ctx: &types::Context,
+    ) -> Result<bool, types::InterpreterError> {
+        match expression {
+            ast::ConditionExpression::Comparison(comparison) => {
+                Self::eval_comparison(comparison, ctx)
+            }
+            ast::ConditionExpression::Group(ast::ConditionGroup::Any(expressions)) => {
+                for expression in expressions {
+                    if Self::eval_condition_expression(expression, ctx)? {
+                        return Ok(true);
+                    }
+                }
+
+                Ok(false)
+            }
+            ast::ConditionExpression::Group(ast::ConditionGroup::All(expressions)) => {
+                Self::eval_if_condition(expressions, ctx)
+            }
+            ast::ConditionExpression::Group(ast::ConditionGroup::Not(expression)) => {
+                Ok(!Self::eval_condition_expression(expression, ctx)?)
+            }
+        }
+    }
+
     fn eval_if_condition(
-        condition: &ast::IfCondition,
+        condition: &[ast::ConditionExpression],
         ctx: &types::Context,
     ) -> Result<bool, types::InterpreterError> {
-        for comparison in condition {
-            let res = Self::eval_comparison(comparison, ctx)?;
+        for expression in condition {
+            let res = Self::eval_condition_expression(expression, ctx)?;
 
             if !res {
                 return Ok(false);
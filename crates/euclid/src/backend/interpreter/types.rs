@@ -1,5 +1,6 @@
 use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
+use common_utils::types::MinorUnit;
 use serde::Serialize;
 
 use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
@@ -37,7 +38,7 @@ impl Deref for Context {
 
 impl From<inputs::BackendInput> for Context {
     fn from(input: inputs::BackendInput) -> Self {
-        let ctx = HashMap::<String, Option<ValueType>>::from_iter([
+        let mut ctx = HashMap::<String, Option<ValueType>>::from_iter([
             (
                 EuclidKey::PaymentMethod.to_string(),
                 input
@@ -70,12 +71,37 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentAmount.to_string(),
                 Some(ValueType::Number(input.payment.amount)),
             ),
+            (
+                EuclidKey::AmountInBaseCurrency.to_string(),
+                input.payment.amount_in_base_currency.map(ValueType::Number),
+            ),
             (
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
         ]);
 
+        if let Some(time) = input.time {
+            ctx.extend([
+                (
+                    EuclidKey::HourOfDay.to_string(),
+                    Some(ValueType::Number(MinorUnit::new(i64::from(
+                        time.hour_of_day,
+                    )))),
+                ),
+                (
+                    EuclidKey::DayOfWeek.to_string(),
+                    Some(ValueType::EnumVariant(time.day_of_week.to_string())),
+                ),
+                (
+                    EuclidKey::CalendarDate.to_string(),
+                    Some(ValueType::Number(MinorUnit::new(i64::from(
+                        time.calendar_date,
+                    )))),
+                ),
+            ]);
+        }
+
         Self(ctx)
     }
 }
@@ -111,7 +111,7 @@ mod test {
     use rustc_hash::FxHashMap;
 
     use super::*;
-    use crate::{enums, types::DummyOutput};
+    use crate::{backend::interpreter::InterpreterBackend, enums, types::DummyOutput};
 
     #[test]
     fn test_execution() {
@@ -134,6 +134,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 card_bin: None,
                 currency: enums::Currency::USD,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -156,6 +157,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -177,6 +179,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -199,6 +202,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -221,6 +225,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -243,6 +248,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -265,6 +271,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -287,6 +294,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -309,6 +317,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -331,6 +340,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -353,6 +363,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 currency: enums::Currency::USD,
                 card_bin: Some("123456".to_string()),
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -375,6 +386,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -397,6 +409,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 currency: enums::Currency::USD,
                 card_bin: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -419,6 +432,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -441,6 +455,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 currency: enums::Currency::USD,
                 card_bin: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -463,6 +478,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -485,6 +501,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 currency: enums::Currency::USD,
                 card_bin: None,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -507,6 +524,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -531,6 +549,7 @@ mod test {
             metadata: Some(meta_map),
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
                 card_bin: None,
                 currency: enums::Currency::USD,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -553,6 +572,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -575,6 +595,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(150),
+                amount_in_base_currency: None,
                 card_bin: None,
                 currency: enums::Currency::USD,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -597,6 +618,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -628,6 +650,7 @@ mod test {
             metadata: None,
             payment: inputs::PaymentInput {
                 amount: MinorUnit::new(120),
+                amount_in_base_currency: None,
                 card_bin: None,
                 currency: enums::Currency::USD,
                 authentication_type: Some(enums::AuthenticationType::NoThreeDs),
@@ -650,6 +673,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            time: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -665,4 +689,260 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_time_based_rules() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        night: ["adyen"]
+        {
+           hour_of_day = (>= 22, < 6)
+        }
+
+        weekend: ["checkout"]
+        {
+           day_of_week = (saturday, sunday) & calendar_date >= 20251201 & calendar_date <= 20251231
+        }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let base_input = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(32),
+                amount_in_base_currency: None,
+                card_bin: None,
+                currency: enums::Currency::EUR,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: None,
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            time: None,
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let utc_offset = inputs::parse_utc_offset("+01:00").expect("UTC offset");
+
+        // 2025-12-06T22:30:00Z is Saturday 23:30 at +01:00
+        let mut night_input = base_input.clone();
+        night_input.time = Some(
+            inputs::TimeInput::from_unix_timestamp(1_765_060_200, utc_offset).expect("Time input"),
+        );
+        let result = backend.execute(night_input).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "night");
+
+        // 2025-12-06T12:00:00Z is Saturday 13:00 at +01:00
+        let mut weekend_input = base_input.clone();
+        weekend_input.time = Some(
+            inputs::TimeInput::from_unix_timestamp(1_765_022_400, utc_offset).expect("Time input"),
+        );
+        let result = backend.execute(weekend_input).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "weekend");
+
+        let result = backend.execute(base_input).expect("Execution");
+        assert!(result.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_amount_in_base_currency() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        high_value: ["adyen"]
+        {
+           amount_in_base_currency > 50000
+        }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let base_input = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(10_000_000),
+                amount_in_base_currency: None,
+                card_bin: None,
+                currency: enums::Currency::JPY,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: None,
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            time: None,
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let mut converted_input = base_input.clone();
+        converted_input.payment.amount_in_base_currency = Some(MinorUnit::new(6_700_000));
+        let result = backend.execute(converted_input).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "high_value");
+
+        let mut converted_input = base_input.clone();
+        converted_input.payment.amount_in_base_currency = Some(MinorUnit::new(6_700));
+        let result = backend.execute(converted_input).expect("Execution");
+        assert!(result.rule_name.is_none());
+
+        // The raw amount is never used in place of the converted amount
+        let result = backend.execute(base_input).expect("Execution");
+        assert!(result.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_condition_groups() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        grouped: ["adyen"]
+        {
+            any(payment_method = pay_later, all(payment_method = card, not(amount > 1000))) & not(capture_method = manual)
+        }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let input = |payment_method, amount, capture_method| inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(amount),
+                amount_in_base_currency: None,
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: None,
+                capture_method,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(payment_method),
+                payment_method_type: None,
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            time: None,
+        };
+        let cases = [
+            (
+                input(
+                    enums::PaymentMethod::PayLater,
+                    5000,
+                    Some(enums::CaptureMethod::Automatic),
+                ),
+                Some("grouped"),
+            ),
+            (
+                input(
+                    enums::PaymentMethod::Card,
+                    500,
+                    Some(enums::CaptureMethod::Automatic),
+                ),
+                Some("grouped"),
+            ),
+            (
+                input(
+                    enums::PaymentMethod::Card,
+                    5000,
+                    Some(enums::CaptureMethod::Automatic),
+                ),
+                None,
+            ),
+            (
+                input(
+                    enums::PaymentMethod::Card,
+                    500,
+                    Some(enums::CaptureMethod::Manual),
+                ),
+                None,
+            ),
+            // A negated comparison holds when its key is absent
+            (
+                input(enums::PaymentMethod::Card, 500, None),
+                Some("grouped"),
+            ),
+        ];
+
+        let interpreter =
+            InterpreterBackend::<DummyOutput>::with_program(program.clone()).expect("Program");
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        for (input, expected) in cases {
+            let interpreted = interpreter.execute(input.clone()).expect("Execution");
+            let result = backend.execute(input).expect("Execution");
+            assert_eq!(result.rule_name.as_deref(), expected);
+            assert_eq!(interpreted.rule_name.as_deref(), expected);
+        }
+    }
+
+    #[test]
+    fn test_condition_groups_deserialization() {
+        let statement: ast::IfStatement = serde_json::from_value(serde_json::json!({
+            "condition": [
+                {
+                    "lhs": "payment_method",
+                    "comparison": "equal",
+                    "value": { "type": "enum_variant", "value": "card" },
+                    "metadata": {}
+                },
+                {
+                    "not": {
+                        "any": [
+                            {
+                                "lhs": "amount",
+                                "comparison": "greater_than",
+                                "value": { "type": "number", "value": 1000 },
+                                "metadata": {}
+                            }
+                        ]
+                    }
+                }
+            ],
+            "nested": null
+        }))
+        .expect("IfStatement");
+
+        assert!(matches!(
+            statement.condition.as_slice(),
+            [
+                ast::ConditionExpression::Comparison(_),
+                ast::ConditionExpression::Group(ast::ConditionGroup::Not(_))
+            ]
+        ));
+    }
 }
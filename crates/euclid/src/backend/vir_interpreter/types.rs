@@ -1,9 +1,10 @@
+use common_utils::types::MinorUnit;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     backend::inputs::BackendInput,
     dssa,
-    types::{self, EuclidKey, EuclidValue, MetadataValue, NumValueRefinement, StrValue},
+    types::{self, EuclidKey, EuclidValue, MetadataValue, NumValue, NumValueRefinement, StrValue},
 };
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
@@ -50,6 +51,16 @@ impl Context {
         }
     }
 
+    pub fn atomic_values(&self) -> impl Iterator<Item = &EuclidValue> {
+        self.atomic_values.iter()
+    }
+
+    pub fn get_num_value(&self, key: &EuclidKey) -> Option<NumValue> {
+        self.numeric_values
+            .get(key)
+            .and_then(|value| value.get_num_value())
+    }
+
     pub fn from_input(input: BackendInput) -> Self {
         let payment = input.payment;
         let payment_method = input.payment_method;
@@ -143,7 +154,7 @@ impl Context {
             }
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
@@ -151,6 +162,34 @@ impl Context {
             }),
         )]);
 
+        if let Some(amount_in_base_currency) = payment.amount_in_base_currency {
+            numeric_values.insert(
+                EuclidKey::AmountInBaseCurrency,
+                EuclidValue::AmountInBaseCurrency(types::NumValue {
+                    number: amount_in_base_currency,
+                    refinement: None,
+                }),
+            );
+        }
+
+        if let Some(time) = input.time {
+            enum_values.insert(EuclidValue::DayOfWeek(time.day_of_week));
+            numeric_values.insert(
+                EuclidKey::HourOfDay,
+                EuclidValue::HourOfDay(types::NumValue {
+                    number: MinorUnit::new(i64::from(time.hour_of_day)),
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::CalendarDate,
+                EuclidValue::CalendarDate(types::NumValue {
+                    number: MinorUnit::new(i64::from(time.calendar_date)),
+                    refinement: None,
+                }),
+            );
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
@@ -18,7 +18,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValue, NumValueRefinement},
 };
 
 /// Analyses conflicting assertions on the same key in a conjunctive context.
@@ -58,6 +58,64 @@ pub fn analyze_conflicting_assertions(
     Ok(())
 }
 
+/// Analyses conflicting ranges asserted on the same time key in a conjunctive context.
+///
+/// For example,
+/// ```notrust
+/// hour_of_day >= 22 && ... && hour_of_day < 6
+/// ```notrust
+/// These ranges do not overlap, so the condition will never evaluate to `true`. Ranges
+/// that wrap around midnight have to be written as a disjunction instead,
+/// eg. `hour_of_day = (>= 22, < 6)`.
+pub fn analyze_conflicting_ranges(
+    keywise_range_assertions: &FxHashMap<dir::DirKey, Vec<&dir::DirValue>>,
+    assertion_metadata: &FxHashMap<&dir::DirValue, &Metadata>,
+) -> Result<(), types::AnalysisError> {
+    for (key, values) in keywise_range_assertions {
+        let (lower_bound, upper_bound) = values.iter().filter_map(|val| val.get_num_value()).fold(
+            (i64::MIN, i64::MAX),
+            |(lower, upper), num_value| {
+                let number = num_value.number.get_amount_as_i64();
+
+                match num_value.refinement {
+                    None => (lower.max(number), upper.min(number)),
+                    Some(NumValueRefinement::GreaterThan) => {
+                        (lower.max(number.saturating_add(1)), upper)
+                    }
+                    Some(NumValueRefinement::GreaterThanEqual) => (lower.max(number), upper),
+                    Some(NumValueRefinement::LessThan) => {
+                        (lower, upper.min(number.saturating_sub(1)))
+                    }
+                    Some(NumValueRefinement::LessThanEqual) => (lower, upper.min(number)),
+                    Some(NumValueRefinement::NotEqual) => (lower, upper),
+                }
+            },
+        );
+
+        if lower_bound > upper_bound {
+            let error_type = types::AnalysisErrorType::ConflictingRanges {
+                key: key.clone(),
+                values: values
+                    .iter()
+                    .map(|val| types::ValueData {
+                        value: (*val).clone(),
+                        metadata: assertion_metadata
+                            .get(val)
+                            .map(|meta| (*meta).clone())
+                            .unwrap_or_default(),
+                    })
+                    .collect(),
+            };
+
+            Err(types::AnalysisError {
+                error_type,
+                metadata: Default::default(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
 /// Analyses exhaustive negations on the same key in a conjunctive context.
 ///
 /// For example,
@@ -151,6 +209,8 @@ fn perform_condition_analyses(
         FxHashMap::default();
     let mut keywise_negations: FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>> =
         FxHashMap::default();
+    let mut keywise_range_assertions: FxHashMap<dir::DirKey, Vec<&dir::DirValue>> =
+        FxHashMap::default();
 
     for ctx_val in context {
         let key = if let Some(k) = ctx_val.value.get_key() {
@@ -163,6 +223,18 @@ fn perform_condition_analyses(
             continue;
         }
 
+        if key.kind.is_time_key() && matches!(key.kind.get_type(), DataType::Number) {
+            if let types::CtxValueKind::Assertion(val) = ctx_val.value {
+                keywise_range_assertions
+                    .entry(key.clone())
+                    .or_default()
+                    .push(val);
+
+                assertion_metadata.insert(val, ctx_val.metadata);
+            }
+            continue;
+        }
+
         if !matches!(key.kind.get_type(), DataType::EnumVariant) {
             continue;
         }
@@ -194,6 +266,7 @@ fn perform_condition_analyses(
     }
 
     analyze_conflicting_assertions(&keywise_assertions, &assertion_metadata)?;
+    analyze_conflicting_ranges(&keywise_range_assertions, &assertion_metadata)?;
     analyze_exhaustive_negations(&keywise_negations, &keywise_negation_metadata)?;
     analyze_negated_assertions(
         &keywise_assertions,
@@ -238,6 +311,310 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     dir::lowering::lower_program(dir_program)
 }
 
+/// A single path through the nested if statements of a rule, the rule fires through this path
+/// when all the comparisons along it hold
+type RuleBranch<'a> = Vec<&'a dir::DirComparison>;
+
+fn collect_rule_branches<'a>(
+    statements: &'a [dir::DirIfStatement],
+    path: &mut RuleBranch<'a>,
+    branches: &mut Vec<RuleBranch<'a>>,
+) {
+    for stmt in statements {
+        let path_len = path.len();
+        path.extend(stmt.condition.iter());
+
+        match &stmt.nested {
+            Some(nested) => collect_rule_branches(nested, path, branches),
+            None => branches.push(path.clone()),
+        }
+
+        path.truncate(path_len);
+    }
+}
+
+/// The inclusive range of numbers satisfying a number value, `None` for a `NotEqual` refinement
+fn num_value_range(value: &NumValue) -> Option<(i64, i64)> {
+    let number = value.number.get_amount_as_i64();
+
+    match value.refinement {
+        None => Some((number, number)),
+        Some(NumValueRefinement::GreaterThan) => Some((number.saturating_add(1), i64::MAX)),
+        Some(NumValueRefinement::GreaterThanEqual) => Some((number, i64::MAX)),
+        Some(NumValueRefinement::LessThan) => Some((i64::MIN, number.saturating_sub(1))),
+        Some(NumValueRefinement::LessThanEqual) => Some((i64::MIN, number)),
+        Some(NumValueRefinement::NotEqual) => None,
+    }
+}
+
+/// Checks whether every input satisfying `value` also satisfies `other`
+fn value_implies(value: &dir::DirValue, other: &dir::DirValue) -> bool {
+    match (value.get_num_value(), other.get_num_value()) {
+        (Some(num_value), Some(other_num_value)) if value.get_key() == other.get_key() => {
+            match (
+                num_value_range(&num_value),
+                num_value_range(&other_num_value),
+            ) {
+                (Some((lower, upper)), Some((other_lower, other_upper))) => {
+                    other_lower <= lower && upper <= other_upper
+                }
+                (Some((lower, upper)), None) => {
+                    !(lower..=upper).contains(&other_num_value.number.get_amount_as_i64())
+                }
+                (None, None) => num_value.number == other_num_value.number,
+                (None, Some(_)) => false,
+            }
+        }
+        _ => value == other,
+    }
+}
+
+/// Checks whether no input satisfies both `value` and `other`
+fn values_disjoint(value: &dir::DirValue, other: &dir::DirValue) -> bool {
+    match (value.get_num_value(), other.get_num_value()) {
+        (Some(num_value), Some(other_num_value)) => {
+            match (
+                num_value_range(&num_value),
+                num_value_range(&other_num_value),
+            ) {
+                (Some((lower, upper)), Some((other_lower, other_upper))) => {
+                    upper < other_lower || other_upper < lower
+                }
+                _ => false,
+            }
+        }
+        _ => value != other,
+    }
+}
+
+/// Checks whether every input satisfying `comparison` also satisfies `other`
+///
+/// This is a conservative check, it can report `false` for comparisons that do imply each other
+/// but never reports `true` for comparisons that do not.
+fn comparison_implies(comparison: &dir::DirComparison, other: &dir::DirComparison) -> bool {
+    let same_key = comparison
+        .values
+        .first()
+        .zip(other.values.first())
+        .is_some_and(|(value, other_value)| value.get_key() == other_value.get_key());
+
+    if !same_key {
+        return false;
+    }
+
+    match (&comparison.logic, &other.logic) {
+        (
+            dir::DirComparisonLogic::PositiveDisjunction,
+            dir::DirComparisonLogic::PositiveDisjunction,
+        ) => comparison.values.iter().all(|value| {
+            other
+                .values
+                .iter()
+                .any(|other_value| value_implies(value, other_value))
+        }),
+        (
+            dir::DirComparisonLogic::NegativeConjunction,
+            dir::DirComparisonLogic::NegativeConjunction,
+        ) => other
+            .values
+            .iter()
+            .all(|other_value| comparison.values.contains(other_value)),
+        (
+            dir::DirComparisonLogic::PositiveDisjunction,
+            dir::DirComparisonLogic::NegativeConjunction,
+        ) => comparison.values.iter().all(|value| {
+            other
+                .values
+                .iter()
+                .all(|other_value| values_disjoint(value, other_value))
+        }),
+        // A negative conjunction holds when the key is absent, while a positive disjunction
+        // does not
+        (
+            dir::DirComparisonLogic::NegativeConjunction,
+            dir::DirComparisonLogic::PositiveDisjunction,
+        ) => false,
+    }
+}
+
+/// Checks whether every input taking `branch` also takes `other`
+fn branch_implies(branch: &RuleBranch<'_>, other: &RuleBranch<'_>) -> bool {
+    other.iter().all(|other_comparison| {
+        branch
+            .iter()
+            .any(|comparison| comparison_implies(comparison, other_comparison))
+    })
+}
+
+fn branch_metadata(branch: &RuleBranch<'_>) -> Vec<Metadata> {
+    branch
+        .iter()
+        .map(|comparison| comparison.metadata.clone())
+        .collect()
+}
+
+/// Analyses rules that can never fire because the rules preceding them always fire first.
+///
+/// For example,
+/// ```notrust
+/// rule_1: payment_method = (card, wallet)
+/// rule_2: payment_method = card & currency = USD
+/// ```notrust
+/// Every payment taking `rule_2` also takes `rule_1`, which is evaluated first, so `rule_2`
+/// is shadowed. It is reported as redundant instead when the shadowing rules select the same
+/// connectors, and only its shadowed branches are reported when it has other branches left.
+fn analyze_shadowed_rules<O: PartialEq>(
+    program: &dir::DirProgram<O>,
+) -> (Vec<types::RuleAnalysisWarning>, FxHashSet<usize>) {
+    let mut warnings = Vec::new();
+    let mut dead_rules = FxHashSet::default();
+    let mut preceding_branches: Vec<(&dir::DirRule<O>, RuleBranch<'_>)> = Vec::new();
+
+    for (rule_idx, rule) in program.rules.iter().enumerate() {
+        let mut branches = Vec::new();
+        collect_rule_branches(&rule.statements, &mut Vec::new(), &mut branches);
+
+        let shadowing_rules: Vec<Option<&dir::DirRule<O>>> = branches
+            .iter()
+            .map(|branch| {
+                preceding_branches
+                    .iter()
+                    .find(|(_, preceding_branch)| branch_implies(branch, preceding_branch))
+                    .map(|(preceding_rule, _)| *preceding_rule)
+            })
+            .collect();
+
+        let fully_shadowed_by = shadowing_rules
+            .iter()
+            .copied()
+            .collect::<Option<Vec<_>>>()
+            .filter(|shadowing_rules| !shadowing_rules.is_empty());
+
+        if let Some(shadowing_rules) = fully_shadowed_by {
+            let mut rule_names = Vec::<String>::new();
+            for shadowing_rule in &shadowing_rules {
+                if !rule_names.contains(&shadowing_rule.name) {
+                    rule_names.push(shadowing_rule.name.clone());
+                }
+            }
+
+            let warning_type = if shadowing_rules.iter().all(|shadowing_rule| {
+                shadowing_rule.connector_selection == rule.connector_selection
+            }) {
+                types::RuleAnalysisWarningType::RedundantRule {
+                    redundant_with: rule_names,
+                }
+            } else {
+                types::RuleAnalysisWarningType::ShadowedRule {
+                    shadowed_by: rule_names,
+                }
+            };
+
+            warnings.push(types::RuleAnalysisWarning {
+                rule_name: rule.name.clone(),
+                warning_type,
+                metadata: branches.iter().flat_map(branch_metadata).collect(),
+            });
+            dead_rules.insert(rule_idx);
+        } else {
+            for (branch, shadowing_rule) in branches.iter().zip(shadowing_rules) {
+                if let Some(shadowing_rule) = shadowing_rule {
+                    warnings.push(types::RuleAnalysisWarning {
+                        rule_name: rule.name.clone(),
+                        warning_type: types::RuleAnalysisWarningType::ShadowedBranch {
+                            shadowed_by: shadowing_rule.name.clone(),
+                        },
+                        metadata: branch_metadata(branch),
+                    });
+                }
+            }
+        }
+
+        preceding_branches.extend(branches.into_iter().map(|branch| (rule, branch)));
+    }
+
+    (warnings, dead_rules)
+}
+
+/// Analyses rules whose conditions cannot be satisfied together with their connector selection,
+/// as per the knowledge graph.
+fn analyze_unreachable_rules<O: EuclidAnalysable>(
+    program: &dir::DirProgram<O>,
+    knowledge_graph: &ConstraintGraph<dir::DirValue>,
+    skipped_rules: &FxHashSet<usize>,
+) -> Result<Vec<types::RuleAnalysisWarning>, types::AnalysisError> {
+    let selection_data = state_machine::make_connector_selection_data(program);
+    let mut warnings = Vec::new();
+
+    for (rule_idx, (rule, connector_selection_data)) in
+        program.rules.iter().zip(selection_data.iter()).enumerate()
+    {
+        if skipped_rules.contains(&rule_idx) {
+            continue;
+        }
+
+        let mut ctx_manager =
+            state_machine::RuleContextManager::new(rule, connector_selection_data);
+        let mut has_reachable_context = false;
+        let mut unreachable_contexts = Vec::new();
+
+        while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+            metadata: Default::default(),
+            error_type: types::AnalysisErrorType::StateMachine(err),
+        })? {
+            if perform_context_analyses(ctx, knowledge_graph).is_ok() {
+                has_reachable_context = true;
+            } else {
+                unreachable_contexts.push(
+                    ctx.iter()
+                        .map(|ctx_val| ctx_val.metadata.clone())
+                        .collect::<Vec<_>>(),
+                );
+            }
+        }
+
+        if !has_reachable_context && !unreachable_contexts.is_empty() {
+            warnings.push(types::RuleAnalysisWarning {
+                rule_name: rule.name.clone(),
+                warning_type: types::RuleAnalysisWarningType::UnreachableRule,
+                metadata: unreachable_contexts.into_iter().flatten().collect(),
+            });
+        } else {
+            warnings.extend(unreachable_contexts.into_iter().map(|metadata| {
+                types::RuleAnalysisWarning {
+                    rule_name: rule.name.clone(),
+                    warning_type: types::RuleAnalysisWarningType::UnreachableBranch,
+                    metadata,
+                }
+            }));
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// Performs the program level analyses, reporting rules and rule branches that can never be
+/// selected, either because preceding rules always fire first or because their conditions
+/// cannot be satisfied together with their connector selection as per the knowledge graph.
+///
+/// Unlike [`analyze`], these do not make the program invalid and are meant to be surfaced as
+/// warnings.
+pub fn analyze_rule_reachability<O: EuclidAnalysable + EuclidDirFilter + PartialEq>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<Vec<types::RuleAnalysisWarning>, types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+
+    let (mut warnings, dead_rules) = analyze_shadowed_rules(&dir_program);
+    warnings.extend(analyze_unreachable_rules(
+        &dir_program,
+        knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH),
+        &dead_rules,
+    )?);
+
+    Ok(warnings)
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     #![allow(clippy::panic, clippy::expect_used)]
@@ -295,6 +672,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conflicting_range_detection() {
+        let program_str = r#"
+            default: ["stripe", "adyen"]
+
+            night_only: ["adyen"]
+            {
+                hour_of_day >= 22 {
+                    payment_method = card & hour_of_day < 6
+                }
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::ConflictingRanges { key, values },
+            ..
+        }) = analysis_result
+        {
+            assert!(
+                matches!(key.kind, dir::DirKeyKind::HourOfDay),
+                "Key should be hour_of_day"
+            );
+            assert_eq!(values.len(), 2, "There should be 2 conflicting ranges");
+        } else {
+            panic!("Did not receive conflicting ranges error");
+        }
+
+        let program_str = r#"
+            default: ["stripe", "adyen"]
+
+            night_only: ["adyen"]
+            {
+                hour_of_day = (>= 22, < 6)
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        assert!(analyze(program, None).is_ok());
+    }
+
     #[test]
     fn test_exhaustive_negation_detection() {
         let program_str = r#"
@@ -378,6 +798,136 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_negated_condition_group_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = wallet & not(any(payment_method = card, payment_method = wallet))
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::NegatedAssertion { value, .. },
+            ..
+        }) = analysis_result
+        {
+            assert_eq!(
+                value,
+                dirval!(PaymentMethod = Wallet),
+                "Expected to catch payment_method = wallet as conflict"
+            );
+        } else {
+            panic!("Expected negated assertion error");
+        }
+    }
+
+    #[test]
+    fn test_shadowed_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = (card, wallet)
+            }
+
+            rule_2: ["stripe"]
+            {
+                payment_method = card & currency = USD
+            }
+
+            rule_3: ["adyen"]
+            {
+                amount > 1000 & payment_method = wallet
+            }
+
+            rule_4: ["stripe"]
+            {
+                payment_method = card & amount < 500
+                payment_method = pay_later
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_rule_reachability(program, None).expect("Analysis");
+
+        assert!(matches!(
+            warnings.as_slice(),
+            [
+                types::RuleAnalysisWarning {
+                    warning_type: types::RuleAnalysisWarningType::ShadowedRule { .. },
+                    ..
+                },
+                types::RuleAnalysisWarning {
+                    warning_type: types::RuleAnalysisWarningType::RedundantRule { .. },
+                    ..
+                },
+                types::RuleAnalysisWarning {
+                    warning_type: types::RuleAnalysisWarningType::ShadowedBranch { .. },
+                    ..
+                },
+            ]
+        ));
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|warning| warning.rule_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["rule_2", "rule_3", "rule_4"]
+        );
+    }
+
+    #[test]
+    fn test_unreachable_rule_detection() {
+        let graph = knowledge! {
+            CaptureMethod(Automatic) ->> PaymentMethod(Card);
+        };
+
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                capture_method = manual & payment_method = card
+            }
+
+            rule_2: ["stripe"]
+            {
+                capture_method = manual & payment_method = (card, pay_later)
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_rule_reachability(program, Some(&graph)).expect("Analysis");
+
+        assert!(matches!(
+            warnings.as_slice(),
+            [
+                types::RuleAnalysisWarning {
+                    warning_type: types::RuleAnalysisWarningType::UnreachableRule,
+                    ..
+                },
+                types::RuleAnalysisWarning {
+                    warning_type: types::RuleAnalysisWarningType::UnreachableBranch,
+                    ..
+                },
+            ]
+        ));
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|warning| warning.rule_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["rule_1", "rule_2"]
+        );
+    }
+
     #[test]
     fn test_negation_graph_analysis() {
         let graph = knowledge! {
@@ -53,6 +53,7 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CryptoType(ct) => ct.to_string(),
             Self::RewardType(rt) => rt.to_string(),
             Self::PaymentAmount(amt) => amt.number.to_string(),
+            Self::AmountInBaseCurrency(amt) => amt.number.to_string(),
             Self::PaymentCurrency(curr) => curr.to_string(),
             Self::AuthenticationType(at) => at.to_string(),
             Self::CaptureMethod(cm) => cm.to_string(),
@@ -82,6 +83,9 @@ impl cgraph::NodeViz for dir::DirValue {
             }
             Self::AcquirerCountry(acquirer_country) => acquirer_country.to_string(),
             Self::AcquirerFraudRate(acquirer_fraud_rate) => acquirer_fraud_rate.number.to_string(),
+            Self::HourOfDay(hour_of_day) => hour_of_day.number.to_string(),
+            Self::DayOfWeek(day_of_week) => day_of_week.to_string(),
+            Self::CalendarDate(calendar_date) => calendar_date.number.to_string(),
         }
     }
 }
@@ -127,6 +131,144 @@ impl<V: cgraph::ValueNode> AnalysisError<V> {
     }
 }
 
+/// Describes the constraints that failed in an analysis trace, one line per constraint. The
+/// constraints that a failure stems from are listed below it, indented one level deeper.
+pub fn explain_analysis_trace(trace: &cgraph::AnalysisTrace<dir::DirValue>) -> Vec<String> {
+    let mut lines = Vec::new();
+    explain_trace(trace, 0, &mut lines);
+    lines
+}
+
+/// Describes the failed constraints of an analysis error, returning the error back if it did not
+/// come out of the analysis itself.
+///
+/// The trace is only reachable while the memoization used for the analysis is alive.
+pub fn explain_graph_error(
+    error: cgraph::GraphError<dir::DirValue>,
+) -> Result<Vec<String>, cgraph::GraphError<dir::DirValue>> {
+    let trace = error.get_analysis_trace()?;
+    Ok(trace
+        .upgrade()
+        .map(|trace| explain_analysis_trace(&trace))
+        .unwrap_or_default())
+}
+
+fn explain_trace(
+    trace: &cgraph::AnalysisTrace<dir::DirValue>,
+    depth: usize,
+    lines: &mut Vec<String>,
+) {
+    let indent = "  ".repeat(depth);
+    let info_prefix =
+        |info: &Option<&'static str>| info.map_or(String::new(), |i| format!("{i}: "));
+
+    match trace {
+        cgraph::AnalysisTrace::Value {
+            value,
+            relation,
+            predecessors,
+            info,
+            ..
+        } => {
+            let value = match value {
+                cgraph::NodeValue::Key(key) => format!("`{}`", key.kind),
+                cgraph::NodeValue::Value(value) => {
+                    format!(
+                        "`{} = {}`",
+                        value.get_key().kind,
+                        cgraph::NodeViz::viz(value)
+                    )
+                }
+            };
+            let requirement = match relation {
+                cgraph::Relation::Positive => "is required",
+                cgraph::Relation::Negative => "is not allowed",
+            };
+            lines.push(format!(
+                "{indent}{}{value} {requirement}",
+                info_prefix(info)
+            ));
+
+            match predecessors {
+                Some(cgraph::error::ValueTracePredecessor::Mandatory(predecessor)) => {
+                    explain_weak_trace(predecessor, depth + 1, lines);
+                }
+                Some(cgraph::error::ValueTracePredecessor::OneOf(predecessors)) => predecessors
+                    .iter()
+                    .for_each(|predecessor| explain_weak_trace(predecessor, depth + 1, lines)),
+                None => {}
+            }
+        }
+
+        cgraph::AnalysisTrace::AllAggregation {
+            unsatisfied, info, ..
+        } => {
+            lines.push(format!(
+                "{indent}all of {} must hold",
+                info.unwrap_or("the constraints")
+            ));
+            unsatisfied
+                .iter()
+                .for_each(|trace| explain_weak_trace(trace, depth + 1, lines));
+        }
+
+        cgraph::AnalysisTrace::AnyAggregation {
+            unsatisfied, info, ..
+        } => {
+            lines.push(format!(
+                "{indent}at least one of {} must hold",
+                info.unwrap_or("the constraints")
+            ));
+            unsatisfied
+                .iter()
+                .for_each(|trace| explain_weak_trace(trace, depth + 1, lines));
+        }
+
+        cgraph::AnalysisTrace::InAggregation {
+            expected,
+            found,
+            relation,
+            info,
+            ..
+        } => {
+            let key = expected
+                .first()
+                .map(|value| value.get_key().kind.to_string())
+                .unwrap_or_default();
+            let expected = expected
+                .iter()
+                .map(cgraph::NodeViz::viz)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let found = found
+                .as_ref()
+                .map_or("none".to_string(), cgraph::NodeViz::viz);
+            let requirement = match relation {
+                cgraph::Relation::Positive => "must be one of",
+                cgraph::Relation::Negative => "must not be one of",
+            };
+            lines.push(format!(
+                "{indent}{}`{key}` {requirement} ({expected}), found {found}",
+                info_prefix(info)
+            ));
+        }
+
+        cgraph::AnalysisTrace::Contradiction { .. } => {
+            lines.push(format!("{indent}the constraints contradict each other"));
+        }
+    }
+}
+
+fn explain_weak_trace(
+    trace: &Weak<cgraph::AnalysisTrace<dir::DirValue>>,
+    depth: usize,
+    lines: &mut Vec<String>,
+) {
+    if let Some(trace) = trace.upgrade() {
+        explain_trace(&trace, depth, lines);
+    }
+}
+
 #[derive(Debug)]
 pub struct AnalysisContext {
     keywise_values: FxHashMap<dir::DirKey, FxHashSet<dir::DirValue>>,
@@ -255,6 +397,17 @@ pub trait CgraphExt {
         domains: Option<&[String]>,
     ) -> Result<bool, cgraph::GraphError<dir::DirValue>>;
 
+    /// Like `check_value_validity`, but describes the constraints that made the value invalid.
+    /// An empty list means the value is valid.
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        memo: &mut cgraph::Memoization<dir::DirValue>,
+        cycle_map: &mut cgraph::CycleCheck,
+        domains: Option<&[String]>,
+    ) -> Result<Vec<String>, cgraph::GraphError<dir::DirValue>>;
+
     fn key_value_analysis(
         &self,
         val: dir::DirValue,
@@ -372,6 +525,36 @@ impl CgraphExt for cgraph::ConstraintGraph<dir::DirValue> {
         }
     }
 
+    fn explain_value_validity(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        memo: &mut cgraph::Memoization<dir::DirValue>,
+        cycle_map: &mut cgraph::CycleCheck,
+        domains: Option<&[String]>,
+    ) -> Result<Vec<String>, cgraph::GraphError<dir::DirValue>> {
+        let Some(node_id) = self.value_map.get(&cgraph::NodeValue::Value(val.clone())) else {
+            return Ok(vec![format!(
+                "`{} = {}` is not configured",
+                val.get_key().kind,
+                cgraph::NodeViz::viz(&val)
+            )]);
+        };
+
+        match self.check_node(
+            analysis_ctx,
+            *node_id,
+            cgraph::Relation::Positive,
+            cgraph::Strength::Weak,
+            memo,
+            cycle_map,
+            domains,
+        ) {
+            Ok(()) => Ok(Vec::new()),
+            Err(error) => explain_graph_error(error),
+        }
+    }
+
     fn key_value_analysis(
         &self,
         val: dir::DirValue,
@@ -559,6 +742,40 @@ mod test {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_explain_value_validity() {
+        let graph = knowledge! {
+            PaymentMethod(Card) ->> CaptureMethod(Automatic);
+        };
+
+        let explanation = graph
+            .explain_value_validity(
+                dirval!(CaptureMethod = Automatic),
+                &AnalysisContext::from_dir_values([dirval!(CaptureMethod = Automatic)]),
+                &mut cgraph::Memoization::new(),
+                &mut CycleCheck::new(),
+                None,
+            )
+            .expect("Explanation");
+        assert!(explanation
+            .iter()
+            .any(|line| line.contains("`payment_method = card` is required")));
+
+        let explanation = graph
+            .explain_value_validity(
+                dirval!(CaptureMethod = Automatic),
+                &AnalysisContext::from_dir_values([
+                    dirval!(CaptureMethod = Automatic),
+                    dirval!(PaymentMethod = Card),
+                ]),
+                &mut cgraph::Memoization::new(),
+                &mut CycleCheck::new(),
+                None,
+            )
+            .expect("Explanation");
+        assert!(explanation.is_empty());
+    }
+
     #[test]
     fn test_strong_negative_relation_success() {
         let graph = knowledge! {
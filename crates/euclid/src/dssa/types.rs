@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fmt};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     dssa::{self, graph},
@@ -128,6 +128,12 @@ pub enum AnalysisErrorType {
         values: Vec<ValueData>,
     },
 
+    #[error("Conflicting ranges received for key '{}'", .key.kind)]
+    ConflictingRanges {
+        key: dir::DirKey,
+        values: Vec<ValueData>,
+    },
+
     #[error("Key '{}' exhaustively negated", .key.kind)]
     ExhaustiveNegation {
         key: dir::DirKey,
@@ -146,6 +152,10 @@ pub enum AnalysisErrorType {
     ),
     #[error("State machine error")]
     StateMachine(dssa::state_machine::StateMachineError),
+    #[error("Condition groups must contain at least one condition")]
+    EmptyConditionGroup,
+    #[error("Condition expands into more than {limit} branches")]
+    ConditionTooComplex { limit: usize },
     #[error("Unsupported program key '{0}'")]
     UnsupportedProgramKey(dir::DirKeyKind),
     #[error("Ran into an unimplemented feature")]
@@ -154,6 +164,32 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+/// A warning raised by the program level analyses, pointing at a rule that is valid on its own but
+/// is (partially) dead in the context of the whole program
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleAnalysisWarning {
+    pub rule_name: String,
+    #[serde(flatten)]
+    pub warning_type: RuleAnalysisWarningType,
+    /// Metadata of the conditions the warning was raised for
+    pub metadata: Vec<Metadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, thiserror::Error)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum RuleAnalysisWarningType {
+    #[error("Rule is always preceded by the rules {shadowed_by:?} and can never be selected")]
+    ShadowedRule { shadowed_by: Vec<String> },
+    #[error("Rule selects the same connectors as the preceding rules {redundant_with:?} and is redundant")]
+    RedundantRule { redundant_with: Vec<String> },
+    #[error("A branch of the rule is always preceded by the rule '{shadowed_by}'")]
+    ShadowedBranch { shadowed_by: String },
+    #[error("No branch of the rule can be satisfied along with its connector selection")]
+    UnreachableRule,
+    #[error("A branch of the rule cannot be satisfied along with its connector selection")]
+    UnreachableBranch,
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
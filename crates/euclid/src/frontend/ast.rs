@@ -1,6 +1,7 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+pub mod printer;
 
 use common_enums::RoutableConnectors;
 use common_utils::types::MinorUnit;
@@ -96,13 +97,45 @@ pub struct Comparison {
     pub metadata: Metadata,
 }
 
+/// Represents a boolean combination of conditions
+/// eg:
+///
+/// ```text
+/// any(payment.method.network = visa, all(billing_country = UnitedStatesOfAmerica, not(amount > 1000)))
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionGroup {
+    /// Holds if at least one of the conditions holds
+    Any(Vec<ConditionExpression>),
+    /// Holds if every one of the conditions holds
+    All(Vec<ConditionExpression>),
+    /// Holds if the condition does not hold
+    Not(Box<ConditionExpression>),
+}
+
+/// Represents a single entry of an IF condition, which is either a plain comparison or a
+/// boolean group of conditions
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum ConditionExpression {
+    Comparison(Comparison),
+    Group(ConditionGroup),
+}
+
+impl From<Comparison> for ConditionExpression {
+    fn from(comparison: Comparison) -> Self {
+        Self::Comparison(comparison)
+    }
+}
+
 /// Represents all the conditions of an IF statement
 /// eg:
 ///
 /// ```text
 /// payment.method = card & payment.method.cardtype = debit & payment.method.network = diners
 /// ```
-pub type IfCondition = Vec<Comparison>;
+pub type IfCondition = Vec<ConditionExpression>;
 
 /// Represents an IF statement with conditions and optional nested IF statements
 ///
@@ -116,7 +149,7 @@ pub type IfCondition = Vec<Comparison>;
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IfStatement {
-    #[schema(value_type=Vec<Comparison>)]
+    #[schema(value_type=Vec<ConditionExpression>)]
     pub condition: IfCondition,
     pub nested: Option<Vec<IfStatement>>,
 }
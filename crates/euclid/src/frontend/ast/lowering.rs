@@ -5,6 +5,8 @@
 
 use std::str::FromStr;
 
+use common_utils::types::MinorUnit;
+
 use crate::{
     dssa::types::{AnalysisError, AnalysisErrorType},
     enums::CollectVariants,
@@ -67,19 +69,23 @@ macro_rules! lower_enum {
 /// payment_amount = 17052001
 /// ```notrust
 /// This is for the cases in which there are numerical values involved and they are lowered
-/// accordingly on basis of the supplied key, currently payment_amount is the only key having this
-/// use case
+/// accordingly on basis of the supplied key. An optional validation closure can be supplied to
+/// restrict the range of numbers accepted for the key
 macro_rules! lower_number {
-    ($key:ident, $value:ident, $comp:ident) => {
+    ($key:ident, $value:ident, $comp:ident $(, $validation_closure:expr)?) => {
         match $value {
-            ast::ValueType::Number(num) => Ok(vec![dir::DirValue::$key(types::NumValue {
-                number: num,
-                refinement: $comp.into(),
-            })]),
+            ast::ValueType::Number(num) => {
+                $($validation_closure(num)?;)?
+                Ok(vec![dir::DirValue::$key(types::NumValue {
+                    number: num,
+                    refinement: $comp.into(),
+                })])
+            }
 
             ast::ValueType::NumberArray(na) => na
                 .into_iter()
                 .map(|num| {
+                    $($validation_closure(num)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: num,
                         refinement: $comp.clone().into(),
@@ -90,6 +96,7 @@ macro_rules! lower_number {
             ast::ValueType::NumberComparisonArray(nca) => nca
                 .into_iter()
                 .map(|nc| {
+                    $($validation_closure(nc.number)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: nc.number,
                         refinement: nc.comparison_type.into(),
@@ -265,6 +272,9 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value),
         dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value),
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
+        dir::DirKeyKind::AmountInBaseCurrency => {
+            lower_number!(AmountInBaseCurrency, value, comparison)
+        }
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
@@ -275,9 +285,54 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::CustomerDeviceDisplaySize => lower_enum!(CustomerDeviceDisplaySize, value),
         dir::DirKeyKind::AcquirerCountry => lower_enum!(AcquirerCountry, value),
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
+        dir::DirKeyKind::HourOfDay => {
+            let validation_closure = |num: MinorUnit| -> Result<(), AnalysisErrorType> {
+                if (0..=23).contains(&num.get_amount_as_i64()) {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::HourOfDay,
+                        value: num.to_string(),
+                        message: Some("Expected an hour between 0 and 23".to_string()),
+                    })
+                }
+            };
+            lower_number!(HourOfDay, value, comparison, validation_closure)
+        }
+        dir::DirKeyKind::DayOfWeek => lower_enum!(DayOfWeek, value),
+        dir::DirKeyKind::CalendarDate => {
+            let validation_closure = |num: MinorUnit| -> Result<(), AnalysisErrorType> {
+                if is_valid_calendar_date(num.get_amount_as_i64()) {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::CalendarDate,
+                        value: num.to_string(),
+                        message: Some("Expected a valid date written as YYYYMMDD".to_string()),
+                    })
+                }
+            };
+            lower_number!(CalendarDate, value, comparison, validation_closure)
+        }
     }
 }
 
+/// checks that the number is a date written as YYYYMMDD, for example 20251224
+fn is_valid_calendar_date(date: i64) -> bool {
+    let (year, month, day) = (date / 10000, (date / 100) % 100, date % 100);
+
+    i32::try_from(year)
+        .ok()
+        .zip(
+            u8::try_from(month)
+                .ok()
+                .and_then(|month| time::Month::try_from(month).ok()),
+        )
+        .zip(u8::try_from(day).ok())
+        .and_then(|((year, month), day)| time::Date::from_calendar_date(year, month, day).ok())
+        .is_some_and(|_| (1000..=9999).contains(&year))
+}
+
 /// returns all the comparison values by matching them appropriately to ComparisonTypes and in turn
 /// calls the lower_comparison_inner function
 fn lower_comparison<O: EuclidDirFilter>(
@@ -304,22 +359,142 @@ fn lower_comparison<O: EuclidDirFilter>(
     })
 }
 
+/// Upper bound on the number of conjunctive branches that the condition groups of a single if
+/// statement may expand into
+const MAX_CONDITION_BRANCHES: usize = 256;
+
+/// A condition in disjunctive normal form, the condition holds if any one of the contained
+/// conjunctions of comparisons holds
+type DisjunctiveCondition = Vec<dir::DirIfCondition>;
+
+fn check_condition_branches(count: Option<usize>) -> Result<(), AnalysisError> {
+    match count {
+        Some(count) if count <= MAX_CONDITION_BRANCHES => Ok(()),
+        _ => Err(AnalysisError {
+            error_type: AnalysisErrorType::ConditionTooComplex {
+                limit: MAX_CONDITION_BRANCHES,
+            },
+            metadata: Default::default(),
+        }),
+    }
+}
+
+/// lowers a list of expressions that must all hold (or with `negated`, all must fail) by taking
+/// the cartesian product of their branches
+fn lower_condition_conjunction<O: EuclidDirFilter>(
+    expressions: Vec<ast::ConditionExpression>,
+    negated: bool,
+) -> Result<DisjunctiveCondition, AnalysisError> {
+    expressions
+        .into_iter()
+        .try_fold(vec![Vec::new()], |branches, expression| {
+            let expression_branches = lower_condition_expression::<O>(expression, negated)?;
+            check_condition_branches(branches.len().checked_mul(expression_branches.len()))?;
+
+            Ok(branches
+                .iter()
+                .flat_map(|branch| {
+                    expression_branches.iter().map(move |expression_branch| {
+                        branch.iter().chain(expression_branch).cloned().collect()
+                    })
+                })
+                .collect())
+        })
+}
+
+/// lowers a list of expressions of which any one must hold (or with `negated`, any one must fail)
+/// by concatenating their branches
+fn lower_condition_disjunction<O: EuclidDirFilter>(
+    expressions: Vec<ast::ConditionExpression>,
+    negated: bool,
+) -> Result<DisjunctiveCondition, AnalysisError> {
+    expressions
+        .into_iter()
+        .try_fold(DisjunctiveCondition::new(), |mut branches, expression| {
+            let expression_branches = lower_condition_expression::<O>(expression, negated)?;
+            check_condition_branches(branches.len().checked_add(expression_branches.len()))?;
+
+            branches.extend(expression_branches);
+            Ok(branches)
+        })
+}
+
+/// lowers a condition expression into disjunctive normal form
+///
+/// Negations are pushed down to the comparisons using De Morgan's laws, where negating a
+/// comparison swaps its logic between a positive disjunction and a negative conjunction.
+fn lower_condition_expression<O: EuclidDirFilter>(
+    expression: ast::ConditionExpression,
+    negated: bool,
+) -> Result<DisjunctiveCondition, AnalysisError> {
+    match expression {
+        ast::ConditionExpression::Comparison(comparison) => {
+            let mut comparison = lower_comparison::<O>(comparison)?;
+            if negated {
+                comparison.logic = match comparison.logic {
+                    dir::DirComparisonLogic::PositiveDisjunction => {
+                        dir::DirComparisonLogic::NegativeConjunction
+                    }
+                    dir::DirComparisonLogic::NegativeConjunction => {
+                        dir::DirComparisonLogic::PositiveDisjunction
+                    }
+                };
+            }
+            Ok(vec![vec![comparison]])
+        }
+        ast::ConditionExpression::Group(
+            ast::ConditionGroup::Any(expressions) | ast::ConditionGroup::All(expressions),
+        ) if expressions.is_empty() => Err(AnalysisError {
+            error_type: AnalysisErrorType::EmptyConditionGroup,
+            metadata: Default::default(),
+        }),
+        ast::ConditionExpression::Group(ast::ConditionGroup::Any(expressions)) => {
+            if negated {
+                lower_condition_conjunction::<O>(expressions, negated)
+            } else {
+                lower_condition_disjunction::<O>(expressions, negated)
+            }
+        }
+        ast::ConditionExpression::Group(ast::ConditionGroup::All(expressions)) => {
+            if negated {
+                lower_condition_disjunction::<O>(expressions, negated)
+            } else {
+                lower_condition_conjunction::<O>(expressions, negated)
+            }
+        }
+        ast::ConditionExpression::Group(ast::ConditionGroup::Not(expression)) => {
+            lower_condition_expression::<O>(*expression, !negated)
+        }
+    }
+}
+
 /// lowers the if statement accordingly with a condition and following nested if statements (if
 /// present)
+///
+/// Since the dir only supports conjunctions of comparisons, a condition containing groups is
+/// expanded into one if statement per branch, all of them sharing the same nested statements.
 fn lower_if_statement<O: EuclidDirFilter>(
     stmt: ast::IfStatement,
-) -> Result<dir::DirIfStatement, AnalysisError> {
-    Ok(dir::DirIfStatement {
-        condition: stmt
-            .condition
-            .into_iter()
-            .map(lower_comparison::<O>)
-            .collect::<Result<_, _>>()?,
-        nested: stmt
-            .nested
-            .map(|n| n.into_iter().map(lower_if_statement::<O>).collect())
-            .transpose()?,
-    })
+) -> Result<Vec<dir::DirIfStatement>, AnalysisError> {
+    let nested = stmt.nested.map(lower_if_statements::<O>).transpose()?;
+
+    Ok(lower_condition_conjunction::<O>(stmt.condition, false)?
+        .into_iter()
+        .map(|condition| dir::DirIfStatement {
+            condition,
+            nested: nested.clone(),
+        })
+        .collect())
+}
+
+fn lower_if_statements<O: EuclidDirFilter>(
+    statements: Vec<ast::IfStatement>,
+) -> Result<Vec<dir::DirIfStatement>, AnalysisError> {
+    statements
+        .into_iter()
+        .map(lower_if_statement::<O>)
+        .collect::<Result<Vec<_>, _>>()
+        .map(|statements| statements.into_iter().flatten().collect())
 }
 
 /// lowers the rules supplied accordingly to DirRule struct by specifying the rule_name,
@@ -330,11 +505,7 @@ pub fn lower_rule<O: EuclidDirFilter>(
     Ok(dir::DirRule {
         name: rule.name,
         connector_selection: rule.connector_selection,
-        statements: rule
-            .statements
-            .into_iter()
-            .map(lower_if_statement::<O>)
-            .collect::<Result<_, _>>()?,
+        statements: lower_if_statements::<O>(rule.statements)?,
     })
 }
 
@@ -1,4 +1,7 @@
-use common_utils::types::MinorUnit;
+use std::str::FromStr;
+
+use common_enums::RoutableConnectors;
+use common_utils::{id_type, types::MinorUnit};
 use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
@@ -6,11 +9,66 @@ use nom::{
 use crate::{frontend::ast, types::DummyOutput};
 pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 
+/// A failure to parse a DSL program, pointing at the position in the source where parsing
+/// could not continue
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{message} at line {line}, column {column}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(source: &str, remaining: &str, message: String) -> Self {
+        let offset = source.len().saturating_sub(remaining.len());
+        let consumed = source.get(..offset).unwrap_or(source);
+
+        Self {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed
+                .rsplit('\n')
+                .next()
+                .map_or(0, |line| line.chars().count())
+                + 1,
+            message,
+        }
+    }
+
+    fn from_verbose_error(source: &str, error: error::VerboseError<&str>) -> Self {
+        let Some((remaining, kind)) = error.errors.first() else {
+            return Self::new(source, source, "invalid program".to_string());
+        };
+
+        let context = error.errors.iter().find_map(|(_, kind)| match kind {
+            error::VerboseErrorKind::Context(context) => Some(*context),
+            _ => None,
+        });
+        let expected = match (kind, context) {
+            (error::VerboseErrorKind::Char(c), _) => format!("expected '{c}'"),
+            (_, Some(context)) => format!("invalid {context}"),
+            (error::VerboseErrorKind::Context(context), None) => format!("invalid {context}"),
+            (error::VerboseErrorKind::Nom(kind), None) => kind.description().to_string(),
+        };
+        let found = remaining
+            .split_whitespace()
+            .next()
+            .map(|token| token.chars().take(32).collect::<String>());
+        let message = match found {
+            Some(token) => format!("{expected}, found `{token}`"),
+            None => format!("{expected}, found end of input"),
+        };
+
+        Self::new(source, remaining, message)
+    }
+}
+
 pub enum EuclidError {
     InvalidPercentage(String),
     InvalidConnector(String),
     InvalidOperator(String),
     InvalidNumber(String),
+    InvalidMerchantConnectorId(String),
 }
 
 pub trait EuclidParsable: Sized {
@@ -19,18 +77,10 @@ pub trait EuclidParsable: Sized {
 
 impl EuclidParsable for DummyOutput {
     fn parse_output(input: &str) -> ParseResult<&str, Self> {
-        let string_w = sequence::delimited(
-            skip_ws(complete::tag("\"")),
-            complete::take_while(|c| c != '"'),
-            skip_ws(complete::tag("\"")),
-        );
+        let string_w = skip_ws(string_str);
         let full_sequence = multi::many0(sequence::preceded(
             skip_ws(complete::tag(",")),
-            sequence::delimited(
-                skip_ws(complete::tag("\"")),
-                complete::take_while(|c| c != '"'),
-                skip_ws(complete::tag("\"")),
-            ),
+            skip_ws(string_str),
         ));
         let sequence = sequence::pair(string_w, full_sequence);
         error::context(
@@ -41,16 +91,108 @@ impl EuclidParsable for DummyOutput {
                     sequence,
                     skip_ws(complete::tag("]")),
                 ),
-                |out: (&str, Vec<&str>)| {
+                |out: (String, Vec<String>)| {
                     let mut first = out.1;
                     first.insert(0, out.0);
-                    let v = first.iter().map(|s| s.to_string()).collect();
-                    Self { outputs: v }
+                    Self { outputs: first }
                 },
             ),
         )(input)
     }
 }
+
+impl EuclidParsable for ast::ConnectorSelection {
+    /// Parses a priority list or, when every connector carries a percentage, a volume split
+    ///
+    /// ```text
+    /// [stripe, adyen:mca_2ehL8yJ1nBkZz9tOQBzm]
+    /// [stripe 70%, adyen 30%]
+    /// ```
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        let entry = sequence::pair(connector_choice, combinator::opt(skip_ws(percentage)));
+
+        error::context(
+            "connector_selection",
+            combinator::map_res(
+                bracketed_list(entry),
+                |entries: Vec<(ast::RoutableConnectorChoice, Option<u8>)>| {
+                    if entries.iter().all(|(_, split)| split.is_none()) {
+                        return Ok(Self::Priority(
+                            entries
+                                .into_iter()
+                                .map(|(connector, _)| connector)
+                                .collect(),
+                        ));
+                    }
+
+                    let splits = entries
+                        .into_iter()
+                        .map(|(connector, split)| {
+                            split.map(|split| ast::ConnectorVolumeSplit { connector, split })
+                        })
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| {
+                            EuclidError::InvalidPercentage(
+                                "every connector of a volume split needs a percentage".to_string(),
+                            )
+                        })?;
+                    let total: u16 = splits.iter().map(|split| u16::from(split.split)).sum();
+                    if total != 100 {
+                        return Err(EuclidError::InvalidPercentage(total.to_string()));
+                    }
+
+                    Ok(Self::VolumeSplit(splits))
+                },
+            ),
+        )(input)
+    }
+}
+
+/// A connector, optionally pinned to a merchant connector account
+///
+/// ```text
+/// stripe
+/// stripe:mca_2ehL8yJ1nBkZz9tOQBzm
+/// ```
+pub fn connector_choice(input: &str) -> ParseResult<&str, ast::RoutableConnectorChoice> {
+    let connector = combinator::map_res(identifier, |name: String| {
+        RoutableConnectors::from_str(&name).map_err(|_| EuclidError::InvalidConnector(name))
+    });
+    let merchant_connector_id = combinator::map_res(
+        sequence::preceded(
+            complete::tag(":"),
+            complete::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+        ),
+        |id: &str| {
+            id_type::MerchantConnectorAccountId::wrap(id.to_string())
+                .map_err(|_| EuclidError::InvalidMerchantConnectorId(id.to_string()))
+        },
+    );
+
+    error::context(
+        "connector",
+        combinator::map(
+            sequence::pair(skip_ws(connector), combinator::opt(merchant_connector_id)),
+            |(connector, merchant_connector_id)| ast::RoutableConnectorChoice {
+                choice_kind: ast::RoutableChoiceKind::FullStruct,
+                connector,
+                merchant_connector_id,
+            },
+        ),
+    )(input)
+}
+
+fn bracketed_list<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> ParseResult<&'a str, Vec<O>>
+where
+    F: FnMut(&'a str) -> ParseResult<&'a str, O> + 'a,
+{
+    sequence::delimited(
+        skip_ws(complete::tag("[")),
+        multi::separated_list1(skip_ws(complete::tag(",")), inner),
+        skip_ws(complete::tag("]")),
+    )
+}
+
 pub fn skip_ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> ParseResult<&'a str, O>
 where
     F: FnMut(&'a str) -> ParseResult<&'a str, O> + 'a,
@@ -71,16 +213,23 @@ pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
     )(input)
 }
 
+/// A double quoted string, in which `\"` and `\\` stand for a quote and a backslash
 pub fn string_str(input: &str) -> ParseResult<&str, String> {
+    let escaped_char = sequence::preceded(
+        complete::tag("\\"),
+        branch::alt((complete::tag("\""), complete::tag("\\"))),
+    );
+    let fragment = branch::alt((complete::is_not("\"\\"), escaped_char));
+
     error::context(
         "String",
-        combinator::map(
-            sequence::delimited(
-                complete::tag("\""),
-                complete::take_while1(|c: char| c != '"'),
-                complete::tag("\""),
-            ),
-            |val: &str| val.to_string(),
+        sequence::delimited(
+            complete::tag("\""),
+            multi::fold_many0(fragment, String::new, |mut string, fragment: &str| {
+                string.push_str(fragment);
+                string
+            }),
+            complete::tag("\""),
         ),
     )(input)
 }
@@ -102,12 +251,14 @@ pub fn percentage(input: &str) -> ParseResult<&str, u8> {
         "volume_split_percentage",
         combinator::map_res(
             sequence::terminated(
-                complete::take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+                complete::take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
                 complete::tag("%"),
             ),
             |o: &str| {
                 o.parse::<u8>()
-                    .map_err(|_| EuclidError::InvalidPercentage(o.to_string()))
+                    .ok()
+                    .filter(|percentage| *percentage <= 100)
+                    .ok_or_else(|| EuclidError::InvalidPercentage(o.to_string()))
             },
         ),
     )(input)
@@ -216,7 +367,7 @@ pub fn number_comparison(input: &str) -> ParseResult<&str, ast::NumberComparison
     error::context(
         "number_comparison",
         combinator::map(
-            sequence::pair(operator, num_i64),
+            sequence::pair(operator, skip_ws(num_i64)),
             |tup: (ast::ComparisonType, i64)| ast::NumberComparison {
                 comparison_type: tup.0,
                 number: MinorUnit::new(tup.1),
@@ -332,22 +483,68 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
     )(input)
 }
 
-pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
+pub fn condition_group(input: &str) -> ParseResult<&str, ast::ConditionGroup> {
+    let expression_list = || {
+        sequence::delimited(
+            skip_ws(complete::tag("(")),
+            multi::separated_list1(skip_ws(complete::tag(",")), condition_expression),
+            skip_ws(complete::tag(")")),
+        )
+    };
+
+    error::context(
+        "condition_group",
+        branch::alt((
+            combinator::map(
+                sequence::preceded(skip_ws(complete::tag("any")), expression_list()),
+                ast::ConditionGroup::Any,
+            ),
+            combinator::map(
+                sequence::preceded(skip_ws(complete::tag("all")), expression_list()),
+                ast::ConditionGroup::All,
+            ),
+            combinator::map(
+                sequence::preceded(
+                    skip_ws(complete::tag("not")),
+                    sequence::delimited(
+                        skip_ws(complete::tag("(")),
+                        condition_expression,
+                        skip_ws(complete::tag(")")),
+                    ),
+                ),
+                |expression| ast::ConditionGroup::Not(Box::new(expression)),
+            ),
+        )),
+    )(input)
+}
+
+pub fn condition_expression(input: &str) -> ParseResult<&str, ast::ConditionExpression> {
+    error::context(
+        "condition_expression",
+        branch::alt((
+            combinator::map(condition_group, ast::ConditionExpression::Group),
+            combinator::map(comparison, ast::ConditionExpression::Comparison),
+            combinator::map(arbitrary_comparison, ast::ConditionExpression::Comparison),
+        )),
+    )(input)
+}
+
+pub fn comparison_array(input: &str) -> ParseResult<&str, ast::IfCondition> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            condition_expression,
+        )),
     );
 
-    let full_sequence = sequence::pair(
-        skip_ws(branch::alt((comparison, arbitrary_comparison))),
-        many_with_ampersand,
-    );
+    let full_sequence = sequence::pair(skip_ws(condition_expression), many_with_ampersand);
 
     error::context(
         "comparison_array",
         combinator::map(
             full_sequence,
-            |tup: (ast::Comparison, Vec<ast::Comparison>)| {
+            |tup: (ast::ConditionExpression, Vec<ast::ConditionExpression>)| {
                 let mut rest = tup.1;
                 rest.insert(0, tup.0);
                 rest
@@ -387,20 +584,11 @@ pub fn rule_conditions_array(input: &str) -> ParseResult<&str, Vec<ast::IfStatem
 }
 
 pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
-    let rule_name = error::context(
-        "rule_name",
-        combinator::map(
-            skip_ws(sequence::pair(
-                complete::take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-                complete::take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
-            )),
-            |out: (&str, &str)| out.0.to_string() + out.1,
-        ),
-    );
+    let rule_name = error::context("rule_name", skip_ws(branch::alt((identifier, string_str))));
 
     let connector_selection = error::context(
         "parse_output",
-        sequence::preceded(skip_ws(complete::tag(":")), output),
+        sequence::preceded(skip_ws(complete::tag(":")), skip_ws(output)),
     );
 
     error::context(
@@ -443,3 +631,33 @@ pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, as
         ),
     )(input)
 }
+
+/// Parses a complete DSL program, failing if anything other than whitespace follows it
+pub fn parse_program<O: EuclidParsable + 'static>(
+    input: &str,
+) -> Result<ast::Program<O>, ParseError> {
+    let (remaining, parsed) = program::<O>(input).map_err(|err| match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => ParseError::from_verbose_error(input, err),
+        nom::Err::Incomplete(_) => {
+            ParseError::new(input, "", "unexpected end of input".to_string())
+        }
+    })?;
+
+    let trailing = remaining.trim_start();
+    if trailing.is_empty() {
+        return Ok(parsed);
+    }
+
+    // The rules are parsed greedily, so a malformed rule ends the program early. Parsing it
+    // again on its own surfaces the actual error instead of just the leftover input.
+    Err(match rule::<O>(trailing) {
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            ParseError::from_verbose_error(input, err)
+        }
+        Ok(_) | Err(nom::Err::Incomplete(_)) => ParseError::new(
+            input,
+            trailing,
+            "unexpected input after the end of the program".to_string(),
+        ),
+    })
+}
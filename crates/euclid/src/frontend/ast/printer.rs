@@ -0,0 +1,305 @@
+//! Renders a [`ast::Program`] back into the textual DSL accepted by the parser.
+//!
+//! The output is canonical: rules keep their order, every nesting level is indented by four
+//! spaces and each IF statement sits on its own line, so that printing a parsed program yields
+//! the same text again. Program and comparison metadata have no textual form and are dropped.
+//!
+//! Not every program has a textual form: rules without conditions and empty value lists are
+//! rejected by the parser, so callers should parse the printed text again before handing it out.
+
+use crate::{frontend::ast, types::DummyOutput};
+
+const INDENT: &str = "    ";
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> String;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> String {
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| quoted(output))
+            .collect::<Vec<_>>();
+        format!("[{}]", outputs.join(", "))
+    }
+}
+
+impl EuclidPrintable for ast::ConnectorSelection {
+    fn print_output(&self) -> String {
+        let entries = match self {
+            Self::Priority(connectors) => connectors.iter().map(connector_choice).collect(),
+            Self::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| format!("{} {}%", connector_choice(&split.connector), split.split))
+                .collect::<Vec<_>>(),
+        };
+        format!("[{}]", entries.join(", "))
+    }
+}
+
+fn connector_choice(choice: &ast::RoutableConnectorChoice) -> String {
+    match &choice.merchant_connector_id {
+        Some(merchant_connector_id) => format!(
+            "{}:{}",
+            choice.connector,
+            merchant_connector_id.get_string_repr()
+        ),
+        None => choice.connector.to_string(),
+    }
+}
+
+pub fn program<O: EuclidPrintable>(program: &ast::Program<O>) -> String {
+    let mut lines = vec![format!(
+        "default: {}",
+        program.default_selection.print_output()
+    )];
+
+    for rule in &program.rules {
+        lines.push(String::new());
+        lines.push(format!(
+            "{}: {}",
+            rule_name(&rule.name),
+            rule.connector_selection.print_output()
+        ));
+        lines.push("{".to_string());
+        statements(&rule.statements, 1, &mut lines);
+        lines.push("}".to_string());
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Rule names that are not plain identifiers are quoted
+fn rule_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_identifier {
+        name.to_string()
+    } else {
+        quoted(name)
+    }
+}
+
+/// Wraps a string in double quotes, escaping the quotes and backslashes it contains
+fn quoted(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn statements(statements: &[ast::IfStatement], depth: usize, lines: &mut Vec<String>) {
+    let indent = INDENT.repeat(depth);
+
+    for statement in statements {
+        let condition = condition(&statement.condition);
+        match &statement.nested {
+            Some(nested) => {
+                lines.push(format!("{indent}{condition} {{"));
+                self::statements(nested, depth + 1, lines);
+                lines.push(format!("{indent}}}"));
+            }
+            None => lines.push(format!("{indent}{condition}")),
+        }
+    }
+}
+
+pub fn condition(condition: &ast::IfCondition) -> String {
+    condition
+        .iter()
+        .map(condition_expression)
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
+
+fn condition_expression(expression: &ast::ConditionExpression) -> String {
+    match expression {
+        ast::ConditionExpression::Comparison(comparison) => self::comparison(comparison),
+        ast::ConditionExpression::Group(ast::ConditionGroup::Any(expressions)) => {
+            format!("any({})", expression_list(expressions))
+        }
+        ast::ConditionExpression::Group(ast::ConditionGroup::All(expressions)) => {
+            format!("all({})", expression_list(expressions))
+        }
+        ast::ConditionExpression::Group(ast::ConditionGroup::Not(expression)) => {
+            format!("not({})", condition_expression(expression))
+        }
+    }
+}
+
+fn expression_list(expressions: &[ast::ConditionExpression]) -> String {
+    expressions
+        .iter()
+        .map(condition_expression)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn comparison(comparison: &ast::Comparison) -> String {
+    let operator = comparison_type(&comparison.comparison);
+    match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) => format!(
+            "{} {operator} {}",
+            quoted(&metadata.key),
+            quoted(&metadata.value)
+        ),
+        value => format!("{} {operator} {}", comparison.lhs, value_type(value)),
+    }
+}
+
+fn comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+fn value_type(value: &ast::ValueType) -> String {
+    match value {
+        ast::ValueType::Number(number) => number.get_amount_as_i64().to_string(),
+        ast::ValueType::EnumVariant(variant) => variant.clone(),
+        ast::ValueType::MetadataVariant(metadata) => {
+            format!("{} = {}", quoted(&metadata.key), quoted(&metadata.value))
+        }
+        ast::ValueType::StrValue(value) => quoted(value),
+        ast::ValueType::NumberArray(numbers) => format!(
+            "({})",
+            numbers
+                .iter()
+                .map(|number| number.get_amount_as_i64().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ast::ValueType::EnumVariantArray(variants) => format!("({})", variants.join(", ")),
+        ast::ValueType::NumberComparisonArray(comparisons) => format!(
+            "({})",
+            comparisons
+                .iter()
+                .map(|comparison| format!(
+                    "{} {}",
+                    comparison_type(&comparison.comparison_type),
+                    comparison.number.get_amount_as_i64()
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    #[test]
+    fn test_connector_selection_round_trip() {
+        let program_str = r#"
+            default: [stripe, adyen:mca_2ehL8yJ1nBkZz9tOQBzm]
+
+            "card rule": [stripe 70%, adyen 30%]
+            {
+                payment_method = card & amount = (> 500, < 1000) {
+                    any(card_network = visa, not(currency = (USD, GBP)))
+                }
+                "merchant_tier" = "gold"
+            }
+        "#;
+        let canonical = r#"default: [stripe, adyen:mca_2ehL8yJ1nBkZz9tOQBzm]
+
+"card rule": [stripe 70%, adyen 30%]
+{
+    payment_method = card & amount = (> 500, < 1000) {
+        any(card_network = visa, not(currency = (USD, GBP)))
+    }
+    "merchant_tier" = "gold"
+}
+"#;
+
+        let parsed =
+            parser::parse_program::<ast::ConnectorSelection>(program_str).expect("Program");
+        assert!(matches!(
+            &parsed.rules.first().expect("Rule").connector_selection,
+            ast::ConnectorSelection::VolumeSplit(splits) if splits.len() == 2
+        ));
+
+        let printed = program(&parsed);
+        assert_eq!(printed, canonical);
+
+        let reparsed = parser::parse_program::<ast::ConnectorSelection>(&printed).expect("Program");
+        assert_eq!(program(&reparsed), canonical);
+    }
+
+    #[test]
+    fn test_quoted_strings_round_trip() {
+        let canonical = r#"default: [stripe]
+
+"rule \"one\"": [adyen]
+{
+    "back\\slash" = ""
+}
+
+rule_2: [stripe]
+{
+    "key" = "value" & "" = "quoted \"value\""
+}
+"#;
+
+        let parsed = parser::parse_program::<ast::ConnectorSelection>(canonical).expect("Program");
+        let rule = parsed.rules.first().expect("Rule");
+        assert_eq!(rule.name, r#"rule "one""#);
+        assert!(matches!(
+            &rule.statements.first().expect("Statement").condition.first(),
+            Some(ast::ConditionExpression::Comparison(ast::Comparison {
+                value: ast::ValueType::MetadataVariant(ast::MetadataValue { key, value }),
+                ..
+            })) if key == r"back\slash" && value.is_empty()
+        ));
+
+        assert_eq!(program(&parsed), canonical);
+    }
+
+    #[test]
+    fn test_rule_without_conditions_is_not_parsable() {
+        let output = DummyOutput {
+            outputs: vec!["stripe".to_string()],
+        };
+        let program_without_conditions = ast::Program {
+            default_selection: output.clone(),
+            rules: vec![ast::Rule {
+                name: "rule_1".to_string(),
+                connector_selection: output,
+                statements: vec![],
+            }],
+            metadata: std::collections::HashMap::new(),
+        };
+
+        let printed = program(&program_without_conditions);
+        assert!(parser::parse_program::<DummyOutput>(&printed).is_err());
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let program_str = "default: [stripe]\n\nrule_1: [stripe 70%, adyen 20%]\n{\n    payment_method = card\n}\n";
+
+        let error = parser::parse_program::<ast::ConnectorSelection>(program_str)
+            .expect_err("Volume split not adding up to 100 should fail");
+        assert_eq!((error.line, error.column), (3, 9));
+
+        let program_str =
+            "default: [stripe]\n\nrule_1: [stripe]\n{\n    payment_method = card &\n}\n";
+
+        let error = parser::parse_program::<ast::ConnectorSelection>(program_str)
+            .expect_err("Dangling '&' should fail");
+        assert_eq!(error.line, 5);
+    }
+}
@@ -205,6 +205,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "amount")]
     PaymentAmount,
+    #[strum(
+        serialize = "amount_in_base_currency",
+        detailed_message = "Value of the transaction, converted to the base currency configured on the profile",
+        props(Category = "Payments")
+    )]
+    #[serde(rename = "amount_in_base_currency")]
+    AmountInBaseCurrency,
     #[strum(
         serialize = "currency",
         detailed_message = "Currency used for the payment",
@@ -334,6 +341,27 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate,
+    #[strum(
+        serialize = "hour_of_day",
+        detailed_message = "Hour of the day (0 to 23) in the timezone of the profile",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "hour_of_day")]
+    HourOfDay,
+    #[strum(
+        serialize = "day_of_week",
+        detailed_message = "Day of the week in the timezone of the profile",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_week")]
+    DayOfWeek,
+    #[strum(
+        serialize = "calendar_date",
+        detailed_message = "Calendar date in the timezone of the profile, written as YYYYMMDD",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "calendar_date")]
+    CalendarDate,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -371,6 +399,7 @@ impl DirKeyKind {
             Self::CryptoType => types::DataType::EnumVariant,
             Self::RewardType => types::DataType::EnumVariant,
             Self::PaymentAmount => types::DataType::Number,
+            Self::AmountInBaseCurrency => types::DataType::Number,
             Self::PaymentCurrency => types::DataType::EnumVariant,
             Self::AuthenticationType => types::DataType::EnumVariant,
             Self::CaptureMethod => types::DataType::EnumVariant,
@@ -391,6 +420,9 @@ impl DirKeyKind {
             Self::CustomerDeviceDisplaySize => types::DataType::EnumVariant,
             Self::AcquirerCountry => types::DataType::EnumVariant,
             Self::AcquirerFraudRate => types::DataType::Number,
+            Self::HourOfDay => types::DataType::Number,
+            Self::DayOfWeek => types::DataType::EnumVariant,
+            Self::CalendarDate => types::DataType::Number,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -465,6 +497,7 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::PaymentAmount => None,
+            Self::AmountInBaseCurrency => None,
             Self::PaymentCurrency => Some(
                 enums::PaymentCurrency::iter()
                     .map(DirValue::PaymentCurrency)
@@ -555,8 +588,16 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::AcquirerFraudRate => None,
+            Self::HourOfDay => None,
+            Self::DayOfWeek => Some(enums::DayOfWeek::iter().map(DirValue::DayOfWeek).collect()),
+            Self::CalendarDate => None,
         }
     }
+
+    /// Returns true for the keys whose values are evaluated against the time of the transaction
+    pub fn is_time_key(&self) -> bool {
+        matches!(self, Self::HourOfDay | Self::DayOfWeek | Self::CalendarDate)
+    }
 }
 
 #[derive(
@@ -602,6 +643,8 @@ pub enum DirValue {
     GiftCardType(enums::GiftCardType),
     #[serde(rename = "amount")]
     PaymentAmount(types::NumValue),
+    #[serde(rename = "amount_in_base_currency")]
+    AmountInBaseCurrency(types::NumValue),
     #[serde(rename = "currency")]
     PaymentCurrency(enums::PaymentCurrency),
     #[serde(rename = "authentication_type")]
@@ -640,6 +683,12 @@ pub enum DirValue {
     AcquirerCountry(enums::Country),
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate(types::NumValue),
+    #[serde(rename = "hour_of_day")]
+    HourOfDay(types::NumValue),
+    #[serde(rename = "day_of_week")]
+    DayOfWeek(enums::DayOfWeek),
+    #[serde(rename = "calendar_date")]
+    CalendarDate(types::NumValue),
 }
 
 impl DirValue {
@@ -662,6 +711,7 @@ impl DirValue {
             Self::AuthenticationType(_) => (DirKeyKind::AuthenticationType, None),
             Self::CaptureMethod(_) => (DirKeyKind::CaptureMethod, None),
             Self::PaymentAmount(_) => (DirKeyKind::PaymentAmount, None),
+            Self::AmountInBaseCurrency(_) => (DirKeyKind::AmountInBaseCurrency, None),
             Self::PaymentCurrency(_) => (DirKeyKind::PaymentCurrency, None),
             Self::Connector(_) => (DirKeyKind::Connector, None),
             Self::BankDebitType(_) => (DirKeyKind::BankDebitType, None),
@@ -683,6 +733,9 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => (DirKeyKind::CustomerDeviceDisplaySize, None),
             Self::AcquirerCountry(_) => (DirKeyKind::AcquirerCountry, None),
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
+            Self::HourOfDay(_) => (DirKeyKind::HourOfDay, None),
+            Self::DayOfWeek(_) => (DirKeyKind::DayOfWeek, None),
+            Self::CalendarDate(_) => (DirKeyKind::CalendarDate, None),
         };
 
         DirKey::new(kind, data)
@@ -702,6 +755,7 @@ impl DirValue {
             Self::CaptureMethod(_) => None,
             Self::GiftCardType(_) => None,
             Self::PaymentAmount(_) => None,
+            Self::AmountInBaseCurrency(_) => None,
             Self::PaymentCurrency(_) => None,
             Self::BusinessCountry(_) => None,
             Self::BillingCountry(_) => None,
@@ -727,6 +781,9 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => None,
             Self::AcquirerCountry(_) => None,
             Self::AcquirerFraudRate(_) => None,
+            Self::HourOfDay(_) => None,
+            Self::DayOfWeek(_) => None,
+            Self::CalendarDate(_) => None,
         }
     }
 
@@ -741,7 +798,10 @@ impl DirValue {
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::AmountInBaseCurrency(val) => Some(val.clone()),
             Self::AcquirerFraudRate(val) => Some(val.clone()),
+            Self::HourOfDay(val) => Some(val.clone()),
+            Self::CalendarDate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -782,6 +842,9 @@ impl DirValue {
             (Self::CustomerDeviceDisplaySize(s1), Self::CustomerDeviceDisplaySize(s2)) => s1 == s2,
             (Self::AcquirerCountry(c1), Self::AcquirerCountry(c2)) => c1 == c2,
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
+            (Self::HourOfDay(h1), Self::HourOfDay(h2)) => h1 == h2,
+            (Self::DayOfWeek(d1), Self::DayOfWeek(d2)) => d1 == d2,
+            (Self::CalendarDate(d1), Self::CalendarDate(d2)) => d1 == d2,
             _ => false,
         }
     }
@@ -960,12 +1023,16 @@ mod test {
             dirval!(CryptoType = CryptoCurrency),
             dirval!("" = "metadata"),
             dirval!(PaymentAmount = 100),
+            dirval!(AmountInBaseCurrency = 100),
             dirval!(PaymentCurrency = USD),
             dirval!(CardRedirectType = Benefit),
             dirval!(AuthenticationType = ThreeDs),
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(HourOfDay = 22),
+            dirval!(DayOfWeek = Saturday),
+            dirval!(CalendarDate = 20251224),
         ];
 
         for val in values {
@@ -11,7 +11,9 @@ use crate::{
     frontend::{
         ast,
         dir::{
-            enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+            enums::{
+                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
+            },
             DirKeyKind, DirValue, EuclidDirFilter,
         },
     },
@@ -53,6 +55,8 @@ pub enum EuclidKey {
     CaptureMethod,
     #[strum(serialize = "amount")]
     PaymentAmount,
+    #[strum(serialize = "amount_in_base_currency")]
+    AmountInBaseCurrency,
     #[strum(serialize = "currency")]
     PaymentCurrency,
     #[strum(serialize = "country", to_string = "business_country")]
@@ -77,6 +81,12 @@ pub enum EuclidKey {
     CustomerDeviceDisplaySize,
     #[strum(serialize = "customer_device_platform")]
     CustomerDevicePlatform,
+    #[strum(serialize = "hour_of_day")]
+    HourOfDay,
+    #[strum(serialize = "day_of_week")]
+    DayOfWeek,
+    #[strum(serialize = "calendar_date")]
+    CalendarDate,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -90,11 +100,15 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::CardBin,
         DirKeyKind::PayLaterType,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::AmountInBaseCurrency,
         DirKeyKind::MetaData,
         DirKeyKind::MandateAcceptanceType,
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::CalendarDate,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -122,7 +136,7 @@ impl EuclidAnalysable for DummyOutput {
             .collect()
     }
 }
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DummyOutput {
     pub outputs: Vec<String>,
 }
@@ -148,6 +162,7 @@ impl EuclidKey {
             Self::AuthenticationType => DataType::EnumVariant,
             Self::CaptureMethod => DataType::EnumVariant,
             Self::PaymentAmount => DataType::Number,
+            Self::AmountInBaseCurrency => DataType::Number,
             Self::PaymentCurrency => DataType::EnumVariant,
             Self::BusinessCountry => DataType::EnumVariant,
             Self::BillingCountry => DataType::EnumVariant,
@@ -163,6 +178,9 @@ impl EuclidKey {
             Self::CustomerDeviceType => DataType::EnumVariant,
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
+            Self::HourOfDay => DataType::Number,
+            Self::DayOfWeek => DataType::EnumVariant,
+            Self::CalendarDate => DataType::Number,
         }
     }
 }
@@ -273,6 +291,7 @@ pub enum EuclidValue {
     MandateAcceptanceType(enums::MandateAcceptanceType),
     MandateType(enums::MandateType),
     PaymentAmount(NumValue),
+    AmountInBaseCurrency(NumValue),
     PaymentCurrency(enums::Currency),
     BusinessCountry(enums::Country),
     BillingCountry(enums::Country),
@@ -285,12 +304,18 @@ pub enum EuclidValue {
     CustomerDeviceType(CustomerDeviceType),
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
+    HourOfDay(NumValue),
+    DayOfWeek(DayOfWeek),
+    CalendarDate(NumValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::AmountInBaseCurrency(val) => Some(val.clone()),
+            Self::HourOfDay(val) => Some(val.clone()),
+            Self::CalendarDate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -308,6 +333,7 @@ impl EuclidValue {
             Self::AuthenticationType(_) => EuclidKey::AuthenticationType,
             Self::CaptureMethod(_) => EuclidKey::CaptureMethod,
             Self::PaymentAmount(_) => EuclidKey::PaymentAmount,
+            Self::AmountInBaseCurrency(_) => EuclidKey::AmountInBaseCurrency,
             Self::PaymentCurrency(_) => EuclidKey::PaymentCurrency,
             Self::BusinessCountry(_) => EuclidKey::BusinessCountry,
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
@@ -320,6 +346,9 @@ impl EuclidValue {
             Self::CustomerDeviceType(_) => EuclidKey::CustomerDeviceType,
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
+            Self::HourOfDay(_) => EuclidKey::HourOfDay,
+            Self::DayOfWeek(_) => EuclidKey::DayOfWeek,
+            Self::CalendarDate(_) => EuclidKey::CalendarDate,
         }
     }
 }
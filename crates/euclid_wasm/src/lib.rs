@@ -34,12 +34,12 @@ use wasm_bindgen::prelude::*;
 use crate::utils::JsResultExt;
 type JsResult = Result<JsValue, JsValue>;
 use api_models::payment_methods::CountryCodeWithName;
-#[cfg(feature = "payouts")]
-use common_enums::PayoutStatus;
 use common_enums::{
     CountryAlpha2, DisputeStatus, EventClass, EventType, IntentStatus, MandateStatus,
-    MerchantCategoryCode, MerchantCategoryCodeWithName, RefundStatus,
+    MerchantCategoryCode, MerchantCategoryCodeWithName, RefundStatus, SubscriptionStatus,
 };
+#[cfg(feature = "payouts")]
+use common_enums::{PayoutBatchStatus, PayoutStatus};
 use strum::IntoEnumIterator;
 
 struct SeedData {
@@ -223,6 +223,18 @@ pub fn analyze_program(js_program: JsValue) -> JsResult {
     Ok(JsValue::NULL)
 }
 
+/// Returns the shadowed, redundant and unreachable rules of a valid program. These do not
+/// invalidate the program, so they are reported separately from `analyzeProgram`.
+#[wasm_bindgen(js_name = getProgramWarnings)]
+pub fn get_program_warnings(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let knowledge_graph = SEED_DATA.get().map(|sd| &sd.cgraph);
+    analyzer::analyze(program.clone(), knowledge_graph).err_to_js()?;
+
+    let warnings = analyzer::analyze_rule_reachability(program, knowledge_graph).err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
+}
+
 #[wasm_bindgen(js_name = runProgram)]
 pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
@@ -331,14 +343,18 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::CustomerDeviceDisplaySize => {
             dir_enums::CustomerDeviceDisplaySize::VARIANTS
         }
+        dir::DirKeyKind::DayOfWeek => dir_enums::DayOfWeek::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
+        | dir::DirKeyKind::AmountInBaseCurrency
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
         | dir::DirKeyKind::MetaData
         | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::AcquirerFraudRate
+        | dir::DirKeyKind::HourOfDay
+        | dir::DirKeyKind::CalendarDate => Err("Key does not have variants".to_string())?,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
@@ -514,5 +530,18 @@ pub fn get_valid_webhook_status(key: &str) -> JsResult {
                 .collect();
             Ok(serde_wasm_bindgen::to_value(&statuses)?)
         }
+        EventClass::Subscriptions => {
+            let statuses: Vec<SubscriptionStatus> = SubscriptionStatus::iter()
+                .filter(|status| Into::<Option<EventType>>::into(*status).is_some())
+                .collect();
+            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+        }
+        #[cfg(feature = "payouts")]
+        EventClass::PayoutBatches => {
+            let statuses: Vec<PayoutBatchStatus> = PayoutBatchStatus::iter()
+                .filter(|status| Into::<Option<EventType>>::into(*status).is_some())
+                .collect();
+            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+        }
     }
 }
@@ -528,6 +528,7 @@ impl TryFrom<&TrustpayRouterData<&PaymentsAuthorizeRouterData>> for TrustpayPaym
             os_type: None,
             os_version: None,
             device_model: None,
+            device_id: None,
             accept_language: Some(browser_info.accept_language.unwrap_or("en".to_string())),
         };
         let params = get_mandatory_fields(item.router_data)?;
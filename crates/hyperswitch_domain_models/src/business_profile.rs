@@ -86,6 +86,10 @@ pub struct Profile {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub routing_timezone: Option<String>,
+    pub routing_base_currency: Option<common_enums::Currency>,
+    pub previous_payment_response_hash_key: OptionalEncryptableName,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -144,6 +148,8 @@ pub struct ProfileSetter {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub routing_timezone: Option<String>,
+    pub routing_base_currency: Option<common_enums::Currency>,
 }
 
 #[cfg(feature = "v1")]
@@ -209,6 +215,10 @@ impl From<ProfileSetter> for Profile {
             dispute_polling_interval: value.dispute_polling_interval,
             is_manual_retry_enabled: value.is_manual_retry_enabled,
             always_enable_overcapture: value.always_enable_overcapture,
+            routing_timezone: value.routing_timezone,
+            routing_base_currency: value.routing_base_currency,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         }
     }
 }
@@ -276,6 +286,8 @@ pub struct ProfileGeneralUpdate {
     pub dispute_polling_interval: Option<primitive_wrappers::DisputePollingIntervalInHours>,
     pub is_manual_retry_enabled: Option<bool>,
     pub always_enable_overcapture: Option<primitive_wrappers::AlwaysEnableOvercaptureBool>,
+    pub routing_timezone: Option<String>,
+    pub routing_base_currency: Option<common_enums::Currency>,
 }
 
 #[cfg(feature = "v1")]
@@ -305,6 +317,12 @@ pub enum ProfileUpdate {
     AcquirerConfigMapUpdate {
         acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     },
+    PaymentResponseHashKeyUpdate {
+        payment_response_hash_key: String,
+        previous_payment_response_hash_key: OptionalEncryptableName,
+        previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+    },
+    PreviousPaymentResponseHashKeyDelete,
 }
 
 #[cfg(feature = "v1")]
@@ -361,6 +379,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     always_request_extended_authorization,
                     is_manual_retry_enabled,
                     always_enable_overcapture,
+                    routing_timezone,
+                    routing_base_currency,
                 } = *update;
 
                 Self {
@@ -416,6 +436,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     dispute_polling_interval,
                     is_manual_retry_enabled,
                     always_enable_overcapture,
+                    routing_timezone,
+                    routing_base_currency,
+                    previous_payment_response_hash_key: None,
+                    previous_payment_response_hash_key_expires_at: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -474,6 +498,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -529,6 +557,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -584,6 +616,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -639,6 +675,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -694,6 +734,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -749,6 +793,10 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::AcquirerConfigMapUpdate {
                 acquirer_config_map,
@@ -804,6 +852,132 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 dispute_polling_interval: None,
                 is_manual_retry_enabled: None,
                 always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
+            },
+            ProfileUpdate::PaymentResponseHashKeyUpdate {
+                payment_response_hash_key,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: Some(payment_response_hash_key),
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+                acquirer_config_map: None,
+                merchant_category_code: None,
+                merchant_country_code: None,
+                dispute_polling_interval: None,
+                is_manual_retry_enabled: None,
+                always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: Some(
+                    previous_payment_response_hash_key.map(Encryption::from),
+                ),
+                previous_payment_response_hash_key_expires_at: Some(
+                    previous_payment_response_hash_key_expires_at,
+                ),
+            },
+            ProfileUpdate::PreviousPaymentResponseHashKeyDelete => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+                acquirer_config_map: None,
+                merchant_category_code: None,
+                merchant_country_code: None,
+                dispute_polling_interval: None,
+                is_manual_retry_enabled: None,
+                always_enable_overcapture: None,
+                routing_timezone: None,
+                routing_base_currency: None,
+                previous_payment_response_hash_key: Some(None),
+                previous_payment_response_hash_key_expires_at: Some(None),
             },
         }
     }
@@ -879,6 +1053,13 @@ impl super::behaviour::Conversion for Profile {
             dispute_polling_interval: self.dispute_polling_interval,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
             always_enable_overcapture: self.always_enable_overcapture,
+            routing_timezone: self.routing_timezone,
+            routing_base_currency: self.routing_base_currency,
+            previous_payment_response_hash_key: self
+                .previous_payment_response_hash_key
+                .map(Encryption::from),
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 
@@ -980,6 +1161,24 @@ impl super::behaviour::Conversion for Profile {
                 dispute_polling_interval: item.dispute_polling_interval,
                 is_manual_retry_enabled: item.is_manual_retry_enabled,
                 always_enable_overcapture: item.always_enable_overcapture,
+                routing_timezone: item.routing_timezone,
+                routing_base_currency: item.routing_base_currency,
+                previous_payment_response_hash_key: item
+                    .previous_payment_response_hash_key
+                    .async_lift(|inner| async {
+                        crypto_operation(
+                            state,
+                            type_name!(Self::DstType),
+                            CryptoOperation::DecryptOptional(inner),
+                            key_manager_identifier.clone(),
+                            key.peek(),
+                        )
+                        .await
+                        .and_then(|val| val.try_into_optionaloperation())
+                    })
+                    .await?,
+                previous_payment_response_hash_key_expires_at: item
+                    .previous_payment_response_hash_key_expires_at,
             })
         }
         .await
@@ -1047,6 +1246,13 @@ impl super::behaviour::Conversion for Profile {
             merchant_country_code: self.merchant_country_code,
             dispute_polling_interval: self.dispute_polling_interval,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
+            routing_timezone: self.routing_timezone,
+            routing_base_currency: self.routing_base_currency,
+            previous_payment_response_hash_key: self
+                .previous_payment_response_hash_key
+                .map(Encryption::from),
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 }
@@ -2134,6 +2340,10 @@ impl super::behaviour::Conversion for Profile {
             split_txns_enabled: Some(self.split_txns_enabled),
             is_manual_retry_enabled: None,
             always_enable_overcapture: None,
+            routing_timezone: None,
+            routing_base_currency: None,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         })
     }
 
@@ -2,11 +2,17 @@ use serde::{self, Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct CardTestingGuardData {
-    pub is_card_ip_blocking_enabled: bool,
-    pub card_ip_blocking_cache_key: String,
-    pub is_guest_user_card_blocking_enabled: bool,
-    pub guest_user_card_blocking_cache_key: String,
-    pub is_customer_id_blocking_enabled: bool,
-    pub customer_id_blocking_cache_key: String,
-    pub card_testing_guard_expiry: i32,
+    /// Counters of the velocity rules measuring failed payments, the payment is recorded against
+    /// them if it fails
+    pub failed_payment_counters: Vec<VelocityCounter>,
+    /// Names of the velocity rules which flagged the payment for a review
+    pub flagged_rules: Vec<String>,
+    /// Whether a velocity rule requires the payment to be authenticated with 3DS
+    pub force_three_ds: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct VelocityCounter {
+    pub cache_key: String,
+    pub window_in_seconds: u32,
 }
@@ -15,6 +15,7 @@ pub struct GatewayStatusMap {
     pub error_category: Option<common_enums::ErrorCategory>,
     pub feature_data: common_types::domain::GsmFeatureData,
     pub feature: common_enums::GsmFeature,
+    pub match_type: common_enums::GsmMatchType,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -59,6 +60,7 @@ impl TryFrom<GatewayStatusMap> for diesel_models::gsm::GatewayStatusMappingNew {
                 .unwrap_or(false),
             feature_data: Some(value.feature_data),
             feature: Some(value.feature),
+            match_type: Some(value.match_type),
         })
     }
 }
@@ -106,11 +108,14 @@ impl TryFrom<diesel_models::gsm::GatewayStatusMap> for GatewayStatusMap {
                     clear_pan_possible: item.clear_pan_possible,
                     alternate_network_possible: false,
                     decision,
+                    retry_later_config: None,
                 },
             ),
         };
 
         let feature = item.feature.unwrap_or(common_enums::GsmFeature::Retry);
+        // Records created before match types were introduced are exact matches
+        let match_type = item.match_type.unwrap_or_default();
         Ok(Self {
             connector: item.connector,
             flow: item.flow,
@@ -124,6 +129,7 @@ impl TryFrom<diesel_models::gsm::GatewayStatusMap> for GatewayStatusMap {
             error_category: item.error_category,
             feature_data,
             feature,
+            match_type,
         })
     }
 }
@@ -1032,6 +1032,8 @@ pub struct PaymentAttempt {
     pub network_transaction_id: Option<String>,
     pub is_overcapture_enabled: Option<OvercaptureEnabledBool>,
     pub network_details: Option<NetworkDetails>,
+    pub flagged_velocity_rules: Option<Vec<String>>,
+    pub blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -1330,6 +1332,8 @@ pub struct PaymentAttemptNew {
     pub connector_request_reference_id: Option<String>,
     pub network_transaction_id: Option<String>,
     pub network_details: Option<NetworkDetails>,
+    pub flagged_velocity_rules: Option<Vec<String>>,
+    pub blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
 }
 
 #[cfg(feature = "v1")]
@@ -1401,6 +1405,7 @@ pub enum PaymentAttemptUpdate {
         routing_approach: Option<storage_enums::RoutingApproach>,
         connector_request_reference_id: Option<String>,
         network_transaction_id: Option<String>,
+        flagged_velocity_rules: Option<Vec<String>>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -1413,6 +1418,7 @@ pub enum PaymentAttemptUpdate {
         error_code: Option<Option<String>>,
         error_message: Option<Option<String>>,
         updated_by: String,
+        blocklist_match_details: Option<common_types::payments::BlocklistMatchDetails>,
     },
     PaymentMethodDetailsUpdate {
         payment_method_id: Option<String>,
@@ -1623,11 +1629,13 @@ impl PaymentAttemptUpdate {
                 error_code,
                 error_message,
                 updated_by,
+                blocklist_match_details,
             } => DieselPaymentAttemptUpdate::BlocklistUpdate {
                 status,
                 error_code,
                 error_message,
                 updated_by,
+                blocklist_match_details,
             },
             Self::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -1676,6 +1684,7 @@ impl PaymentAttemptUpdate {
                 routing_approach,
                 connector_request_reference_id,
                 network_transaction_id,
+                flagged_velocity_rules,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -1720,6 +1729,7 @@ impl PaymentAttemptUpdate {
                 }),
                 connector_request_reference_id,
                 network_transaction_id,
+                flagged_velocity_rules,
             },
             Self::VoidUpdate {
                 status,
@@ -2164,6 +2174,8 @@ impl behaviour::Conversion for PaymentAttempt {
             network_transaction_id: self.network_transaction_id,
             is_overcapture_enabled: self.is_overcapture_enabled,
             network_details: self.network_details,
+            flagged_velocity_rules: self.flagged_velocity_rules,
+            blocklist_match_details: self.blocklist_match_details,
         })
     }
 
@@ -2265,6 +2277,8 @@ impl behaviour::Conversion for PaymentAttempt {
                 network_transaction_id: storage_model.network_transaction_id,
                 is_overcapture_enabled: storage_model.is_overcapture_enabled,
                 network_details: storage_model.network_details,
+                flagged_velocity_rules: storage_model.flagged_velocity_rules,
+                blocklist_match_details: storage_model.blocklist_match_details,
             })
         }
         .await
@@ -2357,6 +2371,8 @@ impl behaviour::Conversion for PaymentAttempt {
             connector_request_reference_id: self.connector_request_reference_id,
             network_transaction_id: self.network_transaction_id,
             network_details: self.network_details,
+            flagged_velocity_rules: self.flagged_velocity_rules,
+            blocklist_match_details: self.blocklist_match_details,
         })
     }
 }
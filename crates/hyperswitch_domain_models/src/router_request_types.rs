@@ -887,6 +887,7 @@ pub struct BrowserInformation {
     pub os_type: Option<String>,
     pub os_version: Option<String>,
     pub device_model: Option<String>,
+    pub device_id: Option<String>,
     pub accept_language: Option<String>,
 }
 
@@ -907,6 +908,7 @@ impl From<common_utils::types::BrowserInformation> for BrowserInformation {
             os_type: value.os_type,
             os_version: value.os_version,
             device_model: value.device_model,
+            device_id: value.device_id,
             accept_language: value.accept_language,
         }
     }
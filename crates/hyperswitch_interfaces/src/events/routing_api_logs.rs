@@ -9,6 +9,12 @@ use serde::Serialize;
 use serde_json::json;
 use time::OffsetDateTime;
 
+/// Flow of the routing events logged for the evaluation of a shadow routing algorithm
+pub const SHADOW_ROUTING_FLOW: &str = "Shadow Routing";
+
+/// Flow of the routing events logged for the decisions of advanced routing algorithms
+pub const RULE_BASED_ROUTING_FLOW: &str = "Rule Based Routing";
+
 /// RoutingEngine enum
 #[derive(Debug, Clone, Copy, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -17,6 +23,8 @@ pub enum RoutingEngine {
     IntelligentRouter,
     /// Decision engine for routing
     DecisionEngine,
+    /// Euclid routing evaluated within the application
+    Euclid,
 }
 
 /// Method type enum
@@ -27,6 +35,8 @@ pub enum ApiMethod {
     Grpc,
     /// Rest call
     Rest(Method),
+    /// Evaluated within the application
+    Internal,
 }
 
 impl fmt::Display for ApiMethod {
@@ -34,6 +44,7 @@ impl fmt::Display for ApiMethod {
         match self {
             Self::Grpc => write!(f, "Grpc"),
             Self::Rest(method) => write!(f, "Rest ({method})"),
+            Self::Internal => write!(f, "Internal"),
         }
     }
 }
@@ -58,6 +69,11 @@ pub struct RoutingEvent {
     request_id: String,
     routing_engine: RoutingEngine,
     routing_approach: Option<String>,
+    shadow_algorithm_id: Option<String>,
+    shadow_connector: Option<String>,
+    is_shadow_agreement: Option<bool>,
+    routing_algorithm_id: Option<String>,
+    rule_name: Option<String>,
 }
 
 impl RoutingEvent {
@@ -96,6 +112,11 @@ impl RoutingEvent {
             routing_engine,
             payment_connector: None,
             routing_approach: None,
+            shadow_algorithm_id: None,
+            shadow_connector: None,
+            is_shadow_agreement: None,
+            routing_algorithm_id: None,
+            rule_name: None,
         }
     }
 
@@ -149,14 +170,32 @@ impl RoutingEvent {
 
     /// set payment connector
     pub fn set_payment_connector(&mut self, connector: RoutableConnectorChoice) {
-        self.payment_connector = Some(format!(
-            "{:?}:{:?}",
-            connector.connector,
-            connector
-                .merchant_connector_id
-                .map(|id| id.get_string_repr().to_string())
-                .unwrap_or(String::from(""))
-        ));
+        self.payment_connector = Some(format_connector_choice(&connector));
+    }
+
+    /// set the decision of the shadow routing algorithm, which agrees with the live decision if
+    /// both picked the same connector account
+    pub fn set_shadow_decision(
+        &mut self,
+        shadow_algorithm_id: &common_utils::id_type::RoutingId,
+        live_connector: Option<&RoutableConnectorChoice>,
+        shadow_connector: Option<&RoutableConnectorChoice>,
+    ) {
+        self.shadow_algorithm_id = Some(shadow_algorithm_id.get_string_repr().to_string());
+        self.payment_connector = live_connector.map(format_connector_choice);
+        self.shadow_connector = shadow_connector.map(format_connector_choice);
+        self.is_shadow_agreement = Some(self.payment_connector == self.shadow_connector);
+    }
+
+    /// set the rule of the advanced routing algorithm that made the decision, the rule is absent
+    /// if the default selection of the algorithm was used
+    pub fn set_matched_rule(
+        &mut self,
+        routing_algorithm_id: &common_utils::id_type::RoutingId,
+        rule_name: Option<String>,
+    ) {
+        self.routing_algorithm_id = Some(routing_algorithm_id.get_string_repr().to_string());
+        self.rule_name = rule_name;
     }
 
     /// set routing approach
@@ -184,3 +223,97 @@ impl RoutingEvent {
         self.profile_id.get_string_repr()
     }
 }
+
+fn format_connector_choice(connector: &RoutableConnectorChoice) -> String {
+    format!(
+        "{:?}:{:?}",
+        connector.connector,
+        connector
+            .merchant_connector_id
+            .as_ref()
+            .map(|id| id.get_string_repr().to_string())
+            .unwrap_or(String::from(""))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use api_models::{enums::RoutableConnectors, routing::RoutableChoiceKind};
+    use common_utils::id_type;
+
+    use super::*;
+
+    fn connector_choice(
+        connector: RoutableConnectors,
+        merchant_connector_id: &str,
+    ) -> RoutableConnectorChoice {
+        RoutableConnectorChoice {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: Some(
+                id_type::MerchantConnectorAccountId::wrap(merchant_connector_id.to_string())
+                    .unwrap(),
+            ),
+        }
+    }
+
+    fn shadow_routing_event(
+        live_connector: Option<&RoutableConnectorChoice>,
+        shadow_connector: Option<&RoutableConnectorChoice>,
+    ) -> RoutingEvent {
+        let mut routing_event = RoutingEvent::new(
+            id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+            String::new(),
+            SHADOW_ROUTING_FLOW,
+            serde_json::Value::Null,
+            String::new(),
+            ApiMethod::Internal,
+            "pay_1".to_string(),
+            id_type::ProfileId::try_from(std::borrow::Cow::Borrowed("pro_1")).unwrap(),
+            id_type::MerchantId::try_from(std::borrow::Cow::Borrowed("merchant_1")).unwrap(),
+            None,
+            RoutingEngine::Euclid,
+        );
+        routing_event.set_shadow_decision(
+            &id_type::RoutingId::try_from(std::borrow::Cow::Borrowed("routing_1")).unwrap(),
+            live_connector,
+            shadow_connector,
+        );
+        routing_event
+    }
+
+    #[test]
+    fn test_set_shadow_decision() {
+        let stripe = connector_choice(RoutableConnectors::Stripe, "mca_1");
+        let other_stripe = connector_choice(RoutableConnectors::Stripe, "mca_2");
+        let adyen = connector_choice(RoutableConnectors::Adyen, "mca_3");
+
+        let routing_event = shadow_routing_event(Some(&stripe), Some(&stripe));
+        assert_eq!(
+            routing_event.shadow_algorithm_id.as_deref(),
+            Some("routing_1")
+        );
+        assert_eq!(
+            routing_event.payment_connector.as_deref(),
+            Some("Stripe:\"mca_1\"")
+        );
+        assert_eq!(
+            routing_event.shadow_connector.as_deref(),
+            Some("Stripe:\"mca_1\"")
+        );
+        assert_eq!(routing_event.is_shadow_agreement, Some(true));
+
+        // The connector accounts are compared, not only the connectors
+        let routing_event = shadow_routing_event(Some(&stripe), Some(&other_stripe));
+        assert_eq!(routing_event.is_shadow_agreement, Some(false));
+
+        let routing_event = shadow_routing_event(Some(&stripe), Some(&adyen));
+        assert_eq!(routing_event.is_shadow_agreement, Some(false));
+
+        let routing_event = shadow_routing_event(Some(&stripe), None);
+        assert_eq!(routing_event.shadow_connector, None);
+        assert_eq!(routing_event.is_shadow_agreement, Some(false));
+    }
+}
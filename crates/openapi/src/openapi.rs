@@ -121,6 +121,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::get_gsm_rule,
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
+        routes::gsm::import_gsm_rules,
+        routes::gsm::export_gsm_rules,
+        routes::gsm::get_unmapped_gsm_errors,
 
         // Routes for mandates
         routes::mandates::get_mandate,
@@ -159,8 +162,15 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_simulate_config,
+        routes::routing::routing_import_dsl_config,
+        routes::routing::routing_export_dsl_config,
+        routes::routing::routing_explain_eligibility,
+        routes::routing::routing_retrieve_rule_hits,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
+        routes::routing::routing_link_shadow_config,
+        routes::routing::routing_unlink_shadow_config,
         routes::routing::routing_update_default_config,
         routes::routing::routing_retrieve_default_config,
         routes::routing::routing_retrieve_linked_config,
@@ -179,6 +189,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::blocklist::remove_entry_from_blocklist,
         routes::blocklist::list_blocked_payment_methods,
         routes::blocklist::add_entry_to_blocklist,
+        routes::blocklist::bulk_add_entries_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
 
         // Routes for payouts
@@ -260,6 +271,7 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::refunds::SplitRefund,
         common_types::refunds::StripeSplitRefundRequest,
         common_types::payments::ConnectorChargeResponseData,
+        common_types::payments::BlocklistMatchDetails,
         common_types::payments::StripeChargeResponseData,
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::three_ds_decision_rule_engine::ThreeDSDecision,
@@ -293,6 +305,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::BusinessPayoutLinkConfig,
         api_models::admin::CardTestingGuardConfig,
         api_models::admin::CardTestingGuardStatus,
+        common_types::domain::VelocityRule,
+        api_models::enums::VelocityRuleKey,
+        api_models::enums::VelocityMeasure,
+        api_models::enums::VelocityAction,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerUpdateRequest,
         api_models::customers::CustomerDeleteResponse,
@@ -411,10 +427,18 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteRequest,
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
+        api_models::gsm::GsmImportRequest,
+        api_models::gsm::GsmImportResponse,
+        api_models::gsm::GsmExportFormat,
+        api_models::gsm::GsmUnmappedErrorsRequest,
+        api_models::gsm::GsmUnmappedErrorsResponse,
+        api_models::gsm::GsmUnmappedError,
         api_models::enums::GsmDecision,
         api_models::enums::GsmFeature,
+        api_models::enums::GsmMatchType,
         common_types::domain::GsmFeatureData,
         common_types::domain::RetryFeatureData,
+        common_types::domain::RetryLaterConfig,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -636,6 +660,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::subscription::SubscriptionResponse,
+        api_models::enums::SubscriptionStatus,
         api_models::mandates::RecurringDetails,
         api_models::mandates::NetworkTransactionIdAndCardDetails,
         api_models::mandates::ProcessorPaymentToken,
@@ -664,6 +690,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutRetrieveRequest,
         api_models::payouts::PayoutAttemptResponse,
         api_models::payouts::PayoutCreateResponse,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::enums::PayoutBatchStatus,
         api_models::payouts::PayoutListConstraints,
         api_models::payouts::PayoutListFilters,
         api_models::payouts::PayoutListFilterConstraints,
@@ -694,6 +722,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
+        common_types::api_keys::ApiKeyRestrictions,
+        common_types::api_keys::ApiKeyPermission,
+        api_models::enums::Resource,
+        api_models::enums::PermissionScope,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
@@ -707,6 +739,21 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationReportFormat,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::RoutingSimulationConnectorVolume,
+        api_models::routing::RoutingSimulationBreakdown,
+        api_models::routing::RoutingSimulationAttempt,
+        api_models::routing::RoutingDslImportRequest,
+        api_models::routing::RoutingDslExportResponse,
+        api_models::routing::RoutingEligibilityExplainRequest,
+        api_models::routing::RoutingEligibilityContext,
+        api_models::routing::RoutingEligibilityExplainResponse,
+        api_models::routing::ConnectorEligibilityExplanation,
+        api_models::routing::EligibilityCheckResult,
+        api_models::routing::RoutingRuleHitsResponse,
+        api_models::routing::RoutingRuleHits,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::DynamicRoutingFeatures,
@@ -744,6 +791,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ast::ProgramConnectorSelection,
         api_models::routing::ast::RuleConnectorSelection,
         api_models::routing::ast::IfStatement,
+        api_models::routing::ast::ConditionExpression,
+        api_models::routing::ast::ConditionGroup,
         api_models::routing::ast::Comparison,
         api_models::routing::ast::ComparisonType,
         api_models::routing::ast::ValueType,
@@ -752,7 +801,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
+        api_models::blocklist::BulkAddToBlocklistRequest,
         api_models::blocklist::BlocklistResponse,
+        api_models::blocklist::BulkAddToBlocklistResponse,
+        api_models::blocklist::BulkBlocklistEntryError,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
@@ -337,6 +337,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::AdditionalMerchantData,
         api_models::admin::CardTestingGuardConfig,
         api_models::admin::CardTestingGuardStatus,
+        common_types::domain::VelocityRule,
+        api_models::enums::VelocityRuleKey,
+        api_models::enums::VelocityMeasure,
+        api_models::enums::VelocityAction,
         api_models::admin::ConnectorWalletDetails,
         api_models::admin::MerchantRecipientData,
         api_models::admin::MerchantAccountData,
@@ -366,8 +370,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmResponse,
         api_models::enums::GsmDecision,
         api_models::enums::GsmFeature,
+        api_models::enums::GsmMatchType,
         common_types::domain::GsmFeatureData,
         common_types::domain::RetryFeatureData,
+        common_types::domain::RetryLaterConfig,
         api_models::payments::NullObject,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
@@ -673,6 +679,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
+        common_types::api_keys::ApiKeyRestrictions,
+        common_types::api_keys::ApiKeyPermission,
+        api_models::enums::Resource,
+        api_models::enums::PermissionScope,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
@@ -723,6 +733,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ast::ProgramConnectorSelection,
         api_models::routing::ast::RuleConnectorSelection,
         api_models::routing::ast::IfStatement,
+        api_models::routing::ast::ConditionExpression,
+        api_models::routing::ast::ConditionGroup,
         api_models::routing::ast::Comparison,
         api_models::routing::ast::ComparisonType,
         api_models::routing::ast::ValueType,
@@ -732,7 +744,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
+        api_models::blocklist::BulkAddToBlocklistRequest,
         api_models::blocklist::BlocklistResponse,
+        api_models::blocklist::BulkAddToBlocklistResponse,
+        api_models::blocklist::BulkBlocklistEntryError,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
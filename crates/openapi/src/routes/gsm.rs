@@ -73,3 +73,59 @@ pub async fn update_gsm_rule() {}
     security(("admin_api_key" = [])),
 )]
 pub async fn delete_gsm_rule() {}
+
+/// Gsm - Import
+///
+/// Imports Gsm Rules in bulk. All the rules are validated before any of them is added, so that either all of them are imported or none. The rules can also be uploaded as a CSV file with one rule per row to `/gsm/import/csv`, in the format of the CSV export
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules imported", body = GsmImportResponse),
+        (status = 400, description = "Invalid Gsm rules")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn import_gsm_rules() {}
+
+/// Gsm - Export
+///
+/// Exports all Gsm Rules, as a JSON list of rules or as a CSV file which can be imported again
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(
+        ("format" = Option<GsmExportFormat>, Query, description = "The format of the exported rules, defaults to json"),
+    ),
+    responses(
+        (status = 200, description = "Gsm rules exported", body = Vec<GsmResponse>),
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn export_gsm_rules() {}
+
+/// Gsm - Unmapped errors
+///
+/// Lists the most frequent errors of failed payment attempts that aren't matched by any Gsm Rule, each with the unified code of the existing rule with the most similar message
+#[utoipa::path(
+    post,
+    path = "/gsm/unmapped_errors",
+    request_body(
+        content = GsmUnmappedErrorsRequest,
+    ),
+    responses(
+        (status = 200, description = "Unmapped errors retrieved", body = GsmUnmappedErrorsResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "List Unmapped Gsm Errors",
+    security(("admin_api_key" = [])),
+)]
+pub async fn get_unmapped_gsm_errors() {}
@@ -6,6 +6,9 @@
 #[utoipa::path(
     post,
     path = "/payments",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Unique key to safely retry the request, the first response is returned for retries with the same key and body")
+    ),
     request_body(
         content = PaymentsCreateRequest,
         examples(
@@ -656,7 +659,8 @@ pub fn payments_update() {}
     post,
     path = "/payments/{payment_id}/confirm",
     params(
-        ("payment_id" = String, Path, description = "The identifier for payment")
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Unique key to safely retry the request, the first response is returned for retries with the same key and body")
     ),
     request_body(
      content = PaymentsConfirmRequest,
@@ -712,7 +716,8 @@ pub fn payments_confirm() {}
     post,
     path = "/payments/{payment_id}/capture",
     params(
-        ("payment_id" = String, Path, description = "The identifier for payment")
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Unique key to safely retry the request, the first response is returned for retries with the same key and body")
     ),
     request_body (
         content = PaymentsCaptureRequest,
@@ -2,6 +2,9 @@
 #[utoipa::path(
     post,
     path = "/payouts/create",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Unique key to safely retry the request, the first response is returned for retries with the same key and body")
+    ),
     request_body=PayoutsCreateRequest,
     responses(
         (status = 200, description = "Payout created", body = PayoutCreateResponse),
@@ -4,6 +4,9 @@
 #[utoipa::path(
     post,
     path = "/refunds",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Unique key to safely retry the request, the first response is returned for retries with the same key and body")
+    ),
     request_body(
         content = RefundRequest,
         examples(
@@ -86,6 +86,124 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Simulate
+///
+/// Replay a routing algorithm against the recent payment attempts of its profile, and compare the connectors it would have picked with the ones actually used
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/simulate",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Successfully simulated routing config", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Import DSL
+///
+/// Create an advanced routing algorithm from a program written in the routing DSL
+#[utoipa::path(
+    post,
+    path = "/routing/dsl/import",
+    request_body = RoutingDslImportRequest,
+    responses(
+        (status = 200, description = "Routing config created", body = RoutingDictionaryRecord),
+        (status = 400, description = "Request body is malformed or the DSL could not be parsed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Import a routing config from DSL",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_import_dsl_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Export DSL
+///
+/// Render an advanced routing algorithm in the canonical form of the routing DSL
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/dsl",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Successfully exported routing config", body = RoutingDslExportResponse),
+        (status = 400, description = "Routing config is not an advanced algorithm"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Export a routing config as DSL",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_export_dsl_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Explain Eligibility
+///
+/// Explain which connectors and payment method types are eligible for a payment, listing the
+/// routing and payment method filter constraints that were not satisfied
+#[utoipa::path(
+    post,
+    path = "/routing/eligibility/explain",
+    request_body = RoutingEligibilityExplainRequest,
+    responses(
+        (status = 200, description = "Eligibility of the connectors explained", body = RoutingEligibilityExplainResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Explain connector eligibility",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_explain_eligibility() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve Rule Hits
+///
+/// Retrieve the number of routing decisions made by each rule of an advanced routing algorithm
+/// within a time range, along with the last time each rule made a decision. Rules without any
+/// hits are included, hits are retained for 30 days
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/rule_hits",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+        ("start_time" = PrimitiveDateTime, Query, description = "The start of the time range, rounded down to the hour"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "The end of the time range, defaults to the current time"),
+    ),
+    responses(
+        (status = 200, description = "Successfully fetched the rule hits", body = RoutingRuleHitsResponse),
+        (status = 400, description = "Routing config is not an advanced algorithm or the time range is invalid"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve rule hits of a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_rule_hits() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Retrieve
 ///
@@ -152,6 +270,50 @@ pub async fn list_routing_configs() {}
 )]
 pub async fn routing_unlink_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Activate in shadow mode
+///
+/// Evaluate a routing algorithm alongside the active one for every payment of its profile, without affecting the connector used for the payment
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/shadow/activate",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Routing config activated in shadow mode", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Bad request"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Activate a routing config in shadow mode",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_link_shadow_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Deactivate shadow mode
+///
+/// Stops evaluating the shadow routing config of a profile
+#[utoipa::path(
+    post,
+    path = "/routing/shadow/deactivate",
+    request_body = RoutingConfigRequest,
+    responses(
+        (status = 200, description = "Successfully deactivated shadow routing config", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Routing",
+   operation_id = "Deactivate the shadow routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_unlink_shadow_config() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Update Default Config
 ///
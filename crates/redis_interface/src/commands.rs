@@ -18,6 +18,7 @@ use fred::{
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         MultipleValues, RedisMap, RedisValue, ScanType, Scanner, SetOptions, XCap, XReadResponse,
+        XReadValue,
     },
 };
 use futures::StreamExt;
@@ -597,6 +598,18 @@ impl super::RedisConnectionPool {
         Ok(values_after_increment)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_fields_from_hash(
+        &self,
+        key: &RedisKey,
+        fields: Vec<String>,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .hdel(key.tenant_aware_key(self), fields)
+            .await
+            .change_context(errors::RedisError::DeleteHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan(
         &self,
@@ -853,6 +866,20 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetLengthFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_entries_in_range(
+        &self,
+        stream: &RedisKey,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<XReadValue<String, String, String>>, errors::RedisError> {
+        self.pool
+            .xrange_values(stream.tenant_aware_key(self), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     pub fn get_keys_with_prefix<K>(&self, keys: K) -> MultipleKeys
     where
         K: Into<MultipleKeys> + Debug + Send + Sync,
@@ -986,6 +1013,19 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::PopListElementsFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn trim_list(
+        &self,
+        key: &RedisKey,
+        start: i64,
+        stop: i64,
+    ) -> CustomResult<(), errors::RedisError> {
+        self.pool
+            .ltrim(key.tenant_aware_key(self), start, stop)
+            .await
+            .change_context(errors::RedisError::TrimListFailed)
+    }
+
     //                                              Consumer Group API
 
     #[instrument(level = "DEBUG", skip(self))]
@@ -74,6 +74,10 @@ pub enum RedisError {
     GetListLengthFailed,
     #[error("Failed to pop list elements in Redis")]
     PopListElementsFailed,
+    #[error("Failed to trim list in Redis")]
+    TrimListFailed,
     #[error("Failed to increment hash field in Redis")]
     IncrementHashFieldFailed,
+    #[error("Failed to delete hash fields in Redis")]
+    DeleteHashFieldFailed,
 }
@@ -379,6 +379,10 @@ pub mod routes {
                                     web::resource("routing_event_logs")
                                         .route(web::get().to(get_profile_routing_events)),
                                 )
+                                .service(
+                                    web::resource("metrics/shadow_routing")
+                                        .route(web::post().to(get_profile_shadow_routing_metrics)),
+                                )
                                 .service(
                                     web::resource("outgoing_webhook_event_logs")
                                         .route(web::get().to(get_profile_outgoing_webhook_events)),
@@ -2455,6 +2459,39 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_profile_shadow_routing_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<api_models::analytics::routing_events::ShadowRoutingMetricsRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetShadowRoutingMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                analytics::routing_events::shadow_routing_metrics_core(
+                    &state.pool,
+                    req,
+                    auth.merchant_account.get_id(),
+                    &profile_id,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_global_search_results(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -330,6 +330,68 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::WebhookSigningSecretExpiryWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::webhook_signing_secret_expiry::WebhookSigningSecretExpiryWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run webhook signing secret expiry workflow when v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::SubscriptionRenewalWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::subscription_renewal::SubscriptionRenewalWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run subscription renewal workflow when v1 feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(all(feature = "payouts", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+
+                    #[cfg(not(all(feature = "payouts", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::PaymentRetryLaterWorkflow => {
+                    #[cfg(all(feature = "retry", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::payment_retry_later::PaymentRetryLaterWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(all(feature = "retry", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payment retry later workflow when retry feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
@@ -285,6 +285,8 @@ pub enum StripeErrorCode {
     PlatformUnauthorizedRequest,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Profile Acquirer not found")]
     ProfileAcquirerNotFound,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyError,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -527,6 +529,14 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 connector,
                 status_code,
             },
+            errors::ApiErrorResponse::ConnectorCircuitOpen { connector } => {
+                Self::ExternalConnectorError {
+                    code: "CE_09".to_string(),
+                    message: format!("Connector '{connector}' is temporarily unavailable"),
+                    connector,
+                    status_code: 503,
+                }
+            }
             errors::ApiErrorResponse::IncorrectConnectorNameGiven => {
                 Self::IncorrectConnectorNameGiven
             }
@@ -693,6 +703,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::ProfileAcquirerNotFound { .. } => {
                 Self::ProfileAcquirerNotFound
             }
+            errors::ApiErrorResponse::IdempotencyKeyConflict { .. } => Self::IdempotencyError,
             errors::ApiErrorResponse::TokenizationRecordNotFound { id } => Self::ResourceMissing {
                 object: "tokenization record".to_owned(),
                 id,
@@ -771,6 +782,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentMethodDeleteFailed
             | Self::ExtendedCardInfoNotFound
             | Self::PlatformBadRequest
+            | Self::IdempotencyError
             | Self::LinkConfigurationError { .. } => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::PayoutFailed
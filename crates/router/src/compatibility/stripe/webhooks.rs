@@ -90,6 +90,10 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    #[cfg(feature = "v1")]
+    Subscription(Box<api_models::subscription::SubscriptionResponse>),
+    #[cfg(feature = "payouts")]
+    PayoutBatch(Box<api_models::payouts::PayoutBatchResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -302,6 +306,12 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::SubscriptionActive
+        | api_models::enums::EventType::SubscriptionTrialing => "customer.subscription.updated",
+        api_models::enums::EventType::SubscriptionPaused => "customer.subscription.paused",
+        api_models::enums::EventType::SubscriptionPastDue => "invoice.payment_failed",
+        api_models::enums::EventType::SubscriptionCancelled => "customer.subscription.deleted",
+        api_models::enums::EventType::PayoutBatchCompleted => "payout_batch.completed",
     }
 }
 
@@ -344,6 +354,14 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            #[cfg(feature = "v1")]
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription(subscription)
+            }
+            #[cfg(feature = "payouts")]
+            api::OutgoingWebhookContent::PayoutBatchDetails(payout_batch) => {
+                Self::PayoutBatch(payout_batch)
+            }
         }
     }
 }
@@ -124,6 +124,20 @@ impl Default for super::settings::KvConfig {
     }
 }
 
+impl Default for super::settings::CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_secs: 60,
+            min_request_count: 20,
+            error_rate_threshold: 50.0,
+            latency_threshold_in_ms: 10000,
+            open_duration_in_secs: 30,
+            half_open_max_requests: 3,
+        }
+    }
+}
+
 impl Default for super::settings::GlobalTenant {
     fn default() -> Self {
         Self {
@@ -156,6 +170,8 @@ impl Default for super::settings::ApiKeys {
 
             #[cfg(feature = "partial-auth")]
             enable_partial_auth: false,
+
+            trusted_proxies: Vec::new(),
         }
     }
 }
@@ -117,6 +117,8 @@ impl SecretsHandler for settings::ApiKeys {
         #[cfg(feature = "partial-auth")]
         let enable_partial_auth = api_keys.enable_partial_auth;
 
+        let trusted_proxies = api_keys.trusted_proxies.clone();
+
         #[cfg(feature = "partial-auth")]
         let (checksum_auth_context, checksum_auth_key) = {
             if enable_partial_auth {
@@ -143,6 +145,8 @@ impl SecretsHandler for settings::ApiKeys {
             checksum_auth_context,
             #[cfg(feature = "partial-auth")]
             enable_partial_auth,
+
+            trusted_proxies,
         }))
     }
 }
@@ -543,6 +547,8 @@ pub(crate) async fn fetch_raw_secrets(
         l2_l3_data_config: conf.l2_l3_data_config,
         authentication_providers: conf.authentication_providers,
         open_router: conf.open_router,
+        in_process_dynamic_routing: conf.in_process_dynamic_routing,
+        circuit_breaker: conf.circuit_breaker,
         #[cfg(feature = "v2")]
         revenue_recovery: conf.revenue_recovery,
         debit_routing_config: conf.debit_routing_config,
@@ -159,6 +159,10 @@ pub struct Settings<S: SecretState> {
     pub platform: Platform,
     pub authentication_providers: AuthenticationProviders,
     pub open_router: OpenRouter,
+    #[serde(default)]
+    pub in_process_dynamic_routing: InProcessDynamicRouting,
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
     #[cfg(feature = "v2")]
     pub revenue_recovery: revenue_recovery::RevenueRecoverySettings,
     pub clone_connector_allowlist: Option<CloneConnectorAllowlistConfig>,
@@ -187,6 +191,33 @@ pub struct OpenRouter {
     pub url: String,
 }
 
+/// Thresholds of the per merchant connector account circuit breaker
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    pub enabled: bool,
+    /// Duration of the window over which connector calls are evaluated
+    pub window_in_secs: u64,
+    /// Minimum number of connector calls in the window before the error rate is evaluated
+    pub min_request_count: u64,
+    /// Percentage of failed or slow calls in the window which opens the circuit
+    pub error_rate_threshold: f64,
+    /// Calls taking longer than this are considered as failures
+    pub latency_threshold_in_ms: u64,
+    /// Duration for which the circuit stays open before probe calls are allowed
+    pub open_duration_in_secs: u64,
+    /// Number of probe calls allowed while the circuit is half open
+    pub half_open_max_requests: u64,
+}
+
+/// Success rate and elimination routing computed by the router itself, used when the dynamic
+/// routing gRPC client is not configured
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct InProcessDynamicRouting {
+    pub enabled: bool,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct CloneConnectorAllowlistConfig {
@@ -888,6 +919,10 @@ pub struct ApiKeys {
 
     #[cfg(feature = "partial-auth")]
     pub enable_partial_auth: bool,
+
+    /// IP ranges of the proxies in front of the application, the `X-Forwarded-For` header is
+    /// honoured for determining the source IP address only for requests received from them
+    pub trusted_proxies: Vec<common_types::api_keys::IpRange>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -1116,6 +1151,8 @@ impl Settings<SecuredSecret> {
 
         self.open_router.validate()?;
 
+        self.circuit_breaker.validate()?;
+
         // Validate gRPC client settings
         #[cfg(feature = "revenue_recovery")]
         self.grpc_client
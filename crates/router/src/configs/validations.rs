@@ -344,6 +344,33 @@ impl super::settings::OpenRouter {
     }
 }
 
+impl super::settings::CircuitBreakerConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled
+                && (self.window_in_secs == 0
+                    || self.open_duration_in_secs == 0
+                    || self.half_open_max_requests == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "circuit breaker window, open duration and half open requests must be greater than zero".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.enabled && !(0.0..=100.0).contains(&self.error_rate_threshold),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "circuit breaker error rate threshold must be between 0 and 100".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::ChatSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -57,6 +57,9 @@ pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32
 pub const MAX_ROUTING_CONFIGS_PER_MERCHANT: usize = 100;
 pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
 
+/// Maximum number of entries accepted by a bulk blocklist upload
+pub const MAX_BULK_BLOCKLIST_ENTRIES: usize = 1000;
+
 pub const LOCKER_REDIS_PREFIX: &str = "LOCKER_PM_TOKEN";
 pub const LOCKER_REDIS_EXPIRY_SECONDS: u32 = 60 * 15; // 15 minutes
 
@@ -85,11 +88,7 @@ pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
 
 pub const ROLE_INFO_CACHE_PREFIX: &str = "CR_INFO_";
 
-pub const CARD_IP_BLOCKING_CACHE_KEY_PREFIX: &str = "CARD_IP_BLOCKING";
-
-pub const GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX: &str = "GUEST_USER_CARD_BLOCKING";
-
-pub const CUSTOMER_ID_BLOCKING_PREFIX: &str = "CUSTOMER_ID_BLOCKING";
+pub const VELOCITY_RULE_CACHE_KEY_PREFIX: &str = "VELOCITY_RULE";
 
 #[cfg(feature = "olap")]
 pub const VERIFY_CONNECTOR_ID_PREFIX: &str = "conn_verify";
@@ -210,6 +209,23 @@ pub const VAULT_GET_FINGERPRINT_FLOW_TYPE: &str = "get_fingerprint_vault";
 /// Max volume split for Dynamic routing
 pub const DYNAMIC_ROUTING_MAX_VOLUME: u8 = 100;
 
+/// Upper bound of the TTL of in-process dynamic routing windows and buckets in seconds
+pub const IN_PROCESS_DYNAMIC_ROUTING_WINDOW_TTL: i64 = 7 * 24 * 60 * 60; // 7 days
+
+/// Number of hourly buckets for which the hit counts of routing rules are kept
+pub const ROUTING_RULE_HITS_RETENTION_IN_HOURS: i64 = 30 * 24; // 30 days
+
+/// TTL of the state of an open circuit in seconds, after which the circuit is closed
+pub const CIRCUIT_BREAKER_STATE_TTL: i64 = 24 * 60 * 60; // 1 day
+
+/// Default duration in seconds for which the previous webhook signing secret of a profile remains
+/// valid after the secret is rotated
+pub const DEFAULT_WEBHOOK_SIGNING_SECRET_GRACE_PERIOD: u32 = 24 * 60 * 60; // 1 day
+
+/// Maximum duration in seconds for which the previous webhook signing secret of a profile remains
+/// valid after the secret is rotated
+pub const MAX_WEBHOOK_SIGNING_SECRET_GRACE_PERIOD: u32 = 7 * 24 * 60 * 60; // 7 days
+
 /// Click To Pay
 pub const CLICK_TO_PAY: &str = "click_to_pay";
 
@@ -281,6 +297,16 @@ pub const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID: &str =
 // Default payment method storing TTL in redis in seconds
 pub const DEFAULT_PAYMENT_METHOD_STORE_TTL: i64 = 86400; // 1 day
 
+// TTL of the stored response for an inbound Idempotency-Key in redis in seconds
+pub const IDEMPOTENCY_KEY_TTL: i64 = 86400; // 1 day
+
+// TTL of the reservation of an inbound Idempotency-Key in redis while the request is in flight, in
+// seconds. It bounds how long a key stays busy if the request never completes
+pub const IDEMPOTENCY_KEY_LOCK_TTL: i64 = 300; // 5 minutes
+
+/// Maximum length of an inbound Idempotency-Key header value
+pub const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
 // List of countries that are part of the PSD2 region
 pub const PSD2_COUNTRIES: [Country; 27] = [
     Country::Austria,
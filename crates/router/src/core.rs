@@ -3,12 +3,14 @@ pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
 pub mod apple_pay_certificates_migration;
+pub mod audit_log;
 pub mod authentication;
 #[cfg(feature = "v1")]
 pub mod blocklist;
 pub mod cache;
 pub mod card_testing_guard;
 pub mod cards_info;
+pub mod circuit_breaker;
 pub mod conditional_config;
 pub mod configs;
 #[cfg(feature = "olap")]
@@ -26,6 +28,7 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
@@ -49,6 +52,8 @@ pub mod refunds_v2;
 #[cfg(feature = "v1")]
 pub mod debit_routing;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscription;
 pub mod surcharge_decision_config;
 pub mod three_ds_decision_rule;
 #[cfg(feature = "olap")]
@@ -28,6 +28,7 @@ use crate::types::transformers::ForeignFrom;
 use crate::{
     consts,
     core::{
+        audit_log,
         connector_validation::ConnectorAuthTypeAndMetadataValidation,
         disputes,
         encryption::transfer_encryption_key,
@@ -2614,7 +2615,20 @@ pub async fn create_connector(
         ),
     );
 
-    let mca_response = mca.foreign_try_into()?;
+    let merchant_connector_id = mca.get_id();
+    let mca_response: api_models::admin::MerchantConnectorResponse = mca.foreign_try_into()?;
+
+    audit_log::record_audit_log(
+        &state,
+        Some(merchant_id),
+        common_enums::AuditLogResourceType::MerchantConnectorAccount,
+        merchant_connector_id.get_string_repr(),
+        common_enums::AuditLogAction::Create,
+        None,
+        Some(&mca_response),
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
 
@@ -2851,6 +2865,8 @@ pub async fn update_connector(
         )
         .await?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+    let mca_response_before_update =
+        api_models::admin::MerchantConnectorResponse::foreign_try_from(mca.clone()).ok();
 
     let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
         merchant_account.clone(),
@@ -2889,6 +2905,17 @@ pub async fn update_connector(
 
     let response = updated_mca.foreign_try_into()?;
 
+    audit_log::record_audit_log(
+        &state,
+        Some(merchant_id),
+        common_enums::AuditLogResourceType::MerchantConnectorAccount,
+        merchant_connector_id.get_string_repr(),
+        common_enums::AuditLogAction::Update,
+        mca_response_before_update.as_ref(),
+        Some(&response),
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
@@ -2956,6 +2983,19 @@ pub async fn delete_connector(
         .retrieve_and_delete_from_default_fallback_routing_algorithm_if_routable_connector_exists()
         .await?;
 
+    audit_log::record_audit_log(
+        &state,
+        Some(&merchant_id),
+        common_enums::AuditLogResourceType::MerchantConnectorAccount,
+        merchant_connector_id.get_string_repr(),
+        common_enums::AuditLogAction::Delete,
+        api_models::admin::MerchantConnectorResponse::foreign_try_from(mca)
+            .ok()
+            .as_ref(),
+        None,
+    )
+    .await;
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -3035,6 +3075,19 @@ pub async fn delete_connector(
         .retrieve_and_delete_from_default_fallback_routing_algorithm_if_routable_connector_exists()
         .await?;
 
+    audit_log::record_audit_log(
+        &state,
+        Some(merchant_id),
+        common_enums::AuditLogResourceType::MerchantConnectorAccount,
+        id.get_string_repr(),
+        common_enums::AuditLogAction::Delete,
+        api_models::admin::MerchantConnectorResponse::foreign_try_from(mca)
+            .ok()
+            .as_ref(),
+        None,
+    )
+    .await;
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id: merchant_id.clone(),
         id,
@@ -3333,7 +3386,17 @@ impl ProfileCreateBridge for api::ProfileCreate {
 
         let card_testing_guard_config = self
             .card_testing_guard_config
-            .map(CardTestingGuardConfig::foreign_from)
+            .map(
+                |card_testing_guard_conf| match card_testing_guard_conf.validate() {
+                    Ok(_) => Ok(CardTestingGuardConfig::foreign_from(
+                        card_testing_guard_conf,
+                    )),
+                    Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: e
+                    })),
+                },
+            )
+            .transpose()?
             .or(Some(CardTestingGuardConfig::default()));
 
         let mut dynamic_routing_algorithm_ref =
@@ -3360,6 +3423,18 @@ impl ProfileCreateBridge for api::ProfileCreate {
                 message: "Invalid merchant country code".to_string(),
             })?;
 
+        self.routing_timezone
+            .as_deref()
+            .map(|timezone| {
+                euclid::backend::inputs::parse_utc_offset(timezone).ok_or(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Invalid routing timezone, expected a UTC offset such as +05:30"
+                            .to_string(),
+                    },
+                )
+            })
+            .transpose()?;
+
         Ok(domain::Profile::from(domain::ProfileSetter {
             profile_id,
             merchant_id: merchant_context.get_merchant_account().get_id().clone(),
@@ -3478,6 +3553,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
             dispute_polling_interval: self.dispute_polling_interval,
             is_manual_retry_enabled: self.is_manual_retry_enabled,
             always_enable_overcapture: self.always_enable_overcapture,
+            routing_timezone: self.routing_timezone,
+            routing_base_currency: self.routing_base_currency,
         }))
     }
 
@@ -3539,7 +3616,17 @@ impl ProfileCreateBridge for api::ProfileCreate {
 
         let card_testing_guard_config = self
             .card_testing_guard_config
-            .map(CardTestingGuardConfig::foreign_from)
+            .map(
+                |card_testing_guard_conf| match card_testing_guard_conf.validate() {
+                    Ok(_) => Ok(CardTestingGuardConfig::foreign_from(
+                        card_testing_guard_conf,
+                    )),
+                    Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: e
+                    })),
+                },
+            )
+            .transpose()?
             .or(Some(CardTestingGuardConfig::default()));
 
         Ok(domain::Profile::from(domain::ProfileSetter {
@@ -3690,11 +3777,22 @@ pub async fn create_profile(
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
     }
 
-    Ok(service_api::ApplicationResponse::Json(
-        api_models::admin::ProfileResponse::foreign_try_from(business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to parse business profile details")?,
-    ))
+    let profile_response = api_models::admin::ProfileResponse::foreign_try_from(business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse business profile details")?;
+
+    audit_log::record_audit_log(
+        &state,
+        Some(merchant_context.get_merchant_account().get_id()),
+        common_enums::AuditLogResourceType::BusinessProfile,
+        profile_id.get_string_repr(),
+        common_enums::AuditLogAction::Create,
+        None,
+        Some(&profile_response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(profile_response))
 }
 
 #[cfg(feature = "olap")]
@@ -3756,6 +3854,32 @@ pub async fn delete_profile(
     merchant_id: &id_type::MerchantId,
 ) -> RouterResponse<bool> {
     let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    // The profile is fetched before it is deleted, so that its state can be recorded
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            merchant_id,
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    let profile_response = api_models::admin::ProfileResponse::foreign_try_from(business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse business profile details")?;
+
     let delete_result = db
         .delete_profile_by_profile_id_merchant_id(&profile_id, merchant_id)
         .await
@@ -3763,6 +3887,17 @@ pub async fn delete_profile(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    audit_log::record_audit_log(
+        &state,
+        Some(merchant_id),
+        common_enums::AuditLogResourceType::BusinessProfile,
+        profile_id.get_string_repr(),
+        common_enums::AuditLogAction::Delete,
+        Some(&profile_response),
+        None,
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(delete_result))
 }
 
@@ -3849,6 +3984,18 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?;
 
+        let card_testing_guard_config = self
+            .card_testing_guard_config
+            .map(
+                |card_testing_guard_conf| match card_testing_guard_conf.validate() {
+                    Ok(_) => Ok(card_testing_guard_conf.foreign_into()),
+                    Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: e
+                    })),
+                },
+            )
+            .transpose()?;
+
         let key = key_store.key.clone().into_inner();
         let key_manager_state = state.into();
 
@@ -3917,6 +4064,18 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 message: "Invalid merchant country code".to_string(),
             })?;
 
+        self.routing_timezone
+            .as_deref()
+            .map(|timezone| {
+                euclid::backend::inputs::parse_utc_offset(timezone).ok_or(
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: "Invalid routing timezone, expected a UTC offset such as +05:30"
+                            .to_string(),
+                    },
+                )
+            })
+            .transpose()?;
+
         Ok(domain::ProfileUpdate::Update(Box::new(
             domain::ProfileGeneralUpdate {
                 profile_name: self.profile_name,
@@ -3961,9 +4120,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 max_auto_retries_enabled: self.max_auto_retries_enabled.map(i16::from),
                 is_click_to_pay_enabled: self.is_click_to_pay_enabled,
                 authentication_product_ids: self.authentication_product_ids,
-                card_testing_guard_config: self
-                    .card_testing_guard_config
-                    .map(ForeignInto::foreign_into),
+                card_testing_guard_config,
                 card_testing_secret_key,
                 is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled,
                 force_3ds_challenge: self.force_3ds_challenge, //
@@ -3976,6 +4133,8 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 dispute_polling_interval: self.dispute_polling_interval,
                 is_manual_retry_enabled: self.is_manual_retry_enabled,
                 always_enable_overcapture: self.always_enable_overcapture,
+                routing_timezone: self.routing_timezone,
+                routing_base_currency: self.routing_base_currency,
             },
         )))
     }
@@ -4039,6 +4198,18 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             })
             .transpose()?;
 
+        let card_testing_guard_config = self
+            .card_testing_guard_config
+            .map(
+                |card_testing_guard_conf| match card_testing_guard_conf.validate() {
+                    Ok(_) => Ok(card_testing_guard_conf.foreign_into()),
+                    Err(e) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: e
+                    })),
+                },
+            )
+            .transpose()?;
+
         let key = key_store.key.clone().into_inner();
         let key_manager_state = state.into();
 
@@ -4106,9 +4277,7 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 is_click_to_pay_enabled: self.is_click_to_pay_enabled,
                 authentication_product_ids: self.authentication_product_ids,
                 three_ds_decision_manager_config: None,
-                card_testing_guard_config: self
-                    .card_testing_guard_config
-                    .map(ForeignInto::foreign_into),
+                card_testing_guard_config,
                 card_testing_secret_key,
                 is_debit_routing_enabled: self.is_debit_routing_enabled,
                 merchant_business_country: self.merchant_business_country,
@@ -4147,6 +4316,9 @@ pub async fn update_profile(
         .get_update_profile_object(&state, &key_store, &business_profile)
         .await?;
 
+    let profile_response_before_update =
+        api_models::admin::ProfileResponse::foreign_try_from(business_profile.clone()).ok();
+
     let updated_business_profile = db
         .update_profile_by_profile_id(
             key_manager_state,
@@ -4159,11 +4331,23 @@ pub async fn update_profile(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
-    Ok(service_api::ApplicationResponse::Json(
+    let profile_response =
         api_models::admin::ProfileResponse::foreign_try_from(updated_business_profile)
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to parse business profile details")?,
-    ))
+            .attach_printable("Failed to parse business profile details")?;
+
+    audit_log::record_audit_log(
+        &state,
+        Some(&key_store.merchant_id),
+        common_enums::AuditLogResourceType::BusinessProfile,
+        profile_id.get_string_repr(),
+        common_enums::AuditLogAction::Update,
+        profile_response_before_update.as_ref(),
+        Some(&profile_response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(profile_response))
 }
 
 #[cfg(feature = "v2")]
@@ -1,4 +1,5 @@
-use common_utils::date_time;
+use common_types::api_keys::ApiKeyRestrictions;
+use common_utils::{date_time, fp_utils};
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
 use error_stack::{report, ResultExt};
@@ -8,11 +9,17 @@ use router_env::{instrument, tracing};
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        audit_log,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
     db::domain,
     routes::{metrics, SessionState},
     services::{authentication, ApplicationResponse},
-    types::{api, storage, transformers::ForeignInto},
+    types::{
+        api, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
 };
 
 #[cfg(feature = "email")]
@@ -119,6 +126,10 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    if let Some(restrictions) = api_key.restrictions.as_ref() {
+        validate_api_key_restrictions(&state, &key_store, restrictions).await?;
+    }
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -131,6 +142,7 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        restrictions: api_key.restrictions,
     };
 
     let api_key = store
@@ -178,11 +190,60 @@ pub async fn create_api_key(
         }
     }
 
+    audit_log::record_audit_log(
+        &state,
+        Some(&merchant_id),
+        common_enums::AuditLogResourceType::ApiKey,
+        api_key.key_id.get_string_repr(),
+        common_enums::AuditLogAction::Create,
+        None,
+        Some(&api::RetrieveApiKeyResponse::foreign_from(api_key.clone())),
+    )
+    .await;
+
     Ok(ApplicationResponse::Json(
         (api_key, plaintext_api_key).foreign_into(),
     ))
 }
 
+async fn validate_api_key_restrictions(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    restrictions: &ApiKeyRestrictions,
+) -> RouterResult<()> {
+    // An empty list would make the API key unusable, omitting the restriction is the way to lift it
+    let has_empty_restriction = restrictions.permissions.as_ref().is_some_and(Vec::is_empty)
+        || restrictions.profile_ids.as_ref().is_some_and(Vec::is_empty)
+        || restrictions
+            .allowed_ip_ranges
+            .as_ref()
+            .is_some_and(Vec::is_empty);
+
+    fp_utils::when(has_empty_restriction, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`permissions`, `profile_ids` and `allowed_ip_ranges` cannot be empty"
+                .to_string(),
+        })
+    })?;
+
+    for profile_id in restrictions.profile_ids.iter().flatten() {
+        state
+            .store
+            .find_business_profile_by_merchant_id_profile_id(
+                &state.into(),
+                key_store,
+                &key_store.merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
+    Ok(())
+}
+
 // Add api_key_expiry task to the process_tracker table.
 // Construct ProcessTrackerNew struct with all required fields, and schedule the first email.
 // After first email has been sent, update the schedule_time based on retry_count in execute_workflow().
@@ -271,11 +332,23 @@ pub async fn retrieve_api_key(
 pub async fn update_api_key(
     state: SessionState,
     api_key: api::UpdateApiKeyRequest,
+    key_store: domain::MerchantKeyStore,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
     let merchant_id = api_key.merchant_id.clone();
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    if let Some(restrictions) = api_key.restrictions.as_ref() {
+        validate_api_key_restrictions(&state, &key_store, restrictions).await?;
+    }
+
+    let api_key_before_update = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .ok()
+        .flatten()
+        .map(api::RetrieveApiKeyResponse::foreign_from);
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -355,7 +428,20 @@ pub async fn update_api_key(
         }
     }
 
-    Ok(ApplicationResponse::Json(api_key.foreign_into()))
+    let api_key = api::RetrieveApiKeyResponse::foreign_from(api_key);
+
+    audit_log::record_audit_log(
+        &state,
+        Some(&api_key.merchant_id),
+        common_enums::AuditLogResourceType::ApiKey,
+        api_key.key_id.get_string_repr(),
+        common_enums::AuditLogAction::Update,
+        api_key_before_update.as_ref(),
+        Some(&api_key),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(api_key))
 }
 
 // Update api_key_expiry task in the process_tracker table.
@@ -439,6 +525,9 @@ pub async fn revoke_api_key(
         .revoke_api_key(&merchant_id, key_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
+    let api_key_before_revoke = api_key
+        .clone()
+        .map(api::RetrieveApiKeyResponse::foreign_from);
 
     if let Some(api_key) = api_key {
         let hashed_api_key = api_key.hashed_api_key;
@@ -479,6 +568,17 @@ pub async fn revoke_api_key(
         }
     }
 
+    audit_log::record_audit_log(
+        &state,
+        Some(&merchant_id),
+        common_enums::AuditLogResourceType::ApiKey,
+        key_id.get_string_repr(),
+        common_enums::AuditLogAction::Delete,
+        api_key_before_revoke.as_ref(),
+        None,
+    )
+    .await;
+
     Ok(ApplicationResponse::Json(api::RevokeApiKeyResponse {
         merchant_id: merchant_id.to_owned(),
         key_id: key_id.to_owned(),
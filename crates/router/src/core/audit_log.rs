@@ -0,0 +1,282 @@
+use std::collections::BTreeSet;
+
+use api_models::audit_log as audit_log_types;
+use common_enums::{AuditLogAction, AuditLogActorType, AuditLogResourceType};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services::{authentication::AuthenticationType, ApplicationResponse},
+    types::storage,
+};
+
+const AUDIT_LOG_ID_PREFIX: &str = "audit";
+const AUDIT_LOG_LIST_DEFAULT_LIMIT: u16 = 100;
+const AUDIT_LOG_LIST_MAX_LIMIT: u16 = 1000;
+
+/// The user or API key that performed the request being served
+#[derive(Clone, Debug)]
+pub struct AuditLogActor {
+    pub actor_type: AuditLogActorType,
+    pub actor_id: String,
+}
+
+impl AuditLogActor {
+    /// Returns the actor for a request authenticated using the specified authentication type, if
+    /// the request was made by an identifiable user or API key.
+    pub fn from_authentication_type(auth_type: &AuthenticationType) -> Option<Self> {
+        match auth_type {
+            AuthenticationType::ApiKey { key_id, .. } => Some(Self {
+                actor_type: AuditLogActorType::ApiKey,
+                actor_id: key_id.get_string_repr().to_owned(),
+            }),
+            AuthenticationType::AdminApiKey
+            | AuthenticationType::AdminApiAuthWithMerchantId { .. } => Some(Self {
+                actor_type: AuditLogActorType::AdminApiKey,
+                actor_id: "admin".to_owned(),
+            }),
+            AuthenticationType::OrganizationJwt { user_id, .. }
+            | AuthenticationType::MerchantJwtWithProfileId { user_id, .. }
+            | AuthenticationType::UserJwt { user_id }
+            | AuthenticationType::SinglePurposeJwt { user_id, .. }
+            | AuthenticationType::SinglePurposeOrLoginJwt { user_id, .. }
+            | AuthenticationType::MerchantJwt {
+                user_id: Some(user_id),
+                ..
+            } => Some(Self {
+                actor_type: AuditLogActorType::User,
+                actor_id: user_id.clone(),
+            }),
+            AuthenticationType::MerchantJwt { user_id: None, .. }
+            | AuthenticationType::MerchantId { .. }
+            | AuthenticationType::PublishableKey { .. }
+            | AuthenticationType::WebhookAuth { .. }
+            | AuthenticationType::NoAuth => None,
+        }
+    }
+
+    fn system() -> Self {
+        Self {
+            actor_type: AuditLogActorType::System,
+            actor_id: "system".to_owned(),
+        }
+    }
+}
+
+/// Records a change to a resource in the audit log. `before` and `after` are the states of the
+/// resource before and after the change, only the fields that differ are stored, with sensitive
+/// values masked.
+///
+/// Failures are logged and not propagated, as the change has already been applied by the time it
+/// is recorded.
+#[instrument(skip_all)]
+pub async fn record_audit_log<T: Serialize>(
+    state: &SessionState,
+    merchant_id: Option<&id_type::MerchantId>,
+    resource_type: AuditLogResourceType,
+    resource_id: &str,
+    action: AuditLogAction,
+    before: Option<&T>,
+    after: Option<&T>,
+) {
+    let diff = get_masked_diff(before, after)
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to compute the diff for the audit log entry");
+        })
+        .ok()
+        .flatten();
+
+    let actor = state
+        .audit_log_actor
+        .clone()
+        .unwrap_or_else(AuditLogActor::system);
+
+    let audit_log_new = storage::AuditLogNew {
+        audit_log_id: common_utils::generate_id(consts::ID_LENGTH, AUDIT_LOG_ID_PREFIX),
+        merchant_id: merchant_id.cloned(),
+        actor_type: actor.actor_type,
+        actor_id: actor.actor_id,
+        action,
+        resource_type,
+        resource_id: resource_id.to_owned(),
+        diff,
+        created_at: common_utils::date_time::now(),
+    };
+
+    let _ = state
+        .store
+        .insert_audit_log(audit_log_new)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to insert audit log entry"));
+}
+
+/// Computes the diff between two states of a resource. When both states are JSON objects, only
+/// the fields that were changed are included. Fields are compared using their actual values, but
+/// the diff contains the masked values, so that changes to secrets are recorded without
+/// revealing them.
+fn get_masked_diff<T: Serialize>(
+    before: Option<&T>,
+    after: Option<&T>,
+) -> Result<Option<Value>, serde_json::Error> {
+    let serialize = |value: Option<&T>| {
+        value
+            .map(|value| {
+                Ok::<_, serde_json::Error>((
+                    serde_json::to_value(value)?,
+                    masking::masked_serialize(value)?,
+                ))
+            })
+            .transpose()
+    };
+
+    let diff = match (serialize(before)?, serialize(after)?) {
+        (None, None) => None,
+        (
+            Some((Value::Object(before), Value::Object(masked_before))),
+            Some((Value::Object(after), Value::Object(masked_after))),
+        ) => {
+            let changed_fields = before
+                .keys()
+                .chain(after.keys())
+                .filter(|field| before.get(*field) != after.get(*field))
+                .collect::<BTreeSet<_>>();
+
+            let select_changed_fields = |masked: &Map<String, Value>| {
+                changed_fields
+                    .iter()
+                    .filter_map(|field| {
+                        masked
+                            .get(*field)
+                            .map(|value| ((*field).clone(), value.clone()))
+                    })
+                    .collect::<Map<_, _>>()
+            };
+
+            Some(serde_json::json!({
+                "before": select_changed_fields(&masked_before),
+                "after": select_changed_fields(&masked_after),
+            }))
+        }
+        (before, after) => Some(serde_json::json!({
+            "before": before.map(|(_, masked)| masked),
+            "after": after.map(|(_, masked)| masked),
+        })),
+    };
+
+    Ok(diff)
+}
+
+#[instrument(skip_all)]
+pub async fn list_audit_logs(
+    state: SessionState,
+    merchant_id: Option<id_type::MerchantId>,
+    constraints: audit_log_types::AuditLogListConstraints,
+) -> RouterResponse<Vec<audit_log_types::AuditLogResponse>> {
+    let limit = constraints.limit.unwrap_or(AUDIT_LOG_LIST_DEFAULT_LIMIT);
+    common_utils::fp_utils::when(limit == 0 || limit > AUDIT_LOG_LIST_MAX_LIMIT, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`limit` must be between 1 and {AUDIT_LOG_LIST_MAX_LIMIT}"),
+        })
+    })?;
+
+    let filters = storage::AuditLogFilters {
+        merchant_id,
+        actor_id: constraints.actor_id,
+        action: constraints.action,
+        resource_type: constraints.resource_type,
+        resource_id: constraints.resource_id,
+        created_after: constraints.created_after,
+        created_before: constraints.created_before,
+    };
+
+    let audit_logs = state
+        .store
+        .list_audit_logs_by_filters(
+            filters,
+            i64::from(limit),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list audit log entries")?;
+
+    Ok(ApplicationResponse::Json(
+        audit_logs
+            .into_iter()
+            .map(|audit_log| audit_log_types::AuditLogResponse {
+                audit_log_id: audit_log.audit_log_id,
+                merchant_id: audit_log.merchant_id,
+                actor_type: audit_log.actor_type,
+                actor_id: audit_log.actor_id,
+                action: audit_log.action,
+                resource_type: audit_log.resource_type,
+                resource_id: audit_log.resource_id,
+                diff: audit_log.diff,
+                created: audit_log.created_at,
+            })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use masking::Secret;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Resource {
+        name: String,
+        disabled: bool,
+        api_key: Secret<String>,
+    }
+
+    #[test]
+    fn test_masked_diff_contains_only_changed_fields() {
+        let before = Resource {
+            name: "stripe".to_owned(),
+            disabled: false,
+            api_key: Secret::new("sk_old".to_owned()),
+        };
+        let after = Resource {
+            name: "stripe".to_owned(),
+            disabled: true,
+            api_key: Secret::new("sk_new".to_owned()),
+        };
+
+        let diff = get_masked_diff(Some(&before), Some(&after))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(diff["before"]["disabled"], Value::Bool(false));
+        assert_eq!(diff["after"]["disabled"], Value::Bool(true));
+        assert!(diff["before"].get("name").is_none());
+        assert!(diff["after"].get("api_key").is_some());
+        assert!(!diff.to_string().contains("sk_old"));
+        assert!(!diff.to_string().contains("sk_new"));
+    }
+
+    #[test]
+    fn test_masked_diff_of_created_resource() {
+        let after = Resource {
+            name: "adyen".to_owned(),
+            disabled: false,
+            api_key: Secret::new("sk_new".to_owned()),
+        };
+
+        let diff = get_masked_diff(None, Some(&after)).unwrap().unwrap();
+
+        assert!(diff["before"].is_null());
+        assert_eq!(diff["after"]["name"], Value::String("adyen".to_owned()));
+        assert!(!diff.to_string().contains("sk_new"));
+        assert!(get_masked_diff::<Resource>(None, None).unwrap().is_none());
+    }
+}
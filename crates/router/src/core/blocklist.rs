@@ -4,7 +4,11 @@ pub mod utils;
 use api_models::blocklist as api_blocklist;
 
 use crate::{
-    core::errors::{self, RouterResponse},
+    consts,
+    core::{
+        audit_log,
+        errors::{self, RouterResponse},
+    },
     routes::SessionState,
     services,
     types::domain,
@@ -15,13 +19,66 @@ pub async fn add_entry_to_blocklist(
     merchant_context: domain::MerchantContext,
     body: api_blocklist::AddToBlocklistRequest,
 ) -> RouterResponse<api_blocklist::AddToBlocklistResponse> {
-    utils::insert_entry_into_blocklist(
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let blocklist_entry = utils::insert_entry_into_blocklist(&state, merchant_id, body).await?;
+
+    audit_log::record_audit_log(
         &state,
-        merchant_context.get_merchant_account().get_id(),
-        body,
+        Some(merchant_id),
+        common_enums::AuditLogResourceType::BlocklistEntry,
+        &blocklist_entry.fingerprint_id,
+        common_enums::AuditLogAction::Create,
+        None,
+        Some(&blocklist_entry),
     )
-    .await
-    .map(services::ApplicationResponse::Json)
+    .await;
+
+    Ok(services::ApplicationResponse::Json(blocklist_entry))
+}
+
+pub async fn bulk_add_entries_to_blocklist(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    body: api_blocklist::BulkAddToBlocklistRequest,
+) -> RouterResponse<api_blocklist::BulkAddToBlocklistResponse> {
+    if body.entries.len() > consts::MAX_BULK_BLOCKLIST_ENTRIES {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "at most {} entries can be added to the blocklist at once",
+                consts::MAX_BULK_BLOCKLIST_ENTRIES
+            ),
+        }
+        .into());
+    }
+
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let mut added = Vec::new();
+    let mut failed = Vec::new();
+    for (index, entry) in body.entries.into_iter().enumerate() {
+        match utils::insert_entry_into_blocklist(&state, merchant_id, entry).await {
+            Ok(blocklist_entry) => {
+                audit_log::record_audit_log(
+                    &state,
+                    Some(merchant_id),
+                    common_enums::AuditLogResourceType::BlocklistEntry,
+                    &blocklist_entry.fingerprint_id,
+                    common_enums::AuditLogAction::Create,
+                    None,
+                    Some(&blocklist_entry),
+                )
+                .await;
+                added.push(blocklist_entry);
+            }
+            Err(error) => failed.push(api_blocklist::BulkBlocklistEntryError {
+                index,
+                message: error.current_context().error_message(),
+            }),
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api_blocklist::BulkAddToBlocklistResponse { added, failed },
+    ))
 }
 
 pub async fn remove_entry_from_blocklist(
@@ -29,13 +86,21 @@ pub async fn remove_entry_from_blocklist(
     merchant_context: domain::MerchantContext,
     body: api_blocklist::DeleteFromBlocklistRequest,
 ) -> RouterResponse<api_blocklist::DeleteFromBlocklistResponse> {
-    utils::delete_entry_from_blocklist(
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let blocklist_entry = utils::delete_entry_from_blocklist(&state, merchant_id, body).await?;
+
+    audit_log::record_audit_log(
         &state,
-        merchant_context.get_merchant_account().get_id(),
-        body,
+        Some(merchant_id),
+        common_enums::AuditLogResourceType::BlocklistEntry,
+        &blocklist_entry.fingerprint_id,
+        common_enums::AuditLogAction::Delete,
+        Some(&blocklist_entry),
+        None,
     )
-    .await
-    .map(services::ApplicationResponse::Json)
+    .await;
+
+    Ok(services::ApplicationResponse::Json(blocklist_entry))
 }
 
 pub async fn list_blocklist_entries(
@@ -57,11 +122,24 @@ pub async fn toggle_blocklist_guard(
     merchant_context: domain::MerchantContext,
     query: api_blocklist::ToggleBlocklistQuery,
 ) -> RouterResponse<api_blocklist::ToggleBlocklistResponse> {
-    utils::toggle_blocklist_guard_for_merchant(
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let action = if query.status {
+        common_enums::AuditLogAction::Activate
+    } else {
+        common_enums::AuditLogAction::Deactivate
+    };
+    let response = utils::toggle_blocklist_guard_for_merchant(&state, merchant_id, query).await?;
+
+    audit_log::record_audit_log::<api_blocklist::ToggleBlocklistResponse>(
         &state,
-        merchant_context.get_merchant_account().get_id(),
-        query,
+        Some(merchant_id),
+        common_enums::AuditLogResourceType::BlocklistEntry,
+        "blocklist_guard",
+        action,
+        None,
+        None,
     )
-    .await
-    .map(services::ApplicationResponse::Json)
+    .await;
+
+    Ok(services::ApplicationResponse::Json(response))
 }
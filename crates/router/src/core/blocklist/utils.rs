@@ -1,9 +1,12 @@
+use std::{net::IpAddr, str::FromStr};
+
 use api_models::blocklist as api_blocklist;
-use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_enums::{BlocklistDataKind, MerchantDecision};
+use common_types::api_keys::IpRange;
+use common_utils::{errors::CustomResult, pii};
 use diesel_models::configs;
-use error_stack::ResultExt;
-use masking::StrongSecret;
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, StrongSecret};
 
 use super::{errors, transformers::generate_fingerprint, SessionState};
 use crate::{
@@ -14,32 +17,49 @@ use crate::{
     },
     logger,
     types::{domain, storage, transformers::ForeignInto},
-    utils,
+    utils::{
+        self,
+        crypto::{self, SignMessage},
+    },
 };
 
+// Prefixes of the fingerprint ids of the kinds of data which are not related to cards, so that
+// they can't collide with the card fingerprints and bins
+const CUSTOMER_EMAIL_PREFIX: &str = "email_";
+const IP_ADDRESS_PREFIX: &str = "ip_";
+const PHONE_NUMBER_PREFIX: &str = "phone_";
+const DEVICE_FINGERPRINT_PREFIX: &str = "device_";
+const BILLING_COUNTRY_PREFIX: &str = "country_";
+const CUSTOMER_ID_PREFIX: &str = "customer_";
+
+const MAX_DEVICE_FINGERPRINT_LENGTH: usize = 200;
+const MAX_BLOCKLIST_REASON_LENGTH: usize = 255;
+
 pub async fn delete_entry_from_blocklist(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     request: api_blocklist::DeleteFromBlocklistRequest,
 ) -> RouterResult<api_blocklist::DeleteFromBlocklistResponse> {
-    let blocklist_entry = match request {
-        api_blocklist::DeleteFromBlocklistRequest::CardBin(bin) => {
-            delete_card_bin_blocklist_entry(state, &bin, merchant_id).await?
+    let (fingerprint_id, data_kind) =
+        get_blocklist_fingerprint_id(state, merchant_id, &request).await?;
+    let message = match data_kind {
+        BlocklistDataKind::PaymentMethod => {
+            "no blocklist record for the given fingerprint id was found"
         }
-
-        api_blocklist::DeleteFromBlocklistRequest::ExtendedCardBin(xbin) => {
-            delete_card_bin_blocklist_entry(state, &xbin, merchant_id).await?
+        BlocklistDataKind::CardBin | BlocklistDataKind::ExtendedCardBin => {
+            "could not find a blocklist entry for the given bin"
         }
-
-        api_blocklist::DeleteFromBlocklistRequest::Fingerprint(fingerprint_id) => state
-            .store
-            .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-                message: "no blocklist record for the given fingerprint id was found".to_string(),
-            })?,
+        _ => "no blocklist record for the given data was found",
     };
 
+    let blocklist_entry = state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: message.to_string(),
+        })?;
+
     Ok(blocklist_entry.foreign_into())
 }
 
@@ -136,68 +156,199 @@ pub async fn insert_entry_into_blocklist(
     merchant_id: &common_utils::id_type::MerchantId,
     to_block: api_blocklist::AddToBlocklistRequest,
 ) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
-    let blocklist_entry = match &to_block {
-        api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
+    let (fingerprint_id, data_kind) =
+        get_blocklist_fingerprint_id(state, merchant_id, &to_block.data).await?;
+
+    if to_block
+        .reason
+        .as_ref()
+        .is_some_and(|reason| reason.len() > MAX_BLOCKLIST_REASON_LENGTH)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "reason must not be longer than {MAX_BLOCKLIST_REASON_LENGTH} characters"
+            ),
+        }
+        .into());
+    }
+
+    let created_at = common_utils::date_time::now();
+    let expires_at = match to_block.ttl_in_seconds {
+        Some(0) => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "ttl_in_seconds must be greater than 0".to_string(),
+            }
+            .into())
+        }
+        Some(ttl_in_seconds) => {
+            Some(created_at.saturating_add(time::Duration::seconds(i64::from(ttl_in_seconds))))
+        }
+        None => None,
+    };
+
+    let blocklist_entry = duplicate_check_insert_entry(
+        state,
+        storage::BlocklistNew {
+            merchant_id: merchant_id.to_owned(),
+            fingerprint_id,
+            data_kind,
+            metadata: None,
+            created_at,
+            reason: to_block.reason,
+            expires_at,
+        },
+    )
+    .await?;
+
+    Ok(blocklist_entry.foreign_into())
+}
+
+/// Builds the fingerprint id the data is stored under in the blocklist, along with its kind.
+/// Emails and phone numbers are stored as their fingerprints keyed with the fingerprint secret of
+/// the merchant, IP addresses are stored as the CIDR block they represent.
+async fn get_blocklist_fingerprint_id(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    data: &api_blocklist::BlocklistRequest,
+) -> RouterResult<(String, BlocklistDataKind)> {
+    match data {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                merchant_id,
-                common_enums::BlocklistDataKind::CardBin,
-            )
-            .await?
+            Ok((bin.clone(), BlocklistDataKind::CardBin))
         }
 
-        api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
-                bin,
-                state,
-                merchant_id,
-                common_enums::BlocklistDataKind::ExtendedCardBin,
-            )
-            .await?
+            Ok((bin.clone(), BlocklistDataKind::ExtendedCardBin))
         }
 
-        api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
-            let blocklist_entry_result = state
-                .store
-                .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
-                .await;
-
-            match blocklist_entry_result {
-                Ok(_) => {
-                    return Err(errors::ApiErrorResponse::PreconditionFailed {
-                        message: "data associated with the given fingerprint is already blocked"
-                            .to_string(),
-                    }
-                    .into());
-                }
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => {
+            Ok((fingerprint_id.clone(), BlocklistDataKind::PaymentMethod))
+        }
+
+        api_blocklist::BlocklistRequest::CustomerEmail(email) => {
+            let email =
+                normalize_email(email).ok_or(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "data".to_string(),
+                    expected_format: "a valid email address".to_string(),
+                })?;
+            let merchant_fingerprint_secret =
+                get_merchant_fingerprint_secret(state, merchant_id).await?;
+            let fingerprint = generate_data_fingerprint(&merchant_fingerprint_secret, &email)?;
+            Ok((
+                format!("{CUSTOMER_EMAIL_PREFIX}{fingerprint}"),
+                BlocklistDataKind::CustomerEmail,
+            ))
+        }
+
+        api_blocklist::BlocklistRequest::IpAddress(ip_address) => Ok((
+            format!("{IP_ADDRESS_PREFIX}{}", normalize_ip_network(ip_address)?),
+            BlocklistDataKind::IpAddress,
+        )),
 
-                // if it is a db not found error, we can proceed as normal
-                Err(inner) if inner.current_context().is_db_not_found() => {}
+        api_blocklist::BlocklistRequest::PhoneNumber(phone_number) => {
+            let phone_number = normalize_phone_number(phone_number).ok_or(
+                errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "data".to_string(),
+                    expected_format: "a phone number in the E.164 format".to_string(),
+                },
+            )?;
+            let merchant_fingerprint_secret =
+                get_merchant_fingerprint_secret(state, merchant_id).await?;
+            let fingerprint =
+                generate_data_fingerprint(&merchant_fingerprint_secret, &phone_number)?;
+            Ok((
+                format!("{PHONE_NUMBER_PREFIX}{fingerprint}"),
+                BlocklistDataKind::PhoneNumber,
+            ))
+        }
 
-                err @ Err(_) => {
-                    err.change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("error fetching blocklist entry from table")?;
+        api_blocklist::BlocklistRequest::DeviceFingerprint(device_id) => {
+            if device_id.is_empty() || device_id.len() > MAX_DEVICE_FINGERPRINT_LENGTH {
+                return Err(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "data".to_string(),
+                    expected_format: format!(
+                        "a non empty string of at most {MAX_DEVICE_FINGERPRINT_LENGTH} characters"
+                    ),
                 }
+                .into());
             }
+            Ok((
+                format!("{DEVICE_FINGERPRINT_PREFIX}{device_id}"),
+                BlocklistDataKind::DeviceFingerprint,
+            ))
+        }
 
-            state
-                .store
-                .insert_blocklist_entry(storage::BlocklistNew {
-                    merchant_id: merchant_id.to_owned(),
-                    fingerprint_id: fingerprint_id.clone(),
-                    data_kind: api_models::enums::enums::BlocklistDataKind::PaymentMethod,
-                    metadata: None,
-                    created_at: common_utils::date_time::now(),
-                })
-                .await
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("failed to add fingerprint to blocklist")?
+        api_blocklist::BlocklistRequest::BillingCountry(country) => Ok((
+            format!("{BILLING_COUNTRY_PREFIX}{country}"),
+            BlocklistDataKind::BillingCountry,
+        )),
+
+        api_blocklist::BlocklistRequest::CustomerId(customer_id) => {
+            let customer_id = common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(
+                customer_id.clone(),
+            ))
+            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "data".to_string(),
+                expected_format: "a valid customer id".to_string(),
+            })?;
+            Ok((
+                format!("{CUSTOMER_ID_PREFIX}{}", customer_id.get_string_repr()),
+                BlocklistDataKind::CustomerId,
+            ))
         }
-    };
-    Ok(blocklist_entry.foreign_into())
+    }
+}
+
+fn normalize_email(email: &str) -> Option<String> {
+    pii::Email::from_str(email.trim())
+        .ok()
+        .map(|email| email.peek().to_lowercase())
+}
+
+/// Strips the formatting of the phone number, keeping the digits of the country code and the
+/// number
+fn normalize_phone_number(phone_number: &str) -> Option<String> {
+    let digits = phone_number
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    (8..=15).contains(&digits.len()).then_some(digits)
+}
+
+/// Parses an IP address or a CIDR block into the `{network}/{prefix_length}` notation, with the
+/// host bits of the network cleared. IP addresses are treated as blocks of a single address.
+fn normalize_ip_network(ip_network: &str) -> RouterResult<String> {
+    IpRange::from_str(ip_network.trim())
+        .map(|ip_range| ip_range.normalize().to_string())
+        .map_err(|_| {
+            report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "data".to_string(),
+                expected_format: "an IP address or a CIDR block".to_string(),
+            })
+        })
+}
+
+/// Returns the fingerprint ids of all the CIDR blocks containing the IP address
+fn get_ip_address_fingerprint_ids(ip_address: IpAddr) -> Vec<String> {
+    IpRange::get_ranges_containing(ip_address)
+        .into_iter()
+        .map(|ip_range| format!("{IP_ADDRESS_PREFIX}{ip_range}"))
+        .collect()
+}
+
+fn generate_data_fingerprint(
+    merchant_fingerprint_secret: &str,
+    data: &str,
+) -> RouterResult<String> {
+    crypto::HmacSha256::sign_message(
+        &crypto::HmacSha256,
+        merchant_fingerprint_secret.as_bytes(),
+        data.as_bytes(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("error in blocklist data fingerprint creation")
+    .map(hex::encode)
 }
 
 pub async fn get_merchant_fingerprint_secret(
@@ -234,25 +385,50 @@ pub async fn get_merchant_fingerprint_secret(
     }
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+/// Inserts the entry unless the data is already blocked. An expired entry of the data is
+/// replaced by the new one.
+async fn duplicate_check_insert_entry(
     state: &SessionState,
-    merchant_id: &common_utils::id_type::MerchantId,
-    data_kind: common_enums::BlocklistDataKind,
+    blocklist_new: storage::BlocklistNew,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(
+            &blocklist_new.merchant_id,
+            &blocklist_new.fingerprint_id,
+        )
         .await;
 
     match blocklist_entry_result {
+        Ok(blocklist_entry) if blocklist_entry.is_expired() => {
+            state
+                .store
+                .delete_blocklist_entry_by_merchant_id_fingerprint_id(
+                    &blocklist_new.merchant_id,
+                    &blocklist_new.fingerprint_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to delete expired blocklist entry")?;
+        }
+
         Ok(_) => {
+            let message = match blocklist_new.data_kind {
+                BlocklistDataKind::PaymentMethod => {
+                    "data associated with the given fingerprint is already blocked"
+                }
+                BlocklistDataKind::CardBin | BlocklistDataKind::ExtendedCardBin => {
+                    "provided bin is already blocked"
+                }
+                _ => "provided data is already blocked",
+            };
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: message.to_string(),
             }
             .into());
         }
 
+        // if it is a db not found error, we can proceed as normal
         Err(e) if e.current_context().is_db_not_found() => {}
 
         err @ Err(_) => {
@@ -264,32 +440,12 @@ async fn duplicate_check_insert_bin(
 
     state
         .store
-        .insert_blocklist_entry(storage::BlocklistNew {
-            merchant_id: merchant_id.to_owned(),
-            fingerprint_id: bin.to_string(),
-            data_kind,
-            metadata: None,
-            created_at: common_utils::date_time::now(),
-        })
+        .insert_blocklist_entry(blocklist_new)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("error inserting pm blocklist item")
 }
 
-async fn delete_card_bin_blocklist_entry(
-    state: &SessionState,
-    bin: &str,
-    merchant_id: &common_utils::id_type::MerchantId,
-) -> RouterResult<storage::Blocklist> {
-    state
-        .store
-        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-            message: "could not find a blocklist entry for the given bin".to_string(),
-        })
-}
-
 pub async fn validate_data_for_blocklist<F>(
     state: &SessionState,
     merchant_context: &domain::MerchantContext,
@@ -347,45 +503,40 @@ where
                 _ => None,
             });
 
-    //validating the payment method.
-    let mut blocklist_futures = Vec::new();
-    if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
+    let mut fingerprint_ids = [
+        card_number_fingerprint,
+        card_bin_fingerprint,
+        extended_card_bin_fingerprint,
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    fingerprint_ids.extend(
+        get_customer_data_fingerprint_ids(payment_data, &merchant_fingerprint_secret)
+            .map_err(|error| logger::error!(?error, "failed to fingerprint the customer data"))
+            .unwrap_or_default(),
+    );
+
+    let matched_entry = if fingerprint_ids.is_empty() {
+        None
+    } else {
+        db.find_active_blocklist_entries_by_merchant_id_fingerprint_ids(
             merchant_id,
-            card_number_fingerprint,
-        ));
-    }
+            fingerprint_ids,
+        )
+        .await
+        .map_err(|e| logger::error!(blocklist_db_error=?e, "failed db operations for blocklist"))
+        .ok()
+        .and_then(|blocklist_entries| blocklist_entries.into_iter().next())
+    };
 
-    if let Some(card_bin_fingerprint) = card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(
-            db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-                merchant_id,
-                card_bin_fingerprint,
-            ),
+    if let Some(matched_entry) = matched_entry {
+        logger::info!(
+            data_kind = %matched_entry.data_kind,
+            fingerprint_id = %matched_entry.fingerprint_id,
+            "payment blocked by blocklist entry"
         );
-    }
-
-    if let Some(extended_card_bin_fingerprint) = extended_card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            extended_card_bin_fingerprint,
-        ));
-    }
-
-    let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
 
-    let mut should_payment_be_blocked = false;
-    for lookup in blocklist_lookups {
-        match lookup {
-            Ok(_) => {
-                should_payment_be_blocked = true;
-            }
-            Err(e) => {
-                logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
-            }
-        }
-    }
-    if should_payment_be_blocked {
         // Update db for attempt and intent status.
         db.update_payment_intent(
             &state.into(),
@@ -407,7 +558,8 @@ where
             "Failed to update status in Payment Intent to failed due to it being blocklisted",
         )?;
 
-        // If payment is blocked not showing connector details
+        // If payment is blocked not showing connector details. The matched entry is only reported
+        // to the merchant through the payment attempt
         let attempt_update = storage::PaymentAttemptUpdate::BlocklistUpdate {
             status: common_enums::AttemptStatus::Failure,
             error_code: Some(Some("HE-03".to_string())),
@@ -416,6 +568,11 @@ where
                 .get_merchant_account()
                 .storage_scheme
                 .to_string(),
+            blocklist_match_details: Some(common_types::payments::BlocklistMatchDetails {
+                fingerprint_id: matched_entry.fingerprint_id,
+                data_kind: matched_entry.data_kind,
+                reason: matched_entry.reason,
+            }),
         };
         db.update_payment_attempt_with_attempt_id(
             payment_data.payment_attempt.clone(),
@@ -446,6 +603,86 @@ where
     }
 }
 
+/// Builds the fingerprint ids of the customer data of the payment, the email, phone number,
+/// billing country, customer id, IP address and device id, whichever are available
+fn get_customer_data_fingerprint_ids<F>(
+    payment_data: &PaymentData<F>,
+    merchant_fingerprint_secret: &str,
+) -> RouterResult<Vec<String>>
+where
+    F: Send + Clone,
+{
+    let mut fingerprint_ids = Vec::new();
+    let billing = payment_data.address.get_payment_method_billing();
+
+    let email = payment_data
+        .email
+        .as_ref()
+        .or(billing.and_then(|billing| billing.email.as_ref()));
+    if let Some(email) = email.and_then(|email| normalize_email(email.peek())) {
+        fingerprint_ids.push(format!(
+            "{CUSTOMER_EMAIL_PREFIX}{}",
+            generate_data_fingerprint(merchant_fingerprint_secret, &email)?
+        ));
+    }
+
+    let phone_number = billing
+        .and_then(|billing| billing.phone.as_ref())
+        .and_then(|phone| {
+            phone.number.as_ref().map(|number| {
+                format!(
+                    "{}{}",
+                    phone.country_code.as_deref().unwrap_or_default(),
+                    number.peek()
+                )
+            })
+        })
+        .and_then(|phone_number| normalize_phone_number(&phone_number));
+    if let Some(phone_number) = phone_number {
+        fingerprint_ids.push(format!(
+            "{PHONE_NUMBER_PREFIX}{}",
+            generate_data_fingerprint(merchant_fingerprint_secret, &phone_number)?
+        ));
+    }
+
+    if let Some(country) = billing
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country)
+    {
+        fingerprint_ids.push(format!("{BILLING_COUNTRY_PREFIX}{country}"));
+    }
+
+    if let Some(customer_id) = payment_data.payment_intent.customer_id.as_ref() {
+        fingerprint_ids.push(format!(
+            "{CUSTOMER_ID_PREFIX}{}",
+            customer_id.get_string_repr()
+        ));
+    }
+
+    #[cfg(feature = "v1")]
+    let browser_info = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .map(serde_json::from_value::<common_utils::types::BrowserInformation>)
+        .transpose()
+        .map_err(|error| logger::error!(?error, "could not parse browser_info"))
+        .ok()
+        .flatten();
+    #[cfg(feature = "v2")]
+    let browser_info = payment_data.payment_attempt.browser_info.clone();
+    if let Some(browser_info) = browser_info {
+        if let Some(ip_address) = browser_info.ip_address {
+            fingerprint_ids.extend(get_ip_address_fingerprint_ids(ip_address));
+        }
+        if let Some(device_id) = browser_info.device_id {
+            fingerprint_ids.push(format!("{DEVICE_FINGERPRINT_PREFIX}{device_id}"));
+        }
+    }
+
+    Ok(fingerprint_ids)
+}
+
 pub async fn generate_payment_fingerprint(
     state: &SessionState,
     merchant_id: common_utils::id_type::MerchantId,
@@ -477,3 +714,78 @@ pub async fn generate_payment_fingerprint(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_ip_network_normalization() {
+        assert_eq!(
+            normalize_ip_network("192.168.1.17").unwrap(),
+            "192.168.1.17/32"
+        );
+        assert_eq!(
+            normalize_ip_network("192.168.1.17/24").unwrap(),
+            "192.168.1.0/24"
+        );
+        assert_eq!(
+            normalize_ip_network("2001:db8::1/32").unwrap(),
+            "2001:db8::/32"
+        );
+        assert!(normalize_ip_network("192.168.1.17/33").is_err());
+        assert!(normalize_ip_network("192.168.1/24").is_err());
+    }
+
+    #[test]
+    fn test_ip_address_fingerprint_ids_cover_containing_blocks() {
+        let ip_address = IpAddr::from_str("10.1.2.3").unwrap();
+        let fingerprint_ids = get_ip_address_fingerprint_ids(ip_address);
+
+        assert_eq!(fingerprint_ids.len(), 33);
+        for blocked_network in ["10.1.2.3", "10.1.0.0/16", "0.0.0.0/0"] {
+            let fingerprint_id = format!(
+                "{IP_ADDRESS_PREFIX}{}",
+                normalize_ip_network(blocked_network).unwrap()
+            );
+            assert!(fingerprint_ids.contains(&fingerprint_id));
+        }
+        let fingerprint_id = format!(
+            "{IP_ADDRESS_PREFIX}{}",
+            normalize_ip_network("10.2.0.0/16").unwrap()
+        );
+        assert!(!fingerprint_ids.contains(&fingerprint_id));
+    }
+
+    #[test]
+    fn test_ipv4_mapped_ip_address_matches_ipv4_blocks() {
+        let ip_address = IpAddr::from_str("::ffff:10.1.2.3").unwrap();
+        let fingerprint_ids = get_ip_address_fingerprint_ids(ip_address);
+
+        assert_eq!(
+            fingerprint_ids,
+            get_ip_address_fingerprint_ids(IpAddr::from_str("10.1.2.3").unwrap())
+        );
+        let fingerprint_id = format!(
+            "{IP_ADDRESS_PREFIX}{}",
+            normalize_ip_network("::ffff:10.1.0.0/112").unwrap()
+        );
+        assert!(fingerprint_ids.contains(&fingerprint_id));
+    }
+
+    #[test]
+    fn test_email_and_phone_number_normalization() {
+        assert_eq!(
+            normalize_email(" John.Doe@Example.com ").as_deref(),
+            Some("john.doe@example.com")
+        );
+        assert_eq!(normalize_email("john.doe"), None);
+        assert_eq!(
+            normalize_phone_number("+1 (415) 555-2671").as_deref(),
+            Some("14155552671")
+        );
+        assert_eq!(normalize_phone_number("+1 555"), None);
+    }
+}
@@ -1,3 +1,4 @@
+#[cfg(feature = "v1")]
 pub mod utils;
 
 use crate::core::errors;
@@ -1,196 +1,359 @@
+use std::collections::HashMap;
+
+use common_enums::{VelocityAction, VelocityMeasure, VelocityRuleKey};
+use common_utils::{id_type, pii};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
-    card_testing_guard_data::CardTestingGuardData, router_request_types::BrowserInformation,
+    card_testing_guard_data::{CardTestingGuardData, VelocityCounter},
+    payments::payment_attempt::PaymentAttempt,
+    router_request_types::BrowserInformation,
 };
 use masking::{PeekInterface, Secret};
 use router_env::logger;
 
 use super::errors;
 use crate::{
-    core::{errors::RouterResult, payments::helpers},
-    routes::SessionState,
+    consts,
+    core::errors::RouterResult,
+    routes::{metrics, SessionState},
     services,
     types::{api, domain},
     utils::crypto::{self, SignMessage},
 };
 
+/// Evaluates the velocity rules of the profile against the payment being confirmed. Payments
+/// exceeding the threshold of a blocking rule are rejected, the outcome of the other rules is
+/// returned along with the counters the payment has to be recorded in if it fails.
 pub async fn validate_card_testing_guard_checks(
     state: &SessionState,
     request: &api::PaymentsRequest,
     payment_method_data: Option<&api_models::payments::PaymentMethodData>,
-    customer_id: &Option<common_utils::id_type::CustomerId>,
+    payment_attempt: &PaymentAttempt,
+    customer_id: &Option<id_type::CustomerId>,
+    email: Option<&pii::Email>,
     business_profile: &domain::Profile,
 ) -> RouterResult<Option<CardTestingGuardData>> {
-    match &business_profile.card_testing_guard_config {
-        Some(card_testing_guard_config) => {
-            let fingerprint = generate_fingerprint(payment_method_data, business_profile).await?;
-
-            let card_testing_guard_expiry = card_testing_guard_config.card_testing_guard_expiry;
-
-            let mut card_ip_blocking_cache_key = String::new();
-            let mut guest_user_card_blocking_cache_key = String::new();
-            let mut customer_id_blocking_cache_key = String::new();
-
-            if card_testing_guard_config.is_card_ip_blocking_enabled {
-                if let Some(browser_info) = &request.browser_info {
-                    #[cfg(feature = "v1")]
-                    {
-                        let browser_info =
-                            serde_json::from_value::<BrowserInformation>(browser_info.clone())
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                                .attach_printable("could not parse browser_info")?;
-
-                        if let Some(browser_info_ip) = browser_info.ip_address {
-                            card_ip_blocking_cache_key =
-                                helpers::validate_card_ip_blocking_for_business_profile(
-                                    state,
-                                    browser_info_ip,
-                                    fingerprint.clone(),
-                                    card_testing_guard_config,
-                                )
-                                .await?;
-                        }
-                    }
-
-                    #[cfg(feature = "v2")]
-                    {
-                        if let Some(browser_info_ip) = browser_info.ip_address {
-                            card_ip_blocking_cache_key =
-                                helpers::validate_card_ip_blocking_for_business_profile(
-                                    state,
-                                    browser_info_ip,
-                                    fingerprint.clone(),
-                                    card_testing_guard_config,
-                                )
-                                .await?;
-                        }
-                    }
-                }
-            }
+    let Some(card_testing_guard_config) = &business_profile.card_testing_guard_config else {
+        return Ok(None);
+    };
 
-            if card_testing_guard_config.is_guest_user_card_blocking_enabled {
-                guest_user_card_blocking_cache_key =
-                    helpers::validate_guest_user_card_blocking_for_business_profile(
-                        state,
-                        fingerprint.clone(),
-                        customer_id.clone(),
-                        card_testing_guard_config,
-                    )
-                    .await?;
-            }
+    let browser_info = request
+        .browser_info
+        .clone()
+        .map(serde_json::from_value::<BrowserInformation>)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("could not parse browser_info")?;
+
+    let is_card_payment = matches!(
+        payment_method_data,
+        Some(api_models::payments::PaymentMethodData::Card(_))
+    );
+
+    let mut key_values = HashMap::new();
+    if let Some(api_models::payments::PaymentMethodData::Card(card)) = payment_method_data {
+        key_values.insert(VelocityRuleKey::CardBin, card.card_number.get_card_isin());
+        if let Some(fingerprint) =
+            generate_fingerprint(&card.card_number.get_card_no(), business_profile)?
+        {
+            key_values.insert(VelocityRuleKey::CardFingerprint, fingerprint.peek().clone());
+        }
+    }
+    if let Some(email) = email {
+        if let Some(fingerprint) = generate_fingerprint(email.peek(), business_profile)? {
+            key_values.insert(VelocityRuleKey::Email, fingerprint.peek().clone());
+        }
+    }
+    if let Some(customer_id) = customer_id {
+        key_values.insert(
+            VelocityRuleKey::CustomerId,
+            customer_id.get_string_repr().to_string(),
+        );
+    }
+    if let Some(browser_info) = browser_info {
+        if let Some(ip_address) = browser_info.ip_address {
+            key_values.insert(VelocityRuleKey::IpAddress, ip_address.to_string());
+        }
+        if let Some(device_id) = browser_info.device_id {
+            key_values.insert(VelocityRuleKey::DeviceId, device_id);
+        }
+    }
 
-            if card_testing_guard_config.is_customer_id_blocking_enabled {
-                if let Some(customer_id) = customer_id.clone() {
-                    customer_id_blocking_cache_key =
-                        helpers::validate_customer_id_blocking_for_business_profile(
-                            state,
-                            customer_id.clone(),
-                            business_profile.get_id(),
-                            card_testing_guard_config,
-                        )
-                        .await?;
+    let mut card_testing_guard_data = CardTestingGuardData {
+        failed_payment_counters: Vec::new(),
+        flagged_rules: Vec::new(),
+        force_three_ds: false,
+    };
+
+    for rule in card_testing_guard_config.get_velocity_rules() {
+        if !is_velocity_rule_applicable(&rule, is_card_payment, customer_id.is_some()) {
+            continue;
+        }
+        let Some(cache_key) = get_velocity_counter_key(
+            business_profile.get_id(),
+            &rule,
+            &key_values,
+            payment_attempt.currency,
+        ) else {
+            continue;
+        };
+
+        // Failed payments are only recorded once they fail, until then the payment is counted as
+        // a potential failure
+        let (amount, record_payment) = match rule.measure {
+            VelocityMeasure::AttemptCount => (None, true),
+            VelocityMeasure::FailedCount => (None, false),
+            VelocityMeasure::AmountSum => (
+                Some(payment_attempt.get_total_amount().get_amount_as_i64()),
+                true,
+            ),
+        };
+        let measure = services::card_testing_guard::evaluate_velocity_counter(
+            state,
+            &cache_key,
+            rule.window_in_seconds,
+            &payment_attempt.attempt_id,
+            amount,
+            record_payment,
+        )
+        .await?;
+
+        if rule.measure == VelocityMeasure::FailedCount {
+            card_testing_guard_data
+                .failed_payment_counters
+                .push(VelocityCounter {
+                    cache_key,
+                    window_in_seconds: rule.window_in_seconds,
+                });
+        }
+
+        if measure <= rule.threshold {
+            continue;
+        }
+
+        logger::info!(
+            velocity_rule = %rule.name,
+            action = %rule.action,
+            measure,
+            threshold = rule.threshold,
+            "payment exceeded the threshold of a velocity rule"
+        );
+        metrics::VELOCITY_RULE_TRIGGERED_COUNT.add(
+            1,
+            router_env::metric_attributes!(("action", rule.action.to_string())),
+        );
+
+        match rule.action {
+            VelocityAction::Block => {
+                return Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Blocked due to suspicious activity".to_string(),
                 }
+                .into())
             }
-
-            Ok(Some(CardTestingGuardData {
-                is_card_ip_blocking_enabled: card_testing_guard_config.is_card_ip_blocking_enabled,
-                card_ip_blocking_cache_key,
-                is_guest_user_card_blocking_enabled: card_testing_guard_config
-                    .is_guest_user_card_blocking_enabled,
-                guest_user_card_blocking_cache_key,
-                is_customer_id_blocking_enabled: card_testing_guard_config
-                    .is_customer_id_blocking_enabled,
-                customer_id_blocking_cache_key,
-                card_testing_guard_expiry,
-            }))
+            VelocityAction::ForceThreeDs => card_testing_guard_data.force_three_ds = true,
+            VelocityAction::FlagForReview => card_testing_guard_data.flagged_rules.push(rule.name),
         }
-        None => Ok(None),
     }
+
+    Ok(Some(card_testing_guard_data))
 }
 
-pub async fn generate_fingerprint(
-    payment_method_data: Option<&api_models::payments::PaymentMethodData>,
-    business_profile: &domain::Profile,
-) -> RouterResult<Secret<String>> {
-    let card_testing_secret_key = &business_profile.card_testing_secret_key;
-
-    match card_testing_secret_key {
-        Some(card_testing_secret_key) => {
-            let card_number_fingerprint = payment_method_data
-                .as_ref()
-                .and_then(|pm_data| match pm_data {
-                    api_models::payments::PaymentMethodData::Card(card) => {
-                        crypto::HmacSha512::sign_message(
-                            &crypto::HmacSha512,
-                            card_testing_secret_key.get_inner().peek().as_bytes(),
-                            card.card_number.clone().get_card_no().as_bytes(),
-                        )
-                        .attach_printable("error in pm fingerprint creation")
-                        .map_or_else(
-                            |err| {
-                                logger::error!(error=?err);
-                                None
-                            },
-                            Some,
-                        )
-                    }
-                    _ => None,
-                })
-                .map(hex::encode);
-
-            card_number_fingerprint.map(Secret::new).ok_or_else(|| {
-                error_stack::report!(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Error while masking fingerprint")
-            })
-        }
-        None => Err(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("card testing secret key not configured")?,
+/// Checks whether the rule is evaluated for the payment. The fixed blockings of the card testing
+/// guard only apply to card payments, and guest rules only to payments made without a customer.
+fn is_velocity_rule_applicable(
+    rule: &common_types::domain::VelocityRule,
+    is_card_payment: bool,
+    has_customer: bool,
+) -> bool {
+    (is_card_payment || !common_types::domain::VelocityRule::is_reserved_name(&rule.name))
+        && !(rule.guest_customers_only && has_customer)
+}
+
+/// Builds the key of the velocity counter of the rule from the values of its keys, or returns
+/// `None` if any of them is not available for the payment. The values are hashed into the key, as
+/// they are client controlled and of arbitrary length. Amounts are summed per currency.
+fn get_velocity_counter_key(
+    profile_id: &id_type::ProfileId,
+    rule: &common_types::domain::VelocityRule,
+    key_values: &HashMap<VelocityRuleKey, String>,
+    currency: Option<common_enums::Currency>,
+) -> Option<String> {
+    let mut hasher = blake3::Hasher::new();
+    for key in &rule.keys {
+        let value = key_values.get(key)?;
+        // Length prefixed so that the boundaries of the values can't be shifted
+        hasher.update(format!("{}:{value}", value.len()).as_bytes());
+    }
+
+    let mut cache_key = format!(
+        "{}_{}_{}_{}",
+        consts::VELOCITY_RULE_CACHE_KEY_PREFIX,
+        profile_id.get_string_repr(),
+        rule.name,
+        hasher.finalize().to_hex()
+    );
+    if rule.measure == VelocityMeasure::AmountSum {
+        cache_key.push('_');
+        cache_key.push_str(&currency?.to_string());
     }
+    Some(cache_key)
+}
+
+/// Generates the fingerprint of sensitive data keyed with the card testing secret of the profile.
+/// Returns `None` if the profile doesn't have a card testing secret.
+pub fn generate_fingerprint(
+    data: &str,
+    business_profile: &domain::Profile,
+) -> RouterResult<Option<Secret<String>>> {
+    let Some(card_testing_secret_key) = &business_profile.card_testing_secret_key else {
+        logger::warn!("card testing secret key not configured");
+        return Ok(None);
+    };
+
+    crypto::HmacSha512::sign_message(
+        &crypto::HmacSha512,
+        card_testing_secret_key.get_inner().peek().as_bytes(),
+        data.as_bytes(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("error in fingerprint creation")
+    .map(|fingerprint| Some(Secret::new(hex::encode(fingerprint))))
 }
 
-pub async fn increment_blocked_count_in_cache(
+/// Records a failed payment in the velocity counters of the rules measuring failed payments.
+/// Failures are only logged, as the payment has already been processed.
+pub async fn record_failed_payment_in_cache(
     state: &SessionState,
     card_testing_guard_data: Option<CardTestingGuardData>,
-) -> RouterResult<()> {
-    if let Some(card_testing_guard_data) = card_testing_guard_data.clone() {
-        if card_testing_guard_data.is_card_ip_blocking_enabled
-            && !card_testing_guard_data
-                .card_ip_blocking_cache_key
-                .is_empty()
-        {
-            let _ = services::card_testing_guard::increment_blocked_count_in_cache(
-                state,
-                &card_testing_guard_data.card_ip_blocking_cache_key,
-                card_testing_guard_data.card_testing_guard_expiry.into(),
-            )
-            .await;
-        }
+    payment_reference: &str,
+) {
+    let failed_payment_counters = card_testing_guard_data
+        .map(|card_testing_guard_data| card_testing_guard_data.failed_payment_counters)
+        .unwrap_or_default();
 
-        if card_testing_guard_data.is_guest_user_card_blocking_enabled
-            && !card_testing_guard_data
-                .guest_user_card_blocking_cache_key
-                .is_empty()
-        {
-            let _ = services::card_testing_guard::increment_blocked_count_in_cache(
-                state,
-                &card_testing_guard_data.guest_user_card_blocking_cache_key,
-                card_testing_guard_data.card_testing_guard_expiry.into(),
-            )
-            .await;
-        }
+    for counter in failed_payment_counters {
+        let _ = services::card_testing_guard::evaluate_velocity_counter(
+            state,
+            &counter.cache_key,
+            counter.window_in_seconds,
+            payment_reference,
+            None,
+            true,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to record the failed payment"));
+    }
+}
 
-        if card_testing_guard_data.is_customer_id_blocking_enabled
-            && !card_testing_guard_data
-                .customer_id_blocking_cache_key
-                .is_empty()
-        {
-            let _ = services::card_testing_guard::increment_blocked_count_in_cache(
-                state,
-                &card_testing_guard_data.customer_id_blocking_cache_key,
-                card_testing_guard_data.card_testing_guard_expiry.into(),
-            )
-            .await;
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_rule(
+        keys: Vec<VelocityRuleKey>,
+        measure: VelocityMeasure,
+    ) -> common_types::domain::VelocityRule {
+        common_types::domain::VelocityRule {
+            name: "card_rule".to_string(),
+            keys,
+            measure,
+            threshold: 3,
+            window_in_seconds: 600,
+            action: VelocityAction::Block,
+            guest_customers_only: false,
         }
     }
-    Ok(())
+
+    #[test]
+    fn test_velocity_counter_key_requires_all_keys() {
+        let profile_id =
+            id_type::ProfileId::try_from(std::borrow::Cow::from("pro_velocity")).unwrap();
+        let key_values = HashMap::from([
+            (VelocityRuleKey::CardBin, "424242".to_string()),
+            (VelocityRuleKey::IpAddress, "127.0.0.1".to_string()),
+        ]);
+
+        let rule = get_rule(
+            vec![VelocityRuleKey::CardBin, VelocityRuleKey::IpAddress],
+            VelocityMeasure::AttemptCount,
+        );
+        let cache_key = get_velocity_counter_key(&profile_id, &rule, &key_values, None).unwrap();
+        assert!(cache_key.starts_with("VELOCITY_RULE_pro_velocity_card_rule_"));
+        assert!(!cache_key.contains("127.0.0.1"));
+
+        let rule = get_rule(
+            vec![VelocityRuleKey::CardBin, VelocityRuleKey::DeviceId],
+            VelocityMeasure::AttemptCount,
+        );
+        assert_eq!(
+            get_velocity_counter_key(&profile_id, &rule, &key_values, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_velocity_counter_key_separates_values() {
+        let profile_id =
+            id_type::ProfileId::try_from(std::borrow::Cow::from("pro_velocity")).unwrap();
+        let rule = get_rule(
+            vec![VelocityRuleKey::CustomerId, VelocityRuleKey::DeviceId],
+            VelocityMeasure::AttemptCount,
+        );
+        let get_key = |customer_id: &str, device_id: &str| {
+            let key_values = HashMap::from([
+                (VelocityRuleKey::CustomerId, customer_id.to_string()),
+                (VelocityRuleKey::DeviceId, device_id.to_string()),
+            ]);
+            get_velocity_counter_key(&profile_id, &rule, &key_values, None).unwrap()
+        };
+
+        assert_eq!(get_key("a_b", "c"), get_key("a_b", "c"));
+        assert_ne!(get_key("a_b", "c"), get_key("a", "b_c"));
+        assert_eq!(
+            get_key("a", &"d".repeat(10_000)).len(),
+            get_key("a", "d").len()
+        );
+    }
+
+    #[test]
+    fn test_fixed_blockings_apply_to_card_payments_only() {
+        let mut rule = get_rule(
+            vec![VelocityRuleKey::CustomerId],
+            VelocityMeasure::FailedCount,
+        );
+        assert!(is_velocity_rule_applicable(&rule, false, true));
+
+        rule.name = common_types::domain::VelocityRule::CUSTOMER_ID_BLOCKING_RULE.to_string();
+        assert!(is_velocity_rule_applicable(&rule, true, true));
+        assert!(!is_velocity_rule_applicable(&rule, false, true));
+
+        rule.name = common_types::domain::VelocityRule::GUEST_USER_CARD_BLOCKING_RULE.to_string();
+        rule.guest_customers_only = true;
+        assert!(is_velocity_rule_applicable(&rule, true, false));
+        assert!(!is_velocity_rule_applicable(&rule, true, true));
+        assert!(!is_velocity_rule_applicable(&rule, false, false));
+    }
+
+    #[test]
+    fn test_velocity_counter_key_separates_amounts_by_currency() {
+        let profile_id =
+            id_type::ProfileId::try_from(std::borrow::Cow::from("pro_velocity")).unwrap();
+        let key_values = HashMap::from([(VelocityRuleKey::CardBin, "424242".to_string())]);
+        let rule = get_rule(vec![VelocityRuleKey::CardBin], VelocityMeasure::AmountSum);
+
+        assert!(get_velocity_counter_key(
+            &profile_id,
+            &rule,
+            &key_values,
+            Some(common_enums::Currency::USD)
+        )
+        .unwrap()
+        .ends_with("_USD"));
+        assert_eq!(
+            get_velocity_counter_key(&profile_id, &rule, &key_values, None),
+            None
+        );
+    }
 }
@@ -0,0 +1,698 @@
+//! Circuit breaker for merchant connector accounts.
+//!
+//! The state of every circuit is kept in redis so that all router instances agree on it.
+//! A closed circuit opens when the share of failed (5xx or timed out) and slow connector calls
+//! in the current window crosses the configured threshold. Once the open duration elapses, the
+//! circuit is half open and lets a limited number of probe calls through. The first probe
+//! outcome either closes the circuit or opens it again.
+
+use std::{collections::HashMap, sync::Arc};
+
+use api_models::{admin::CircuitBreakerState, routing::RoutableConnectorChoice};
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisKey};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    errors::{self, RouterResult},
+    metrics,
+};
+use crate::{configs::settings::CircuitBreakerConfig, consts, routes::SessionState};
+#[cfg(feature = "v1")]
+use crate::{
+    core::{
+        errors::{RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
+    services::ApplicationResponse,
+};
+
+pub const CIRCUIT_BREAKER_PREFIX: &str = "CIRCUIT_BREAKER";
+
+const TOTAL_COUNT_FIELD: &str = "total";
+const FAILURE_COUNT_FIELD: &str = "failure";
+const PROBE_COUNT_FIELD: &str = "probes";
+
+/// The state of a circuit as stored in redis, a missing record means the circuit is closed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CircuitBreakerRecord {
+    state: CircuitBreakerState,
+    changed_at: Option<i64>,
+}
+
+impl CircuitBreakerRecord {
+    /// An open circuit is half open once the open duration has elapsed
+    fn get_effective_state(&self, config: &CircuitBreakerConfig) -> CircuitBreakerState {
+        match (self.state, self.changed_at) {
+            (CircuitBreakerState::Open, Some(changed_at))
+                if date_time::now_unix_timestamp()
+                    >= changed_at.saturating_add(
+                        i64::try_from(config.open_duration_in_secs).unwrap_or(i64::MAX),
+                    ) =>
+            {
+                CircuitBreakerState::HalfOpen
+            }
+            (state, _) => state,
+        }
+    }
+}
+
+/// Outcome of a connector call as seen by the circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectorCallOutcome {
+    Success,
+    Failure,
+}
+
+impl ConnectorCallOutcome {
+    /// Returns `None` if the connector was not called, a call is a failure if the connector
+    /// responded with a 5xx status code (timeouts are reported as 504) or took longer than the
+    /// latency threshold
+    pub fn from_response(
+        connector_http_status_code: Option<u16>,
+        external_latency: Option<u128>,
+        config: &CircuitBreakerConfig,
+    ) -> Option<Self> {
+        let status_code = connector_http_status_code?;
+        let is_slow = external_latency
+            .is_some_and(|latency| latency > u128::from(config.latency_threshold_in_ms));
+
+        if (500..=599).contains(&status_code) || is_slow {
+            Some(Self::Failure)
+        } else {
+            Some(Self::Success)
+        }
+    }
+}
+
+fn get_state_key(merchant_connector_id: &id_type::MerchantConnectorAccountId) -> RedisKey {
+    format!(
+        "{CIRCUIT_BREAKER_PREFIX}_STATE_{}",
+        merchant_connector_id.get_string_repr()
+    )
+    .as_str()
+    .into()
+}
+
+fn get_probe_key(merchant_connector_id: &id_type::MerchantConnectorAccountId) -> RedisKey {
+    format!(
+        "{CIRCUIT_BREAKER_PREFIX}_PROBES_{}",
+        merchant_connector_id.get_string_repr()
+    )
+    .as_str()
+    .into()
+}
+
+fn get_window_key(
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    config: &CircuitBreakerConfig,
+) -> RedisKey {
+    let window = u64::try_from(date_time::now_unix_timestamp()).unwrap_or_default()
+        / config.window_in_secs.max(1);
+    format!(
+        "{CIRCUIT_BREAKER_PREFIX}_WINDOW_{}_{window}",
+        merchant_connector_id.get_string_repr()
+    )
+    .as_str()
+    .into()
+}
+
+fn get_redis_conn(state: &SessionState) -> RouterResult<Arc<RedisConnectionPool>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+}
+
+async fn get_record(
+    redis_conn: &RedisConnectionPool,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<CircuitBreakerRecord> {
+    match redis_conn
+        .get_and_deserialize_key::<CircuitBreakerRecord>(
+            &get_state_key(merchant_connector_id),
+            "CircuitBreakerRecord",
+        )
+        .await
+    {
+        Ok(record) => Ok(record),
+        Err(error) if error.current_context() == &RedisError::NotFound => {
+            Ok(CircuitBreakerRecord::default())
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch circuit breaker state from redis"),
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn get_window_counts(
+    redis_conn: &RedisConnectionPool,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    config: &CircuitBreakerConfig,
+) -> RouterResult<(u64, u64)> {
+    let counts = redis_conn
+        .get_hash_fields::<HashMap<String, u64>>(&get_window_key(merchant_connector_id, config))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch circuit breaker window from redis")?;
+
+    Ok((
+        counts.get(TOTAL_COUNT_FIELD).copied().unwrap_or_default(),
+        counts.get(FAILURE_COUNT_FIELD).copied().unwrap_or_default(),
+    ))
+}
+
+/// Number of probe calls let through in the current half open period
+async fn get_probe_count(
+    redis_conn: &RedisConnectionPool,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<u64> {
+    let counts = redis_conn
+        .get_hash_fields::<HashMap<String, u64>>(&get_probe_key(merchant_connector_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch circuit breaker probes from redis")?;
+
+    Ok(counts.get(PROBE_COUNT_FIELD).copied().unwrap_or_default())
+}
+
+/// A half open circuit rejects calls once all of its probe calls have been let through, so it is
+/// skipped in routing like an open circuit
+fn is_rejecting_calls(
+    state: CircuitBreakerState,
+    probe_count: u64,
+    config: &CircuitBreakerConfig,
+) -> bool {
+    match state {
+        CircuitBreakerState::Closed => false,
+        CircuitBreakerState::Open => true,
+        CircuitBreakerState::HalfOpen => probe_count >= config.half_open_max_requests,
+    }
+}
+
+fn push_state_change_metric(
+    connector: &str,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    from: CircuitBreakerState,
+    to: CircuitBreakerState,
+) {
+    logger::info!(
+        connector,
+        merchant_connector_id = merchant_connector_id.get_string_repr(),
+        %from,
+        %to,
+        "Circuit breaker state changed"
+    );
+    metrics::CONNECTOR_CIRCUIT_BREAKER_STATE_CHANGE.add(
+        1,
+        router_env::metric_attributes!(
+            ("connector", connector.to_owned()),
+            (
+                "merchant_connector_id",
+                merchant_connector_id.get_string_repr().to_owned()
+            ),
+            ("from", from.to_string()),
+            ("to", to.to_string()),
+        ),
+    );
+}
+
+async fn transition_to(
+    redis_conn: &RedisConnectionPool,
+    connector: &str,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    config: &CircuitBreakerConfig,
+    from: CircuitBreakerState,
+    to: CircuitBreakerState,
+) -> RouterResult<()> {
+    match to {
+        CircuitBreakerState::Closed => {
+            redis_conn
+                .delete_multiple_keys(&[
+                    get_state_key(merchant_connector_id),
+                    get_window_key(merchant_connector_id, config),
+                ])
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to close circuit in redis")?;
+        }
+        CircuitBreakerState::Open | CircuitBreakerState::HalfOpen => {
+            redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &get_state_key(merchant_connector_id),
+                    CircuitBreakerRecord {
+                        state: to,
+                        changed_at: Some(date_time::now_unix_timestamp()),
+                    },
+                    consts::CIRCUIT_BREAKER_STATE_TTL,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update circuit breaker state in redis")?;
+        }
+    }
+
+    // Every new half open period starts with a fresh set of probe calls
+    redis_conn
+        .delete_key(&get_probe_key(merchant_connector_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reset circuit breaker probes in redis")?;
+
+    push_state_change_metric(connector, merchant_connector_id, from, to);
+
+    Ok(())
+}
+
+/// Returns an error if the circuit of the merchant connector account is open, or if it is half
+/// open and all the probe calls have already been let through
+#[instrument(skip_all)]
+pub async fn check_circuit(
+    state: &SessionState,
+    connector: &str,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<()> {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let is_allowed = async {
+        let redis_conn = get_redis_conn(state)?;
+        let record = get_record(&redis_conn, merchant_connector_id).await?;
+
+        match record.get_effective_state(config) {
+            CircuitBreakerState::Closed => Ok(true),
+            CircuitBreakerState::Open => Ok(false),
+            CircuitBreakerState::HalfOpen => {
+                let probe_key = get_probe_key(merchant_connector_id);
+                let probes = redis_conn
+                    .increment_fields_in_hash(&probe_key, &[(PROBE_COUNT_FIELD, 1)])
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to increment circuit breaker probes in redis")?
+                    .first()
+                    .copied()
+                    .unwrap_or_default();
+
+                if probes == 1 {
+                    redis_conn
+                        .set_expiry(
+                            &probe_key,
+                            i64::try_from(config.open_duration_in_secs).unwrap_or(i64::MAX),
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to set expiry of circuit breaker probes")?;
+                    push_state_change_metric(
+                        connector,
+                        merchant_connector_id,
+                        CircuitBreakerState::Open,
+                        CircuitBreakerState::HalfOpen,
+                    );
+                }
+
+                Ok(u64::try_from(probes).unwrap_or(u64::MAX) <= config.half_open_max_requests)
+            }
+        }
+    }
+    .await
+    // Connector calls are not blocked if the circuit breaker state cannot be determined
+    .unwrap_or_else(|error: error_stack::Report<errors::ApiErrorResponse>| {
+        logger::error!(?error, "Failed to check the circuit breaker state");
+        true
+    });
+
+    if is_allowed {
+        Ok(())
+    } else {
+        metrics::CONNECTOR_CIRCUIT_BREAKER_REJECTED_REQUEST.add(
+            1,
+            router_env::metric_attributes!(("connector", connector.to_owned())),
+        );
+        Err(errors::ApiErrorResponse::ConnectorCircuitOpen {
+            connector: connector.to_owned(),
+        }
+        .into())
+    }
+}
+
+/// Records the outcome of a connector call and opens or closes the circuit if required.
+/// Failures in updating the circuit breaker are logged and do not affect the payment.
+#[instrument(skip_all)]
+pub async fn record_connector_call_outcome(
+    state: &SessionState,
+    connector: &str,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    outcome: ConnectorCallOutcome,
+) {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled {
+        return;
+    }
+
+    let result = async {
+        let redis_conn = get_redis_conn(state)?;
+        let record = get_record(&redis_conn, merchant_connector_id).await?;
+
+        match (record.get_effective_state(config), outcome) {
+            // Outcomes of calls which were let through before the circuit opened
+            (CircuitBreakerState::Open, _) => Ok(()),
+            (CircuitBreakerState::HalfOpen, ConnectorCallOutcome::Success) => {
+                transition_to(
+                    &redis_conn,
+                    connector,
+                    merchant_connector_id,
+                    config,
+                    CircuitBreakerState::HalfOpen,
+                    CircuitBreakerState::Closed,
+                )
+                .await
+            }
+            (CircuitBreakerState::HalfOpen, ConnectorCallOutcome::Failure) => {
+                transition_to(
+                    &redis_conn,
+                    connector,
+                    merchant_connector_id,
+                    config,
+                    CircuitBreakerState::HalfOpen,
+                    CircuitBreakerState::Open,
+                )
+                .await
+            }
+            (CircuitBreakerState::Closed, outcome) => {
+                let window_key = get_window_key(merchant_connector_id, config);
+                let is_failure = outcome == ConnectorCallOutcome::Failure;
+                let counts = redis_conn
+                    .increment_fields_in_hash(
+                        &window_key,
+                        &[
+                            (TOTAL_COUNT_FIELD, 1),
+                            (FAILURE_COUNT_FIELD, i64::from(is_failure)),
+                        ],
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update circuit breaker window in redis")?;
+                redis_conn
+                    .set_expiry(
+                        &window_key,
+                        i64::try_from(config.window_in_secs.saturating_mul(2)).unwrap_or(i64::MAX),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to set expiry of circuit breaker window")?;
+
+                let (total_count, failure_count) = match counts.as_slice() {
+                    [total_count, failure_count] => (*total_count, *failure_count),
+                    _ => (0, 0),
+                };
+
+                #[allow(clippy::as_conversions)]
+                let error_rate = if total_count == 0 {
+                    0.0
+                } else {
+                    failure_count as f64 * 100.0 / total_count as f64
+                };
+
+                if is_failure
+                    && u64::try_from(total_count).unwrap_or(u64::MAX) >= config.min_request_count
+                    && error_rate >= config.error_rate_threshold
+                {
+                    transition_to(
+                        &redis_conn,
+                        connector,
+                        merchant_connector_id,
+                        config,
+                        CircuitBreakerState::Closed,
+                        CircuitBreakerState::Open,
+                    )
+                    .await
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            "Failed to record the connector call in the circuit breaker"
+        );
+    }
+}
+
+/// Removes the connectors whose circuit is open, or half open without probe calls left, from the
+/// routing result, so that the payment falls back to the next eligible connector. If the circuit
+/// of every connector is open, the routing result is left as is.
+#[instrument(skip_all)]
+pub async fn filter_connectors_with_open_circuit(
+    state: &SessionState,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> Vec<RoutableConnectorChoice> {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled || connectors.is_empty() {
+        return connectors;
+    }
+
+    let redis_conn = match get_redis_conn(state) {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to check the circuit breaker state");
+            return connectors;
+        }
+    };
+
+    let is_rejecting = futures::future::join_all(connectors.iter().map(|choice| async {
+        let Some(merchant_connector_id) = choice.merchant_connector_id.as_ref() else {
+            return false;
+        };
+
+        async {
+            let state = get_record(&redis_conn, merchant_connector_id)
+                .await?
+                .get_effective_state(config);
+            let probe_count = match state {
+                CircuitBreakerState::HalfOpen => {
+                    get_probe_count(&redis_conn, merchant_connector_id).await?
+                }
+                CircuitBreakerState::Closed | CircuitBreakerState::Open => 0,
+            };
+
+            Ok(is_rejecting_calls(state, probe_count, config))
+        }
+        .await
+        .unwrap_or_else(|error: error_stack::Report<errors::ApiErrorResponse>| {
+            logger::error!(?error, "Failed to check the circuit breaker state");
+            false
+        })
+    }))
+    .await;
+
+    let (eligible, skipped): (Vec<_>, Vec<_>) = connectors
+        .iter()
+        .cloned()
+        .zip(is_rejecting)
+        .partition(|(_, is_rejecting)| !is_rejecting);
+
+    if skipped.is_empty() {
+        return connectors;
+    }
+
+    if eligible.is_empty() {
+        logger::warn!("Circuit of every routable connector is open, not skipping any connector");
+        return connectors;
+    }
+
+    for (choice, _) in &skipped {
+        logger::info!(connector = %choice.connector, "Skipping connector with open circuit");
+        metrics::CONNECTOR_CIRCUIT_BREAKER_SKIPPED_IN_ROUTING.add(
+            1,
+            router_env::metric_attributes!(("connector", choice.connector.to_string())),
+        );
+    }
+
+    eligible.into_iter().map(|(choice, _)| choice).collect()
+}
+
+#[cfg(feature = "v1")]
+async fn validate_merchant_connector_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<()> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_circuit_breaker(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<api_models::admin::ConnectorCircuitBreakerResponse> {
+    validate_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+        .await?;
+
+    let config = &state.conf.circuit_breaker;
+    let redis_conn = get_redis_conn(&state)?;
+    let record = get_record(&redis_conn, &merchant_connector_id).await?;
+    let (window_request_count, window_failure_count) =
+        get_window_counts(&redis_conn, &merchant_connector_id, config).await?;
+
+    Ok(ApplicationResponse::Json(
+        api_models::admin::ConnectorCircuitBreakerResponse {
+            state: record.get_effective_state(config),
+            state_changed_at: record
+                .changed_at
+                .and_then(|changed_at| time::OffsetDateTime::from_unix_timestamp(changed_at).ok())
+                .map(date_time::convert_to_pdt),
+            merchant_connector_id,
+            window_request_count,
+            window_failure_count,
+        },
+    ))
+}
+
+/// Closes the circuit of the merchant connector account and clears the current window
+#[cfg(feature = "v1")]
+pub async fn reset_circuit_breaker(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<api_models::admin::ConnectorCircuitBreakerResponse> {
+    validate_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+        .await?;
+
+    let config = &state.conf.circuit_breaker;
+    let redis_conn = get_redis_conn(&state)?;
+    let record = get_record(&redis_conn, &merchant_connector_id).await?;
+
+    transition_to(
+        &redis_conn,
+        "manual_reset",
+        &merchant_connector_id,
+        config,
+        record.get_effective_state(config),
+        CircuitBreakerState::Closed,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        api_models::admin::ConnectorCircuitBreakerResponse {
+            merchant_connector_id,
+            state: CircuitBreakerState::Closed,
+            state_changed_at: Some(date_time::now()),
+            window_request_count: 0,
+            window_failure_count: 0,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connector_call_outcome() {
+        let config = CircuitBreakerConfig::default();
+
+        assert_eq!(
+            ConnectorCallOutcome::from_response(Some(200), Some(100), &config),
+            Some(ConnectorCallOutcome::Success)
+        );
+        assert_eq!(
+            ConnectorCallOutcome::from_response(Some(400), Some(100), &config),
+            Some(ConnectorCallOutcome::Success)
+        );
+        assert_eq!(
+            ConnectorCallOutcome::from_response(Some(504), None, &config),
+            Some(ConnectorCallOutcome::Failure)
+        );
+        assert_eq!(
+            ConnectorCallOutcome::from_response(
+                Some(200),
+                Some(u128::from(config.latency_threshold_in_ms) + 1),
+                &config
+            ),
+            Some(ConnectorCallOutcome::Failure)
+        );
+        assert_eq!(
+            ConnectorCallOutcome::from_response(None, None, &config),
+            None
+        );
+    }
+
+    #[test]
+    fn test_open_circuit_is_half_open_after_open_duration() {
+        let config = CircuitBreakerConfig::default();
+        let now = date_time::now_unix_timestamp();
+
+        let record = CircuitBreakerRecord {
+            state: CircuitBreakerState::Open,
+            changed_at: Some(now),
+        };
+        assert_eq!(
+            record.get_effective_state(&config),
+            CircuitBreakerState::Open
+        );
+
+        let record = CircuitBreakerRecord {
+            state: CircuitBreakerState::Open,
+            changed_at: Some(now - i64::try_from(config.open_duration_in_secs).unwrap_or_default()),
+        };
+        assert_eq!(
+            record.get_effective_state(&config),
+            CircuitBreakerState::HalfOpen
+        );
+    }
+
+    #[test]
+    fn test_half_open_circuit_rejects_calls_without_probes_left() {
+        let config = CircuitBreakerConfig::default();
+
+        assert!(!is_rejecting_calls(CircuitBreakerState::Closed, 0, &config));
+        assert!(is_rejecting_calls(CircuitBreakerState::Open, 0, &config));
+        assert!(!is_rejecting_calls(
+            CircuitBreakerState::HalfOpen,
+            config.half_open_max_requests - 1,
+            &config
+        ));
+        assert!(is_rejecting_calls(
+            CircuitBreakerState::HalfOpen,
+            config.half_open_max_requests,
+            &config
+        ));
+    }
+}
@@ -1,27 +1,104 @@
-use api_models::gsm as gsm_api_types;
+#[cfg(feature = "olap")]
+pub mod unmapped_errors;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::Arc,
+};
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::{enums as api_enums, gsm as gsm_api_types};
+use common_utils::fp_utils::when;
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use hyperswitch_domain_models::gsm::GatewayStatusMap;
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::cache::{self, CacheKey, CacheKind, GSM_CACHE};
 
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        audit_log,
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    },
     db::gsm::GsmInterface,
     services,
     types::transformers::{ForeignFrom, ForeignInto},
     SessionState,
 };
 
+/// Stored in the fields of a GSM rule that are ignored by its match type
+const GSM_WILDCARD: &str = "*";
+
+/// The maximum number of rules accepted in a single import, all of them are inserted in a single
+/// statement
+const GSM_IMPORT_MAX_RULES: usize = 1000;
+/// The maximum number of invalid rows listed in the error returned for an invalid import
+const GSM_IMPORT_MAX_REPORTED_ERRORS: usize = 20;
+
+/// GSM rules are identified by the combination of these fields in the audit log
+fn get_gsm_rule_resource_id(
+    connector: &str,
+    flow: &str,
+    sub_flow: &str,
+    code: &str,
+    message: &str,
+) -> String {
+    format!("{connector}:{flow}:{sub_flow}:{code}:{message}")
+}
+
 #[instrument(skip_all)]
 pub async fn create_gsm_rule(
     state: SessionState,
     gsm_rule: gsm_api_types::GsmCreateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
     let db = state.store.as_ref();
-    GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
+    let gsm_rule = normalize_gsm_rule(gsm_rule)
+        .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })?;
+    match GsmInterface::find_gsm_rule(
+        db,
+        gsm_rule.connector.to_string(),
+        gsm_rule.flow.clone(),
+        gsm_rule.sub_flow.clone(),
+        gsm_rule.code.clone(),
+        gsm_rule.message.clone(),
+    )
+    .await
+    {
+        Ok(existing_rule) => Err(get_gsm_rule_conflict_error(
+            existing_rule.match_type,
+            gsm_rule.match_type.unwrap_or_default(),
+        ))?,
+        Err(error) if error.current_context().is_db_not_found() => {}
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the GSM rule with the same key")?,
+    }
+    let gsm: gsm_api_types::GsmResponse = GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
             message: "GSM with given key already exists in our records".to_string(),
-        })
-        .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+        })?
+        .foreign_into();
+    invalidate_gsm_cache(&state, &gsm.connector, &gsm.flow, &gsm.sub_flow).await?;
+
+    audit_log::record_audit_log(
+        &state,
+        None,
+        common_enums::AuditLogResourceType::GsmRule,
+        &get_gsm_rule_resource_id(
+            &gsm.connector,
+            &gsm.flow,
+            &gsm.sub_flow,
+            &gsm.code,
+            &gsm.message,
+        ),
+        common_enums::AuditLogAction::Create,
+        None,
+        Some(&gsm),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm))
 }
 
 #[instrument(skip_all)]
@@ -64,6 +141,8 @@ pub async fn update_gsm_rule(
                 message: "GSM with given key does not exist in our records".to_string(),
             })?;
 
+    let gsm_before_update = gsm_api_types::GsmResponse::foreign_from(gsm_db_record.clone());
+
     let inferred_feature_info = <(
         common_enums::GsmFeature,
         common_types::domain::GsmFeatureData,
@@ -86,7 +165,7 @@ pub async fn update_gsm_rule(
         feature,
         feature_data,
     } = gsm_request;
-    GsmInterface::update_gsm_rule(
+    let gsm = GsmInterface::update_gsm_rule(
         db,
         connector.to_string(),
         flow,
@@ -118,8 +197,28 @@ pub async fn update_gsm_rule(
     .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
         message: "GSM with given key does not exist in our records".to_string(),
     })
-    .attach_printable("Failed while updating Gsm rule")
-    .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+    .attach_printable("Failed while updating Gsm rule")?;
+    let gsm = gsm_api_types::GsmResponse::foreign_from(gsm);
+    invalidate_gsm_cache(&state, &gsm.connector, &gsm.flow, &gsm.sub_flow).await?;
+
+    audit_log::record_audit_log(
+        &state,
+        None,
+        common_enums::AuditLogResourceType::GsmRule,
+        &get_gsm_rule_resource_id(
+            &gsm.connector,
+            &gsm.flow,
+            &gsm.sub_flow,
+            &gsm.code,
+            &gsm.message,
+        ),
+        common_enums::AuditLogAction::Update,
+        Some(&gsm_before_update),
+        Some(&gsm),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm))
 }
 
 #[instrument(skip_all)]
@@ -151,6 +250,18 @@ pub async fn delete_gsm_rule(
     {
         Ok(is_deleted) => {
             if is_deleted {
+                invalidate_gsm_cache(&state, &connector, &flow, &sub_flow).await?;
+                audit_log::record_audit_log::<gsm_api_types::GsmResponse>(
+                    &state,
+                    None,
+                    common_enums::AuditLogResourceType::GsmRule,
+                    &get_gsm_rule_resource_id(&connector, &flow, &sub_flow, &code, &message),
+                    common_enums::AuditLogAction::Delete,
+                    None,
+                    None,
+                )
+                .await;
+
                 Ok(services::ApplicationResponse::Json(
                     gsm_api_types::GsmDeleteResponse {
                         gsm_rule_delete: true,
@@ -168,3 +279,823 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+/// Builds an import request from an uploaded CSV file, with one rule per row. The header row of
+/// the file contains the field names of [`gsm_api_types::GsmCsvRecord`], as in exported files.
+pub fn get_gsm_import_request(
+    form: GsmImportForm,
+) -> Result<gsm_api_types::GsmImportRequest, errors::ApiErrorResponse> {
+    let records = parse_gsm_csv(form.file.data.as_ref()).map_err(|e| {
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: e.to_string(),
+        }
+    })?;
+
+    let mut rules = Vec::with_capacity(records.len());
+    let mut row_errors = Vec::new();
+    for (record, row_number) in records.into_iter().zip(1..) {
+        match api_enums::Connector::from_str(&record.connector) {
+            Ok(connector) => rules.push(get_gsm_create_request(connector, record)),
+            Err(_) => row_errors.push(format!(
+                "row {row_number}: `{}` is not a valid connector",
+                record.connector
+            )),
+        }
+    }
+    when(!row_errors.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: get_row_errors_message(&row_errors),
+        })
+    })?;
+
+    Ok(gsm_api_types::GsmImportRequest { rules })
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct GsmImportForm {
+    #[multipart(limit = "10MB")]
+    pub file: Bytes,
+}
+
+fn parse_gsm_csv(data: &[u8]) -> csv::Result<Vec<gsm_api_types::GsmCsvRecord>> {
+    csv::Reader::from_reader(data).deserialize().collect()
+}
+
+fn get_gsm_create_request(
+    connector: api_enums::Connector,
+    record: gsm_api_types::GsmCsvRecord,
+) -> gsm_api_types::GsmCreateRequest {
+    gsm_api_types::GsmCreateRequest {
+        connector,
+        flow: record.flow,
+        sub_flow: record.sub_flow,
+        code: record.code,
+        message: record.message,
+        match_type: record.match_type,
+        status: record.status,
+        router_error: record.router_error,
+        decision: record.decision,
+        step_up_possible: record.step_up_possible,
+        unified_code: record.unified_code,
+        unified_message: record.unified_message,
+        error_category: record.error_category,
+        clear_pan_possible: record.clear_pan_possible,
+        feature: Some(api_enums::GsmFeature::Retry),
+        feature_data: Some(common_types::domain::GsmFeatureData::Retry(
+            common_types::domain::RetryFeatureData {
+                step_up_possible: record.step_up_possible,
+                clear_pan_possible: record.clear_pan_possible,
+                alternate_network_possible: record.alternate_network_possible,
+                decision: record.decision,
+                retry_later_config: record
+                    .retry_later_initial_delay_in_seconds
+                    .zip(record.retry_later_max_retries)
+                    .map(|(initial_delay_in_seconds, max_retries)| {
+                        common_types::domain::RetryLaterConfig {
+                            initial_delay_in_seconds,
+                            max_retries,
+                        }
+                    }),
+            },
+        )),
+    }
+}
+
+fn get_row_errors_message(row_errors: &[String]) -> String {
+    let remaining_errors = row_errors
+        .len()
+        .saturating_sub(GSM_IMPORT_MAX_REPORTED_ERRORS);
+    let mut message = row_errors
+        .iter()
+        .take(GSM_IMPORT_MAX_REPORTED_ERRORS)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("; ");
+    if remaining_errors > 0 {
+        message.push_str(&format!("; and {remaining_errors} more invalid rows"));
+    }
+    message
+}
+
+/// Imports the rules as a whole: all rules are validated before any of them is added, and they
+/// are added in a single statement, so that either all of them are imported or none.
+#[instrument(skip_all)]
+pub async fn import_gsm_rules(
+    state: SessionState,
+    request: gsm_api_types::GsmImportRequest,
+) -> RouterResponse<gsm_api_types::GsmImportResponse> {
+    let db = state.store.as_ref();
+    when(
+        request.rules.is_empty() || request.rules.len() > GSM_IMPORT_MAX_RULES,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "An import must contain between 1 and {GSM_IMPORT_MAX_RULES} rules"
+                ),
+            })
+        },
+    )?;
+
+    let existing_rule_keys = db
+        .find_all_gsm_rules()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the existing GSM rules")?
+        .into_iter()
+        .map(|rule| {
+            (
+                (
+                    rule.connector,
+                    rule.flow,
+                    rule.sub_flow,
+                    rule.code,
+                    rule.message,
+                ),
+                rule.match_type,
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut rules = Vec::with_capacity(request.rules.len());
+    let mut seen_rule_keys = HashMap::new();
+    let mut row_errors = Vec::new();
+    for (rule, row_number) in request.rules.into_iter().zip(1..) {
+        let rule: GatewayStatusMap = match normalize_gsm_rule(rule) {
+            Ok(rule) => rule.foreign_into(),
+            Err(error) => {
+                row_errors.push(format!("row {row_number}: {error}"));
+                continue;
+            }
+        };
+        let rule_key = (
+            rule.connector.clone(),
+            rule.flow.clone(),
+            rule.sub_flow.clone(),
+            rule.code.clone(),
+            rule.message.clone(),
+        );
+        if let Some(existing_match_type) = existing_rule_keys.get(&rule_key) {
+            row_errors.push(format!(
+                "row {row_number}: {}",
+                get_gsm_rule_conflict_message(*existing_match_type, rule.match_type)
+            ));
+        } else if let Some(seen_match_type) = seen_rule_keys.insert(rule_key, rule.match_type) {
+            row_errors.push(format!(
+                "row {row_number}: {} in the import",
+                get_gsm_rule_conflict_message(seen_match_type, rule.match_type)
+            ));
+        }
+        rules.push(rule);
+    }
+    when(!row_errors.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: get_row_errors_message(&row_errors),
+        })
+    })?;
+
+    db.add_gsm_rules(rules.clone())
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "GSM with given key already exists in our records".to_string(),
+        })?;
+
+    let connector_flows = rules
+        .iter()
+        .map(|rule| (&rule.connector, &rule.flow, &rule.sub_flow))
+        .collect::<HashSet<_>>();
+    for (connector, flow, sub_flow) in connector_flows {
+        invalidate_gsm_cache(&state, connector, flow, sub_flow).await?;
+    }
+
+    let imported_count = rules.len();
+    for rule in rules {
+        let gsm = gsm_api_types::GsmResponse::foreign_from(rule);
+        audit_log::record_audit_log(
+            &state,
+            None,
+            common_enums::AuditLogResourceType::GsmRule,
+            &get_gsm_rule_resource_id(
+                &gsm.connector,
+                &gsm.flow,
+                &gsm.sub_flow,
+                &gsm.code,
+                &gsm.message,
+            ),
+            common_enums::AuditLogAction::Create,
+            None,
+            Some(&gsm),
+        )
+        .await;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        gsm_api_types::GsmImportResponse { imported_count },
+    ))
+}
+
+/// Exports every GSM rule, as a JSON list of rules or as a CSV file which can be imported again
+#[instrument(skip_all)]
+pub async fn export_gsm_rules(
+    state: SessionState,
+    query: gsm_api_types::GsmExportQuery,
+) -> RouterResponse<Vec<gsm_api_types::GsmResponse>> {
+    let rules = state
+        .store
+        .find_all_gsm_rules()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the GSM rules")?;
+
+    match query.format {
+        gsm_api_types::GsmExportFormat::Json => Ok(services::ApplicationResponse::Json(
+            rules.into_iter().map(ForeignInto::foreign_into).collect(),
+        )),
+        gsm_api_types::GsmExportFormat::Csv => {
+            let mut csv_writer = csv::Writer::from_writer(Vec::new());
+            for rule in rules {
+                csv_writer
+                    .serialize(gsm_api_types::GsmCsvRecord::foreign_from(rule))
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to write the GSM rules")?;
+            }
+            let file_data = csv_writer
+                .into_inner()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write the GSM rules")?;
+
+            Ok(services::ApplicationResponse::FileData((
+                file_data,
+                mime::TEXT_CSV,
+            )))
+        }
+    }
+}
+
+/// Stores the fields ignored by the match type of the rule as wildcards, so that the key of the
+/// rule doesn't depend on values that are never matched against. The wildcard can't be used in
+/// the fields that are matched against, as the key would then clash with rules of other match
+/// types, for instance a `code` rule for the code `*` with the `connector_default` rule.
+fn normalize_gsm_rule(
+    mut gsm_rule: gsm_api_types::GsmCreateRequest,
+) -> Result<gsm_api_types::GsmCreateRequest, String> {
+    let match_type = gsm_rule.match_type.unwrap_or_default();
+    let is_wildcard = |value: &str| value == GSM_WILDCARD;
+    match match_type {
+        common_enums::GsmMatchType::Exact => {
+            if is_wildcard(&gsm_rule.code) || is_wildcard(&gsm_rule.message) {
+                return Err(format!(
+                    "code and message must not be `{GSM_WILDCARD}` for exact rules"
+                ));
+            }
+        }
+        common_enums::GsmMatchType::Code => {
+            if is_wildcard(&gsm_rule.code) {
+                return Err(format!(
+                    "code must not be `{GSM_WILDCARD}` for code rules, use a connector_default \
+                     rule to match any error"
+                ));
+            }
+            gsm_rule.message = GSM_WILDCARD.to_string();
+        }
+        common_enums::GsmMatchType::MessageContains => {
+            if gsm_rule.message.is_empty() || is_wildcard(&gsm_rule.message) {
+                return Err(format!(
+                    "message must not be empty or `{GSM_WILDCARD}` for message_contains rules"
+                ));
+            }
+            gsm_rule.code = GSM_WILDCARD.to_string();
+        }
+        common_enums::GsmMatchType::MessageRegex => {
+            if is_wildcard(&gsm_rule.message) {
+                return Err(format!(
+                    "message must not be `{GSM_WILDCARD}` for message_regex rules"
+                ));
+            }
+            regex::Regex::new(&gsm_rule.message)
+                .map_err(|error| format!("message is not a valid regex: {error}"))?;
+            gsm_rule.code = GSM_WILDCARD.to_string();
+        }
+        common_enums::GsmMatchType::ConnectorDefault => {
+            gsm_rule.code = GSM_WILDCARD.to_string();
+            gsm_rule.message = GSM_WILDCARD.to_string();
+        }
+    }
+    Ok(gsm_rule)
+}
+
+/// `message_contains` and `message_regex` rules are both keyed by their pattern, so the two
+/// can't hold the same pattern for a connector and flow
+fn get_gsm_rule_conflict_message(
+    existing_match_type: common_enums::GsmMatchType,
+    match_type: common_enums::GsmMatchType,
+) -> String {
+    if existing_match_type == match_type {
+        "a rule with the same key already exists".to_string()
+    } else {
+        format!(
+            "a {existing_match_type} rule with the same key already exists, a {match_type} rule \
+             can't use the same code and message"
+        )
+    }
+}
+
+fn get_gsm_rule_conflict_error(
+    existing_match_type: common_enums::GsmMatchType,
+    match_type: common_enums::GsmMatchType,
+) -> errors::ApiErrorResponse {
+    if existing_match_type == match_type {
+        errors::ApiErrorResponse::GenericDuplicateError {
+            message: "GSM with given key already exists in our records".to_string(),
+        }
+    } else {
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: get_gsm_rule_conflict_message(existing_match_type, match_type),
+        }
+    }
+}
+
+fn get_gsm_cache_key(connector: &str, flow: &str, sub_flow: &str) -> String {
+    format!("gsm_{connector}_{flow}_{sub_flow}")
+}
+
+async fn invalidate_gsm_cache(
+    state: &SessionState,
+    connector: &str,
+    flow: &str,
+    sub_flow: &str,
+) -> RouterResult<()> {
+    let cache_key = CacheKind::Gsm(get_gsm_cache_key(connector, flow, sub_flow).into());
+    cache::redact_from_redis_and_publish(state.store.get_cache_store().as_ref(), [cache_key])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to invalidate the GSM cache")?;
+    Ok(())
+}
+
+/// Pattern matched against the error message by `message_contains` and `message_regex` rules
+#[derive(Debug)]
+enum MessagePattern {
+    /// Matched case insensitively, hence stored in lowercase
+    Contains(String),
+    Regex(regex::Regex),
+}
+
+impl MessagePattern {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Contains(pattern) => pattern,
+            Self::Regex(regex) => regex.as_str(),
+        }
+    }
+
+    fn is_match(&self, message: &str, lowercase_message: &str) -> bool {
+        match self {
+            Self::Contains(pattern) => lowercase_message.contains(pattern.as_str()),
+            Self::Regex(regex) => regex.is_match(message),
+        }
+    }
+}
+
+/// The GSM rules of a connector and flow, indexed by how they are matched
+#[derive(Debug, Default)]
+pub struct GsmRuleSet {
+    exact: HashMap<(String, String), GatewayStatusMap>,
+    code: HashMap<String, GatewayStatusMap>,
+    /// Ordered by the length of the pattern, so that more specific patterns are tried first
+    patterns: Vec<(MessagePattern, GatewayStatusMap)>,
+    connector_default: Option<GatewayStatusMap>,
+}
+
+impl GsmRuleSet {
+    pub fn new(rules: Vec<GatewayStatusMap>) -> Self {
+        let mut rule_set = Self::default();
+        for rule in rules {
+            match rule.match_type {
+                common_enums::GsmMatchType::Exact => {
+                    rule_set
+                        .exact
+                        .insert((rule.code.clone(), rule.message.clone()), rule);
+                }
+                common_enums::GsmMatchType::Code => {
+                    rule_set.code.insert(rule.code.clone(), rule);
+                }
+                common_enums::GsmMatchType::MessageContains => {
+                    let pattern = MessagePattern::Contains(rule.message.to_lowercase());
+                    rule_set.patterns.push((pattern, rule));
+                }
+                common_enums::GsmMatchType::MessageRegex => {
+                    match regex::Regex::new(&rule.message) {
+                        Ok(regex) => rule_set.patterns.push((MessagePattern::Regex(regex), rule)),
+                        Err(error) => logger::error!(
+                            ?error,
+                            connector = %rule.connector,
+                            flow = %rule.flow,
+                            pattern = %rule.message,
+                            "Skipping GSM rule with an invalid message regex"
+                        ),
+                    }
+                }
+                common_enums::GsmMatchType::ConnectorDefault => {
+                    rule_set.connector_default = Some(rule);
+                }
+            }
+        }
+        rule_set
+            .patterns
+            .sort_by_key(|(pattern, _)| std::cmp::Reverse(pattern.as_str().len()));
+        rule_set
+    }
+
+    /// Finds the most specific rule matching the error, trying exact rules first, then code rules,
+    /// then message patterns and finally the connector default
+    pub fn find(&self, code: &str, message: &str) -> Option<&GatewayStatusMap> {
+        self.find_specific(code, message)
+            .or(self.connector_default.as_ref())
+    }
+
+    /// Finds the most specific rule matching the error, without falling back to the connector
+    /// default
+    fn find_specific(&self, code: &str, message: &str) -> Option<&GatewayStatusMap> {
+        let lowercase_message = message.to_lowercase();
+        self.exact
+            .get(&(code.to_string(), message.to_string()))
+            .or_else(|| self.code.get(code))
+            .or_else(|| {
+                self.patterns
+                    .iter()
+                    .find(|(pattern, _)| pattern.is_match(message, &lowercase_message))
+                    .map(|(_, rule)| rule)
+            })
+    }
+}
+
+/// Finds the rule matching the error across the rule sets of several flows, in order of
+/// preference. A specific rule of any flow is preferred over the connector default of an earlier
+/// flow.
+fn find_in_rule_sets<'a>(
+    rule_sets: &'a [Arc<GsmRuleSet>],
+    code: &str,
+    message: &str,
+) -> Option<&'a GatewayStatusMap> {
+    rule_sets
+        .iter()
+        .find_map(|rule_set| rule_set.find_specific(code, message))
+        .or_else(|| {
+            rule_sets
+                .iter()
+                .find_map(|rule_set| rule_set.connector_default.as_ref())
+        })
+}
+
+async fn get_gsm_rule_set(
+    state: &SessionState,
+    connector: String,
+    flow: String,
+    sub_flow: String,
+) -> CustomResult<Arc<GsmRuleSet>, errors::StorageError> {
+    let cache_key = CacheKey {
+        key: get_gsm_cache_key(&connector, &flow, &sub_flow),
+        prefix: state.tenant.redis_key_prefix.clone(),
+    };
+    match GSM_CACHE
+        .get_val::<Arc<GsmRuleSet>>(cache_key.clone())
+        .await
+    {
+        Some(rule_set) => Ok(rule_set),
+        None => {
+            let rules = state
+                .store
+                .find_gsm_rules_by_connector_flow(connector, flow, sub_flow)
+                .await?;
+            let rule_set = Arc::new(GsmRuleSet::new(rules));
+            GSM_CACHE.push(cache_key, rule_set.clone()).await;
+            Ok(rule_set)
+        }
+    }
+}
+
+/// Finds the GSM rule best matching the error of the connector. The rules of the connector and
+/// flow are cached in memory and invalidated whenever one of them is changed.
+pub async fn find_matching_gsm_rule(
+    state: &SessionState,
+    connector: String,
+    flow: String,
+    sub_flow: String,
+    code: &str,
+    message: &str,
+) -> CustomResult<Option<GatewayStatusMap>, errors::StorageError> {
+    find_matching_gsm_rule_in_flows(state, connector, &[flow], sub_flow, code, message).await
+}
+
+/// Finds the GSM rule best matching the error of the connector, looking through the rules of the
+/// flows in order. The connector defaults are only used if no flow has a more specific rule, so
+/// that the default of one flow doesn't hide a specific rule of the next one.
+pub async fn find_matching_gsm_rule_in_flows(
+    state: &SessionState,
+    connector: String,
+    flows: &[String],
+    sub_flow: String,
+    code: &str,
+    message: &str,
+) -> CustomResult<Option<GatewayStatusMap>, errors::StorageError> {
+    let mut rule_sets = Vec::with_capacity(flows.len());
+    for flow in flows {
+        let rule_set =
+            get_gsm_rule_set(state, connector.clone(), flow.clone(), sub_flow.clone()).await?;
+        // The rule sets of the remaining flows are only needed if this one has no specific rule
+        if let Some(rule) = rule_set.find_specific(code, message) {
+            return Ok(Some(rule.clone()));
+        }
+        rule_sets.push(rule_set);
+    }
+    Ok(find_in_rule_sets(&rule_sets, code, message).cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gsm_rule(
+        match_type: common_enums::GsmMatchType,
+        code: &str,
+        message: &str,
+    ) -> GatewayStatusMap {
+        GatewayStatusMap {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+            status: "Failure".to_string(),
+            router_error: None,
+            unified_code: None,
+            unified_message: None,
+            error_category: None,
+            feature_data: common_types::domain::GsmFeatureData::Retry(
+                common_types::domain::RetryFeatureData {
+                    step_up_possible: false,
+                    clear_pan_possible: false,
+                    alternate_network_possible: false,
+                    decision: common_enums::GsmDecision::DoDefault,
+                    retry_later_config: None,
+                },
+            ),
+            feature: common_enums::GsmFeature::Retry,
+            match_type,
+        }
+    }
+
+    fn matched_rule(rule_set: &GsmRuleSet, code: &str, message: &str) -> Option<(String, String)> {
+        rule_set
+            .find(code, message)
+            .map(|rule| (rule.code.clone(), rule.message.clone()))
+    }
+
+    #[test]
+    fn test_gsm_rule_set_precedence() {
+        let rule_set = GsmRuleSet::new(vec![
+            gsm_rule(common_enums::GsmMatchType::ConnectorDefault, "*", "*"),
+            gsm_rule(
+                common_enums::GsmMatchType::MessageContains,
+                "*",
+                "Insufficient",
+            ),
+            gsm_rule(
+                common_enums::GsmMatchType::MessageRegex,
+                "*",
+                "^card .* declined$",
+            ),
+            gsm_rule(common_enums::GsmMatchType::Code, "card_declined", "*"),
+            gsm_rule(
+                common_enums::GsmMatchType::Exact,
+                "card_declined",
+                "Do not honor",
+            ),
+        ]);
+
+        let rule = |code: &str, message: &str| Some((code.to_string(), message.to_string()));
+        assert_eq!(
+            matched_rule(&rule_set, "card_declined", "Do not honor"),
+            rule("card_declined", "Do not honor")
+        );
+        assert_eq!(
+            matched_rule(&rule_set, "card_declined", "Insufficient funds"),
+            rule("card_declined", "*")
+        );
+        assert_eq!(
+            matched_rule(&rule_set, "generic_decline", "insufficient funds"),
+            rule("*", "Insufficient")
+        );
+        assert_eq!(
+            matched_rule(&rule_set, "generic_decline", "card was declined"),
+            rule("*", "^card .* declined$")
+        );
+        assert_eq!(
+            matched_rule(&rule_set, "processing_error", "Try again later"),
+            rule("*", "*")
+        );
+    }
+
+    #[test]
+    fn test_gsm_rule_sets_prefer_specific_rules_over_connector_defaults() {
+        let refund_rule_set = Arc::new(GsmRuleSet::new(vec![
+            gsm_rule(
+                common_enums::GsmMatchType::ConnectorDefault,
+                "refund_default",
+                "*",
+            ),
+            gsm_rule(
+                common_enums::GsmMatchType::Exact,
+                "refund_failed",
+                "Refund failed",
+            ),
+        ]));
+        let authorize_rule_set = Arc::new(GsmRuleSet::new(vec![
+            gsm_rule(
+                common_enums::GsmMatchType::ConnectorDefault,
+                "authorize_default",
+                "*",
+            ),
+            gsm_rule(common_enums::GsmMatchType::Code, "card_declined", "*"),
+        ]));
+        let rule_sets = [refund_rule_set, authorize_rule_set];
+
+        let matched = |code: &str, message: &str| {
+            find_in_rule_sets(&rule_sets, code, message).map(|rule| rule.code.clone())
+        };
+        assert_eq!(
+            matched("refund_failed", "Refund failed"),
+            Some("refund_failed".to_string())
+        );
+        assert_eq!(
+            matched("card_declined", "Card declined"),
+            Some("card_declined".to_string())
+        );
+        assert_eq!(
+            matched("processing_error", "Try again later"),
+            Some("refund_default".to_string())
+        );
+    }
+
+    #[test]
+    fn test_gsm_rule_set_prefers_longer_patterns() {
+        let rule_set = GsmRuleSet::new(vec![
+            gsm_rule(common_enums::GsmMatchType::MessageContains, "*", "declined"),
+            gsm_rule(
+                common_enums::GsmMatchType::MessageContains,
+                "*",
+                "declined by issuer",
+            ),
+        ]);
+
+        assert_eq!(
+            matched_rule(&rule_set, "", "Card declined by issuer"),
+            Some(("*".to_string(), "declined by issuer".to_string()))
+        );
+        assert_eq!(
+            matched_rule(&rule_set, "", "Card declined"),
+            Some(("*".to_string(), "declined".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_gsm_rule_set_skips_invalid_regex() {
+        let rule_set = GsmRuleSet::new(vec![gsm_rule(
+            common_enums::GsmMatchType::MessageRegex,
+            "*",
+            "card (declined",
+        )]);
+
+        assert_eq!(matched_rule(&rule_set, "", "card (declined"), None);
+    }
+
+    fn gsm_create_request(
+        match_type: common_enums::GsmMatchType,
+        code: &str,
+        message: &str,
+    ) -> gsm_api_types::GsmCreateRequest {
+        gsm_api_types::GsmCreateRequest {
+            connector: api_enums::Connector::Stripe,
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+            match_type: Some(match_type),
+            status: "Failure".to_string(),
+            router_error: None,
+            decision: common_enums::GsmDecision::DoDefault,
+            step_up_possible: false,
+            unified_code: None,
+            unified_message: None,
+            error_category: None,
+            clear_pan_possible: false,
+            feature: None,
+            feature_data: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_gsm_rule_wildcards() {
+        let normalized_key = |match_type, code: &str, message: &str| {
+            normalize_gsm_rule(gsm_create_request(match_type, code, message))
+                .map(|rule| (rule.code, rule.message))
+        };
+        let key =
+            |code: &str, message: &str| Ok::<_, String>((code.to_string(), message.to_string()));
+
+        assert_eq!(
+            normalized_key(
+                common_enums::GsmMatchType::Code,
+                "card_declined",
+                "Declined"
+            ),
+            key("card_declined", "*")
+        );
+        assert_eq!(
+            normalized_key(common_enums::GsmMatchType::MessageRegex, "51", "^declined$"),
+            key("*", "^declined$")
+        );
+        assert_eq!(
+            normalized_key(
+                common_enums::GsmMatchType::ConnectorDefault,
+                "51",
+                "Declined"
+            ),
+            key("*", "*")
+        );
+
+        assert!(normalized_key(common_enums::GsmMatchType::Code, "*", "").is_err());
+        assert!(normalized_key(common_enums::GsmMatchType::Exact, "51", "*").is_err());
+        assert!(normalized_key(common_enums::GsmMatchType::MessageContains, "", "*").is_err());
+        assert!(normalized_key(common_enums::GsmMatchType::MessageRegex, "", "(").is_err());
+    }
+
+    #[test]
+    fn test_get_row_errors_message() {
+        let row_errors = (1..=GSM_IMPORT_MAX_REPORTED_ERRORS + 2)
+            .map(|row_number| format!("row {row_number}: invalid"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            get_row_errors_message(row_errors.get(..2).unwrap_or_default()),
+            "row 1: invalid; row 2: invalid"
+        );
+
+        let message = get_row_errors_message(&row_errors);
+        assert!(message.starts_with("row 1: invalid; row 2: invalid;"));
+        assert!(message.contains(&format!("row {GSM_IMPORT_MAX_REPORTED_ERRORS}: invalid")));
+        assert!(!message.contains(&format!(
+            "row {}: invalid",
+            GSM_IMPORT_MAX_REPORTED_ERRORS + 1
+        )));
+        assert!(message.ends_with("; and 2 more invalid rows"));
+    }
+
+    #[test]
+    fn test_parse_gsm_csv() {
+        let header = "connector,flow,sub_flow,code,message,match_type,status,router_error,\
+                      decision,step_up_possible,clear_pan_possible,alternate_network_possible,\
+                      retry_later_initial_delay_in_seconds,retry_later_max_retries,unified_code,\
+                      unified_message,error_category";
+        let data = format!(
+            "{header}\n\
+             stripe,Authorize,sub_flow,card_declined,\"Declined, do not honor\",exact,Failure,,\
+             retry_later,false,false,true,60,3,UE_1000,Declined,\n\
+             adyen,Authorize,sub_flow,*,Insufficient,message_contains,Failure,,do_default,\
+             false,false,false,,,,,\n"
+        );
+
+        let records = parse_gsm_csv(data.as_bytes()).unwrap_or_default();
+        assert_eq!(records.len(), 2);
+
+        let record = records.first();
+        assert_eq!(
+            record.map(|record| record.message.as_str()),
+            Some("Declined, do not honor")
+        );
+        assert_eq!(
+            record.map(|record| (
+                record.retry_later_initial_delay_in_seconds,
+                record.retry_later_max_retries
+            )),
+            Some((Some(60), Some(3)))
+        );
+        assert_eq!(
+            record.and_then(|record| record.unified_code.as_deref()),
+            Some("UE_1000")
+        );
+
+        let record = records.get(1);
+        assert_eq!(
+            record.and_then(|record| record.match_type),
+            Some(common_enums::GsmMatchType::MessageContains)
+        );
+        assert_eq!(
+            record.map(|record| (record.unified_code.clone(), record.error_category)),
+            Some((None, None))
+        );
+
+        let data = format!("{header}\nstripe,Authorize,sub_flow,51,Declined,exact,Failure\n");
+        assert!(parse_gsm_csv(data.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,352 @@
+//! Errors of failed payment attempts that aren't matched by any GSM rule
+
+use std::collections::{HashMap, HashSet};
+
+use analytics::connector_errors::ConnectorErrorRow;
+use api_models::gsm as gsm_api_types;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::gsm::GatewayStatusMap;
+use router_env::{instrument, tracing};
+
+use super::GsmRuleSet;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    db::gsm::GsmInterface,
+    services, SessionState,
+};
+
+/// The sub flow of the rules looked up when payments fail
+const GSM_SUB_FLOW: &str = "sub_flow";
+const GSM_UNMAPPED_ERRORS_DEFAULT_LIMIT: u32 = 100;
+/// The maximum number of errors fetched per connector while looking for unmapped errors
+const GSM_UNMAPPED_ERRORS_MAX_FETCH_LIMIT: u64 = 10_000;
+
+/// Reports the most frequent errors of failed payment attempts that aren't matched by any GSM
+/// rule of the authorize flow, each with the unified code of the rule with the most similar
+/// message, so that the gaps in the GSM coverage can be closed.
+///
+/// Only the errors of payment attempts are looked at, which are matched against the rules of the
+/// authorize flow as done when payments fail. The errors of refunds and payouts aren't stored in
+/// the analytics of payment attempts, so the rules of their flows aren't covered.
+#[instrument(skip_all)]
+pub async fn retrieve_unmapped_gsm_errors(
+    state: SessionState,
+    request: gsm_api_types::GsmUnmappedErrorsRequest,
+) -> RouterResponse<gsm_api_types::GsmUnmappedErrorsResponse> {
+    let limit = request.limit.unwrap_or(GSM_UNMAPPED_ERRORS_DEFAULT_LIMIT);
+
+    let rules = state
+        .store
+        .find_all_gsm_rules()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the GSM rules")?
+        .into_iter()
+        .filter(|rule| rule.flow == consts::AUTHORIZE_FLOW_STR && rule.sub_flow == GSM_SUB_FLOW)
+        .collect::<Vec<_>>();
+    let mut rules_by_connector = HashMap::<_, Vec<_>>::new();
+    for rule in &rules {
+        rules_by_connector
+            .entry(rule.connector.as_str())
+            .or_default()
+            .push(rule.clone());
+    }
+    let rule_sets = rules_by_connector
+        .into_iter()
+        .map(|(connector, rules)| (connector, GsmRuleSet::new(rules)))
+        .collect::<HashMap<_, _>>();
+    let is_mapped = |row: &ConnectorErrorRow| {
+        row.connector
+            .as_deref()
+            .and_then(|connector| rule_sets.get(connector))
+            .zip(row.error_code.as_deref())
+            .is_some_and(|(rule_set, code)| {
+                rule_set
+                    .find(code, row.error_message.as_deref().unwrap_or_default())
+                    .is_some()
+            })
+    };
+
+    // The most frequent errors of each connector are fetched before the mapped ones are dropped,
+    // so more errors are fetched until each connector has `limit` unmapped errors or no more
+    // errors
+    let mut fetch_limit = u64::from(limit).max(1);
+    let connector_errors = loop {
+        let connector_errors = analytics::connector_errors::connector_errors_core(
+            &state.pool,
+            &request.time_range,
+            fetch_limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the errors of failed payment attempts")?;
+
+        if fetch_limit >= GSM_UNMAPPED_ERRORS_MAX_FETCH_LIMIT
+            || has_enough_unmapped_errors(
+                &connector_errors,
+                fetch_limit,
+                u64::from(limit),
+                is_mapped,
+            )
+        {
+            break connector_errors;
+        }
+        fetch_limit = fetch_limit
+            .saturating_mul(4)
+            .min(GSM_UNMAPPED_ERRORS_MAX_FETCH_LIMIT);
+    };
+
+    let mut data = connector_errors
+        .into_iter()
+        .filter(|row| !is_mapped(row))
+        .filter_map(|row| {
+            let connector = row.connector?;
+            let code = row.error_code?;
+
+            let suggested_rule = row
+                .error_message
+                .as_deref()
+                .and_then(|message| find_most_similar_gsm_rule(message, &rules));
+            Some(gsm_api_types::GsmUnmappedError {
+                connector,
+                flow: consts::AUTHORIZE_FLOW_STR.to_string(),
+                code,
+                message: row.error_message,
+                occurrences: row
+                    .count
+                    .and_then(|count| u64::try_from(count).ok())
+                    .unwrap_or_default(),
+                suggested_unified_code: suggested_rule.and_then(|rule| rule.unified_code.clone()),
+                suggested_unified_message: suggested_rule
+                    .and_then(|rule| rule.unified_message.clone()),
+            })
+        })
+        .collect::<Vec<_>>();
+    data.sort_by(|a, b| b.occurrences.cmp(&a.occurrences));
+    data.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+    Ok(services::ApplicationResponse::Json(
+        gsm_api_types::GsmUnmappedErrorsResponse { data },
+    ))
+}
+
+/// Checks whether the errors fetched with `fetch_limit` errors per connector contain the `limit`
+/// most frequent unmapped errors of each connector, which is the case when a connector has
+/// `limit` unmapped errors or fewer than `fetch_limit` errors in total
+fn has_enough_unmapped_errors(
+    connector_errors: &[ConnectorErrorRow],
+    fetch_limit: u64,
+    limit: u64,
+    is_mapped: impl Fn(&ConnectorErrorRow) -> bool,
+) -> bool {
+    let mut counts_by_connector = HashMap::<_, (u64, u64)>::new();
+    for row in connector_errors {
+        let (fetched, unmapped) = counts_by_connector
+            .entry(row.connector.as_deref())
+            .or_default();
+        *fetched += 1;
+        if !is_mapped(row) {
+            *unmapped += 1;
+        }
+    }
+    counts_by_connector
+        .into_values()
+        .all(|(fetched, unmapped)| fetched < fetch_limit || unmapped >= limit)
+}
+
+fn get_message_words(message: &str) -> HashSet<String> {
+    message
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Finds the rule with a unified code whose message shares the largest fraction of its words with
+/// the message, ignoring rules whose message shares less than half of the words of both messages
+fn find_most_similar_gsm_rule<'a>(
+    message: &str,
+    rules: &'a [GatewayStatusMap],
+) -> Option<&'a GatewayStatusMap> {
+    let message_words = get_message_words(message);
+    // The similarity of each rule is `common_words / all_words`, kept as a fraction so that
+    // similarities are compared exactly
+    let mut most_similar: Option<(&GatewayStatusMap, usize, usize)> = None;
+    for rule in rules {
+        if rule.unified_code.is_none()
+            || rule.match_type == common_enums::GsmMatchType::MessageRegex
+        {
+            continue;
+        }
+        let rule_words = get_message_words(&rule.message);
+        let common_words = message_words.intersection(&rule_words).count();
+        let all_words = message_words.union(&rule_words).count();
+        if common_words * 2 < all_words || common_words == 0 {
+            continue;
+        }
+        let is_more_similar = most_similar.map_or(true, |(_, most_common, most_all)| {
+            common_words * most_all > most_common * all_words
+        });
+        if is_more_similar {
+            most_similar = Some((rule, common_words, all_words));
+        }
+    }
+    most_similar.map(|(rule, _, _)| rule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gsm_rule(
+        match_type: common_enums::GsmMatchType,
+        message: &str,
+        unified_code: Option<&str>,
+    ) -> GatewayStatusMap {
+        GatewayStatusMap {
+            connector: "stripe".to_string(),
+            flow: consts::AUTHORIZE_FLOW_STR.to_string(),
+            sub_flow: GSM_SUB_FLOW.to_string(),
+            code: "*".to_string(),
+            message: message.to_string(),
+            status: "Failure".to_string(),
+            router_error: None,
+            unified_code: unified_code.map(str::to_string),
+            unified_message: None,
+            error_category: None,
+            feature_data: common_types::domain::GsmFeatureData::Retry(
+                common_types::domain::RetryFeatureData {
+                    step_up_possible: false,
+                    clear_pan_possible: false,
+                    alternate_network_possible: false,
+                    decision: common_enums::GsmDecision::DoDefault,
+                    retry_later_config: None,
+                },
+            ),
+            feature: common_enums::GsmFeature::Retry,
+            match_type,
+        }
+    }
+
+    fn most_similar_message<'a>(message: &str, rules: &'a [GatewayStatusMap]) -> Option<&'a str> {
+        find_most_similar_gsm_rule(message, rules).map(|rule| rule.message.as_str())
+    }
+
+    #[test]
+    fn test_find_most_similar_gsm_rule() {
+        let rules = vec![
+            gsm_rule(
+                common_enums::GsmMatchType::MessageContains,
+                "Insufficient funds",
+                Some("UE_1000"),
+            ),
+            gsm_rule(
+                common_enums::GsmMatchType::Exact,
+                "Card declined by issuer",
+                Some("UE_2000"),
+            ),
+            gsm_rule(
+                common_enums::GsmMatchType::Exact,
+                "Card declined",
+                Some("UE_3000"),
+            ),
+        ];
+
+        // Words are compared case insensitively, ignoring punctuation
+        assert_eq!(
+            most_similar_message("INSUFFICIENT FUNDS!", &rules),
+            Some("Insufficient funds")
+        );
+        // The rule sharing the largest fraction of words is preferred
+        assert_eq!(
+            most_similar_message("card declined by the issuer", &rules),
+            Some("Card declined by issuer")
+        );
+        assert_eq!(
+            most_similar_message("card was declined", &rules),
+            Some("Card declined")
+        );
+        // Rules sharing less than half of the words aren't suggested
+        assert_eq!(
+            most_similar_message("insufficient balance on the account", &rules),
+            None
+        );
+        assert_eq!(most_similar_message("", &rules), None);
+    }
+
+    fn connector_error(connector: &str, code: &str) -> ConnectorErrorRow {
+        ConnectorErrorRow {
+            connector: Some(connector.to_string()),
+            error_code: Some(code.to_string()),
+            error_message: None,
+            count: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_has_enough_unmapped_errors() {
+        let is_mapped = |row: &ConnectorErrorRow| row.error_code.as_deref() == Some("mapped");
+        let connector_errors = vec![
+            connector_error("stripe", "mapped"),
+            connector_error("stripe", "unmapped_1"),
+            connector_error("adyen", "mapped"),
+        ];
+
+        // A connector with fewer errors than fetched has no more errors
+        assert!(has_enough_unmapped_errors(
+            &connector_errors,
+            3,
+            2,
+            is_mapped
+        ));
+        // stripe may have more unmapped errors than the ones fetched
+        assert!(!has_enough_unmapped_errors(
+            &connector_errors,
+            2,
+            2,
+            is_mapped
+        ));
+        assert!(has_enough_unmapped_errors(
+            &connector_errors,
+            2,
+            1,
+            is_mapped
+        ));
+        // adyen may have unmapped errors after its mapped one
+        assert!(!has_enough_unmapped_errors(
+            &connector_errors,
+            1,
+            1,
+            is_mapped
+        ));
+    }
+
+    #[test]
+    fn test_find_most_similar_gsm_rule_skips_unusable_rules() {
+        let rules = vec![
+            gsm_rule(common_enums::GsmMatchType::Exact, "Do not honor", None),
+            gsm_rule(
+                common_enums::GsmMatchType::MessageRegex,
+                "do not honor",
+                Some("UE_1000"),
+            ),
+        ];
+
+        assert_eq!(most_similar_message("Do not honor", &rules), None);
+
+        let rules = [
+            rules,
+            vec![gsm_rule(
+                common_enums::GsmMatchType::MessageContains,
+                "Do not honour",
+                Some("UE_2000"),
+            )],
+        ]
+        .concat();
+        assert_eq!(
+            most_similar_message("Do not honor", &rules),
+            Some("Do not honour")
+        );
+    }
+}
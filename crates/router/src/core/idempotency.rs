@@ -0,0 +1,326 @@
+use std::{fmt::Debug, future::Future};
+
+use actix_web::http::header::HeaderMap;
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use redis_interface::SetnxReply;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::errors::{self, RouterResponse, RouterResult};
+use crate::{
+    consts, headers,
+    routes::{lock_utils, SessionState},
+    services::ApplicationResponse,
+};
+
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+
+/// The idempotency key sent by the client along with a fingerprint of the request body it was
+/// sent with
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    pub idempotency_key: String,
+    pub api_identifier: lock_utils::ApiIdentifier,
+    pub request_fingerprint: String,
+}
+
+impl IdempotencyInput {
+    /// Reads the `Idempotency-Key` header of the request, returns `None` if the header is absent.
+    ///
+    /// The fingerprint must be computed from the payload as sent by the client, before any
+    /// server side defaults (such as generated ids) are populated on it.
+    pub fn from_request<F, T>(
+        request_headers: &HeaderMap,
+        flow: F,
+        payload: &T,
+    ) -> RouterResult<Option<Self>>
+    where
+        F: router_env::types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+        T: Serialize,
+    {
+        request_headers
+            .get(headers::IDEMPOTENCY_KEY)
+            .map(|header_value| {
+                let idempotency_key = header_value
+                    .to_str()
+                    .ok()
+                    .map(str::trim)
+                    .filter(|key| {
+                        !key.is_empty() && key.len() <= consts::IDEMPOTENCY_KEY_MAX_LENGTH
+                    })
+                    .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "`{}` header must be a non empty string of at most {} characters",
+                            headers::IDEMPOTENCY_KEY,
+                            consts::IDEMPOTENCY_KEY_MAX_LENGTH
+                        ),
+                    })?
+                    .to_owned();
+
+                let serialized_payload = serde_json::to_vec(payload)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize request for idempotency fingerprint")?;
+
+                Ok(Self {
+                    idempotency_key,
+                    api_identifier: lock_utils::ApiIdentifier::from(flow),
+                    request_fingerprint: hex::encode(openssl::sha::sha256(&serialized_payload)),
+                })
+            })
+            .transpose()
+    }
+
+    fn get_redis_key(&self, merchant_id: &id_type::MerchantId) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            IDEMPOTENCY_KEY_PREFIX,
+            merchant_id.get_string_repr(),
+            self.api_identifier,
+            self.idempotency_key
+        )
+    }
+}
+
+/// The record stored in redis against an idempotency key.
+/// Both `response` and `non_json_response` are `None` while the first request is still being
+/// processed.
+#[derive(Debug, Serialize, Deserialize)]
+struct IdempotencyRecord {
+    request_fingerprint: String,
+    response: Option<serde_json::Value>,
+    #[serde(default)]
+    non_json_response: Option<StoredNonJsonResponse>,
+}
+
+/// A response other than a JSON body, stored so that retries are not processed again
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+enum StoredNonJsonResponse {
+    StatusOk,
+    TextPlain(String),
+    JsonForRedirection(api_models::payments::RedirectionResponse),
+    FileData {
+        data: Vec<u8>,
+        mime_type: String,
+    },
+    /// HTML responses, which are rendered from state that can't be stored
+    NotReplayable,
+}
+
+impl StoredNonJsonResponse {
+    fn from_response<Q>(response: &ApplicationResponse<Q>) -> Option<Self> {
+        match response {
+            ApplicationResponse::Json(_) | ApplicationResponse::JsonWithHeaders(_) => None,
+            ApplicationResponse::StatusOk => Some(Self::StatusOk),
+            ApplicationResponse::TextPlain(text) => Some(Self::TextPlain(text.clone())),
+            ApplicationResponse::JsonForRedirection(redirection) => {
+                Some(Self::JsonForRedirection(redirection.clone()))
+            }
+            ApplicationResponse::FileData((data, mime_type)) => Some(Self::FileData {
+                data: data.clone(),
+                mime_type: mime_type.to_string(),
+            }),
+            ApplicationResponse::Form(_)
+            | ApplicationResponse::PaymentLinkForm(_)
+            | ApplicationResponse::GenericLinkForm(_) => Some(Self::NotReplayable),
+        }
+    }
+
+    fn into_response<Q>(self) -> RouterResponse<IdempotentResponse<Q>> {
+        match self {
+            Self::StatusOk => Ok(ApplicationResponse::StatusOk),
+            Self::TextPlain(text) => Ok(ApplicationResponse::TextPlain(text)),
+            Self::JsonForRedirection(redirection) => {
+                Ok(ApplicationResponse::JsonForRedirection(redirection))
+            }
+            Self::FileData { data, mime_type } => mime_type
+                .parse()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the mime type of the stored response")
+                .map(|mime_type| ApplicationResponse::FileData((data, mime_type))),
+            Self::NotReplayable => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "the request with the given idempotency key has already been processed"
+                    .to_string(),
+            })),
+        }
+    }
+}
+
+/// Response of an API which honours the `Idempotency-Key` header.
+/// Both variants serialize to the same JSON body that the client received the first time.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum IdempotentResponse<Q> {
+    Processed(Q),
+    Replayed(serde_json::Value),
+}
+
+impl<Q: ApiEventMetric> ApiEventMetric for IdempotentResponse<Q> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        match self {
+            Self::Processed(response) => response.get_api_event_type(),
+            Self::Replayed(_) => None,
+        }
+    }
+}
+
+fn to_idempotent_response<Q>(
+    response: ApplicationResponse<Q>,
+) -> ApplicationResponse<IdempotentResponse<Q>> {
+    match response {
+        ApplicationResponse::Json(body) => {
+            ApplicationResponse::Json(IdempotentResponse::Processed(body))
+        }
+        ApplicationResponse::JsonWithHeaders((body, headers)) => {
+            ApplicationResponse::JsonWithHeaders((IdempotentResponse::Processed(body), headers))
+        }
+        ApplicationResponse::StatusOk => ApplicationResponse::StatusOk,
+        ApplicationResponse::TextPlain(text) => ApplicationResponse::TextPlain(text),
+        ApplicationResponse::JsonForRedirection(redirection) => {
+            ApplicationResponse::JsonForRedirection(redirection)
+        }
+        ApplicationResponse::Form(form) => ApplicationResponse::Form(form),
+        ApplicationResponse::PaymentLinkForm(form) => ApplicationResponse::PaymentLinkForm(form),
+        ApplicationResponse::FileData(file_data) => ApplicationResponse::FileData(file_data),
+        ApplicationResponse::GenericLinkForm(form) => ApplicationResponse::GenericLinkForm(form),
+    }
+}
+
+/// Runs `core_fn` at most once per (merchant, api, idempotency key).
+///
+/// The first response is stored in redis and replayed for subsequent requests carrying the same
+/// key and request body. A request reusing the key with a different body is rejected, and a
+/// request arriving while the first one is still in flight is asked to retry later. The key is
+/// only reserved for a short while during the request, so that a request which never completes
+/// doesn't keep the key busy for the lifetime of the stored response.
+/// Failed requests are not stored, so the client may retry them.
+#[instrument(skip_all)]
+pub async fn with_idempotency<Q, Fut>(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    idempotency_input: Option<IdempotencyInput>,
+    core_fn: Fut,
+) -> RouterResponse<IdempotentResponse<Q>>
+where
+    Fut: Future<Output = RouterResponse<Q>>,
+    Q: Serialize + Debug,
+{
+    let Some(idempotency_input) = idempotency_input else {
+        return core_fn.await.map(to_idempotent_response);
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let redis_key = idempotency_input.get_redis_key(&merchant_id);
+
+    let setnx_reply = redis_conn
+        .serialize_and_set_key_if_not_exist(
+            &redis_key.as_str().into(),
+            IdempotencyRecord {
+                request_fingerprint: idempotency_input.request_fingerprint.clone(),
+                response: None,
+                non_json_response: None,
+            },
+            Some(consts::IDEMPOTENCY_KEY_LOCK_TTL),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reserve idempotency key in redis")?;
+
+    match setnx_reply {
+        SetnxReply::KeyNotSet => {
+            let record = redis_conn
+                .get_and_deserialize_key::<IdempotencyRecord>(
+                    &redis_key.as_str().into(),
+                    "IdempotencyRecord",
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch idempotency record from redis")?;
+
+            if record.request_fingerprint != idempotency_input.request_fingerprint {
+                return Err(report!(errors::ApiErrorResponse::IdempotencyKeyConflict {
+                    idempotency_key: idempotency_input.idempotency_key,
+                }));
+            }
+
+            if let Some(non_json_response) = record.non_json_response {
+                return non_json_response.into_response();
+            }
+
+            let response = record.response.ok_or_else(|| {
+                logger::info!(
+                    "Request with idempotency key {} is still being processed",
+                    idempotency_input.idempotency_key
+                );
+                report!(errors::ApiErrorResponse::ResourceBusy)
+            })?;
+
+            Ok(ApplicationResponse::JsonWithHeaders((
+                IdempotentResponse::Replayed(response),
+                vec![(
+                    headers::IDEMPOTENT_REPLAYED.to_string(),
+                    "true".to_string().into(),
+                )],
+            )))
+        }
+        SetnxReply::KeySet => {
+            let result = core_fn.await;
+
+            let record_to_store = match &result {
+                Ok(ApplicationResponse::Json(body))
+                | Ok(ApplicationResponse::JsonWithHeaders((body, _))) => serde_json::to_value(body)
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to serialize idempotent response");
+                    })
+                    .ok()
+                    .map(|response| IdempotencyRecord {
+                        request_fingerprint: idempotency_input.request_fingerprint,
+                        response: Some(response),
+                        non_json_response: None,
+                    }),
+                Ok(response) => Some(IdempotencyRecord {
+                    request_fingerprint: idempotency_input.request_fingerprint,
+                    response: None,
+                    non_json_response: StoredNonJsonResponse::from_response(response),
+                }),
+                Err(_) => None,
+            };
+
+            match record_to_store {
+                Some(record) => {
+                    redis_conn
+                        .serialize_and_set_key_with_expiry(
+                            &redis_key.as_str().into(),
+                            record,
+                            consts::IDEMPOTENCY_KEY_TTL,
+                        )
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to store idempotent response in redis")
+                        })
+                        .ok();
+                }
+                None => {
+                    redis_conn
+                        .delete_key(&redis_key.as_str().into())
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to release idempotency key in redis")
+                        })
+                        .ok();
+                }
+            }
+
+            result.map(to_idempotent_response)
+        }
+    }
+}
@@ -87,5 +87,9 @@ counter_metric!(DYNAMIC_CONTRACT_BASED_ROUTING, GLOBAL_METER);
 #[cfg(feature = "partial-auth")]
 counter_metric!(PARTIAL_AUTH_FAILURE, GLOBAL_METER);
 
+counter_metric!(CONNECTOR_CIRCUIT_BREAKER_STATE_CHANGE, GLOBAL_METER);
+counter_metric!(CONNECTOR_CIRCUIT_BREAKER_REJECTED_REQUEST, GLOBAL_METER);
+counter_metric!(CONNECTOR_CIRCUIT_BREAKER_SKIPPED_IN_ROUTING, GLOBAL_METER);
+
 counter_metric!(API_KEY_REQUEST_INITIATED, GLOBAL_METER);
 counter_metric!(API_KEY_REQUEST_COMPLETED, GLOBAL_METER);
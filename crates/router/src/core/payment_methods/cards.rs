@@ -2564,6 +2564,61 @@ fn get_val(str: String, val: &serde_json::Value) -> Option<String> {
         .map(|s| s.to_string())
 }
 
+/// Fetches the payment method filter graph of a profile from the cache, building it out of the
+/// given merchant connector accounts and caching it when absent.
+#[cfg(feature = "v1")]
+pub async fn get_or_build_pm_filter_graph(
+    state: &routes::SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    merchant_connector_accounts: &[domain::MerchantConnectorAccount],
+) -> errors::RouterResult<std::sync::Arc<cgraph::ConstraintGraph<dir::DirValue>>> {
+    // Key creation for storing PM_FILTER_CGRAPH
+    let key = format!(
+        "pm_filters_cgraph_{}_{}",
+        merchant_id.get_string_repr(),
+        profile_id.get_string_repr()
+    );
+
+    if let Some(graph) = get_merchant_pm_filter_graph(state, &key).await {
+        // Derivation of PM_FILTER_CGRAPH from MokaCache successful
+        return Ok(graph);
+    }
+
+    // No PM_FILTER_CGRAPH Cache present in MokaCache
+    let mut builder = cgraph::ConstraintGraphBuilder::new();
+    for mca in merchant_connector_accounts {
+        let domain_id = builder.make_domain(
+            mca.get_id().get_string_repr().to_string(),
+            mca.connector_name.as_str(),
+        );
+
+        let Ok(domain_id) = domain_id else {
+            logger::error!("Failed to construct domain for list payment methods");
+            return Err(errors::ApiErrorResponse::InternalServerError.into());
+        };
+
+        let payment_methods = match &mca.payment_methods_enabled {
+            Some(pm) => pm,
+            None => continue,
+        };
+        if let Err(e) = make_pm_graph(
+            &mut builder,
+            domain_id,
+            payment_methods,
+            mca.connector_name.clone(),
+            &state.conf.pm_filters,
+            &state.conf.mandates.supported_payment_methods,
+            &state.conf.mandates.update_mandate_supported,
+        ) {
+            logger::error!("Failed to construct constraint graph for list payment methods {e:?}");
+        }
+    }
+
+    // Refreshing our CGraph cache
+    Ok(refresh_pm_filters_cache(state, &key, builder.build()).await)
+}
+
 #[cfg(feature = "v1")]
 pub async fn list_payment_methods(
     state: routes::SessionState,
@@ -2571,7 +2626,6 @@ pub async fn list_payment_methods(
     mut req: api::PaymentMethodListRequest,
 ) -> errors::RouterResponse<api::PaymentMethodListResponse> {
     let db = &*state.store;
-    let pm_config_mapping = &state.conf.pm_filters;
     let key_manager_state = &(&state).into();
     let payment_intent = if let Some(cs) = &req.client_secret {
         if cs.starts_with("pm_") {
@@ -2715,94 +2769,32 @@ pub async fn list_payment_methods(
     logger::debug!(mca_before_filtering=?filtered_mcas);
 
     let mut response: Vec<ResponsePaymentMethodIntermediate> = vec![];
-    // Key creation for storing PM_FILTER_CGRAPH
-    let key = {
-        format!(
-            "pm_filters_cgraph_{}_{}",
-            merchant_context
-                .get_merchant_account()
-                .get_id()
-                .get_string_repr(),
-            profile_id.get_string_repr()
-        )
-    };
-
-    if let Some(graph) = get_merchant_pm_filter_graph(&state, &key).await {
-        // Derivation of PM_FILTER_CGRAPH from MokaCache successful
-        for mca in &filtered_mcas {
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            filter_payment_methods(
-                &graph,
-                mca.get_id(),
-                payment_methods,
-                &mut req,
-                &mut response,
-                payment_intent.as_ref(),
-                payment_attempt.as_ref(),
-                billing_address.as_ref(),
-                mca.connector_name.clone(),
-                &state.conf,
-            )
-            .await?;
-        }
-    } else {
-        // No PM_FILTER_CGRAPH Cache present in MokaCache
-        let mut builder = cgraph::ConstraintGraphBuilder::new();
-        for mca in &filtered_mcas {
-            let domain_id = builder.make_domain(
-                mca.get_id().get_string_repr().to_string(),
-                mca.connector_name.as_str(),
-            );
-
-            let Ok(domain_id) = domain_id else {
-                logger::error!("Failed to construct domain for list payment methods");
-                return Err(errors::ApiErrorResponse::InternalServerError.into());
-            };
-
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            if let Err(e) = make_pm_graph(
-                &mut builder,
-                domain_id,
-                payment_methods,
-                mca.connector_name.clone(),
-                pm_config_mapping,
-                &state.conf.mandates.supported_payment_methods,
-                &state.conf.mandates.update_mandate_supported,
-            ) {
-                logger::error!(
-                    "Failed to construct constraint graph for list payment methods {e:?}"
-                );
-            }
-        }
-
-        // Refreshing our CGraph cache
-        let graph = refresh_pm_filters_cache(&state, &key, builder.build()).await;
+    let graph = get_or_build_pm_filter_graph(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id,
+        &filtered_mcas,
+    )
+    .await?;
 
-        for mca in &filtered_mcas {
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            filter_payment_methods(
-                &graph,
-                mca.get_id().clone(),
-                payment_methods,
-                &mut req,
-                &mut response,
-                payment_intent.as_ref(),
-                payment_attempt.as_ref(),
-                billing_address.as_ref(),
-                mca.connector_name.clone(),
-                &state.conf,
-            )
-            .await?;
-        }
+    for mca in &filtered_mcas {
+        let payment_methods = match &mca.payment_methods_enabled {
+            Some(pm) => pm,
+            None => continue,
+        };
+        filter_payment_methods(
+            &graph,
+            mca.get_id(),
+            payment_methods,
+            &mut req,
+            &mut response,
+            payment_intent.as_ref(),
+            payment_attempt.as_ref(),
+            billing_address.as_ref(),
+            mca.connector_name.clone(),
+            &state.conf,
+        )
+        .await?;
     }
     logger::info!(
         "The Payment Methods available after Constraint Graph filtering are {:?}",
@@ -3639,6 +3631,7 @@ pub async fn call_surcharge_decision_management(
     let (surcharge_results, merchant_sucharge_configs) =
         perform_surcharge_decision_management_for_payment_method_list(
             &state,
+            business_profile,
             algorithm_ref,
             payment_attempt,
             &payment_intent,
@@ -3699,6 +3692,7 @@ pub async fn call_surcharge_decision_management_for_saved_card(
     // TODO: Move to business profile surcharge column
     let surcharge_results = perform_surcharge_decision_management_for_saved_cards(
         state,
+        business_profile,
         algorithm_ref,
         payment_attempt,
         &payment_intent,
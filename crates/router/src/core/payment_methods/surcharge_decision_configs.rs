@@ -23,12 +23,16 @@ use crate::{
     core::{
         errors::{self, ConditionalConfigError as ConfigError},
         payments::{
-            conditional_configs::ConditionalConfigResult, routing::make_dsl_input_for_surcharge,
+            conditional_configs::ConditionalConfigResult,
+            routing::{
+                make_dsl_amount_in_base_currency, make_dsl_input_for_surcharge, make_dsl_time_input,
+            },
             types,
         },
     },
     db::StorageInterface,
     types::{
+        domain,
         storage::{self, payment_attempt::PaymentAttemptExt},
         transformers::ForeignTryFrom,
     },
@@ -101,6 +105,7 @@ impl SurchargeSource {
 #[cfg(feature = "v2")]
 pub async fn perform_surcharge_decision_management_for_payment_method_list(
     _state: &SessionState,
+    _business_profile: &domain::Profile,
     _algorithm_ref: routing::RoutingAlgorithmRef,
     _payment_attempt: &storage::PaymentAttempt,
     _payment_intent: &storage::PaymentIntent,
@@ -116,6 +121,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
 #[cfg(feature = "v1")]
 pub async fn perform_surcharge_decision_management_for_payment_method_list(
     state: &SessionState,
+    business_profile: &domain::Profile,
     algorithm_ref: routing::RoutingAlgorithmRef,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
@@ -165,6 +171,9 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
     let mut backend_input =
         make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
             .change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment.amount_in_base_currency =
+        make_dsl_amount_in_base_currency(state, business_profile, &backend_input.payment).await;
+    backend_input.time = make_dsl_time_input(business_profile);
 
     for payment_methods_enabled in response_payment_method_types.iter_mut() {
         for payment_method_type_response in
@@ -236,6 +245,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
 #[cfg(feature = "v1")]
 pub async fn perform_surcharge_decision_management_for_session_flow(
     state: &SessionState,
+    business_profile: &domain::Profile,
     algorithm_ref: routing::RoutingAlgorithmRef,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
@@ -265,6 +275,9 @@ pub async fn perform_surcharge_decision_management_for_session_flow(
     let mut backend_input =
         make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
             .change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment.amount_in_base_currency =
+        make_dsl_amount_in_base_currency(state, business_profile, &backend_input.payment).await;
+    backend_input.time = make_dsl_time_input(business_profile);
     for payment_method_type in payment_method_type_list {
         backend_input.payment_method.payment_method_type = Some(*payment_method_type);
         // in case of session flow, payment_method will always be wallet
@@ -288,6 +301,7 @@ pub async fn perform_surcharge_decision_management_for_session_flow(
 #[cfg(feature = "v1")]
 pub async fn perform_surcharge_decision_management_for_saved_cards(
     state: &SessionState,
+    business_profile: &domain::Profile,
     algorithm_ref: routing::RoutingAlgorithmRef,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
@@ -320,6 +334,9 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     logger::debug!(customer_saved_card_list_surcharge_source = surcharge_source_log_message);
     let mut backend_input = make_dsl_input_for_surcharge(payment_attempt, payment_intent, None)
         .change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment.amount_in_base_currency =
+        make_dsl_amount_in_base_currency(state, business_profile, &backend_input.payment).await;
+    backend_input.time = make_dsl_time_input(business_profile);
 
     for customer_payment_method in customer_payment_method_list.iter_mut() {
         let payment_token = customer_payment_method.payment_token.clone();
@@ -137,7 +137,7 @@ use crate::{
 };
 #[cfg(feature = "v1")]
 use crate::{
-    core::authentication as authentication_core,
+    core::{authentication as authentication_core, circuit_breaker},
     types::{api::authentication, BrowserInformation},
 };
 
@@ -1791,7 +1791,7 @@ where
 pub async fn call_decision_manager<F, D>(
     state: &SessionState,
     merchant_context: &domain::MerchantContext,
-    _business_profile: &domain::Profile,
+    business_profile: &domain::Profile,
     payment_data: &D,
 ) -> RouterResult<Option<enums::AuthenticationType>>
 where
@@ -1823,6 +1823,7 @@ where
         state,
         algorithm_ref,
         merchant_context.get_merchant_account().get_id(),
+        business_profile,
         &payment_dsl_data,
     )
     .await
@@ -2018,7 +2019,7 @@ pub async fn call_surcharge_decision_management_for_session_flow(
 pub async fn call_surcharge_decision_management_for_session_flow(
     state: &SessionState,
     merchant_context: &domain::MerchantContext,
-    _business_profile: &domain::Profile,
+    business_profile: &domain::Profile,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
     billing_address: Option<hyperswitch_domain_models::address::Address>,
@@ -2061,6 +2062,7 @@ pub async fn call_surcharge_decision_management_for_session_flow(
         let surcharge_results =
             surcharge_decision_configs::perform_surcharge_decision_management_for_session_flow(
                 state,
+                business_profile,
                 algorithm_ref,
                 payment_attempt,
                 payment_intent,
@@ -3899,6 +3901,20 @@ where
     dyn api::Connector:
         services::api::ConnectorIntegration<F, RouterDReq, router_types::PaymentsResponseData>,
 {
+    // The circuit breaker only tracks calls made to the connector by us
+    let circuit_breaker_mca_id = matches!(call_connector_action, CallConnectorAction::Trigger)
+        .then(|| connector.merchant_connector_id.clone())
+        .flatten();
+
+    if let Some(merchant_connector_id) = circuit_breaker_mca_id.as_ref() {
+        circuit_breaker::check_circuit(
+            state,
+            &connector.connector_name.to_string(),
+            merchant_connector_id,
+        )
+        .await?;
+    }
+
     let add_access_token_result = router_data
         .add_access_token(
             state,
@@ -4050,6 +4066,22 @@ where
         Ok(router_data)
     }?;
 
+    if let Some(merchant_connector_id) = circuit_breaker_mca_id.as_ref() {
+        if let Some(outcome) = circuit_breaker::ConnectorCallOutcome::from_response(
+            router_data.connector_http_status_code,
+            router_data.external_latency,
+            &state.conf.circuit_breaker,
+        ) {
+            circuit_breaker::record_connector_call_outcome(
+                state,
+                &connector.connector_name.to_string(),
+                merchant_connector_id,
+                outcome,
+            )
+            .await;
+        }
+    }
+
     Ok((router_data, merchant_connector_account))
 }
 
@@ -9708,7 +9740,7 @@ where
     F: Send + Clone,
     D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
 {
-    let routing_algorithm_id = {
+    let (routing_algorithm_id, shadow_algorithm_id) = {
         let routing_algorithm = business_profile.routing_algorithm.clone();
 
         let algorithm_ref = routing_algorithm
@@ -9717,7 +9749,10 @@ where
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Could not decode merchant routing algorithm ref")?
             .unwrap_or_default();
-        algorithm_ref.algorithm_id
+        (
+            algorithm_ref.algorithm_id,
+            algorithm_ref.shadow_algorithm_id,
+        )
     };
 
     let (connectors, routing_approach) = routing::perform_static_routing_v1(
@@ -9739,14 +9774,26 @@ where
         &state.clone(),
         merchant_context.get_merchant_key_store(),
         connectors,
-        &TransactionData::Payment(transaction_data),
-        eligible_connectors,
+        &TransactionData::Payment(transaction_data.clone()),
+        eligible_connectors.clone(),
         business_profile,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    if let Some(shadow_algorithm_id) = shadow_algorithm_id.as_ref() {
+        routing::perform_shadow_routing_v1(
+            state,
+            merchant_context.get_merchant_key_store(),
+            shadow_algorithm_id,
+            business_profile,
+            &transaction_data,
+            eligible_connectors,
+            &connectors,
+        );
+    }
+
     // dynamic success based connector selection
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
     let connectors = if let Some(algo) = business_profile.dynamic_routing_algorithm.clone() {
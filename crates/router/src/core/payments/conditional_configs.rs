@@ -6,11 +6,14 @@ use router_env::{instrument, tracing};
 use storage_impl::redis::cache::{self, DECISION_MANAGER_CACHE};
 
 use super::routing::make_dsl_input;
+#[cfg(feature = "v1")]
+use super::routing::{make_dsl_amount_in_base_currency, make_dsl_time_input};
 #[cfg(feature = "v2")]
-use crate::{core::errors::RouterResult, types::domain};
+use crate::core::errors::RouterResult;
 use crate::{
     core::{errors, errors::ConditionalConfigError as ConfigError, routing as core_routing},
     routes,
+    types::domain,
 };
 pub type ConditionalConfigResult<O> = errors::CustomResult<O, ConfigError>;
 
@@ -20,6 +23,7 @@ pub async fn perform_decision_management(
     state: &routes::SessionState,
     algorithm_ref: routing::RoutingAlgorithmRef,
     merchant_id: &common_utils::id_type::MerchantId,
+    business_profile: &domain::Profile,
     payment_data: &core_routing::PaymentsDslInput<'_>,
 ) -> ConditionalConfigResult<common_types::payments::ConditionalConfigs> {
     let algorithm_id = if let Some(id) = algorithm_ref.config_algo_id {
@@ -54,8 +58,11 @@ pub async fn perform_decision_management(
     .await
     .change_context(ConfigError::DslCachePoisoned)?;
 
-    let backend_input =
+    let mut backend_input =
         make_dsl_input(payment_data).change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment.amount_in_base_currency =
+        make_dsl_amount_in_base_currency(state, business_profile, &backend_input.payment).await;
+    backend_input.time = make_dsl_time_input(business_profile);
 
     execute_dsl_and_get_conditional_config(backend_input, &interpreter)
 }
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, collections::HashSet, net::IpAddr, ops::Deref, str::FromStr};
+use std::{borrow::Cow, collections::HashSet, ops::Deref, str::FromStr};
 
 pub use ::payment_methods::helpers::{
     populate_bin_details_for_payment_method_create,
@@ -70,6 +70,7 @@ use crate::{
     core::{
         authentication,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        gsm,
         mandate::helpers::MandateGenericData,
         payment_methods::{
             self,
@@ -1499,84 +1500,6 @@ pub fn validate_customer_information(
     }
 }
 
-pub async fn validate_card_ip_blocking_for_business_profile(
-    state: &SessionState,
-    ip: IpAddr,
-    fingerprnt: masking::Secret<String>,
-    card_testing_guard_config: &diesel_models::business_profile::CardTestingGuardConfig,
-) -> RouterResult<String> {
-    let cache_key = format!(
-        "{}_{}_{}",
-        consts::CARD_IP_BLOCKING_CACHE_KEY_PREFIX,
-        fingerprnt.peek(),
-        ip
-    );
-
-    let unsuccessful_payment_threshold = card_testing_guard_config.card_ip_blocking_threshold;
-
-    validate_blocking_threshold(state, unsuccessful_payment_threshold, cache_key).await
-}
-
-pub async fn validate_guest_user_card_blocking_for_business_profile(
-    state: &SessionState,
-    fingerprnt: masking::Secret<String>,
-    customer_id: Option<id_type::CustomerId>,
-    card_testing_guard_config: &diesel_models::business_profile::CardTestingGuardConfig,
-) -> RouterResult<String> {
-    let cache_key = format!(
-        "{}_{}",
-        consts::GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX,
-        fingerprnt.peek()
-    );
-
-    let unsuccessful_payment_threshold =
-        card_testing_guard_config.guest_user_card_blocking_threshold;
-
-    if customer_id.is_none() {
-        Ok(validate_blocking_threshold(state, unsuccessful_payment_threshold, cache_key).await?)
-    } else {
-        Ok(cache_key)
-    }
-}
-
-pub async fn validate_customer_id_blocking_for_business_profile(
-    state: &SessionState,
-    customer_id: id_type::CustomerId,
-    profile_id: &id_type::ProfileId,
-    card_testing_guard_config: &diesel_models::business_profile::CardTestingGuardConfig,
-) -> RouterResult<String> {
-    let cache_key = format!(
-        "{}_{}_{}",
-        consts::CUSTOMER_ID_BLOCKING_PREFIX,
-        profile_id.get_string_repr(),
-        customer_id.get_string_repr(),
-    );
-
-    let unsuccessful_payment_threshold = card_testing_guard_config.customer_id_blocking_threshold;
-
-    validate_blocking_threshold(state, unsuccessful_payment_threshold, cache_key).await
-}
-
-pub async fn validate_blocking_threshold(
-    state: &SessionState,
-    unsuccessful_payment_threshold: i32,
-    cache_key: String,
-) -> RouterResult<String> {
-    match services::card_testing_guard::get_blocked_count_from_cache(state, &cache_key).await {
-        Ok(Some(unsuccessful_payment_count)) => {
-            if unsuccessful_payment_count >= unsuccessful_payment_threshold {
-                Err(errors::ApiErrorResponse::PreconditionFailed {
-                    message: "Blocked due to suspicious activity".to_string(),
-                })?
-            } else {
-                Ok(cache_key)
-            }
-        }
-        Ok(None) => Ok(cache_key),
-        Err(error) => Err(errors::ApiErrorResponse::InternalServerError).attach_printable(error)?,
-    }
-}
-
 #[cfg(feature = "v1")]
 /// Get the customer details from customer field if present
 /// or from the individual fields in `PaymentsRequest`
@@ -4657,6 +4580,8 @@ impl AttemptType {
             connector_request_reference_id: None,
             network_transaction_id: None,
             network_details: None,
+            flagged_velocity_rules: None,
+            blocklist_match_details: None,
         }
     }
 
@@ -6557,39 +6482,47 @@ pub async fn get_gsm_record(
     connector_name: String,
     flow: String,
 ) -> Option<hyperswitch_domain_models::gsm::GatewayStatusMap> {
-    let get_gsm = || async {
-        state.store.find_gsm_rule(
-                connector_name.clone(),
-                flow.clone(),
-                "sub_flow".to_string(),
-                error_code.clone().unwrap_or_default(), // TODO: make changes in connector to get a mandatory code in case of success or error response
-                error_message.clone().unwrap_or_default(),
-            )
-            .await
-            .map_err(|err| {
-                if err.current_context().is_db_not_found() {
-                    logger::warn!(
-                        "GSM miss for connector - {}, flow - {}, error_code - {:?}, error_message - {:?}",
-                        connector_name,
-                        flow,
-                        error_code,
-                        error_message
-                    );
-                    metrics::AUTO_RETRY_GSM_MISS_COUNT.add( 1, &[]);
-                } else {
-                    metrics::AUTO_RETRY_GSM_FETCH_FAILURE_COUNT.add( 1, &[]);
-                };
-                err.change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("failed to fetch decision from gsm")
-            })
-    };
-    get_gsm()
-        .await
-        .inspect_err(|err| {
+    get_gsm_record_in_flows(state, error_code, error_message, connector_name, &[flow]).await
+}
+
+/// Fetches the GSM record of the error from the rules of the flows, in order of preference. A
+/// specific rule of a later flow is preferred over the connector default of an earlier one.
+pub async fn get_gsm_record_in_flows(
+    state: &SessionState,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    connector_name: String,
+    flows: &[String],
+) -> Option<hyperswitch_domain_models::gsm::GatewayStatusMap> {
+    let gsm_rule = gsm::find_matching_gsm_rule_in_flows(
+        state,
+        connector_name.clone(),
+        flows,
+        "sub_flow".to_string(),
+        error_code.as_deref().unwrap_or_default(), // TODO: make changes in connector to get a mandatory code in case of success or error response
+        error_message.as_deref().unwrap_or_default(),
+    )
+    .await;
+    match gsm_rule {
+        Ok(Some(gsm_rule)) => Some(gsm_rule),
+        Ok(None) => {
+            logger::warn!(
+                "GSM miss for connector - {}, flows - {:?}, error_code - {:?}, error_message - {:?}",
+                connector_name,
+                flows,
+                error_code,
+                error_message
+            );
+            metrics::AUTO_RETRY_GSM_MISS_COUNT.add(1, &[]);
+            None
+        }
+        Err(err) => {
+            metrics::AUTO_RETRY_GSM_FETCH_FAILURE_COUNT.add(1, &[]);
             // warn log should suffice here because we are not propagating this error
             logger::warn!(get_gsm_decision_fetch_error=?err, "error fetching gsm decision");
-        })
-        .ok()
+            None
+        }
+    }
 }
 
 pub async fn get_unified_translation(
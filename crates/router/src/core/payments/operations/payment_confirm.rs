@@ -858,23 +858,36 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 request_payment_method_data.payment_method_data.as_ref()
             });
 
-        let customer_id = &payment_data.payment_intent.customer_id;
+        let card_testing_guard_data = card_testing_guard_utils::validate_card_testing_guard_checks(
+            state,
+            request,
+            payment_method_data,
+            &payment_data.payment_attempt,
+            &payment_data.payment_intent.customer_id,
+            payment_data.email.as_ref(),
+            business_profile,
+        )
+        .await?;
 
-        match payment_method_data {
-            Some(api_models::payments::PaymentMethodData::Card(_card)) => {
-                payment_data.card_testing_guard_data =
-                    card_testing_guard_utils::validate_card_testing_guard_checks(
-                        state,
-                        request,
-                        payment_method_data,
-                        customer_id,
-                        business_profile,
-                    )
-                    .await?;
-                Ok(())
+        if let Some(card_testing_guard_data) = &card_testing_guard_data {
+            // The authentication type of the attempt takes precedence over the decision manager
+            if card_testing_guard_data.force_three_ds {
+                payment_data.payment_attempt.authentication_type =
+                    Some(storage_enums::AuthenticationType::ThreeDs);
+            }
+            if !card_testing_guard_data.flagged_rules.is_empty() {
+                logger::warn!(
+                    payment_id = ?payment_data.payment_intent.payment_id,
+                    flagged_rules = ?card_testing_guard_data.flagged_rules,
+                    "payment flagged for review by velocity rules"
+                );
+                payment_data.payment_attempt.flagged_velocity_rules =
+                    Some(card_testing_guard_data.flagged_rules.clone());
             }
-            _ => Ok(()),
         }
+        payment_data.card_testing_guard_data = card_testing_guard_data;
+
+        Ok(())
     }
 }
 
@@ -1170,7 +1183,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
             // get three_ds_decision_rule_output using algorithm_id and payment data
             let decision = three_ds_decision_rule::get_three_ds_decision_rule_output(
                 state,
-                &business_profile.merchant_id,
+                business_profile,
                 api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest {
                     routing_id: algorithm_id,
                     payment: api_models::three_ds_decision_rule::PaymentData {
@@ -1924,6 +1937,10 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                             .payment_attempt
                             .network_transaction_id
                             .clone(),
+                        flagged_velocity_rules: payment_data
+                            .payment_attempt
+                            .flagged_velocity_rules
+                            .clone(),
                     },
                     storage_scheme,
                 )
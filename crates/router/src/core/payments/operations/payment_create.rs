@@ -1395,6 +1395,8 @@ impl PaymentCreate {
                 connector_request_reference_id: None,
                 network_transaction_id:None,
                 network_details:None,
+                flagged_velocity_rules: None,
+                blocklist_match_details: None,
             },
             additional_pm_data,
 
@@ -29,6 +29,8 @@ use crate::{
     types::{self, api, domain, storage, transformers::ForeignFrom},
 };
 
+pub const RETRY_LATER_TASK: &str = "PAYMENT_RETRY_LATER";
+
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 #[cfg(feature = "v1")]
@@ -124,6 +126,12 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
+            let retry_later_config = gsm
+                .as_ref()
+                .and_then(|data| data.feature_data.get_retry_feature_data())
+                .map(|data| data.get_retry_later_config())
+                .unwrap_or_default();
+
             match get_gsm_decision(gsm) {
                 storage_enums::GsmDecision::Retry => {
                     retries = get_retries(
@@ -222,6 +230,28 @@ where
 
                     retries = retries.map(|i| i - 1);
                 }
+                storage_enums::GsmDecision::RetryLater => {
+                    if is_retry_later_applicable::<F, _>(payment_data)? {
+                        let is_scheduled = schedule_retry_later_task(
+                            state,
+                            payment_data.get_payment_intent(),
+                            retry_later_config,
+                        )
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to schedule the deferred retry")
+                        })
+                        .unwrap_or(false);
+
+                        if is_scheduled {
+                            metrics::AUTO_RETRY_DEFERRED_COUNT.add(1, &[]);
+                            park_payment_attempt(&mut router_data);
+                        } else {
+                            logger::info!("deferred retries exhausted for payment");
+                        }
+                    }
+                    break;
+                }
                 storage_enums::GsmDecision::DoDefault => break,
             }
             initial_gsm = None;
@@ -324,6 +354,166 @@ pub fn get_gsm_decision(
     option_gsm_decision.unwrap_or_default()
 }
 
+/// Deferred retries re-run the payment against its saved mandate or payment method, so they are
+/// only made for merchant initiated authorizations
+#[cfg(feature = "v1")]
+fn is_retry_later_applicable<F, D>(payment_data: &D) -> RouterResult<bool>
+where
+    F: Clone,
+    D: payments::OperationSessionGetters<F>,
+{
+    // Currently we are taking off_session as a source of truth to identify MIT payments.
+    let is_mit_payment = payment_data
+        .get_payment_intent()
+        .off_session
+        .unwrap_or(false);
+    let payment_attempt = payment_data.get_payment_attempt();
+    let has_recurring_details =
+        payment_attempt.mandate_id.is_some() || payment_attempt.payment_method_id.is_some();
+
+    Ok(is_mit_payment && has_recurring_details && get_flow_name::<F>()? == "Authorize")
+}
+
+/// Keeps the attempt pending instead of failing it, so that the payment stays in processing until
+/// it is retried by the retry later task
+#[cfg(feature = "v1")]
+fn park_payment_attempt<F, FData>(
+    router_data: &mut types::RouterData<F, FData, types::PaymentsResponseData>,
+) {
+    if let Err(error_response) = router_data.response.as_mut() {
+        error_response.attempt_status = Some(storage_enums::AttemptStatus::Pending);
+    }
+}
+
+/// Tracking data of the task retrying a payment parked by the `retry_later` GSM decision
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentRetryLaterTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
+/// Schedules the next deferred retry of a payment. All deferred retries of a payment are made by a
+/// single task, which is rescheduled with the next delay when the payment is parked again.
+///
+/// Returns false once the deferred retries of the payment are exhausted
+#[cfg(feature = "v1")]
+async fn schedule_retry_later_task(
+    state: &app::SessionState,
+    payment_intent: &storage::PaymentIntent,
+    retry_later_config: common_types::domain::RetryLaterConfig,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::PaymentRetryLaterWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        RETRY_LATER_TASK,
+        payment_intent.get_id().get_string_repr(),
+        &payment_intent.merchant_id,
+    );
+    let process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the retry later task")?;
+
+    // The task is running while it retries the payment, any other state of an existing task means
+    // that the deferred retries of the payment are already over
+    let retry_count = match &process {
+        None => 0,
+        Some(process) if process.status == storage_enums::ProcessTrackerStatus::ProcessStarted => {
+            process.retry_count + 1
+        }
+        Some(_) => return Ok(false),
+    };
+    let Some(delay_in_seconds) = retry_later_config.get_delay_in_seconds(retry_count) else {
+        return Ok(false);
+    };
+    let schedule_time =
+        common_utils::date_time::now().saturating_add(time::Duration::seconds(delay_in_seconds));
+
+    match process {
+        Some(process) => {
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+        }
+        None => {
+            let tracking_data = PaymentRetryLaterTrackingData {
+                merchant_id: payment_intent.merchant_id.clone(),
+                payment_id: payment_intent.get_id().clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                RETRY_LATER_TASK,
+                runner,
+                ["RETRY_LATER", "PAYMENT"],
+                tracking_data,
+                None,
+                schedule_time,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct the retry later task")?;
+
+            db.insert_process(process_tracker_entry).await.map(|_| ())
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to schedule the retry later task")?;
+
+    Ok(true)
+}
+
+/// Fails the attempt parked by the `retry_later` decision and makes a new attempt for the deferred
+/// retry, in the same way as manual retries do
+#[cfg(feature = "v1")]
+pub async fn make_retry_later_attempt(
+    state: &app::SessionState,
+    merchant_context: &domain::MerchantContext,
+    request: &api::PaymentsRequest,
+    payment_intent: storage::PaymentIntent,
+    parked_payment_attempt: storage::PaymentAttempt,
+) -> RouterResult<(storage::PaymentIntent, storage::PaymentAttempt)> {
+    let db = &*state.store;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            parked_payment_attempt,
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage_enums::AttemptStatus::Failure,
+                error_code: None,
+                error_message: None,
+                error_reason: None,
+                amount_capturable: Some(MinorUnit::new(0)),
+                updated_by: storage_scheme.to_string(),
+                unified_code: None,
+                unified_message: None,
+                connector_transaction_id: None,
+                payment_method_data: None,
+                authentication_type: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+                network_details: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payments::helpers::AttemptType::New
+        .modify_payment_intent_and_payment_attempt(
+            request,
+            payment_intent,
+            payment_attempt,
+            state,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+}
+
 #[inline]
 fn get_flow_name<F>() -> RouterResult<String> {
     Ok(std::any::type_name::<F>()
@@ -748,6 +938,8 @@ pub fn make_new_payment_attempt(
         connector_request_reference_id: Default::default(),
         network_transaction_id: old_payment_attempt.network_transaction_id,
         network_details: Default::default(),
+        flagged_velocity_rules: Default::default(),
+        blocklist_match_details: Default::default(),
     }
 }
 
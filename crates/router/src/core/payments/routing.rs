@@ -28,11 +28,10 @@ use euclid::{
 };
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use external_services::grpc_client::dynamic_routing::{
-    contract_routing_client::ContractBasedDynamicRouting,
-    elimination_based_client::EliminationBasedRouting,
-    success_rate_client::SuccessBasedDynamicRouting, DynamicRoutingError,
+    contract_routing_client::ContractBasedDynamicRouting, DynamicRoutingError,
 };
 use hyperswitch_domain_models::address::Address;
+use hyperswitch_interfaces::events::routing_api_logs as routing_events;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use hyperswitch_interfaces::events::routing_api_logs::{ApiMethod, RoutingEngine};
 use kgraph_utils::{
@@ -59,7 +58,7 @@ use crate::core::routing::transformers::OpenRouterDecideGatewayRequestExt;
 use crate::routes::app::SessionStateInfo;
 use crate::{
     core::{
-        errors, errors as oss_errors,
+        circuit_breaker, errors, errors as oss_errors,
         payments::{
             routing::utils::DecisionEngineApiHandler, OperationSessionGetters,
             OperationSessionSetters,
@@ -72,7 +71,7 @@ use crate::{
         domain, storage as oss_storage,
         transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
     },
-    utils::{OptionExt, ValueExt},
+    utils::{currency, OptionExt, ValueExt},
     SessionState,
 };
 
@@ -164,6 +163,7 @@ pub fn make_dsl_input_for_payouts(
         .unwrap_or(None);
     let payment = dsl_inputs::PaymentInput {
         amount: payout_data.payouts.amount,
+        amount_in_base_currency: None,
         card_bin: None,
         currency: payout_data.payouts.destination_currency,
         authentication_type: None,
@@ -212,6 +212,7 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time: None,
     })
 }
 
@@ -295,6 +296,7 @@ pub fn make_dsl_input(
                 _ => None,
             },
         ),
+        amount_in_base_currency: None,
         currency: payments_dsl_input.currency,
         authentication_type: Some(payments_dsl_input.payment_attempt.authentication_type),
         capture_method: Some(payments_dsl_input.payment_intent.capture_method),
@@ -327,9 +329,78 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time: None,
     })
 }
 
+/// Builds the time based inputs of the routing DSL from the current time, as observed in the
+/// routing timezone of the profile. Falls back to UTC if the profile doesn't configure a valid one.
+pub fn make_dsl_time_input(business_profile: &domain::Profile) -> Option<dsl_inputs::TimeInput> {
+    make_dsl_time_input_at(business_profile, common_utils::date_time::now())
+}
+
+/// Builds the time based inputs of the routing DSL from the given UTC time, as observed in the
+/// routing timezone of the profile.
+pub fn make_dsl_time_input_at(
+    business_profile: &domain::Profile,
+    time: time::PrimitiveDateTime,
+) -> Option<dsl_inputs::TimeInput> {
+    #[cfg(feature = "v1")]
+    let routing_timezone = business_profile.routing_timezone.as_deref();
+    #[cfg(feature = "v2")]
+    let routing_timezone: Option<&str> = {
+        let _ = business_profile;
+        None
+    };
+
+    let utc_offset = routing_timezone
+        .and_then(|timezone| {
+            let utc_offset = dsl_inputs::parse_utc_offset(timezone);
+            if utc_offset.is_none() {
+                logger::warn!(
+                    timezone,
+                    "euclid_routing: invalid routing timezone in profile"
+                );
+            }
+            utc_offset
+        })
+        .unwrap_or(dsl_inputs::UtcOffset::UTC);
+
+    dsl_inputs::TimeInput::from_unix_timestamp(time.assume_utc().unix_timestamp(), utc_offset)
+}
+
+/// Converts the amount of the payment to the routing base currency of the profile, for evaluating
+/// `amount_in_base_currency` conditions. Such conditions don't match if the profile doesn't
+/// configure a base currency, or if the forex rates aren't available.
+pub async fn make_dsl_amount_in_base_currency(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_input: &dsl_inputs::PaymentInput,
+) -> Option<common_utils::types::MinorUnit> {
+    #[cfg(feature = "v1")]
+    let routing_base_currency = business_profile.routing_base_currency;
+    #[cfg(feature = "v2")]
+    let routing_base_currency: Option<api_enums::Currency> = {
+        let _ = business_profile;
+        None
+    };
+
+    currency::convert_minor_unit_amount(
+        state,
+        payment_input.amount,
+        payment_input.currency,
+        routing_base_currency?,
+    )
+    .await
+    .map_err(|error| {
+        logger::warn!(
+            ?error,
+            "euclid_routing: unable to convert amount to the routing base currency"
+        )
+    })
+    .ok()
+}
+
 #[cfg(feature = "v1")]
 pub fn make_dsl_input(
     payments_dsl_input: &routing::PaymentsDslInput<'_>,
@@ -406,6 +477,7 @@ pub fn make_dsl_input(
                 _ => None,
             },
         ),
+        amount_in_base_currency: None,
         currency: payments_dsl_input.currency,
         authentication_type: payments_dsl_input.payment_attempt.authentication_type,
         capture_method: payments_dsl_input
@@ -441,6 +513,7 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time: None,
     })
 }
 
@@ -485,11 +558,14 @@ pub async fn perform_static_routing_v1(
     )
     .await?;
 
-    let backend_input = match transaction_data {
+    let mut backend_input = match transaction_data {
         routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
         #[cfg(feature = "payouts")]
         routing::TransactionData::Payout(payout_data) => make_dsl_input_for_payouts(payout_data)?,
     };
+    backend_input.time = make_dsl_time_input(business_profile);
+    backend_input.payment.amount_in_base_currency =
+        make_dsl_amount_in_base_currency(state, business_profile, &backend_input.payment).await;
 
     let payment_id = match transaction_data {
         routing::TransactionData::Payment(payment_data) => payment_data
@@ -515,7 +591,7 @@ pub async fn perform_static_routing_v1(
             state,
             backend_input.clone(),
             business_profile,
-            payment_id,
+            payment_id.clone(),
             get_merchant_fallback_config().await?,
         )
         .await
@@ -526,22 +602,20 @@ pub async fn perform_static_routing_v1(
         .unwrap_or_default()
     };
 
-    let (routable_connectors, routing_approach) = match cached_algorithm.as_ref() {
-        CachedAlgorithm::Single(conn) => (
-            vec![(**conn).clone()],
-            Some(common_enums::RoutingApproach::StraightThroughRouting),
-        ),
-        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
-        CachedAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec())
-                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            Some(common_enums::RoutingApproach::VolumeBasedRouting),
-        ),
-        CachedAlgorithm::Advanced(interpreter) => (
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?,
-            Some(common_enums::RoutingApproach::RuleBasedRouting),
-        ),
-    };
+    let (routable_connectors, routing_approach, rule_name) =
+        execute_cached_algorithm_v1(cached_algorithm.as_ref(), backend_input.clone())?;
+
+    if let CachedAlgorithm::Advanced(_) = cached_algorithm.as_ref() {
+        record_rule_based_routing_decision(
+            state,
+            algorithm_id,
+            business_profile,
+            payment_id,
+            backend_input,
+            &routable_connectors,
+            rule_name,
+        );
+    }
 
     // Results are logged for diff(between legacy and decision_engine's euclid) and have parameters as:
     // is_equal: verifies all output are matching in order,
@@ -567,6 +641,291 @@ pub async fn perform_static_routing_v1(
     ))
 }
 
+/// Returns the chosen connectors and the routing approach, along with the name of the matched
+/// rule for advanced algorithms
+fn execute_cached_algorithm_v1(
+    cached_algorithm: &CachedAlgorithm,
+    backend_input: dsl_inputs::BackendInput,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Option<common_enums::RoutingApproach>,
+    Option<String>,
+)> {
+    Ok(match cached_algorithm {
+        CachedAlgorithm::Single(conn) => (
+            vec![(**conn).clone()],
+            Some(common_enums::RoutingApproach::StraightThroughRouting),
+            None,
+        ),
+        CachedAlgorithm::Priority(plist) => (plist.clone(), None, None),
+        CachedAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits.to_vec())
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            Some(common_enums::RoutingApproach::VolumeBasedRouting),
+            None,
+        ),
+        CachedAlgorithm::Advanced(interpreter) => {
+            let (connectors, rule_name) =
+                execute_dsl_and_get_connector_v1(backend_input, interpreter)?;
+            (
+                connectors,
+                Some(common_enums::RoutingApproach::RuleBasedRouting),
+                rule_name,
+            )
+        }
+    })
+}
+
+/// Counts the decision of an advanced routing algorithm against the rule that matched, and logs
+/// a routing event so that the payment can be attributed to the rule. Both are done in the
+/// background, so that the payment isn't held up by the bookkeeping.
+fn record_rule_based_routing_decision(
+    state: &SessionState,
+    algorithm_id: &common_utils::id_type::RoutingId,
+    business_profile: &domain::Profile,
+    payment_id: String,
+    backend_input: dsl_inputs::BackendInput,
+    connectors: &[routing_types::RoutableConnectorChoice],
+    rule_name: Option<String>,
+) {
+    use router_env::tracing::Instrument;
+
+    let state = state.clone();
+    let algorithm_id = algorithm_id.clone();
+    let profile_id = business_profile.get_id().clone();
+    let merchant_id = business_profile.merchant_id.clone();
+    let connectors = connectors.to_vec();
+    tokio::spawn(
+        async move {
+            if let Err(error) =
+                routing::rule_hits::increment_rule_hit(&state, &algorithm_id, rule_name.as_deref())
+                    .await
+            {
+                logger::error!(?error, "Failed to record the hit of the routing rule");
+            }
+
+            let request = serde_json::to_value(&backend_input)
+                .map_err(|error| {
+                    logger::error!(?error, "euclid_routing: unable to serialize input")
+                })
+                .unwrap_or_default();
+            let mut routing_event = routing_events::RoutingEvent::new(
+                state.tenant.tenant_id.clone(),
+                String::new(),
+                routing_events::RULE_BASED_ROUTING_FLOW,
+                request,
+                String::new(),
+                routing_events::ApiMethod::Internal,
+                payment_id,
+                profile_id,
+                merchant_id,
+                state.request_id,
+                routing_events::RoutingEngine::Euclid,
+            );
+            if let Some(connector) = connectors.first() {
+                routing_event.set_payment_connector(connector.clone());
+            }
+            routing_event.set_routable_connectors(connectors);
+            routing_event
+                .set_routing_approach(common_enums::RoutingApproach::RuleBasedRouting.to_string());
+            routing_event.set_matched_rule(&algorithm_id, rule_name);
+            routing_event.set_status_code(200);
+            state.event_handler.log_event(&routing_event);
+        }
+        .in_current_span(),
+    );
+}
+
+/// Evaluates the shadow routing algorithm of the profile for a payment in the background, and logs
+/// a routing event comparing its decision with the connectors picked by the live algorithm. The
+/// shadow decision never affects the payment, so failures are only logged.
+#[cfg(feature = "v1")]
+pub fn perform_shadow_routing_v1(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    shadow_algorithm_id: &common_utils::id_type::RoutingId,
+    business_profile: &domain::Profile,
+    payments_dsl_input: &routing::PaymentsDslInput<'_>,
+    eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
+    live_connectors: &[routing_types::RoutableConnectorChoice],
+) {
+    use router_env::tracing::Instrument;
+
+    let backend_input = match make_dsl_input(payments_dsl_input) {
+        Ok(backend_input) => backend_input,
+        Err(error) => {
+            logger::error!(?error, "shadow_routing: unable to build routing input");
+            return;
+        }
+    };
+    let payment_id = payments_dsl_input
+        .payment_attempt
+        .payment_id
+        .get_string_repr()
+        .to_string();
+
+    let state = state.clone();
+    let key_store = key_store.clone();
+    let shadow_algorithm_id = shadow_algorithm_id.clone();
+    let business_profile = business_profile.clone();
+    let live_connector = live_connectors.first().cloned();
+    tokio::spawn(
+        async move {
+            evaluate_shadow_routing_v1(
+                &state,
+                &key_store,
+                &shadow_algorithm_id,
+                &business_profile,
+                backend_input,
+                eligible_connectors.as_ref(),
+                payment_id,
+                live_connector.as_ref(),
+            )
+            .await
+        }
+        .in_current_span(),
+    );
+}
+
+#[cfg(feature = "v1")]
+async fn evaluate_shadow_routing_v1(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    shadow_algorithm_id: &common_utils::id_type::RoutingId,
+    business_profile: &domain::Profile,
+    mut backend_input: dsl_inputs::BackendInput,
+    eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
+    payment_id: String,
+    live_connector: Option<&routing_types::RoutableConnectorChoice>,
+) {
+    let profile_id = business_profile.get_id();
+    // The eligibility of the live connectors is analysed without the inputs added below
+    let eligibility_input = backend_input.clone();
+    backend_input.time = make_dsl_time_input(business_profile);
+    backend_input.payment.amount_in_base_currency =
+        make_dsl_amount_in_base_currency(state, business_profile, &backend_input.payment).await;
+
+    let shadow_connectors = async {
+        // Routing algorithms are never modified once created, so the cache entry of the shadow
+        // algorithm is keyed by its id and doesn't need to be invalidated
+        let key = format!(
+            "routing_config_shadow_{}_{}_{}",
+            key_store.merchant_id.get_string_repr(),
+            profile_id.get_string_repr(),
+            shadow_algorithm_id.get_string_repr(),
+        );
+        let cached_algorithm = ROUTING_CACHE
+            .get_val::<Arc<CachedAlgorithm>>(CacheKey {
+                key: key.clone(),
+                prefix: state.tenant.redis_key_prefix.clone(),
+            })
+            .await;
+        let cached_algorithm = if let Some(algo) = cached_algorithm {
+            algo
+        } else {
+            refresh_routing_cache_v1(state, key, shadow_algorithm_id, profile_id).await?
+        };
+
+        let (connectors, _, _) =
+            execute_cached_algorithm_v1(cached_algorithm.as_ref(), backend_input.clone())?;
+        perform_shadow_eligibility_analysis_with_fallback(
+            state,
+            key_store,
+            connectors,
+            eligibility_input,
+            eligible_connectors,
+            business_profile,
+        )
+        .await
+    }
+    .await;
+
+    let shadow_connectors = match shadow_connectors {
+        Ok(shadow_connectors) => shadow_connectors,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "shadow_routing: failed to evaluate shadow algorithm"
+            );
+            return;
+        }
+    };
+
+    let request = serde_json::to_value(&backend_input)
+        .map_err(|error| logger::error!(?error, "shadow_routing: unable to serialize input"))
+        .unwrap_or_default();
+    let mut routing_event = routing_events::RoutingEvent::new(
+        state.tenant.tenant_id.clone(),
+        String::new(),
+        routing_events::SHADOW_ROUTING_FLOW,
+        request,
+        String::new(),
+        routing_events::ApiMethod::Internal,
+        payment_id,
+        profile_id.clone(),
+        key_store.merchant_id.clone(),
+        state.request_id,
+        routing_events::RoutingEngine::Euclid,
+    );
+    routing_event.set_routable_connectors(shadow_connectors.clone());
+    routing_event.set_shadow_decision(
+        shadow_algorithm_id,
+        live_connector,
+        shadow_connectors.first(),
+    );
+    routing_event.set_status_code(200);
+    state.event_handler.log_event(&routing_event);
+}
+
+/// Runs the connectors picked by the shadow algorithm through the eligibility analysis and the
+/// fallback applied to the connectors picked by the live algorithm, so that both decisions are
+/// compared after the same filtering
+#[cfg(feature = "v1")]
+async fn perform_shadow_eligibility_analysis_with_fallback(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    backend_input: dsl_inputs::BackendInput,
+    eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
+    business_profile: &domain::Profile,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let profile_id = business_profile.get_id();
+    let mut final_selection = perform_cgraph_filtering(
+        state,
+        key_store,
+        chosen,
+        backend_input.clone(),
+        eligible_connectors,
+        profile_id,
+        &api_enums::TransactionType::Payment,
+    )
+    .await?;
+
+    let fallback_selection = async {
+        let fallback_config = routing::helpers::get_merchant_default_config(
+            &*state.store,
+            profile_id.get_string_repr(),
+            &api_enums::TransactionType::Payment,
+        )
+        .await
+        .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
+        perform_cgraph_filtering(
+            state,
+            key_store,
+            fallback_config,
+            backend_input,
+            eligible_connectors,
+            profile_id,
+            &api_enums::TransactionType::Payment,
+        )
+        .await
+    }
+    .await;
+    append_fallback_connectors(&mut final_selection, fallback_selection.unwrap_or_default());
+
+    Ok(circuit_breaker::filter_connectors_with_open_circuit(state, final_selection).await)
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -649,16 +1008,18 @@ pub fn perform_routing_for_single_straight_through_algorithm(
     })
 }
 
+/// Returns the connectors chosen by the program along with the name of the rule that matched,
+/// which is absent if the default selection was used
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::StaticRoutingAlgorithm = interpreter
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    let (routing_output, rule_name): (routing_types::StaticRoutingAlgorithm, _) = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
+        .map(|out| (out.connector_selection.foreign_into(), out.rule_name))
         .change_context(errors::RoutingError::DslExecutionError)?;
 
-    Ok(match routing_output {
+    let connectors = match routing_output {
         routing_types::StaticRoutingAlgorithm::Priority(plist) => plist,
 
         routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits)
@@ -666,7 +1027,9 @@ fn execute_dsl_and_get_connector_v1(
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((connectors, rule_name))
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -934,13 +1297,25 @@ pub async fn perform_cgraph_filtering(
     eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
     profile_id: &common_utils::id_type::ProfileId,
     transaction_type: &api_enums::TransactionType,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let cached_cgraph = get_merchant_cgraph(state, key_store, profile_id, transaction_type).await?;
+
+    perform_cgraph_filtering_with_graph(&cached_cgraph, chosen, backend_input, eligible_connectors)
+}
+
+/// Filters the chosen connectors using a constraint graph that has already been fetched, for
+/// callers that evaluate many inputs against the same graph
+pub fn perform_cgraph_filtering_with_graph(
+    cached_cgraph: &hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    backend_input: dsl_inputs::BackendInput,
+    eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let context = euclid_graph::AnalysisContext::from_dir_values(
         backend_input
             .into_context()
             .change_context(errors::RoutingError::KgraphAnalysisError)?,
     );
-    let cached_cgraph = get_merchant_cgraph(state, key_store, profile_id, transaction_type).await?;
 
     let mut final_selection = Vec::<routing_types::RoutableConnectorChoice>::new();
     for choice in chosen {
@@ -1073,16 +1448,10 @@ pub async fn perform_eligibility_analysis_with_fallback(
     )
     .await;
 
-    final_selection.append(
-        &mut fallback_selection
-            .unwrap_or_default()
-            .iter()
-            .filter(|&routable_connector_choice| {
-                !final_selection.contains(routable_connector_choice)
-            })
-            .cloned()
-            .collect::<Vec<_>>(),
-    );
+    append_fallback_connectors(&mut final_selection, fallback_selection.unwrap_or_default());
+
+    let final_selection =
+        circuit_breaker::filter_connectors_with_open_circuit(state, final_selection).await;
 
     let final_selected_connectors = final_selection
         .iter()
@@ -1093,6 +1462,18 @@ pub async fn perform_eligibility_analysis_with_fallback(
     Ok(final_selection)
 }
 
+/// Appends the fallback connectors that aren't already selected to the selected connectors
+fn append_fallback_connectors(
+    selection: &mut Vec<routing_types::RoutableConnectorChoice>,
+    fallback_selection: Vec<routing_types::RoutableConnectorChoice>,
+) {
+    for routable_connector_choice in fallback_selection {
+        if !selection.contains(&routable_connector_choice) {
+            selection.push(routable_connector_choice);
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 pub async fn perform_session_flow_routing<'a>(
     state: &'a SessionState,
@@ -1121,6 +1502,7 @@ pub async fn perform_session_flow_routing<'a>(
             .payment_intent
             .amount_details
             .calculate_net_amount(),
+        amount_in_base_currency: None,
         currency: session_input.payment_intent.amount_details.currency,
         authentication_type: session_input.payment_intent.authentication_type,
         card_bin: None,
@@ -1156,6 +1538,7 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time: make_dsl_time_input(business_profile),
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1262,6 +1645,7 @@ pub async fn perform_session_flow_routing(
 
     let payment_input = dsl_inputs::PaymentInput {
         amount: session_input.payment_attempt.get_total_amount(),
+        amount_in_base_currency: None,
         currency: session_input
             .payment_intent
             .currency
@@ -1305,7 +1689,14 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time: make_dsl_time_input(business_profile),
     };
+    backend_input.payment.amount_in_base_currency = make_dsl_amount_in_base_currency(
+        session_input.state,
+        business_profile,
+        &backend_input.payment,
+    )
+    .await;
 
     for connector_data in session_input.chosen.iter() {
         pm_type_map
@@ -1415,13 +1806,21 @@ async fn perform_session_routing_for_pm_type(
                     .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
                 Some(common_enums::RoutingApproach::VolumeBasedRouting),
             ),
-            CachedAlgorithm::Advanced(interpreter) => (
-                execute_dsl_and_get_connector_v1(
+            CachedAlgorithm::Advanced(interpreter) => {
+                let (connectors, rule_name) = execute_dsl_and_get_connector_v1(
                     session_pm_input.backend_input.clone(),
                     interpreter,
-                )?,
-                Some(common_enums::RoutingApproach::RuleBasedRouting),
-            ),
+                )?;
+                routing::rule_hits::record_rule_hit(
+                    session_pm_input.state,
+                    algorithm_id,
+                    rule_name,
+                );
+                (
+                    connectors,
+                    Some(common_enums::RoutingApproach::RuleBasedRouting),
+                )
+            }
         }
     } else {
         (
@@ -1502,10 +1901,13 @@ async fn get_chosen_connectors<'a>(
             CachedAlgorithm::Priority(plist) => plist.clone(),
             CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                session_pm_input.backend_input.clone(),
-                interpreter,
-            )?,
+            CachedAlgorithm::Advanced(interpreter) => {
+                execute_dsl_and_get_connector_v1(
+                    session_pm_input.backend_input.clone(),
+                    interpreter,
+                )?
+                .0
+            }
         }
     } else {
         profile_wrapper
@@ -1590,6 +1992,7 @@ pub fn make_dsl_input_for_surcharge(
 
     let payment_input = dsl_inputs::PaymentInput {
         amount: payment_attempt.get_total_amount(),
+        amount_in_base_currency: None,
         // currency is always populated in payment_attempt during payment create
         currency: payment_attempt
             .currency
@@ -1629,6 +2032,7 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        time: None,
     };
     Ok(backend_input)
 }
@@ -2115,13 +2519,9 @@ where
             "performing success_based_routing for profile {}",
             profile_id.get_string_repr()
         );
-        let client = &state
-            .grpc_client
-            .dynamic_routing
-            .as_ref()
+        let client = routing::helpers::get_success_rate_client(state)
             .ok_or(errors::RoutingError::SuccessRateClientInitializationError)
-            .attach_printable("dynamic routing gRPC client not found")?
-            .success_rate_client;
+            .attach_printable("dynamic routing client not found")?;
 
         let success_based_routing_configs = routing::helpers::fetch_dynamic_routing_configs::<
             api_routing::SuccessBasedRoutingConfig,
@@ -2299,13 +2699,9 @@ pub async fn perform_elimination_routing(
             "performing elimination_routing for profile {}",
             profile_id.get_string_repr()
         );
-        let client = &state
-            .grpc_client
-            .dynamic_routing
-            .as_ref()
+        let client = routing::helpers::get_elimination_client(state)
             .ok_or(errors::RoutingError::EliminationClientInitializationError)
-            .attach_printable("dynamic routing gRPC client not found")?
-            .elimination_based_client;
+            .attach_printable("dynamic routing client not found")?;
 
         let elimination_routing_config = routing::helpers::fetch_dynamic_routing_configs::<
             api_routing::EliminationRoutingConfig,
@@ -1384,7 +1384,7 @@ fn convert_if_stmt(stmt: ast::IfStatement) -> RoutingResult<IfStatement> {
         condition: stmt
             .condition
             .into_iter()
-            .map(convert_comparison)
+            .map(convert_condition_expression)
             .collect::<RoutingResult<Vec<Comparison>>>()?,
 
         nested: stmt
@@ -1398,6 +1398,18 @@ fn convert_if_stmt(stmt: ast::IfStatement) -> RoutingResult<IfStatement> {
     })
 }
 
+fn convert_condition_expression(expression: ast::ConditionExpression) -> RoutingResult<Comparison> {
+    match expression {
+        ast::ConditionExpression::Comparison(c) => convert_comparison(c),
+        // The decision engine only supports conjunctions of comparisons
+        ast::ConditionExpression::Group(_) => Err(errors::RoutingError::GenericConversionError {
+            from: "ConditionGroup".to_string(),
+            to: "Comparison".to_string(),
+        }
+        .into()),
+    }
+}
+
 fn convert_comparison(c: ast::Comparison) -> RoutingResult<Comparison> {
     Ok(Comparison {
         lhs: c.lhs,
@@ -3426,6 +3426,8 @@ where
             network_details: payment_attempt
                 .network_details
                 .map(NetworkDetails::foreign_from),
+            flagged_velocity_rules: payment_attempt.flagged_velocity_rules,
+            blocklist_match_details: payment_attempt.blocklist_match_details,
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -3726,6 +3728,8 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             enable_overcapture: pi.enable_overcapture,
             is_overcapture_enabled: pa.is_overcapture_enabled,
             network_details: pa.network_details.map(NetworkDetails::foreign_from),
+            flagged_velocity_rules: pa.flagged_velocity_rules,
+            blocklist_match_details: pa.blocklist_match_details,
         }
     }
 }
@@ -0,0 +1,786 @@
+use std::collections::HashSet;
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::{payouts as payout_types, webhooks};
+use common_enums::{EventType, PayoutBatchItemStatus, PayoutBatchStatus, PayoutStatus};
+use common_utils::{
+    date_time,
+    ext_traits::ValueExt,
+    fp_utils::when,
+    id_type::{self, GenerateId},
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+use serde::{Deserialize, Serialize};
+
+use super::validator;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+const PAYOUT_BATCH_ID_PREFIX: &str = "pbatch";
+
+/// The maximum number of payouts accepted in a single batch
+const PAYOUT_BATCH_MAX_SIZE: usize = 10_000;
+const PAYOUT_BATCH_DEFAULT_CONCURRENCY: u16 = 10;
+const PAYOUT_BATCH_MAX_CONCURRENCY: u16 = 50;
+/// The number of rows of a batch inserted in a single query
+const PAYOUT_BATCH_INSERT_CHUNK_SIZE: usize = 1000;
+/// The maximum number of invalid rows listed in the error returned for an invalid batch
+const PAYOUT_BATCH_MAX_REPORTED_ERRORS: usize = 20;
+
+const PAYOUT_BATCH_TAG: &str = "PAYOUT";
+const PAYOUT_BATCH_NAME: &str = "PAYOUT_BATCH";
+const PAYOUT_BATCH_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::PayoutBatchWorkflow;
+
+/// The tracking data of the process tracker task that processes the payouts of a batch
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PayoutBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
+
+#[derive(Debug, MultipartForm)]
+pub struct PayoutBatchUploadForm {
+    #[multipart(limit = "10MB")]
+    pub file: Bytes,
+}
+
+fn parse_payout_batch_csv(data: &[u8]) -> csv::Result<Vec<payout_types::PayoutBatchRow>> {
+    csv::Reader::from_reader(data).deserialize().collect()
+}
+
+/// Builds a batch create request from an uploaded CSV file, with one payout per row. The header
+/// row of the file contains the field names of [`payout_types::PayoutBatchRow`].
+pub fn get_payout_batch_create_request(
+    form: PayoutBatchUploadForm,
+    query: payout_types::PayoutBatchUploadQuery,
+) -> Result<payout_types::PayoutBatchCreateRequest, errors::ApiErrorResponse> {
+    match parse_payout_batch_csv(form.file.data.as_ref()) {
+        Ok(payouts) => Ok(payout_types::PayoutBatchCreateRequest {
+            profile_id: query.profile_id,
+            max_concurrency: query.max_concurrency,
+            payouts,
+        }),
+        Err(e) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: e.to_string(),
+        }),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn create_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: payout_types::PayoutBatchCreateRequest,
+) -> RouterResponse<payout_types::PayoutBatchResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let business_profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        None,
+        None,
+        &merchant_context,
+        request.profile_id.as_ref().or(profile_id.as_ref()),
+        db,
+        false,
+    )
+    .await?;
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        merchant_context.get_merchant_key_store(),
+        Some(&business_profile_id),
+        merchant_id,
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::ProfileNotFound {
+        id: business_profile_id.get_string_repr().to_owned(),
+    })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &business_profile)?;
+
+    let max_concurrency = request
+        .max_concurrency
+        .unwrap_or(PAYOUT_BATCH_DEFAULT_CONCURRENCY);
+    when(
+        max_concurrency == 0 || max_concurrency > PAYOUT_BATCH_MAX_CONCURRENCY,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`max_concurrency` must be between 1 and {PAYOUT_BATCH_MAX_CONCURRENCY}"
+                ),
+            })
+        },
+    )?;
+    when(
+        request.payouts.is_empty() || request.payouts.len() > PAYOUT_BATCH_MAX_SIZE,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "A batch must contain between 1 and {PAYOUT_BATCH_MAX_SIZE} payouts"
+                ),
+            })
+        },
+    )?;
+
+    let payout_ids =
+        validate_payout_batch_rows(&state, &merchant_context, &request.payouts).await?;
+
+    let now = date_time::now();
+    let batch_id = common_utils::generate_id(consts::ID_LENGTH, PAYOUT_BATCH_ID_PREFIX);
+    let total_count = i32::try_from(request.payouts.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Number of payouts in the batch does not fit in an i32")?;
+
+    let payout_batch = db
+        .insert_payout_batch(storage::PayoutBatchNew {
+            batch_id: batch_id.clone(),
+            merchant_id: merchant_id.clone(),
+            profile_id: business_profile_id,
+            status: PayoutBatchStatus::Pending,
+            total_count,
+            succeeded_count: 0,
+            failed_count: 0,
+            max_concurrency: i32::from(max_concurrency),
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    let payout_batch_items = request
+        .payouts
+        .into_iter()
+        .zip(payout_ids)
+        .zip(1..)
+        .map(|((row, payout_id), row_number)| {
+            let request = serde_json::to_value(row)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize payout batch row")?;
+            Ok(storage::PayoutBatchItemNew {
+                batch_id: batch_id.clone(),
+                merchant_id: merchant_id.clone(),
+                row_number,
+                payout_id,
+                status: PayoutBatchItemStatus::Pending,
+                payout_status: None,
+                error_code: None,
+                error_message: None,
+                request,
+                created_at: now,
+                modified_at: now,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let mut payout_batch_items = payout_batch_items.into_iter().peekable();
+    while payout_batch_items.peek().is_some() {
+        db.insert_payout_batch_items(
+            payout_batch_items
+                .by_ref()
+                .take(PAYOUT_BATCH_INSERT_CHUNK_SIZE)
+                .collect(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch items")?;
+    }
+
+    add_payout_batch_task(db, &payout_batch).await?;
+
+    Ok(ApplicationResponse::Json(get_payout_batch_response(
+        payout_batch,
+    )))
+}
+
+/// Validates all rows of a batch before any payout is created, so that a batch is either
+/// accepted as a whole or rejected with the list of invalid rows. Returns the payout ID of each
+/// row, generating one for the rows that do not provide it.
+async fn validate_payout_batch_rows(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    rows: &[payout_types::PayoutBatchRow],
+) -> RouterResult<Vec<id_type::PayoutId>> {
+    let db = state.store.as_ref();
+    let merchant_account = merchant_context.get_merchant_account();
+
+    let requested_payout_ids = rows
+        .iter()
+        .filter_map(|row| row.payout_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let existing_payout_ids = if requested_payout_ids.is_empty() {
+        HashSet::new()
+    } else {
+        db.find_payouts_by_merchant_id_payout_ids(
+            merchant_account.get_id(),
+            &requested_payout_ids,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the payouts of the batch")?
+        .into_iter()
+        .map(|payout| payout.payout_id)
+        .collect::<HashSet<_>>()
+    };
+
+    let mut payout_ids = Vec::with_capacity(rows.len());
+    let mut seen_payout_ids = HashSet::new();
+    let mut row_errors = Vec::new();
+
+    for (row, row_number) in rows.iter().zip(1..) {
+        let mut errors_in_row = Vec::new();
+
+        if row.amount <= MinorUnit::zero() {
+            errors_in_row.push("`amount` must be greater than 0".to_owned());
+        }
+        match (&row.payout_method_id, &row.payout_token) {
+            (Some(_), Some(_)) => errors_in_row.push(
+                "only one of `payout_method_id` or `payout_token` must be provided".to_owned(),
+            ),
+            (None, None) => errors_in_row
+                .push("one of `payout_method_id` or `payout_token` is required".to_owned()),
+            _ => (),
+        }
+
+        let payout_id = match &row.payout_id {
+            Some(payout_id) => {
+                if !seen_payout_ids.insert(payout_id.clone()) {
+                    errors_in_row.push("`payout_id` is repeated in the batch".to_owned());
+                } else if existing_payout_ids.contains(payout_id) {
+                    errors_in_row
+                        .push("a payout with the same `payout_id` already exists".to_owned());
+                }
+                payout_id.clone()
+            }
+            None => id_type::PayoutId::generate(),
+        };
+        payout_ids.push(payout_id);
+
+        if !errors_in_row.is_empty() {
+            row_errors.push(format!("row {row_number}: {}", errors_in_row.join(", ")));
+        }
+    }
+
+    when(!row_errors.is_empty(), || {
+        let remaining_errors = row_errors
+            .len()
+            .saturating_sub(PAYOUT_BATCH_MAX_REPORTED_ERRORS);
+        let mut message = row_errors
+            .iter()
+            .take(PAYOUT_BATCH_MAX_REPORTED_ERRORS)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("; ");
+        if remaining_errors > 0 {
+            message.push_str(&format!("; and {remaining_errors} more invalid rows"));
+        }
+        Err(errors::ApiErrorResponse::InvalidRequestData { message })
+    })?;
+
+    Ok(payout_ids)
+}
+
+async fn add_payout_batch_task(
+    db: &dyn StorageInterface,
+    payout_batch: &storage::PayoutBatch,
+) -> RouterResult<()> {
+    let process_tracker_id = format!(
+        "{PAYOUT_BATCH_RUNNER}_{PAYOUT_BATCH_NAME}_{}_{}",
+        payout_batch.merchant_id.get_string_repr(),
+        payout_batch.batch_id
+    );
+    let tracking_data = PayoutBatchTrackingData {
+        batch_id: payout_batch.batch_id.clone(),
+        merchant_id: payout_batch.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYOUT_BATCH_NAME,
+        PAYOUT_BATCH_RUNNER,
+        [PAYOUT_BATCH_TAG],
+        tracking_data,
+        None,
+        date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch task")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: payout_types::PayoutBatchId,
+) -> RouterResponse<payout_types::PayoutBatchResponse> {
+    let payout_batch = find_payout_batch(
+        state.store.as_ref(),
+        merchant_context.get_merchant_account().get_id(),
+        &request.batch_id,
+    )
+    .await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payout_batch)?;
+
+    Ok(ApplicationResponse::Json(get_payout_batch_response(
+        payout_batch,
+    )))
+}
+
+/// Returns the outcome of each payout of a batch as a CSV file, with one row per row of the batch
+#[instrument(skip_all)]
+pub async fn retrieve_payout_batch_results(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: payout_types::PayoutBatchId,
+) -> RouterResponse<serde_json::Value> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let payout_batch = find_payout_batch(db, merchant_id, &request.batch_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payout_batch)?;
+
+    let payout_batch_items = db
+        .list_payout_batch_items_by_merchant_id_batch_id(merchant_id, &payout_batch.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batch items")?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for payout_batch_item in payout_batch_items {
+        csv_writer
+            .serialize(get_payout_batch_item_response(payout_batch_item))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write payout batch results")?;
+    }
+    let file_data = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write payout batch results")?;
+
+    Ok(ApplicationResponse::FileData((file_data, mime::TEXT_CSV)))
+}
+
+/// Processes the next payouts of a batch, at most `max_concurrency` of them at the same time.
+/// Once all payouts have been processed, the final status of the batch is recorded and the
+/// batch completed webhook is sent.
+#[instrument(skip_all)]
+pub(crate) async fn process_payout_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    if payout_batch.status.is_terminal() {
+        return Ok(payout_batch);
+    }
+
+    let db = state.store.as_ref();
+
+    let pending_items = db
+        .list_payout_batch_items_by_merchant_id_batch_id_status(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+            PayoutBatchItemStatus::Pending,
+            i64::from(payout_batch.max_concurrency),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list pending payout batch items")?;
+
+    if pending_items.is_empty() {
+        let payout_batch = complete_payout_batch(db, payout_batch).await?;
+        trigger_payout_batch_outgoing_webhook(state, merchant_context, &payout_batch)
+            .await
+            .map_err(|error| logger::warn!(payout_batch_outgoing_webhook_error=?error))
+            .ok();
+        return Ok(payout_batch);
+    }
+
+    let results = futures::future::join_all(pending_items.into_iter().map(|payout_batch_item| {
+        process_payout_batch_item(state, merchant_context, &payout_batch, payout_batch_item)
+    }))
+    .await;
+
+    let (succeeded_count, failed_count) = results.iter().flatten().fold(
+        (payout_batch.succeeded_count, payout_batch.failed_count),
+        |(succeeded_count, failed_count), payout_batch_item| match payout_batch_item.status {
+            PayoutBatchItemStatus::Succeeded => (succeeded_count + 1, failed_count),
+            PayoutBatchItemStatus::Failed => (succeeded_count, failed_count + 1),
+            PayoutBatchItemStatus::Pending => (succeeded_count, failed_count),
+        },
+    );
+
+    let payout_batch = db
+        .update_payout_batch_by_merchant_id_batch_id(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+            storage::PayoutBatchUpdate::ProgressUpdate {
+                status: PayoutBatchStatus::Processing,
+                succeeded_count,
+                failed_count,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch")?;
+
+    // Payouts that could not be processed due to an internal error are retried with the task
+    results.into_iter().collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(payout_batch)
+}
+
+/// Creates and confirms the payout of a row of a batch. If the payout already exists, the batch
+/// task was interrupted after creating it, and the status of the existing payout is recorded
+/// instead of creating it again.
+async fn process_payout_batch_item(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: &storage::PayoutBatch,
+    payout_batch_item: storage::PayoutBatchItem,
+) -> RouterResult<storage::PayoutBatchItem> {
+    let db = state.store.as_ref();
+    let merchant_account = merchant_context.get_merchant_account();
+
+    let existing_payout = validator::validate_uniqueness_of_payout_id_against_merchant_id(
+        db,
+        &payout_batch_item.payout_id,
+        merchant_account.get_id(),
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    let payout_batch_item_update = match existing_payout {
+        Some(payout) => storage::PayoutBatchItemUpdate::ResultUpdate {
+            status: get_payout_batch_item_status(payout.status),
+            payout_status: Some(payout.status),
+            error_code: None,
+            error_message: None,
+        },
+        None => {
+            let row: payout_types::PayoutBatchRow = payout_batch_item
+                .request
+                .clone()
+                .parse_value("PayoutBatchRow")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            let request = payout_types::PayoutCreateRequest {
+                payout_id: Some(payout_batch_item.payout_id.clone()),
+                merchant_order_reference_id: row.merchant_order_reference_id,
+                amount: Some(row.amount.into()),
+                currency: Some(row.currency),
+                connector: row.connector.map(|connector| vec![connector]),
+                confirm: Some(true),
+                payout_type: row.payout_type,
+                auto_fulfill: row.auto_fulfill,
+                customer_id: Some(row.customer_id),
+                description: row.description,
+                payout_token: row.payout_token,
+                profile_id: Some(payout_batch.profile_id.clone()),
+                priority: row.priority,
+                payout_method_id: row.payout_method_id,
+                ..Default::default()
+            };
+
+            match Box::pin(super::payouts_create_core(
+                state.clone(),
+                merchant_context.clone(),
+                request,
+            ))
+            .await
+            {
+                Ok(ApplicationResponse::Json(response))
+                | Ok(ApplicationResponse::JsonWithHeaders((response, _))) => {
+                    storage::PayoutBatchItemUpdate::ResultUpdate {
+                        status: get_payout_batch_item_status(response.status),
+                        payout_status: Some(response.status),
+                        error_code: response.error_code,
+                        error_message: response.error_message,
+                    }
+                }
+                Ok(_) => {
+                    return Err(report!(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Unexpected response from payouts create"));
+                }
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        errors::ApiErrorResponse::InternalServerError
+                    ) =>
+                {
+                    return Err(error);
+                }
+                Err(error) => {
+                    logger::info!(?error, "Failed to create payout of payout batch");
+                    storage::PayoutBatchItemUpdate::ResultUpdate {
+                        status: PayoutBatchItemStatus::Failed,
+                        payout_status: None,
+                        error_code: Some(error.current_context().error_code()),
+                        error_message: Some(error.current_context().error_message()),
+                    }
+                }
+            }
+        }
+    };
+
+    db.update_payout_batch_item_by_merchant_id_batch_id_row_number(
+        &payout_batch_item.merchant_id,
+        &payout_batch_item.batch_id,
+        payout_batch_item.row_number,
+        payout_batch_item_update,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payout batch item")
+}
+
+/// Records the final status of a batch, once all of its payouts have been processed. The counts
+/// are recomputed from the rows of the batch, as the counts updated while processing may be
+/// stale if the batch task was interrupted.
+async fn complete_payout_batch(
+    db: &dyn StorageInterface,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    let payout_batch_items = db
+        .list_payout_batch_items_by_merchant_id_batch_id(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batch items")?;
+    let statuses = payout_batch_items
+        .iter()
+        .map(|payout_batch_item| payout_batch_item.status)
+        .collect::<Vec<_>>();
+
+    let count = |status| {
+        i32::try_from(statuses.iter().filter(|item| **item == status).count())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Number of payouts in the batch does not fit in an i32")
+    };
+
+    db.update_payout_batch_by_merchant_id_batch_id(
+        &payout_batch.merchant_id,
+        &payout_batch.batch_id,
+        storage::PayoutBatchUpdate::ProgressUpdate {
+            status: get_payout_batch_status(&statuses),
+            succeeded_count: count(PayoutBatchItemStatus::Succeeded)?,
+            failed_count: count(PayoutBatchItemStatus::Failed)?,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payout batch")
+}
+
+/// Payouts rejected by the connector, or which could not be sent to the connector, are failed.
+/// The rest have been accepted by the connector, including the ones which are still to be
+/// fulfilled.
+fn get_payout_batch_item_status(payout_status: PayoutStatus) -> PayoutBatchItemStatus {
+    match payout_status {
+        PayoutStatus::Success
+        | PayoutStatus::Initiated
+        | PayoutStatus::Pending
+        | PayoutStatus::RequiresFulfillment
+        | PayoutStatus::RequiresVendorAccountCreation => PayoutBatchItemStatus::Succeeded,
+        PayoutStatus::Failed
+        | PayoutStatus::Cancelled
+        | PayoutStatus::Expired
+        | PayoutStatus::Reversed
+        | PayoutStatus::Ineligible
+        | PayoutStatus::RequiresCreation
+        | PayoutStatus::RequiresConfirmation
+        | PayoutStatus::RequiresPayoutMethodData => PayoutBatchItemStatus::Failed,
+    }
+}
+
+fn get_payout_batch_status(item_statuses: &[PayoutBatchItemStatus]) -> PayoutBatchStatus {
+    if item_statuses.contains(&PayoutBatchItemStatus::Pending) {
+        PayoutBatchStatus::Processing
+    } else if item_statuses.contains(&PayoutBatchItemStatus::Failed) {
+        PayoutBatchStatus::PartiallyFailed
+    } else {
+        PayoutBatchStatus::Completed
+    }
+}
+
+pub(crate) async fn trigger_payout_batch_outgoing_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: &storage::PayoutBatch,
+) -> RouterResult<()> {
+    let Some(event_type) = Option::<EventType>::from(payout_batch.status) else {
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &payout_batch.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: payout_batch.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let payout_batch_response = get_payout_batch_response(payout_batch.clone());
+    let cloned_state = state.clone();
+    let cloned_merchant_context = merchant_context.clone();
+    let batch_id = payout_batch_response.batch_id.clone();
+    let primary_object_created_at = Some(payout_batch_response.created);
+    tokio::spawn(
+        async move {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                cloned_merchant_context,
+                business_profile,
+                event_type,
+                diesel_models::enums::EventClass::PayoutBatches,
+                batch_id,
+                diesel_models::enums::EventObjectType::PayoutBatchDetails,
+                webhooks::OutgoingWebhookContent::PayoutBatchDetails(Box::new(
+                    payout_batch_response,
+                )),
+                primary_object_created_at,
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
+pub(crate) async fn find_payout_batch(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    db.find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payout batch not found".to_owned(),
+        })
+}
+
+pub(crate) fn get_payout_batch_response(
+    payout_batch: storage::PayoutBatch,
+) -> payout_types::PayoutBatchResponse {
+    payout_types::PayoutBatchResponse {
+        batch_id: payout_batch.batch_id,
+        merchant_id: payout_batch.merchant_id,
+        profile_id: payout_batch.profile_id,
+        status: payout_batch.status,
+        total_count: payout_batch.total_count,
+        succeeded_count: payout_batch.succeeded_count,
+        failed_count: payout_batch.failed_count,
+        max_concurrency: payout_batch.max_concurrency,
+        created: payout_batch.created_at,
+        modified_at: payout_batch.modified_at,
+    }
+}
+
+fn get_payout_batch_item_response(
+    payout_batch_item: storage::PayoutBatchItem,
+) -> payout_types::PayoutBatchItemResponse {
+    let merchant_order_reference_id = payout_batch_item
+        .request
+        .get("merchant_order_reference_id")
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned);
+
+    payout_types::PayoutBatchItemResponse {
+        row_number: payout_batch_item.row_number,
+        payout_id: payout_batch_item.payout_id,
+        merchant_order_reference_id,
+        status: payout_batch_item.status,
+        payout_status: payout_batch_item.payout_status,
+        error_code: payout_batch_item.error_code,
+        error_message: payout_batch_item.error_message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_payout_batch_csv() {
+        let data = "\
+amount,currency,customer_id,payout_method_id,payout_token,payout_type,merchant_order_reference_id
+1000,USD,cus_1,pm_1,,bank,seller_1
+2550,EUR,cus_2,,tok_2,,
+";
+        let rows = parse_payout_batch_csv(data.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 2);
+
+        let first_row = rows.first().unwrap();
+        assert_eq!(first_row.amount, MinorUnit::new(1000));
+        assert_eq!(first_row.payout_method_id.as_deref(), Some("pm_1"));
+        assert!(first_row.payout_token.is_none());
+        assert_eq!(first_row.payout_type, Some(common_enums::PayoutType::Bank));
+
+        let second_row = rows.get(1).unwrap();
+        assert_eq!(second_row.currency, common_enums::Currency::EUR);
+        assert!(second_row.payout_type.is_none());
+        assert!(second_row.merchant_order_reference_id.is_none());
+
+        assert!(parse_payout_batch_csv(b"amount,unknown_column\n1000,x\n").is_err());
+    }
+
+    #[test]
+    fn test_payout_batch_status() {
+        use PayoutBatchItemStatus::{Failed, Pending, Succeeded};
+
+        assert_eq!(
+            get_payout_batch_status(&[Succeeded, Pending]),
+            PayoutBatchStatus::Processing
+        );
+        assert_eq!(
+            get_payout_batch_status(&[Succeeded, Failed]),
+            PayoutBatchStatus::PartiallyFailed
+        );
+        assert_eq!(
+            get_payout_batch_status(&[Succeeded, Succeeded]),
+            PayoutBatchStatus::Completed
+        );
+        assert_eq!(
+            get_payout_batch_item_status(PayoutStatus::RequiresFulfillment),
+            PayoutBatchItemStatus::Succeeded
+        );
+        assert_eq!(
+            get_payout_batch_item_status(PayoutStatus::Ineligible),
+            PayoutBatchItemStatus::Failed
+        );
+    }
+}
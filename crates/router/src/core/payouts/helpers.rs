@@ -25,6 +25,7 @@ use crate::{
     consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        gsm,
         payment_methods::{
             cards,
             transformers::{DataDuplicationCheck, StoreCardReq, StoreGenericReq, StoreLockerReq},
@@ -1123,39 +1124,35 @@ pub async fn get_gsm_record(
     flow: &str,
 ) -> Option<hyperswitch_domain_models::gsm::GatewayStatusMap> {
     let connector_name = connector_name.unwrap_or_default();
-    let get_gsm = || async {
-        state.store.find_gsm_rule(
-                connector_name.clone(),
-                flow.to_string(),
-                "sub_flow".to_string(),
-                error_code.clone().unwrap_or_default(), // TODO: make changes in connector to get a mandatory code in case of success or error response
-                error_message.clone().unwrap_or_default(),
-            )
-            .await
-            .map_err(|err| {
-                if err.current_context().is_db_not_found() {
-                    logger::warn!(
-                        "GSM miss for connector - {}, flow - {}, error_code - {:?}, error_message - {:?}",
-                        connector_name,
-                        flow,
-                        error_code,
-                        error_message
-                    );
-                    metrics::AUTO_PAYOUT_RETRY_GSM_MISS_COUNT.add( 1, &[]);
-                } else {
-                    metrics::AUTO_PAYOUT_RETRY_GSM_FETCH_FAILURE_COUNT.add( 1, &[]);
-                };
-                err.change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("failed to fetch decision from gsm")
-            })
-    };
-    get_gsm()
-        .await
-        .inspect_err(|err| {
+    let gsm_rule = gsm::find_matching_gsm_rule(
+        state,
+        connector_name.clone(),
+        flow.to_string(),
+        "sub_flow".to_string(),
+        error_code.as_deref().unwrap_or_default(), // TODO: make changes in connector to get a mandatory code in case of success or error response
+        error_message.as_deref().unwrap_or_default(),
+    )
+    .await;
+    match gsm_rule {
+        Ok(Some(gsm_rule)) => Some(gsm_rule),
+        Ok(None) => {
+            logger::warn!(
+                "GSM miss for connector - {}, flow - {}, error_code - {:?}, error_message - {:?}",
+                connector_name,
+                flow,
+                error_code,
+                error_message
+            );
+            metrics::AUTO_PAYOUT_RETRY_GSM_MISS_COUNT.add(1, &[]);
+            None
+        }
+        Err(err) => {
+            metrics::AUTO_PAYOUT_RETRY_GSM_FETCH_FAILURE_COUNT.add(1, &[]);
             // warn log should suffice here because we are not propagating this error
             logger::warn!(get_gsm_decision_fetch_error=?err, "error fetching gsm decision");
-        })
-        .ok()
+            None
+        }
+    }
 }
 
 pub fn is_payout_initiated(status: api_enums::PayoutStatus) -> bool {
@@ -71,7 +71,8 @@ pub async fn do_gsm_multiple_connector_actions(
 
                 retries = retries.map(|i| i - 1);
             }
-            common_enums::GsmDecision::DoDefault => break,
+            // Payouts are not parked for deferred retries
+            common_enums::GsmDecision::DoDefault | common_enums::GsmDecision::RetryLater => break,
         }
     }
     Ok(())
@@ -126,7 +127,8 @@ pub async fn do_gsm_single_connector_actions(
 
                 retries = retries.map(|i| i - 1);
             }
-            common_enums::GsmDecision::DoDefault => break,
+            // Payouts are not parked for deferred retries
+            common_enums::GsmDecision::DoDefault | common_enums::GsmDecision::RetryLater => break,
         }
     }
     Ok(())
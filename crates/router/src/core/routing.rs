@@ -1,4 +1,11 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod eligibility;
 pub mod helpers;
+#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+pub mod in_process_dynamic_routing;
+pub mod rule_hits;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod simulation;
 pub mod transformers;
 use std::collections::HashSet;
 
@@ -22,11 +29,7 @@ use common_utils::request::Method;
 use diesel_models::routing_algorithm::RoutingAlgorithm;
 use error_stack::ResultExt;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use external_services::grpc_client::dynamic_routing::{
-    contract_routing_client::ContractBasedDynamicRouting,
-    elimination_based_client::EliminationBasedRouting,
-    success_rate_client::SuccessBasedDynamicRouting,
-};
+use external_services::grpc_client::dynamic_routing::contract_routing_client::ContractBasedDynamicRouting;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use helpers::{
     enable_decision_engine_dynamic_routing_setup, update_decision_engine_dynamic_routing_setup,
@@ -55,6 +58,7 @@ use crate::utils::ValueExt;
 use crate::{core::admin, utils::ValueExt};
 use crate::{
     core::{
+        audit_log,
         errors::{self, CustomResult, RouterResponse},
         metrics, utils as core_utils,
     },
@@ -335,7 +339,15 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    let mut new_record: routing_types::RoutingDictionaryRecord = record.foreign_into();
+    new_record.warnings = helpers::get_routing_algorithm_warnings(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        business_profile.get_id(),
+        &request.algorithm,
+        &transaction_type,
+    )
+    .await;
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -512,7 +524,26 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    audit_log::record_audit_log(
+        &state,
+        Some(&record.merchant_id),
+        common_enums::AuditLogResourceType::RoutingAlgorithm,
+        record.algorithm_id.get_string_repr(),
+        common_enums::AuditLogAction::Create,
+        None,
+        Some(&record),
+    )
+    .await;
+
+    let mut new_record: routing_types::RoutingDictionaryRecord = record.foreign_into();
+    new_record.warnings = helpers::get_routing_algorithm_warnings(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        business_profile.get_id(),
+        &algorithm,
+        &transaction_type,
+    )
+    .await;
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -625,7 +656,9 @@ pub async fn link_routing_config(
 
     core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
 
-    match routing_algorithm.kind {
+    // Active algorithm references of the profile before and after the change, keyed by the
+    // profile field holding them, for the audit log
+    let (active_algorithm_before, active_algorithm_after) = match routing_algorithm.kind {
         diesel_models::enums::RoutingAlgorithmKind::Dynamic => {
             let mut dynamic_routing_ref: routing_types::DynamicRoutingAlgorithmRef =
                 business_profile
@@ -638,6 +671,7 @@ pub async fn link_routing_config(
                         "unable to deserialize Dynamic routing algorithm ref from business profile",
                     )?
                     .unwrap_or_default();
+            let dynamic_routing_ref_before = dynamic_routing_ref.clone();
 
             utils::when(
                 matches!(
@@ -823,9 +857,14 @@ pub async fn link_routing_config(
                 key_manager_state,
                 merchant_context.get_merchant_key_store(),
                 business_profile.clone(),
-                dynamic_routing_ref,
+                dynamic_routing_ref.clone(),
             )
             .await?;
+
+            (
+                serde_json::json!({ "dynamic_routing_algorithm": dynamic_routing_ref_before }),
+                serde_json::json!({ "dynamic_routing_algorithm": dynamic_routing_ref }),
+            )
         }
         diesel_models::enums::RoutingAlgorithmKind::Single
         | diesel_models::enums::RoutingAlgorithmKind::Priority
@@ -860,16 +899,23 @@ pub async fn link_routing_config(
                     })
                 },
             )?;
+            let routing_ref_before = routing_ref.clone();
             routing_ref.update_algorithm_id(algorithm_id);
             helpers::update_profile_active_algorithm_ref(
                 db,
                 key_manager_state,
                 merchant_context.get_merchant_key_store(),
                 business_profile.clone(),
-                routing_ref,
+                routing_ref.clone(),
                 &transaction_type,
             )
             .await?;
+
+            let field = get_active_routing_algorithm_field(transaction_type);
+            (
+                serde_json::json!({ field: routing_ref_before }),
+                serde_json::json!({ field: routing_ref }),
+            )
         }
     };
     if let Some(euclid_routing_id) = routing_algorithm.decision_engine_routing_id.clone() {
@@ -896,6 +942,18 @@ pub async fn link_routing_config(
             }
         }
     }
+
+    audit_log::record_audit_log(
+        &state,
+        Some(&routing_algorithm.merchant_id),
+        common_enums::AuditLogResourceType::RoutingAlgorithm,
+        routing_algorithm.algorithm_id.get_string_repr(),
+        common_enums::AuditLogAction::Activate,
+        Some(&active_algorithm_before),
+        Some(&active_algorithm_after),
+    )
+    .await;
+
     metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(
         routing_algorithm.foreign_into(),
@@ -1035,6 +1093,17 @@ pub async fn unlink_routing_config_under_profile(
     }
 }
 
+/// Name of the profile field holding the active routing algorithm of a transaction type
+#[cfg(feature = "v1")]
+fn get_active_routing_algorithm_field(transaction_type: enums::TransactionType) -> &'static str {
+    match transaction_type {
+        enums::TransactionType::Payment => "routing_algorithm",
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => "payout_routing_algorithm",
+        enums::TransactionType::ThreeDsAuthentication => "three_ds_decision_rule_algorithm",
+    }
+}
+
 #[cfg(feature = "v1")]
 pub async fn unlink_routing_config(
     state: SessionState,
@@ -1087,14 +1156,17 @@ pub async fn unlink_routing_config(
 
             let timestamp = common_utils::date_time::now_unix_timestamp();
 
-            match routing_algo_ref.algorithm_id {
+            match routing_algo_ref.algorithm_id.clone() {
                 Some(algorithm_id) => {
                     let routing_algorithm: routing_types::RoutingAlgorithmRef =
                         routing_types::RoutingAlgorithmRef {
                             algorithm_id: None,
                             timestamp,
                             config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                            surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                            surcharge_config_algo_id: routing_algo_ref
+                                .surcharge_config_algo_id
+                                .clone(),
+                            shadow_algorithm_id: routing_algo_ref.shadow_algorithm_id.clone(),
                         };
 
                     let record = db
@@ -1110,11 +1182,23 @@ pub async fn unlink_routing_config(
                         key_manager_state,
                         merchant_context.get_merchant_key_store(),
                         business_profile,
-                        routing_algorithm,
+                        routing_algorithm.clone(),
                         &transaction_type,
                     )
                     .await?;
 
+                    let field = get_active_routing_algorithm_field(transaction_type);
+                    audit_log::record_audit_log(
+                        &state,
+                        Some(merchant_context.get_merchant_account().get_id()),
+                        common_enums::AuditLogResourceType::RoutingAlgorithm,
+                        algorithm_id.get_string_repr(),
+                        common_enums::AuditLogAction::Deactivate,
+                        Some(&serde_json::json!({ field: routing_algo_ref })),
+                        Some(&serde_json::json!({ field: routing_algorithm })),
+                    )
+                    .await;
+
                     metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
                     Ok(service_api::ApplicationResponse::Json(response))
                 }
@@ -1130,6 +1214,289 @@ pub async fn unlink_routing_config(
     }
 }
 
+/// Attaches a static routing algorithm to its profile in shadow mode. The shadow algorithm is
+/// evaluated for every payment alongside the active one, without affecting the connector choice.
+#[cfg(feature = "v1")]
+pub async fn link_shadow_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: routing_algorithm.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    utils::when(
+        matches!(
+            routing_algorithm.kind,
+            diesel_models::enums::RoutingAlgorithmKind::Dynamic
+                | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule
+        ) || routing_algorithm.algorithm_for != enums::TransactionType::Payment,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only static payment routing algorithms can be evaluated in shadow mode"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
+
+    utils::when(
+        routing_ref.algorithm_id.as_ref() == Some(&algorithm_id),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already active".to_string(),
+            })
+        },
+    )?;
+    utils::when(
+        routing_ref.shadow_algorithm_id.as_ref() == Some(&algorithm_id),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already in shadow mode".to_string(),
+            })
+        },
+    )?;
+
+    routing_ref.update_shadow_algorithm_id(Some(algorithm_id));
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        business_profile,
+        routing_ref,
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_algorithm.foreign_into(),
+    ))
+}
+
+/// Detaches the shadow routing algorithm of a profile.
+#[cfg(feature = "v1")]
+pub async fn unlink_shadow_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: routing_types::RoutingConfigRequest,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = request
+        .profile_id
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })
+        .attach_printable("Profile_id not provided")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
+
+    let algorithm_id = routing_ref.shadow_algorithm_id.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No algorithm is in shadow mode".to_string(),
+        },
+    )?;
+
+    let record = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    routing_ref.update_shadow_algorithm_id(None);
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        business_profile,
+        routing_ref,
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        record.foreign_into(),
+    ))
+}
+
+/// Creates an advanced routing algorithm from a program written in the routing DSL.
+#[cfg(feature = "v1")]
+pub async fn import_routing_algorithm_from_dsl(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingDslImportRequest,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    let program: routing_types::ast::Program<routing_types::ConnectorSelection> =
+        routing_types::ast::parser::parse_program(&request.dsl).map_err(|err| {
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid routing DSL: {err}"),
+            }
+        })?;
+
+    let transaction_type = request
+        .transaction_type
+        .unwrap_or(enums::TransactionType::Payment);
+
+    create_routing_algorithm_under_profile(
+        state,
+        merchant_context,
+        authentication_profile_id,
+        routing_types::RoutingConfigRequest {
+            name: Some(request.name),
+            description: Some(request.description),
+            algorithm: Some(routing_types::StaticRoutingAlgorithm::Advanced(program)),
+            profile_id: Some(request.profile_id),
+            transaction_type: Some(transaction_type),
+        },
+        transaction_type,
+    )
+    .await
+}
+
+/// Renders an advanced routing algorithm in the canonical form of the routing DSL.
+#[cfg(feature = "v1")]
+pub async fn export_routing_algorithm_as_dsl(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingDslExportResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    utils::when(
+        routing_algorithm.kind != diesel_models::enums::RoutingAlgorithmKind::Advanced,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only advanced routing algorithms can be exported as DSL".to_string(),
+            })
+        },
+    )?;
+
+    let program = match routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::StaticRoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm")?
+    {
+        routing_types::StaticRoutingAlgorithm::Advanced(program) => program,
+        routing_types::StaticRoutingAlgorithm::Single(_)
+        | routing_types::StaticRoutingAlgorithm::Priority(_)
+        | routing_types::StaticRoutingAlgorithm::VolumeSplit(_)
+        | routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_) => {
+            return Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("advanced routing algorithm holds a non advanced program");
+        }
+    };
+
+    // Some programs, like ones with rules without any conditions, have no textual form. Parsing
+    // the printed program again makes sure that the exported DSL can be imported back.
+    let dsl = routing_types::ast::printer::program(&program);
+    let reparsed: routing_types::ast::Program<routing_types::ConnectorSelection> =
+        routing_types::ast::parser::parse_program(&dsl).map_err(|err| {
+            errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Routing algorithm can't be represented in the routing DSL: {err}"
+                ),
+            }
+        })?;
+    utils::when(
+        routing_types::ast::printer::program(&reparsed) != dsl,
+        || {
+            Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("routing DSL of the algorithm doesn't round trip")
+        },
+    )?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingDslExportResponse {
+            algorithm_id: routing_algorithm.algorithm_id,
+            profile_id: routing_algorithm.profile_id,
+            name: routing_algorithm.name,
+            description: routing_algorithm.description.unwrap_or_default(),
+            dsl,
+        },
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn update_default_fallback_routing(
     state: SessionState,
@@ -1260,6 +1627,17 @@ pub async fn update_default_routing_config(
     )
     .await?;
 
+    audit_log::record_audit_log(
+        &state,
+        Some(merchant_context.get_merchant_account().get_id()),
+        common_enums::AuditLogResourceType::RoutingAlgorithm,
+        &format!("default_fallback_{transaction_type}"),
+        common_enums::AuditLogAction::Update,
+        Some(&default_config),
+        Some(&updated_config),
+    )
+    .await;
+
     metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(updated_config))
 }
@@ -1988,13 +2366,9 @@ pub async fn success_based_routing_update_configs(
     );
 
     if !state.conf.open_router.dynamic_routing_enabled {
-        state
-            .grpc_client
-            .dynamic_routing
-            .as_ref()
-            .async_map(|dr_client| async {
-                dr_client
-                    .success_rate_client
+        helpers::get_success_rate_client(&state)
+            .async_map(|client| async move {
+                client
                     .invalidate_success_rate_routing_keys(
                         profile_id.get_string_repr().into(),
                         state.get_grpc_headers(),
@@ -2092,13 +2466,9 @@ pub async fn elimination_routing_update_configs(
     );
 
     if !state.conf.open_router.dynamic_routing_enabled {
-        state
-            .grpc_client
-            .dynamic_routing
-            .as_ref()
-            .async_map(|dr_client| async {
-                dr_client
-                    .elimination_based_client
+        helpers::get_elimination_client(&state)
+            .async_map(|client| async move {
+                client
                     .invalidate_elimination_bucket(
                         profile_id.get_string_repr().into(),
                         state.get_grpc_headers(),
@@ -0,0 +1,364 @@
+//! Explains why the connectors of a profile are or are not eligible for a payment. Each enabled
+//! payment method type of a connector is checked against the routing constraint graph, which
+//! filters the connectors chosen by the routing algorithm, and against the payment method filter
+//! graph, which filters the payment methods listed to the customer.
+
+use std::str::FromStr;
+
+use api_models::{admin::PaymentMethodsEnabled, enums as api_enums, routing as routing_types};
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use euclid::{
+    backend::inputs as dsl_inputs,
+    dssa::graph::{self as euclid_graph, CgraphExt},
+    enums as euclid_enums,
+    frontend::{ast, dir},
+};
+use hyperswitch_constraint_graph as cgraph;
+use kgraph_utils::transformers::{IntoContext, IntoDirValue};
+use masking::ExposeInterface;
+
+use super::simulation;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        payments::routing as payments_routing,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+    utils::{OptionExt, ValueExt},
+};
+
+pub async fn explain_connector_eligibility(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    request: routing_types::RoutingEligibilityExplainRequest,
+) -> RouterResponse<routing_types::RoutingEligibilityExplainResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        key_store,
+        Some(&request.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let backend_input = match (&request.payment_id, request.context) {
+        (Some(payment_id), _) => {
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    key_manager_state,
+                    payment_id,
+                    merchant_id,
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            if payment_intent.profile_id.as_ref() != Some(business_profile.get_id()) {
+                return Err(errors::ApiErrorResponse::PaymentNotFound.into());
+            }
+
+            let payment_attempt = db
+                .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                    &payment_intent.payment_id,
+                    merchant_id,
+                    payment_intent.active_attempt.get_id().as_str(),
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            let forex_rates =
+                simulation::get_simulation_forex_rates(&state, &business_profile).await;
+            simulation::make_simulation_dsl_input(
+                &business_profile,
+                &payment_intent,
+                &payment_attempt,
+                forex_rates
+                    .as_ref()
+                    .map(|forex_rates| forex_rates.data.as_ref()),
+            )
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The currency of the payment is not known yet".to_string(),
+            })?
+        }
+        (None, Some(context)) => make_context_dsl_input(&state, &business_profile, context).await,
+        (None, None) => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Either payment_id or context must be provided".to_string(),
+            }
+            .into());
+        }
+    };
+
+    let merchant_connector_accounts = db
+        .list_enabled_connector_accounts_by_profile_id(
+            key_manager_state,
+            business_profile.get_id(),
+            key_store,
+            common_enums::ConnectorType::PaymentProcessor,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error when fetching merchant connector accounts")?;
+
+    let routing_graph = payments_routing::get_merchant_cgraph(
+        &state,
+        key_store,
+        business_profile.get_id(),
+        &api_enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the routing constraint graph")?;
+    let pm_filter_graph = cards::get_or_build_pm_filter_graph(
+        &state,
+        merchant_id,
+        business_profile.get_id(),
+        &merchant_connector_accounts,
+    )
+    .await?;
+
+    let requested_payment_method = backend_input.payment_method.payment_method;
+    let requested_payment_method_type = backend_input.payment_method.payment_method_type;
+
+    let mut results = Vec::new();
+    for mca in &merchant_connector_accounts {
+        let payment_methods_enabled =
+            mca.payment_methods_enabled
+                .iter()
+                .flatten()
+                .filter_map(|payment_method| {
+                    payment_method
+                        .clone()
+                        .expose()
+                        .parse_value::<PaymentMethodsEnabled>("PaymentMethodsEnabled")
+                        .ok()
+                });
+
+        for payment_methods_enabled in payment_methods_enabled {
+            let payment_method = payment_methods_enabled.payment_method;
+            if requested_payment_method.is_some_and(|requested| requested != payment_method) {
+                continue;
+            }
+
+            for payment_method_type in payment_methods_enabled
+                .payment_method_types
+                .unwrap_or_default()
+                .into_iter()
+                .map(|payment_method_type_info| payment_method_type_info.payment_method_type)
+            {
+                if requested_payment_method_type
+                    .is_some_and(|requested| requested != payment_method_type)
+                {
+                    continue;
+                }
+
+                let mut candidate_input = backend_input.clone();
+                candidate_input.payment_method.payment_method = Some(payment_method);
+                candidate_input.payment_method.payment_method_type = Some(payment_method_type);
+
+                let routing = explain_routing_eligibility(
+                    &routing_graph,
+                    &mca.connector_name,
+                    candidate_input,
+                )?;
+                let payment_method_list = explain_payment_method_list_eligibility(
+                    &pm_filter_graph,
+                    mca,
+                    payment_method,
+                    payment_method_type,
+                    &backend_input,
+                )?;
+
+                results.push(routing_types::ConnectorEligibilityExplanation {
+                    connector: mca.connector_name.clone(),
+                    merchant_connector_id: mca.get_id(),
+                    payment_method,
+                    payment_method_type,
+                    eligible: routing.eligible && payment_method_list.eligible,
+                    routing,
+                    payment_method_list,
+                });
+            }
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        routing_types::RoutingEligibilityExplainResponse {
+            profile_id: business_profile.get_id().clone(),
+            payment_id: request.payment_id,
+            results,
+        },
+    ))
+}
+
+/// Builds the routing input of a hypothetical payment. Off session payments are treated as
+/// mandate setups, which are zero amount when no amount is charged.
+async fn make_context_dsl_input(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    context: routing_types::RoutingEligibilityContext,
+) -> dsl_inputs::BackendInput {
+    let payment_type = match context.setup_future_usage {
+        Some(common_enums::FutureUsage::OffSession) if context.amount == MinorUnit::zero() => {
+            euclid_enums::PaymentType::SetupMandate
+        }
+        Some(common_enums::FutureUsage::OffSession) => euclid_enums::PaymentType::NewMandate,
+        Some(common_enums::FutureUsage::OnSession) | None => euclid_enums::PaymentType::NonMandate,
+    };
+
+    let mut payment = dsl_inputs::PaymentInput {
+        amount: context.amount,
+        amount_in_base_currency: None,
+        currency: context.currency,
+        authentication_type: context.authentication_type,
+        card_bin: None,
+        capture_method: context.capture_method,
+        business_country: None,
+        billing_country: context
+            .billing_country
+            .map(common_enums::Country::from_alpha2),
+        business_label: None,
+        setup_future_usage: context.setup_future_usage,
+    };
+    payment.amount_in_base_currency =
+        payments_routing::make_dsl_amount_in_base_currency(state, business_profile, &payment).await;
+
+    dsl_inputs::BackendInput {
+        metadata: None,
+        payment,
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: context.payment_method,
+            payment_method_type: context.payment_method_type,
+            card_network: context.card_network,
+        },
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: Some(payment_type),
+        },
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+        time: payments_routing::make_dsl_time_input(business_profile),
+    }
+}
+
+/// Checks the connector against the routing constraint graph the same way
+/// `perform_cgraph_filtering` does.
+fn explain_routing_eligibility(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    connector_name: &str,
+    backend_input: dsl_inputs::BackendInput,
+) -> RouterResult<routing_types::EligibilityCheckResult> {
+    let Ok(connector) = api_enums::RoutableConnectors::from_str(connector_name) else {
+        return Ok(routing_types::EligibilityCheckResult {
+            eligible: false,
+            failed_constraints: vec![format!("`{connector_name}` is not a routable connector")],
+        });
+    };
+
+    let context = euclid_graph::AnalysisContext::from_dir_values(
+        backend_input
+            .into_context()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct the routing analysis context")?,
+    );
+    let failed_constraints = graph
+        .explain_value_validity(
+            dir::DirValue::Connector(Box::new(ast::ConnectorChoice { connector })),
+            &context,
+            &mut cgraph::Memoization::new(),
+            &mut cgraph::CycleCheck::new(),
+            None,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to analyse the routing constraint graph")?;
+
+    Ok(routing_types::EligibilityCheckResult {
+        eligible: failed_constraints.is_empty(),
+        failed_constraints,
+    })
+}
+
+/// Checks the payment method type against the payment method filters of the connector, with the
+/// context `filter_payment_methods` builds while listing payment methods. The amount, recurring
+/// and installment filters of the payment method list request are not covered.
+fn explain_payment_method_list_eligibility(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    payment_method: common_enums::PaymentMethod,
+    payment_method_type: common_enums::PaymentMethodType,
+    backend_input: &dsl_inputs::BackendInput,
+) -> RouterResult<routing_types::EligibilityCheckResult> {
+    let pm_dir_value: dir::DirValue = (payment_method_type, payment_method)
+        .into_dir_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("pm_value_node not created")?;
+
+    let mut context_values = vec![
+        pm_dir_value.clone(),
+        dir::DirValue::PaymentCurrency(backend_input.payment.currency),
+        dir::DirValue::PaymentType(
+            backend_input
+                .mandate
+                .payment_type
+                .unwrap_or(euclid_enums::PaymentType::NonMandate),
+        ),
+    ];
+    if let Some(billing_country) = backend_input.payment.billing_country {
+        context_values.push(dir::DirValue::BillingCountry(billing_country));
+    }
+    if let Some(capture_method) = backend_input.payment.capture_method {
+        context_values.push(dir::DirValue::CaptureMethod(capture_method));
+    }
+    if let Ok(connector) =
+        api_enums::RoutableConnectors::from_str(&merchant_connector_account.connector_name)
+    {
+        context_values.push(dir::DirValue::Connector(Box::new(ast::ConnectorChoice {
+            connector,
+        })));
+    }
+
+    let context = euclid_graph::AnalysisContext::from_dir_values(context_values);
+    let domain_ident = [merchant_connector_account
+        .get_id()
+        .get_string_repr()
+        .to_string()];
+    // The analysis trace of an error only lives as long as the memoization
+    let mut memo = cgraph::Memoization::new();
+    let failed_constraints = match graph.key_value_analysis(
+        pm_dir_value,
+        &context,
+        &mut memo,
+        &mut cgraph::CycleCheck::new(),
+        Some(&domain_ident),
+    ) {
+        Ok(()) => Vec::new(),
+        Err(error) => euclid_graph::explain_graph_error(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to analyse the payment method filter graph")?,
+    };
+
+    Ok(routing_types::EligibilityCheckResult {
+        eligible: failed_constraints.is_empty(),
+        failed_constraints,
+    })
+}
@@ -30,7 +30,6 @@ use external_services::grpc_client::dynamic_routing::{
 use hyperswitch_domain_models::api::ApplicationResponse;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use hyperswitch_interfaces::events::routing_api_logs as routing_events;
-#[cfg(feature = "v1")]
 use router_env::logger;
 #[cfg(feature = "v1")]
 use router_env::{instrument, tracing};
@@ -579,6 +578,45 @@ pub async fn validate_connectors_in_routing_config(
     Ok(())
 }
 
+/// Runs the program level analyses of an advanced routing algorithm against the profile's
+/// knowledge graph, returning the shadowed, redundant and unreachable rules as warnings.
+///
+/// The analyses never fail the request, any error is only logged.
+pub async fn get_routing_algorithm_warnings(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    routing_algorithm: &routing_types::StaticRoutingAlgorithm,
+    transaction_type: &storage::enums::TransactionType,
+) -> Option<Vec<euclid::dssa::types::RuleAnalysisWarning>> {
+    let routing_types::StaticRoutingAlgorithm::Advanced(program) = routing_algorithm else {
+        return None;
+    };
+
+    let knowledge_graph = crate::core::payments::routing::get_merchant_cgraph(
+        state,
+        key_store,
+        profile_id,
+        transaction_type,
+    )
+    .await
+    .map_err(|error| logger::warn!(?error, "Failed to get the knowledge graph of the profile"))
+    .ok()
+    .and_then(|merchant_graph| {
+        hyperswitch_constraint_graph::ConstraintGraph::combine(
+            &merchant_graph,
+            &euclid::dssa::truth::ANALYSIS_GRAPH,
+        )
+        .map_err(|error| logger::warn!(?error, "Failed to combine the knowledge graphs"))
+        .ok()
+    });
+
+    euclid::dssa::analyzer::analyze_rule_reachability(program.clone(), knowledge_graph.as_ref())
+        .map_err(|error| logger::warn!(?error, "Failed to analyze the routing algorithm"))
+        .ok()
+        .filter(|warnings| !warnings.is_empty())
+}
+
 /// Provides the identifier for the specific merchant's routing_dictionary_key
 #[inline(always)]
 pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
@@ -731,6 +769,30 @@ impl DynamicRoutingCache for routing_types::EliminationRoutingConfig {
     }
 }
 
+/// Success rate client of the dynamic routing gRPC service if it is configured, otherwise the
+/// in-process engine if it is enabled
+#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+pub fn get_success_rate_client(
+    state: &SessionState,
+) -> Option<Box<dyn SuccessBasedDynamicRouting>> {
+    match state.grpc_client.dynamic_routing.as_ref() {
+        Some(dr_client) => Some(Box::new(dr_client.success_rate_client.clone())),
+        None => routing::in_process_dynamic_routing::InProcessDynamicRouting::from_state(state)
+            .map(|engine| Box::new(engine) as Box<dyn SuccessBasedDynamicRouting>),
+    }
+}
+
+/// Elimination client of the dynamic routing gRPC service if it is configured, otherwise the
+/// in-process engine if it is enabled
+#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+pub fn get_elimination_client(state: &SessionState) -> Option<Box<dyn EliminationBasedRouting>> {
+    match state.grpc_client.dynamic_routing.as_ref() {
+        Some(dr_client) => Some(Box::new(dr_client.elimination_based_client.clone())),
+        None => routing::in_process_dynamic_routing::InProcessDynamicRouting::from_state(state)
+            .map(|engine| Box::new(engine) as Box<dyn EliminationBasedRouting>),
+    }
+}
+
 /// Cfetch dynamic routing configs
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 #[instrument(skip_all)]
@@ -845,14 +907,11 @@ pub async fn push_metrics_with_update_window_for_success_based_routing(
 ) -> RouterResult<()> {
     if let Some(success_based_algo_ref) = dynamic_routing_algo_ref.success_based_algorithm {
         if success_based_algo_ref.enabled_feature != routing_types::DynamicRoutingFeatures::None {
-            let client = &state
-                .grpc_client
-                .dynamic_routing
-                .as_ref()
-                .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
-                    message: "dynamic routing gRPC client not found".to_string(),
-                })?
-                .success_rate_client;
+            let client = get_success_rate_client(state).ok_or(
+                errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "dynamic routing client not found".to_string(),
+                },
+            )?;
 
             let payment_connector = &payment_attempt.connector.clone().ok_or(
                 errors::ApiErrorResponse::GenericNotFoundError {
@@ -1214,14 +1273,11 @@ pub async fn update_window_for_elimination_routing(
 ) -> RouterResult<()> {
     if let Some(elimination_algo_ref) = dynamic_algo_ref.elimination_routing_algorithm {
         if elimination_algo_ref.enabled_feature != routing_types::DynamicRoutingFeatures::None {
-            let client = &state
-                .grpc_client
-                .dynamic_routing
-                .as_ref()
-                .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
-                    message: "dynamic routing gRPC client not found".to_string(),
-                })?
-                .elimination_based_client;
+            let client = get_elimination_client(state).ok_or(
+                errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "dynamic routing client not found".to_string(),
+                },
+            )?;
 
             let elimination_routing_config = fetch_dynamic_routing_configs::<
                 routing_types::EliminationRoutingConfig,
@@ -0,0 +1,783 @@
+//! Redis backed implementation of success rate and elimination based dynamic routing.
+//!
+//! This is used in place of the dynamic routing gRPC service when it is not configured, and
+//! implements the same client interfaces so that the routing flows don't need to know which
+//! engine they are talking to.
+//!
+//! Every window is scoped to a profile (`id`), the interpolated routing params (payment method,
+//! currency, card network etc.) and a label (`connector:merchant_connector_id`). Global windows
+//! are maintained per connector and are shared across profiles.
+
+use std::{collections::HashMap, sync::Arc};
+
+use api_models::routing::{
+    EliminationAnalyserConfig, EliminationRoutingConfig, RoutableConnectorChoice,
+    RoutableConnectorChoiceWithBucketName, RoutableConnectorChoiceWithStatus,
+    SuccessBasedRoutingConfig,
+};
+use common_utils::{date_time, transformers::ForeignTryFrom};
+use error_stack::ResultExt;
+use external_services::grpc_client::{
+    dynamic_routing::{
+        elimination_based_client::{
+            elimination_rate::{
+                invalidate_bucket_response, update_elimination_bucket_response, BucketInformation,
+                EliminationInformation, LabelWithStatus as EliminationLabel,
+            },
+            EliminationBasedRouting, EliminationBucketConfig, EliminationResponse,
+            InvalidateBucketResponse, UpdateEliminationBucketResponse,
+        },
+        success_rate_client::{
+            success_rate::{
+                invalidate_windows_response, update_success_rate_window_response, LabelWithScore,
+                RoutingApproach,
+            },
+            CalGlobalSuccessRateResponse, CalSuccessRateConfig, CalSuccessRateResponse,
+            InvalidateWindowsResponse, ProtoSpecificityLevel, SuccessBasedDynamicRouting,
+            UpdateSuccessRateWindowConfig, UpdateSuccessRateWindowResponse,
+        },
+        DynamicRoutingError, DynamicRoutingResult,
+    },
+    GrpcHeaders,
+};
+use rand::{seq::SliceRandom, Rng};
+use redis_interface::{RedisConnectionPool, RedisKey};
+use router_env::{instrument, logger, tracing};
+
+use crate::{consts, routes::SessionState};
+
+const SUCCESS_RATE_PREFIX: &str = "dynamic_routing_sr";
+const ELIMINATION_PREFIX: &str = "dynamic_routing_elimination";
+
+/// Outcome of an attempt as stored in the success rate window, `{unix_timestamp}:{1|0}`
+struct WindowEntry {
+    recorded_at: i64,
+    is_success: bool,
+}
+
+impl WindowEntry {
+    fn new(is_success: bool) -> Self {
+        Self {
+            recorded_at: date_time::now_unix_timestamp(),
+            is_success,
+        }
+    }
+
+    fn parse(entry: &str) -> Option<Self> {
+        let (recorded_at, status) = entry.split_once(':')?;
+        Some(Self {
+            recorded_at: recorded_at.parse().ok()?,
+            is_success: status == "1",
+        })
+    }
+}
+
+impl std::fmt::Display for WindowEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.recorded_at, u8::from(self.is_success))
+    }
+}
+
+/// The size of the sliding window used for success rate calculation.
+///
+/// A window is made up of `max_aggregates_size` blocks, each holding `max_total_count` outcomes.
+/// If `block_duration_in_secs` is present, outcomes older than
+/// `max_aggregates_size * block_duration_in_secs` are not considered either.
+struct SuccessRateWindow {
+    max_aggregates_size: u64,
+    max_total_count: u64,
+    block_duration_in_secs: Option<i64>,
+}
+
+impl SuccessRateWindow {
+    fn capacity(&self) -> i64 {
+        i64::try_from(self.max_aggregates_size * self.max_total_count).unwrap_or(i64::MAX)
+    }
+
+    fn ttl_in_secs(&self) -> i64 {
+        self.block_duration_in_secs
+            .zip(i64::try_from(self.max_aggregates_size).ok())
+            .map(|(duration, size)| {
+                duration
+                    .saturating_mul(size)
+                    .min(consts::IN_PROCESS_DYNAMIC_ROUTING_WINDOW_TTL)
+            })
+            .unwrap_or(consts::IN_PROCESS_DYNAMIC_ROUTING_WINDOW_TTL)
+    }
+
+    fn oldest_valid_timestamp(&self, now: i64) -> Option<i64> {
+        self.block_duration_in_secs
+            .map(|_| now.saturating_sub(self.ttl_in_secs()))
+    }
+}
+
+impl TryFrom<&SuccessBasedRoutingConfig> for SuccessRateWindow {
+    type Error = error_stack::Report<DynamicRoutingError>;
+
+    fn try_from(config: &SuccessBasedRoutingConfig) -> Result<Self, Self::Error> {
+        let window_config =
+            UpdateSuccessRateWindowConfig::foreign_try_from(get_success_rate_config_body(config)?)?;
+        let current_block_threshold = window_config.current_block_threshold.ok_or(
+            DynamicRoutingError::MissingRequiredField {
+                field: "current_block_threshold".to_string(),
+            },
+        )?;
+
+        Ok(Self {
+            max_aggregates_size: u64::from(window_config.max_aggregates_size.max(1)),
+            max_total_count: current_block_threshold.max_total_count.max(1),
+            block_duration_in_secs: current_block_threshold
+                .duration_in_mins
+                .and_then(|duration| i64::try_from(duration.saturating_mul(60)).ok()),
+        })
+    }
+}
+
+fn get_success_rate_config_body(
+    config: &SuccessBasedRoutingConfig,
+) -> DynamicRoutingResult<api_models::routing::SuccessBasedRoutingConfigBody> {
+    config
+        .config
+        .clone()
+        .or_else(|| SuccessBasedRoutingConfig::default().config)
+        .ok_or(DynamicRoutingError::MissingRequiredField {
+            field: "config".to_string(),
+        })
+        .map_err(error_stack::Report::from)
+}
+
+/// Success rate and elimination routing engine backed by the router's redis
+#[derive(Clone)]
+pub struct InProcessDynamicRouting {
+    redis_conn: Arc<RedisConnectionPool>,
+}
+
+impl InProcessDynamicRouting {
+    pub fn new(redis_conn: Arc<RedisConnectionPool>) -> Self {
+        Self { redis_conn }
+    }
+
+    /// Builds the engine if it is enabled in the configuration
+    pub fn from_state(state: &SessionState) -> Option<Self> {
+        if !state.conf.in_process_dynamic_routing.enabled {
+            return None;
+        }
+
+        state
+            .store
+            .get_redis_conn()
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to get redis connection for in-process dynamic routing"
+                )
+            })
+            .ok()
+            .map(Self::new)
+    }
+
+    /// Windows of an id are invalidated by bumping its generation, the old windows expire on
+    /// their own. The generation outlives every window of the id, so that none of the old
+    /// windows are picked up again.
+    async fn get_generation(&self, prefix: &str, id: &str) -> DynamicRoutingResult<i64> {
+        self.redis_conn
+            .get_key::<Option<i64>>(&get_generation_key(prefix, id))
+            .await
+            .map(Option::unwrap_or_default)
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to fetch the dynamic routing window generation".to_string(),
+            ))
+    }
+
+    async fn bump_generation(&self, prefix: &str, id: &str) -> DynamicRoutingResult<()> {
+        self.redis_conn
+            .set_key_with_expiry(
+                &get_generation_key(prefix, id),
+                date_time::now_unix_timestamp(),
+                consts::IN_PROCESS_DYNAMIC_ROUTING_WINDOW_TTL,
+            )
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to invalidate the dynamic routing windows".to_string(),
+            ))
+    }
+
+    async fn get_window_score(
+        &self,
+        key: &RedisKey,
+        window: &SuccessRateWindow,
+        min_aggregates_size: u32,
+        default_success_rate: f64,
+    ) -> DynamicRoutingResult<f64> {
+        let now = date_time::now_unix_timestamp();
+        let oldest_valid_timestamp = window.oldest_valid_timestamp(now);
+
+        let entries = self
+            .redis_conn
+            .get_list_elements(key, 0, -1)
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to fetch the success rate window".to_string(),
+            ))?
+            .iter()
+            .filter_map(|entry| WindowEntry::parse(entry))
+            .filter(|entry| {
+                oldest_valid_timestamp.map_or(true, |oldest| entry.recorded_at >= oldest)
+            })
+            .collect::<Vec<_>>();
+
+        let total_count = u64::try_from(entries.len()).unwrap_or(u64::MAX);
+        let aggregates = total_count / window.max_total_count;
+
+        if total_count == 0 || aggregates < u64::from(min_aggregates_size) {
+            return Ok(default_success_rate);
+        }
+
+        let success_count = entries.iter().filter(|entry| entry.is_success).count();
+        #[allow(clippy::as_conversions)]
+        let success_rate = success_count as f64 * 100.0 / entries.len() as f64;
+
+        Ok(success_rate)
+    }
+
+    async fn push_to_window(
+        &self,
+        key: &RedisKey,
+        window: &SuccessRateWindow,
+        is_success: bool,
+    ) -> DynamicRoutingResult<()> {
+        let update_window = || async {
+            self.redis_conn
+                .append_elements_to_list(key, WindowEntry::new(is_success).to_string())
+                .await?;
+            self.redis_conn
+                .trim_list(key, -window.capacity(), -1)
+                .await?;
+            self.redis_conn.set_expiry(key, window.ttl_in_secs()).await
+        };
+
+        update_window()
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to update the success rate window".to_string(),
+            ))
+    }
+
+    async fn get_scores(
+        &self,
+        keys_with_labels: Vec<(RedisKey, String)>,
+        window: &SuccessRateWindow,
+        min_aggregates_size: u32,
+        default_success_rate: f64,
+    ) -> DynamicRoutingResult<Vec<LabelWithScore>> {
+        let scores = futures::future::try_join_all(keys_with_labels.iter().map(|(key, _)| {
+            self.get_window_score(key, window, min_aggregates_size, default_success_rate)
+        }))
+        .await?;
+
+        Ok(keys_with_labels
+            .into_iter()
+            .zip(scores)
+            .map(|((_, label), score)| LabelWithScore { score, label })
+            .collect())
+    }
+
+    /// Returns the buckets in which the label has crossed the bucket size
+    async fn get_eliminated_buckets(
+        &self,
+        key: &RedisKey,
+        config: &EliminationBucketConfig,
+    ) -> DynamicRoutingResult<Vec<String>> {
+        let slots = self
+            .redis_conn
+            .get_hash_fields::<HashMap<String, u64>>(key)
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to fetch the elimination buckets".to_string(),
+            ))?;
+
+        let current_slot = get_current_slot(config);
+        let mut eliminated_buckets = group_slots_by_bucket(slots)
+            .into_iter()
+            .filter(|(_, bucket_slots)| {
+                get_bucket_level(bucket_slots, current_slot, config.bucket_size)
+                    >= config.bucket_size
+            })
+            .map(|(bucket_name, _)| bucket_name)
+            .collect::<Vec<_>>();
+        eliminated_buckets.sort();
+
+        Ok(eliminated_buckets)
+    }
+
+    async fn push_to_bucket(
+        &self,
+        key: &RedisKey,
+        bucket_name: &str,
+        config: &EliminationBucketConfig,
+    ) -> DynamicRoutingResult<()> {
+        let current_slot = get_current_slot(config);
+        let oldest_relevant_slot = current_slot.saturating_sub(config.bucket_size);
+
+        let update_bucket = || async {
+            self.redis_conn
+                .increment_fields_in_hash(key, &[(format!("{bucket_name}:{current_slot}"), 1)])
+                .await?;
+
+            let stale_fields = self
+                .redis_conn
+                .get_hash_fields::<HashMap<String, u64>>(key)
+                .await?
+                .into_keys()
+                .filter(|field| {
+                    parse_slot_field(field).map_or(true, |(_, slot)| slot < oldest_relevant_slot)
+                })
+                .collect::<Vec<_>>();
+            if !stale_fields.is_empty() {
+                self.redis_conn
+                    .delete_fields_from_hash(key, stale_fields)
+                    .await?;
+            }
+
+            self.redis_conn
+                .set_expiry(
+                    key,
+                    i64::try_from(
+                        config
+                            .bucket_leak_interval_in_secs
+                            .saturating_mul(config.bucket_size.saturating_add(1)),
+                    )
+                    .unwrap_or(consts::IN_PROCESS_DYNAMIC_ROUTING_WINDOW_TTL)
+                    .min(consts::IN_PROCESS_DYNAMIC_ROUTING_WINDOW_TTL),
+                )
+                .await
+        };
+
+        update_bucket()
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to update the elimination bucket".to_string(),
+            ))
+    }
+}
+
+fn get_generation_key(prefix: &str, id: &str) -> RedisKey {
+    format!("{prefix}_generation_{id}").as_str().into()
+}
+
+fn get_entity_key(prefix: &str, id: &str, generation: i64, params: &str, label: &str) -> RedisKey {
+    format!("{prefix}_{id}_{generation}_{params}_{label}")
+        .as_str()
+        .into()
+}
+
+fn get_global_key(prefix: &str, params: &str, connector: &str) -> RedisKey {
+    format!("{prefix}_global_{params}_{connector}")
+        .as_str()
+        .into()
+}
+
+fn get_current_slot(config: &EliminationBucketConfig) -> u64 {
+    u64::try_from(date_time::now_unix_timestamp()).unwrap_or_default()
+        / config.bucket_leak_interval_in_secs.max(1)
+}
+
+/// Bucket fields are stored as `{bucket_name}:{slot}`, where a slot is one leak interval
+fn parse_slot_field(field: &str) -> Option<(&str, u64)> {
+    let (bucket_name, slot) = field.rsplit_once(':')?;
+    Some((bucket_name, slot.parse().ok()?))
+}
+
+fn group_slots_by_bucket(slots: HashMap<String, u64>) -> HashMap<String, Vec<(u64, u64)>> {
+    slots
+        .iter()
+        .filter_map(|(field, count)| {
+            parse_slot_field(field)
+                .map(|(bucket_name, slot)| (bucket_name.to_owned(), slot, *count))
+        })
+        .fold(HashMap::new(), |mut buckets, (bucket_name, slot, count)| {
+            buckets
+                .entry(bucket_name)
+                .or_insert_with(Vec::new)
+                .push((slot, count));
+            buckets
+        })
+}
+
+/// Replays the failures recorded in each slot through a leaky bucket which leaks one failure
+/// per slot, and returns the level of the bucket in the current slot.
+fn get_bucket_level(slots: &[(u64, u64)], current_slot: u64, bucket_size: u64) -> u64 {
+    let mut slots = slots
+        .iter()
+        .filter(|(slot, _)| *slot <= current_slot)
+        .copied()
+        .collect::<Vec<_>>();
+    slots.sort_by_key(|(slot, _)| *slot);
+
+    let (level, last_slot) =
+        slots
+            .into_iter()
+            .fold((0u64, None::<u64>), |(level, last_slot), (slot, count)| {
+                let leaked = last_slot.map_or(0, |last_slot| slot - last_slot);
+                (
+                    level
+                        .saturating_sub(leaked)
+                        .saturating_add(count)
+                        .min(bucket_size),
+                    Some(slot),
+                )
+            });
+
+    last_slot.map_or(0, |last_slot| {
+        level.saturating_sub(current_slot - last_slot)
+    })
+}
+
+/// Orders the labels by score, or shuffles them when exploring
+fn rank_labels(
+    mut labels_with_score: Vec<LabelWithScore>,
+    config: &CalSuccessRateConfig,
+) -> (Vec<LabelWithScore>, RoutingApproach) {
+    let mut rng = rand::thread_rng();
+
+    let should_explore = config
+        .exploration_percent
+        .is_some_and(|percent| rng.gen_range(0.0..100.0) < percent);
+
+    if should_explore {
+        labels_with_score.shuffle(&mut rng);
+        return (labels_with_score, RoutingApproach::Exploration);
+    }
+
+    if config.shuffle_on_tie_during_exploitation.unwrap_or(false) {
+        labels_with_score.shuffle(&mut rng);
+    }
+    labels_with_score.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    (labels_with_score, RoutingApproach::Exploitation)
+}
+
+#[async_trait::async_trait]
+impl SuccessBasedDynamicRouting for InProcessDynamicRouting {
+    #[instrument(skip_all)]
+    async fn calculate_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        _headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalSuccessRateResponse> {
+        let config = CalSuccessRateConfig::foreign_try_from(get_success_rate_config_body(
+            &success_rate_based_config,
+        )?)?;
+        let window = SuccessRateWindow::try_from(&success_rate_based_config)?;
+
+        let keys_with_labels = if config.specificity_level
+            == Some(ProtoSpecificityLevel::Global.into())
+        {
+            label_input
+                .into_iter()
+                .map(|choice| {
+                    (
+                        get_global_key(SUCCESS_RATE_PREFIX, &params, &choice.connector.to_string()),
+                        choice.to_string(),
+                    )
+                })
+                .collect()
+        } else {
+            let generation = self.get_generation(SUCCESS_RATE_PREFIX, &id).await?;
+            label_input
+                .into_iter()
+                .map(|choice| {
+                    let label = choice.to_string();
+                    (
+                        get_entity_key(SUCCESS_RATE_PREFIX, &id, generation, &params, &label),
+                        label,
+                    )
+                })
+                .collect()
+        };
+
+        let labels_with_score = self
+            .get_scores(
+                keys_with_labels,
+                &window,
+                config.min_aggregates_size,
+                config.default_success_rate,
+            )
+            .await?;
+        let (labels_with_score, routing_approach) = rank_labels(labels_with_score, &config);
+
+        let response = CalSuccessRateResponse {
+            labels_with_score,
+            routing_approach: routing_approach.into(),
+        };
+
+        logger::info!(in_process_dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoiceWithStatus>,
+        _headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateSuccessRateWindowResponse> {
+        let window = SuccessRateWindow::try_from(&success_rate_based_config)?;
+        let generation = self.get_generation(SUCCESS_RATE_PREFIX, &id).await?;
+
+        for choice_with_status in label_input {
+            let choice = choice_with_status.routable_connector_choice;
+            let entity_key = get_entity_key(
+                SUCCESS_RATE_PREFIX,
+                &id,
+                generation,
+                &params,
+                &choice.to_string(),
+            );
+            let global_key =
+                get_global_key(SUCCESS_RATE_PREFIX, &params, &choice.connector.to_string());
+
+            self.push_to_window(&entity_key, &window, choice_with_status.status)
+                .await?;
+            self.push_to_window(&global_key, &window, choice_with_status.status)
+                .await?;
+        }
+
+        Ok(UpdateSuccessRateWindowResponse {
+            status: update_success_rate_window_response::UpdationStatus::WindowUpdationSucceeded
+                .into(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_success_rate_routing_keys(
+        &self,
+        id: String,
+        _headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateWindowsResponse> {
+        self.bump_generation(SUCCESS_RATE_PREFIX, &id).await?;
+
+        Ok(InvalidateWindowsResponse {
+            status: invalidate_windows_response::InvalidationStatus::WindowInvalidationSucceeded
+                .into(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn calculate_entity_and_global_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        _headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalGlobalSuccessRateResponse> {
+        let config = CalSuccessRateConfig::foreign_try_from(get_success_rate_config_body(
+            &success_rate_based_config,
+        )?)?;
+        let window = SuccessRateWindow::try_from(&success_rate_based_config)?;
+        let generation = self.get_generation(SUCCESS_RATE_PREFIX, &id).await?;
+
+        let entity_keys_with_labels = label_input
+            .iter()
+            .map(|choice| {
+                let label = choice.to_string();
+                (
+                    get_entity_key(SUCCESS_RATE_PREFIX, &id, generation, &params, &label),
+                    label,
+                )
+            })
+            .collect();
+
+        let mut global_connectors = label_input
+            .iter()
+            .map(|choice| choice.connector.to_string())
+            .collect::<Vec<_>>();
+        global_connectors.sort();
+        global_connectors.dedup();
+        let global_keys_with_labels = global_connectors
+            .into_iter()
+            .map(|connector| {
+                (
+                    get_global_key(SUCCESS_RATE_PREFIX, &params, &connector),
+                    connector,
+                )
+            })
+            .collect();
+
+        let mut entity_scores_with_labels = self
+            .get_scores(
+                entity_keys_with_labels,
+                &window,
+                config.min_aggregates_size,
+                config.default_success_rate,
+            )
+            .await?;
+        let mut global_scores_with_labels = self
+            .get_scores(
+                global_keys_with_labels,
+                &window,
+                config.min_aggregates_size,
+                config.default_success_rate,
+            )
+            .await?;
+
+        entity_scores_with_labels.sort_by(|a, b| b.score.total_cmp(&a.score));
+        global_scores_with_labels.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        Ok(CalGlobalSuccessRateResponse {
+            entity_scores_with_labels,
+            global_scores_with_labels,
+        })
+    }
+}
+
+fn get_elimination_config(
+    config: Option<EliminationAnalyserConfig>,
+) -> DynamicRoutingResult<EliminationBucketConfig> {
+    config
+        .or_else(|| EliminationRoutingConfig::default().elimination_analyser_config)
+        .ok_or(DynamicRoutingError::MissingRequiredField {
+            field: "elimination_analyser_config".to_string(),
+        })
+        .map_err(error_stack::Report::from)
+        .and_then(EliminationBucketConfig::foreign_try_from)
+}
+
+#[async_trait::async_trait]
+impl EliminationBasedRouting for InProcessDynamicRouting {
+    #[instrument(skip_all)]
+    async fn perform_elimination_routing(
+        &self,
+        id: String,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        configs: Option<EliminationAnalyserConfig>,
+        _headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<EliminationResponse> {
+        let config = get_elimination_config(configs)?;
+        let generation = self.get_generation(ELIMINATION_PREFIX, &id).await?;
+
+        let mut labels_with_status = Vec::with_capacity(label_input.len());
+        for choice in label_input {
+            let label = choice.to_string();
+            let entity_buckets = self
+                .get_eliminated_buckets(
+                    &get_entity_key(ELIMINATION_PREFIX, &id, generation, &params, &label),
+                    &config,
+                )
+                .await?;
+            let global_buckets = self
+                .get_eliminated_buckets(
+                    &get_global_key(ELIMINATION_PREFIX, &params, &choice.connector.to_string()),
+                    &config,
+                )
+                .await?;
+
+            labels_with_status.push(EliminationLabel {
+                label,
+                elimination_information: Some(EliminationInformation {
+                    entity: Some(BucketInformation {
+                        is_eliminated: !entity_buckets.is_empty(),
+                        bucket_name: entity_buckets,
+                    }),
+                    global: Some(BucketInformation {
+                        is_eliminated: !global_buckets.is_empty(),
+                        bucket_name: global_buckets,
+                    }),
+                }),
+            });
+        }
+
+        let response = EliminationResponse { labels_with_status };
+
+        logger::info!(in_process_dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_elimination_bucket_config(
+        &self,
+        id: String,
+        params: String,
+        report: Vec<RoutableConnectorChoiceWithBucketName>,
+        configs: Option<EliminationAnalyserConfig>,
+        _headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateEliminationBucketResponse> {
+        let config = get_elimination_config(configs)?;
+        let generation = self.get_generation(ELIMINATION_PREFIX, &id).await?;
+
+        for choice_with_bucket in report {
+            let choice = choice_with_bucket.routable_connector_choice;
+            let entity_key = get_entity_key(
+                ELIMINATION_PREFIX,
+                &id,
+                generation,
+                &params,
+                &choice.to_string(),
+            );
+            let global_key =
+                get_global_key(ELIMINATION_PREFIX, &params, &choice.connector.to_string());
+
+            self.push_to_bucket(&entity_key, &choice_with_bucket.bucket_name, &config)
+                .await?;
+            self.push_to_bucket(&global_key, &choice_with_bucket.bucket_name, &config)
+                .await?;
+        }
+
+        Ok(UpdateEliminationBucketResponse {
+            status: update_elimination_bucket_response::UpdationStatus::BucketUpdationSucceeded
+                .into(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_elimination_bucket(
+        &self,
+        id: String,
+        _headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateBucketResponse> {
+        self.bump_generation(ELIMINATION_PREFIX, &id).await?;
+
+        Ok(InvalidateBucketResponse {
+            status: invalidate_bucket_response::InvalidationStatus::BucketInvalidationSucceeded
+                .into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_bucket_level_leaks_one_failure_per_slot() {
+        // 3 failures at slot 10, 1 leaked by slot 11 and 2 more added
+        let slots = vec![(10, 3), (11, 2)];
+        assert_eq!(get_bucket_level(&slots, 11, 5), 4);
+        assert_eq!(get_bucket_level(&slots, 13, 5), 2);
+        assert_eq!(get_bucket_level(&slots, 20, 5), 0);
+    }
+
+    #[test]
+    fn test_bucket_level_is_capped_at_bucket_size() {
+        let slots = vec![(10, 10)];
+        assert_eq!(get_bucket_level(&slots, 10, 5), 5);
+        assert_eq!(get_bucket_level(&slots, 12, 5), 3);
+    }
+
+    #[test]
+    fn test_window_entry_round_trip() {
+        let entry = WindowEntry::parse(&WindowEntry::new(true).to_string()).unwrap();
+        assert!(entry.is_success);
+        assert!(WindowEntry::parse("invalid").is_none());
+    }
+}
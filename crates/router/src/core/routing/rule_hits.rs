@@ -0,0 +1,401 @@
+//! Hit counts and last hit timestamps of the rules of advanced routing algorithms.
+//!
+//! The hits of an algorithm are counted in hourly buckets, kept in one redis hash per day which
+//! expires once all of its hours are older than `ROUTING_RULE_HITS_RETENTION_IN_HOURS`. The last
+//! hit timestamps are kept in a separate hash. Decisions made by the default selection of the
+//! program are tracked alongside the rules, without a rule name.
+
+use std::{collections::HashMap, fmt, ops::RangeInclusive};
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+use api_models::routing as routing_types;
+use common_utils::{date_time, errors::CustomResult, id_type};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use diesel_models::enums as storage_enums;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use error_stack::ResultExt;
+use redis_interface::{errors::RedisError, RedisKey};
+use router_env::{logger, tracing::Instrument};
+
+use crate::{consts, routes::SessionState};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
+    services::ApplicationResponse,
+    types::domain,
+    utils::{OptionExt, ValueExt},
+};
+
+const RULE_HITS_PREFIX: &str = "routing_rule_hits";
+const HIT_COUNT_FIELD: &str = "hits";
+const LAST_HIT_FIELD: &str = "last_hit";
+const SECONDS_PER_HOUR: i64 = 60 * 60;
+const HOURS_PER_DAY: i64 = 24;
+
+/// Fields of the rule hits hashes, a `None` rule name stands for the default selection
+#[derive(Debug, PartialEq)]
+enum RuleHitField<'a> {
+    /// `hits:{hour}` or `hits:{hour}:{rule_name}`, the number of hits within the hour
+    HitCount {
+        hour: i64,
+        rule_name: Option<&'a str>,
+    },
+    /// `last_hit` or `last_hit:{rule_name}`, the unix timestamp of the last hit
+    LastHit { rule_name: Option<&'a str> },
+}
+
+impl<'a> RuleHitField<'a> {
+    fn parse(field: &'a str) -> Option<Self> {
+        if let Some(hit_count) = field.strip_prefix(HIT_COUNT_FIELD) {
+            let hit_count = hit_count.strip_prefix(':')?;
+            let (hour, rule_name) = match hit_count.split_once(':') {
+                Some((hour, rule_name)) => (hour, Some(rule_name)),
+                None => (hit_count, None),
+            };
+            Some(Self::HitCount {
+                hour: hour.parse().ok()?,
+                rule_name,
+            })
+        } else {
+            let last_hit = field.strip_prefix(LAST_HIT_FIELD)?;
+            let rule_name = match last_hit {
+                "" => None,
+                _ => Some(last_hit.strip_prefix(':')?),
+            };
+            Some(Self::LastHit { rule_name })
+        }
+    }
+}
+
+impl fmt::Display for RuleHitField<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HitCount {
+                hour,
+                rule_name: None,
+            } => write!(f, "{HIT_COUNT_FIELD}:{hour}"),
+            Self::HitCount {
+                hour,
+                rule_name: Some(rule_name),
+            } => write!(f, "{HIT_COUNT_FIELD}:{hour}:{rule_name}"),
+            Self::LastHit { rule_name: None } => write!(f, "{LAST_HIT_FIELD}"),
+            Self::LastHit {
+                rule_name: Some(rule_name),
+            } => write!(f, "{LAST_HIT_FIELD}:{rule_name}"),
+        }
+    }
+}
+
+/// Key of the hash holding the hit counts of the hours of a day
+fn get_hit_counts_key(algorithm_id: &id_type::RoutingId, day: i64) -> RedisKey {
+    format!(
+        "{RULE_HITS_PREFIX}_{}_{day}",
+        algorithm_id.get_string_repr()
+    )
+    .as_str()
+    .into()
+}
+
+/// Key of the hash holding the last hit timestamps
+fn get_last_hits_key(algorithm_id: &id_type::RoutingId) -> RedisKey {
+    format!(
+        "{RULE_HITS_PREFIX}_{}_{LAST_HIT_FIELD}",
+        algorithm_id.get_string_repr()
+    )
+    .as_str()
+    .into()
+}
+
+/// Unix timestamp at which the hit counts of a day expire, once its last hour is no longer
+/// retained
+fn get_hit_counts_expiry(day: i64) -> i64 {
+    ((day + 1) * HOURS_PER_DAY + consts::ROUTING_RULE_HITS_RETENTION_IN_HOURS - 1)
+        * SECONDS_PER_HOUR
+}
+
+/// Hours of the requested time range that are still retained at `now`
+fn get_retained_hours(start_time: i64, end_time: i64, now: i64) -> RangeInclusive<i64> {
+    let oldest_retained_hour =
+        now / SECONDS_PER_HOUR - consts::ROUTING_RULE_HITS_RETENTION_IN_HOURS + 1;
+    (start_time / SECONDS_PER_HOUR).max(oldest_retained_hour)..=end_time / SECONDS_PER_HOUR
+}
+
+/// Records a decision of an advanced routing algorithm in the background, so that the payment
+/// isn't held up by the bookkeeping. Failures are only logged.
+pub fn record_rule_hit(
+    state: &SessionState,
+    algorithm_id: &id_type::RoutingId,
+    rule_name: Option<String>,
+) {
+    let state = state.clone();
+    let algorithm_id = algorithm_id.clone();
+    tokio::spawn(
+        async move {
+            increment_rule_hit(&state, &algorithm_id, rule_name.as_deref())
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to record the hit of the routing rule")
+                })
+        }
+        .in_current_span(),
+    );
+}
+
+/// Counts a decision of an advanced routing algorithm in the bucket of the current hour, and
+/// updates the last hit timestamp of the rule
+pub async fn increment_rule_hit(
+    state: &SessionState,
+    algorithm_id: &id_type::RoutingId,
+    rule_name: Option<&str>,
+) -> CustomResult<(), RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+    let now = date_time::now_unix_timestamp();
+    let hour = now / SECONDS_PER_HOUR;
+    let day = hour / HOURS_PER_DAY;
+    let hit_counts_key = get_hit_counts_key(algorithm_id, day);
+
+    let hit_counts = redis_conn
+        .increment_fields_in_hash(
+            &hit_counts_key,
+            &[(RuleHitField::HitCount { hour, rule_name }, 1)],
+        )
+        .await?;
+
+    // The expiry only needs to be set when a new bucket is opened
+    if hit_counts.first() == Some(&1) {
+        redis_conn
+            .set_expire_at(&hit_counts_key, get_hit_counts_expiry(day))
+            .await?;
+    }
+
+    redis_conn
+        .set_hash_fields(
+            &get_last_hits_key(algorithm_id),
+            vec![(
+                RuleHitField::LastHit { rule_name }.to_string(),
+                now.to_string(),
+            )],
+            Some(consts::ROUTING_RULE_HITS_RETENTION_IN_HOURS * SECONDS_PER_HOUR),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Hit count and last hit timestamp of a rule, or of the default selection
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[derive(Debug, Default)]
+struct RuleHitStats {
+    hit_count: u64,
+    last_hit_at: Option<i64>,
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn retrieve_rule_hits(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    algorithm_id: id_type::RoutingId,
+    query: routing_types::RoutingRuleHitsQuery,
+) -> RouterResponse<routing_types::RoutingRuleHitsResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let start_time = query.start_time.assume_utc().unix_timestamp();
+    let end_time = query
+        .end_time
+        .unwrap_or_else(date_time::now)
+        .assume_utc()
+        .unix_timestamp();
+    if end_time < start_time {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "end_time should not be earlier than start_time".to_string(),
+        }
+        .into());
+    }
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(&algorithm_id, merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        key_store,
+        Some(&routing_algorithm.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    if routing_algorithm.kind != storage_enums::RoutingAlgorithmKind::Advanced {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Rule hits are only recorded for advanced routing algorithms".to_string(),
+        }
+        .into());
+    }
+    let routing_types::StaticRoutingAlgorithm::Advanced(program) = routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::StaticRoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to deserialize routing algorithm")?
+    else {
+        return Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Advanced routing algorithm does not contain a program");
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let hours = get_retained_hours(start_time, end_time, date_time::now_unix_timestamp());
+    let days = hours.start() / HOURS_PER_DAY..=hours.end() / HOURS_PER_DAY;
+    let hit_counts = futures::future::try_join_all(days.map(|day| {
+        let redis_conn = redis_conn.clone();
+        let hit_counts_key = get_hit_counts_key(&algorithm_id, day);
+        async move {
+            redis_conn
+                .get_hash_fields::<HashMap<String, i64>>(&hit_counts_key)
+                .await
+        }
+    }))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the routing rule hit counts")?;
+    let last_hits = redis_conn
+        .get_hash_fields::<HashMap<String, i64>>(&get_last_hits_key(&algorithm_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing rule last hits")?;
+
+    let mut stats = HashMap::<Option<&str>, RuleHitStats>::new();
+    for (field, value) in hit_counts.iter().flatten().chain(&last_hits) {
+        match RuleHitField::parse(field) {
+            Some(RuleHitField::HitCount { hour, rule_name }) if hours.contains(&hour) => {
+                stats.entry(rule_name).or_default().hit_count +=
+                    u64::try_from(*value).unwrap_or_default();
+            }
+            Some(RuleHitField::LastHit { rule_name }) => {
+                stats.entry(rule_name).or_default().last_hit_at = Some(*value);
+            }
+            Some(RuleHitField::HitCount { .. }) | None => {}
+        }
+    }
+
+    let to_date_time = |timestamp: i64| {
+        time::OffsetDateTime::from_unix_timestamp(timestamp)
+            .ok()
+            .map(|date_time| time::PrimitiveDateTime::new(date_time.date(), date_time.time()))
+    };
+
+    let mut rule_names = Vec::new();
+    for rule in &program.rules {
+        if !rule_names.contains(&rule.name.as_str()) {
+            rule_names.push(rule.name.as_str());
+        }
+    }
+    let rules = rule_names
+        .into_iter()
+        .map(|rule_name| {
+            let rule_stats = stats.get(&Some(rule_name));
+            routing_types::RoutingRuleHits {
+                rule_name: rule_name.to_string(),
+                hit_count: rule_stats.map_or(0, |rule_stats| rule_stats.hit_count),
+                last_hit_at: rule_stats
+                    .and_then(|rule_stats| rule_stats.last_hit_at)
+                    .and_then(to_date_time),
+            }
+        })
+        .collect();
+    let default_selection = stats.get(&None);
+
+    Ok(ApplicationResponse::Json(
+        routing_types::RoutingRuleHitsResponse {
+            algorithm_id,
+            profile_id: business_profile.get_id().clone(),
+            rules,
+            default_selection_hit_count: default_selection
+                .map_or(0, |default_selection| default_selection.hit_count),
+            default_selection_last_hit_at: default_selection
+                .and_then(|default_selection| default_selection.last_hit_at)
+                .and_then(to_date_time),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_hit_field_round_trip() {
+        let fields = [
+            RuleHitField::HitCount {
+                hour: 481_234,
+                rule_name: Some("card_rule"),
+            },
+            RuleHitField::HitCount {
+                hour: 481_234,
+                rule_name: None,
+            },
+            RuleHitField::LastHit {
+                rule_name: Some("card_rule"),
+            },
+            RuleHitField::LastHit { rule_name: None },
+        ];
+        for field in fields {
+            let formatted = field.to_string();
+            assert_eq!(RuleHitField::parse(&formatted), Some(field));
+        }
+    }
+
+    #[test]
+    fn test_rule_hit_field_rejects_unknown_fields() {
+        assert_eq!(RuleHitField::parse("hits"), None);
+        assert_eq!(RuleHitField::parse("hits:not_an_hour:card_rule"), None);
+        assert_eq!(RuleHitField::parse("last_hits"), None);
+        assert_eq!(RuleHitField::parse("misses:481234"), None);
+    }
+
+    #[test]
+    fn test_hit_counts_expire_once_no_longer_retained() {
+        let now = 1_765_060_200;
+        let hours = get_retained_hours(0, now, now);
+        let (oldest_day, newest_day) = (hours.start() / HOURS_PER_DAY, hours.end() / HOURS_PER_DAY);
+
+        assert_eq!(
+            hours.end() - hours.start() + 1,
+            consts::ROUTING_RULE_HITS_RETENTION_IN_HOURS
+        );
+        // Buckets of the retained days are still alive, older ones have expired
+        for day in oldest_day..=newest_day {
+            assert!(get_hit_counts_expiry(day) > now);
+        }
+        assert!(get_hit_counts_expiry(oldest_day - 1) <= now);
+    }
+
+    #[test]
+    fn test_retained_hours_of_time_range() {
+        let now = 1_765_060_200;
+        let hour = now / SECONDS_PER_HOUR;
+        let start_time = now - 2 * SECONDS_PER_HOUR;
+
+        assert_eq!(get_retained_hours(start_time, now, now), hour - 2..=hour);
+        assert!(get_retained_hours(
+            0,
+            now - consts::ROUTING_RULE_HITS_RETENTION_IN_HOURS * SECONDS_PER_HOUR,
+            now
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,616 @@
+//! Replays a static routing algorithm against historical payment attempts of a profile, to
+//! estimate how the connector volumes would shift if the algorithm was activated.
+
+use std::{
+    collections::{hash_map, BTreeMap, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use api_models::routing::{self as routing_types, RoutingSimulationReportFormat};
+use common_utils::{ext_traits::ValueExt, id_type};
+use currency_conversion::types::ExchangeRates;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use euclid::{
+    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    enums as euclid_enums,
+    frontend::dir,
+};
+use hyperswitch_domain_models::{
+    mandates::MandateDataType, payments::payment_intent::PaymentIntentFetchConstraints,
+};
+use masking::ExposeInterface;
+use rand::{distributions::Distribution, SeedableRng};
+use router_env::logger;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::routing as payments_routing,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, transformers::ForeignInto},
+    utils::{currency, OptionExt},
+};
+
+const SIMULATION_DEFAULT_LIMIT: u32 = 1000;
+/// The maximum number of payment attempts replayed in a single simulation
+const SIMULATION_MAX_LIMIT: u32 = 10_000;
+
+/// The decision of the simulated algorithm for a single payment attempt
+struct SimulatedDecision {
+    connector: Option<String>,
+    rule_name: Option<String>,
+}
+
+type BreakdownKey = (
+    Option<common_enums::PaymentMethod>,
+    Option<common_enums::Currency>,
+    Option<common_enums::CardNetwork>,
+);
+
+/// Per connector counts of actual and simulated decisions
+#[derive(Default)]
+struct ConnectorVolumes(BTreeMap<String, (u64, u64)>);
+
+impl ConnectorVolumes {
+    fn record(&mut self, actual_connector: &str, simulated_connector: Option<&str>) {
+        self.0.entry(actual_connector.to_string()).or_default().0 += 1;
+        if let Some(simulated_connector) = simulated_connector {
+            self.0.entry(simulated_connector.to_string()).or_default().1 += 1;
+        }
+    }
+
+    fn total_count(&self) -> u64 {
+        self.0.values().map(|(actual_count, _)| actual_count).sum()
+    }
+
+    fn into_response(self) -> Vec<routing_types::RoutingSimulationConnectorVolume> {
+        self.0
+            .into_iter()
+            .map(|(connector, (actual_count, simulated_count))| {
+                routing_types::RoutingSimulationConnectorVolume {
+                    connector,
+                    actual_count,
+                    simulated_count,
+                }
+            })
+            .collect()
+    }
+}
+
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    algorithm_id: id_type::RoutingId,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let limit = request.limit.unwrap_or(SIMULATION_DEFAULT_LIMIT);
+    if limit == 0 || limit > SIMULATION_MAX_LIMIT {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be between 1 and {SIMULATION_MAX_LIMIT}"),
+        }
+        .into());
+    }
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(&algorithm_id, merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        key_store,
+        Some(&routing_algorithm.profile_id),
+        merchant_id,
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    if routing_algorithm.kind == storage_enums::RoutingAlgorithmKind::Dynamic
+        || routing_algorithm.algorithm_for != storage_enums::TransactionType::Payment
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Only static payment routing algorithms can be simulated".to_string(),
+        }
+        .into());
+    }
+
+    let algorithm = routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::StaticRoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to deserialize routing algorithm")?;
+    let interpreter = match &algorithm {
+        routing_types::StaticRoutingAlgorithm::Advanced(program) => Some(
+            backend::VirInterpreterBackend::with_program(program.clone())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error initializing DSL interpreter backend")?,
+        ),
+        routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_) => {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only static payment routing algorithms can be simulated".to_string(),
+            }
+            .into());
+        }
+        routing_types::StaticRoutingAlgorithm::Single(_)
+        | routing_types::StaticRoutingAlgorithm::Priority(_)
+        | routing_types::StaticRoutingAlgorithm::VolumeSplit(_) => None,
+    };
+
+    let mut constraints = PaymentIntentFetchConstraints::from(request.time_range);
+    if let PaymentIntentFetchConstraints::List(params) = &mut constraints {
+        params.profile_id = Some(vec![business_profile.get_id().clone()]);
+        params.limit = Some(limit);
+    }
+    let payments = db
+        .get_filtered_payment_intents_attempt(
+            key_manager_state,
+            merchant_id,
+            &constraints,
+            key_store,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment attempts for routing simulation")?;
+
+    // The constraint graph and the forex rates are shared by all the replayed payments
+    let cgraph = payments_routing::get_merchant_cgraph(
+        &state,
+        key_store,
+        business_profile.get_id(),
+        &storage_enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the routing constraint graph")?;
+    let forex_rates = get_simulation_forex_rates(&state, &business_profile).await;
+
+    let mut total_count = 0;
+    let mut skipped_count = 0;
+    let mut connector_volumes = ConnectorVolumes::default();
+    let mut breakdown = HashMap::<BreakdownKey, ConnectorVolumes>::new();
+    let mut changed_attempts = Vec::new();
+
+    for (payment_intent, payment_attempt) in payments {
+        let Some(actual_connector) = payment_attempt.connector.clone() else {
+            continue;
+        };
+        total_count += 1;
+
+        let backend_input = make_simulation_dsl_input(
+            &business_profile,
+            &payment_intent,
+            &payment_attempt,
+            forex_rates
+                .as_ref()
+                .map(|forex_rates| forex_rates.data.as_ref()),
+        );
+        let decision = match backend_input {
+            Some(backend_input) => simulate_decision(
+                &cgraph,
+                &algorithm,
+                interpreter.as_ref(),
+                &payment_attempt.payment_id,
+                backend_input,
+            ),
+            None => Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to reconstruct the routing input of the payment"),
+        };
+        let decision = match decision {
+            Ok(decision) => decision,
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    payment_id = ?payment_attempt.payment_id,
+                    "routing_simulation: skipping payment attempt"
+                );
+                skipped_count += 1;
+                continue;
+            }
+        };
+
+        let card_network = payment_attempt.extract_card_network();
+        let currency = payment_attempt.currency.or(payment_intent.currency);
+        connector_volumes.record(&actual_connector, decision.connector.as_deref());
+        breakdown
+            .entry((
+                payment_attempt.payment_method,
+                currency,
+                card_network.clone(),
+            ))
+            .or_default()
+            .record(&actual_connector, decision.connector.as_deref());
+
+        if decision.connector.as_deref() != Some(actual_connector.as_str()) {
+            changed_attempts.push(routing_types::RoutingSimulationAttempt {
+                payment_id: payment_attempt.payment_id.clone(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                payment_method: payment_attempt.payment_method,
+                payment_method_type: payment_attempt.payment_method_type,
+                currency,
+                card_network,
+                amount: payment_attempt.get_total_amount().get_amount_as_i64(),
+                actual_connector,
+                simulated_connector: decision.connector,
+                matched_rule: decision.rule_name,
+            });
+        }
+    }
+
+    if matches!(request.report_format, RoutingSimulationReportFormat::Csv) {
+        let mut csv_writer = csv::Writer::from_writer(Vec::new());
+        for attempt in changed_attempts {
+            csv_writer
+                .serialize(attempt)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write routing simulation report")?;
+        }
+        let file_data = csv_writer
+            .into_inner()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write routing simulation report")?;
+
+        return Ok(ApplicationResponse::FileData((file_data, mime::TEXT_CSV)));
+    }
+
+    // The largest segments of the sample are listed first
+    let mut breakdown = breakdown.into_iter().collect::<Vec<_>>();
+    breakdown.sort_by_key(|(_, volumes)| std::cmp::Reverse(volumes.total_count()));
+
+    Ok(ApplicationResponse::Json(
+        routing_types::RoutingSimulationResponse {
+            algorithm_id,
+            profile_id: business_profile.get_id().clone(),
+            total_count,
+            changed_count: u64::try_from(changed_attempts.len()).unwrap_or(u64::MAX),
+            skipped_count,
+            connector_volumes: connector_volumes.into_response(),
+            breakdown: breakdown
+                .into_iter()
+                .map(|((payment_method, currency, card_network), volumes)| {
+                    routing_types::RoutingSimulationBreakdown {
+                        payment_method,
+                        currency,
+                        card_network,
+                        connector_volumes: volumes.into_response(),
+                    }
+                })
+                .collect(),
+            changed_attempts,
+        },
+    ))
+}
+
+/// Runs the algorithm for a single payment, followed by the eligibility analysis of the profile.
+/// The simulated connector is the first eligible one, as it would have been tried first.
+fn simulate_decision(
+    cgraph: &hyperswitch_constraint_graph::ConstraintGraph<dir::DirValue>,
+    algorithm: &routing_types::StaticRoutingAlgorithm,
+    interpreter: Option<&backend::VirInterpreterBackend<routing_types::ConnectorSelection>>,
+    payment_id: &id_type::PaymentId,
+    backend_input: dsl_inputs::BackendInput,
+) -> RouterResult<SimulatedDecision> {
+    let (choices, rule_name) = match (algorithm, interpreter) {
+        (routing_types::StaticRoutingAlgorithm::Single(choice), _) => {
+            (vec![(**choice).clone()], None)
+        }
+        (routing_types::StaticRoutingAlgorithm::Priority(choices), _) => (choices.clone(), None),
+        (routing_types::StaticRoutingAlgorithm::VolumeSplit(splits), _) => {
+            (simulate_volume_split(splits.clone(), payment_id)?, None)
+        }
+        (routing_types::StaticRoutingAlgorithm::Advanced(_), Some(interpreter)) => {
+            let output = interpreter
+                .execute(backend_input.clone())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to execute the routing algorithm")?;
+            let selection: routing_types::StaticRoutingAlgorithm =
+                output.connector_selection.foreign_into();
+            let choices = match selection {
+                routing_types::StaticRoutingAlgorithm::Priority(choices) => choices,
+                routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
+                    simulate_volume_split(splits, payment_id)?
+                }
+                _ => Err(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unexpected output of the routing algorithm")?,
+            };
+            (choices, output.rule_name)
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unsupported routing algorithm")?,
+    };
+
+    let eligible =
+        payments_routing::perform_cgraph_filtering_with_graph(cgraph, choices, backend_input, None)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to perform eligibility analysis")?;
+
+    Ok(SimulatedDecision {
+        connector: eligible.first().map(|choice| choice.connector.to_string()),
+        rule_name,
+    })
+}
+
+/// Picks the connector of a volume split deterministically from the payment id, so that repeated
+/// simulations over the same payments report the same decisions.
+fn simulate_volume_split(
+    mut splits: Vec<routing_types::ConnectorVolumeSplit>,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let weights: Vec<u8> = splits.iter().map(|split| split.split).collect();
+    let weighted_index = rand::distributions::WeightedIndex::new(weights)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error creating weighted distribution for volume split")?;
+
+    let mut hasher = hash_map::DefaultHasher::new();
+    payment_id.get_string_repr().hash(&mut hasher);
+    let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(hasher.finish());
+    let idx = weighted_index.sample(&mut rng);
+
+    if idx < splits.len() {
+        let picked = splits.remove(idx);
+        splits.insert(0, picked);
+    }
+
+    Ok(splits.into_iter().map(|split| split.connector).collect())
+}
+
+/// Fetches the forex rates used to convert the amounts of historical payments to the routing base
+/// currency of the profile, if it has one. The conversion is skipped when they can't be fetched.
+pub(super) async fn get_simulation_forex_rates(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+) -> Option<currency::FxExchangeRatesCacheEntry> {
+    business_profile.routing_base_currency?;
+
+    currency::get_forex_rates(
+        state,
+        state
+            .conf
+            .forex_api
+            .get_inner()
+            .data_expiration_delay_in_seconds,
+    )
+    .await
+    .map_err(|error| logger::warn!(?error, "routing_simulation: unable to fetch forex rates"))
+    .ok()
+}
+
+/// Reconstructs the routing input of a historical payment from what was persisted for it. Fields
+/// that aren't stored, such as the full card number, are approximated from the additional payment
+/// method data, and the time based inputs are evaluated at the creation time of the attempt.
+pub(super) fn make_simulation_dsl_input(
+    business_profile: &domain::Profile,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    forex_rates: Option<&ExchangeRates>,
+) -> Option<dsl_inputs::BackendInput> {
+    let currency = payment_attempt.currency.or(payment_intent.currency)?;
+
+    let mandate = dsl_inputs::MandateData {
+        mandate_acceptance_type: None,
+        mandate_type: payment_attempt
+            .mandate_details
+            .as_ref()
+            .map(|mandate_details| match mandate_details {
+                MandateDataType::SingleUse(_) => euclid_enums::MandateType::SingleUse,
+                MandateDataType::MultiUse(_) => euclid_enums::MandateType::MultiUse,
+            }),
+        payment_type: Some(
+            payment_attempt
+                .mandate_details
+                .as_ref()
+                .map_or(euclid_enums::PaymentType::NonMandate, |_| {
+                    euclid_enums::PaymentType::SetupMandate
+                }),
+        ),
+    };
+
+    let payment_method = dsl_inputs::PaymentMethodInput {
+        payment_method: payment_attempt.payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        card_network: payment_attempt.extract_card_network(),
+    };
+
+    let billing_country = payment_intent
+        .billing_details
+        .clone()
+        .and_then(|billing_details| {
+            billing_details
+                .into_inner()
+                .expose()
+                .parse_value::<api_models::payments::Address>("Address")
+                .ok()
+        })
+        .and_then(|address| address.address)
+        .and_then(|address| address.country)
+        .map(common_enums::Country::from_alpha2);
+
+    let mut payment = dsl_inputs::PaymentInput {
+        amount: payment_attempt.get_total_amount(),
+        amount_in_base_currency: None,
+        currency,
+        authentication_type: payment_attempt.authentication_type,
+        card_bin: payment_attempt
+            .get_payment_method_data()
+            .and_then(|payment_method_data| payment_method_data.get_additional_card_info())
+            .and_then(|card_info| card_info.card_isin),
+        capture_method: payment_attempt
+            .capture_method
+            .and_then(|capture_method| capture_method.foreign_into()),
+        business_country: payment_intent
+            .business_country
+            .map(common_enums::Country::from_alpha2),
+        billing_country,
+        business_label: payment_intent.business_label.clone(),
+        setup_future_usage: payment_intent.setup_future_usage,
+    };
+    payment.amount_in_base_currency = business_profile
+        .routing_base_currency
+        .zip(forex_rates)
+        .and_then(|(routing_base_currency, forex_rates)| {
+            currency::convert_minor_unit_amount_with_rates(
+                forex_rates,
+                payment.amount,
+                payment.currency,
+                routing_base_currency,
+            )
+            .map_err(|error| {
+                logger::warn!(
+                    ?error,
+                    "routing_simulation: unable to convert amount to the routing base currency"
+                )
+            })
+            .ok()
+        });
+
+    let metadata = payment_intent
+        .parse_and_get_metadata("routing_parameters")
+        .unwrap_or(None);
+
+    Some(dsl_inputs::BackendInput {
+        metadata,
+        payment,
+        payment_method,
+        mandate,
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+        time: payments_routing::make_dsl_time_input_at(
+            business_profile,
+            payment_attempt.created_at,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    fn connector_choice(
+        connector: common_enums::RoutableConnectors,
+    ) -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: None,
+        }
+    }
+
+    fn volume_splits(
+        splits: &[(common_enums::RoutableConnectors, u8)],
+    ) -> Vec<routing_types::ConnectorVolumeSplit> {
+        splits
+            .iter()
+            .map(|(connector, split)| routing_types::ConnectorVolumeSplit {
+                connector: connector_choice(*connector),
+                split: *split,
+            })
+            .collect()
+    }
+
+    fn payment_id(index: usize) -> id_type::PaymentId {
+        id_type::PaymentId::try_from(std::borrow::Cow::Owned(format!("pay_{index}")))
+            .expect("valid payment id")
+    }
+
+    #[test]
+    fn test_simulate_volume_split_is_deterministic() {
+        let splits = volume_splits(&[
+            (common_enums::RoutableConnectors::Stripe, 50),
+            (common_enums::RoutableConnectors::Adyen, 30),
+            (common_enums::RoutableConnectors::Checkout, 20),
+        ]);
+
+        for index in 0..100 {
+            let first =
+                simulate_volume_split(splits.clone(), &payment_id(index)).expect("volume split");
+            let second =
+                simulate_volume_split(splits.clone(), &payment_id(index)).expect("volume split");
+            assert_eq!(first, second);
+
+            // The picked connector is moved to the front, every connector is kept as a fallback
+            assert_eq!(first.len(), splits.len());
+            for split in &splits {
+                assert!(first.contains(&split.connector));
+            }
+        }
+    }
+
+    #[test]
+    fn test_simulate_volume_split_follows_the_weights() {
+        let splits = volume_splits(&[
+            (common_enums::RoutableConnectors::Stripe, 80),
+            (common_enums::RoutableConnectors::Adyen, 20),
+            (common_enums::RoutableConnectors::Checkout, 0),
+        ]);
+
+        let mut picks = HashMap::<common_enums::RoutableConnectors, usize>::new();
+        for index in 0..1000 {
+            let choices =
+                simulate_volume_split(splits.clone(), &payment_id(index)).expect("volume split");
+            let picked = choices.first().expect("picked connector").connector;
+            *picks.entry(picked).or_default() += 1;
+        }
+
+        let stripe_picks = picks
+            .get(&common_enums::RoutableConnectors::Stripe)
+            .copied()
+            .unwrap_or_default();
+        assert!(
+            (700..=900).contains(&stripe_picks),
+            "stripe picked {stripe_picks} times"
+        );
+        assert!(!picks.contains_key(&common_enums::RoutableConnectors::Checkout));
+    }
+
+    #[test]
+    fn test_simulate_volume_split_rejects_zero_weights() {
+        let splits = volume_splits(&[
+            (common_enums::RoutableConnectors::Stripe, 0),
+            (common_enums::RoutableConnectors::Adyen, 0),
+        ]);
+
+        assert!(simulate_volume_split(splits, &payment_id(0)).is_err());
+    }
+
+    #[test]
+    fn test_connector_volumes() {
+        let mut volumes = ConnectorVolumes::default();
+        volumes.record("stripe", Some("adyen"));
+        volumes.record("stripe", Some("stripe"));
+        volumes.record("adyen", None);
+
+        assert_eq!(volumes.total_count(), 3);
+        let volumes = volumes
+            .into_response()
+            .into_iter()
+            .map(|volume| {
+                (
+                    volume.connector,
+                    volume.actual_count,
+                    volume.simulated_count,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            volumes,
+            vec![("adyen".to_string(), 1, 1), ("stripe".to_string(), 2, 1),]
+        );
+    }
+}
@@ -0,0 +1,1495 @@
+use std::str::FromStr;
+
+use api_models::{subscription as subscription_types, webhooks};
+use common_enums::{
+    EventType, IntentStatus, MandateStatus, SubscriptionInvoiceStatus, SubscriptionPlanInterval,
+    SubscriptionStatus,
+};
+use common_utils::{date_time, ext_traits::StringExt, fp_utils::when, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+use scheduler::{types::process_data, utils as scheduler_utils};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, SessionState},
+    services::{self, ApplicationResponse},
+    types::{api, domain, storage},
+};
+
+const SUBSCRIPTION_ID_PREFIX: &str = "sub";
+const SUBSCRIPTION_PLAN_ID_PREFIX: &str = "plan";
+const SUBSCRIPTION_INVOICE_ID_PREFIX: &str = "inv";
+
+const SUBSCRIPTION_RENEWAL_TAG: &str = "SUBSCRIPTION";
+const SUBSCRIPTION_RENEWAL_NAME: &str = "SUBSCRIPTION_RENEWAL";
+const SUBSCRIPTION_RENEWAL_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::SubscriptionRenewalWorkflow;
+
+/// Interval at which the payment of an invoice is synced while it is being processed
+const SUBSCRIPTION_INVOICE_PAYMENT_SYNC_INTERVAL: time::Duration = time::Duration::hours(1);
+
+/// The tracking data of the process tracker task that renews a subscription at the end of each
+/// billing period, and retries failed renewal payments.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubscriptionRenewalTrackingData {
+    pub subscription_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
+
+#[instrument(skip_all)]
+pub async fn create_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionPlanCreateRequest,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        merchant_context.get_merchant_key_store(),
+        request.profile_id.as_ref().or(profile_id.as_ref()),
+        merchant_id,
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "profile_id",
+    })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &business_profile)?;
+
+    let interval_count = request.interval_count.unwrap_or(1);
+    when(interval_count == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`interval_count` must be at least 1".to_owned(),
+        })
+    })?;
+    when(request.amount <= MinorUnit::zero(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`amount` must be greater than 0".to_owned(),
+        })
+    })?;
+
+    let now = date_time::now();
+    let subscription_plan_new = storage::SubscriptionPlanNew {
+        plan_id: common_utils::generate_id(consts::ID_LENGTH, SUBSCRIPTION_PLAN_ID_PREFIX),
+        merchant_id: merchant_id.clone(),
+        profile_id: business_profile.get_id().clone(),
+        name: request.name,
+        description: request.description,
+        amount: request.amount,
+        currency: request.currency,
+        billing_interval: request.interval,
+        billing_interval_count: i32::from(interval_count),
+        trial_period_days: request.trial_period_days.map(i32::from),
+        is_active: true,
+        metadata: request.metadata,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let subscription_plan = db
+        .insert_subscription_plan(subscription_plan_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription plan")?;
+
+    Ok(ApplicationResponse::Json(get_subscription_plan_response(
+        subscription_plan,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscription_plans(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    constraints: subscription_types::SubscriptionPlanListConstraints,
+) -> RouterResponse<Vec<subscription_types::SubscriptionPlanResponse>> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        merchant_context.get_merchant_key_store(),
+        constraints.profile_id.as_ref().or(profile_id.as_ref()),
+        merchant_id,
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "profile_id",
+    })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &business_profile)?;
+
+    let subscription_plans = db
+        .list_subscription_plans_by_merchant_id_profile_id(merchant_id, business_profile.get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription plans")?;
+
+    Ok(ApplicationResponse::Json(
+        subscription_plans
+            .into_iter()
+            .map(get_subscription_plan_response)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionPlanId,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let subscription_plan = find_subscription_plan(
+        state.store.as_ref(),
+        merchant_context.get_merchant_account().get_id(),
+        &request.plan_id,
+    )
+    .await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription_plan)?;
+
+    Ok(ApplicationResponse::Json(get_subscription_plan_response(
+        subscription_plan,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn update_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionPlanUpdateRequestInternal,
+) -> RouterResponse<subscription_types::SubscriptionPlanResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let subscription_plan = find_subscription_plan(db, merchant_id, &request.plan_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription_plan)?;
+
+    let subscription_plan_update = storage::SubscriptionPlanUpdate::Update {
+        name: request.request.name,
+        description: request.request.description,
+        is_active: request.request.is_active,
+        metadata: request.request.metadata,
+    };
+
+    let subscription_plan = db
+        .update_subscription_plan_by_merchant_id_plan_id(
+            merchant_id,
+            &subscription_plan.plan_id,
+            subscription_plan_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription plan not found".to_owned(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_subscription_plan_response(
+        subscription_plan,
+    )))
+}
+
+/// Creates a subscription to a plan. A subscription with a trial period is charged when the trial
+/// ends, otherwise the first billing period is charged right away.
+#[instrument(skip_all)]
+pub async fn create_subscription(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionCreateRequest,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let merchant_account = merchant_context.get_merchant_account();
+    let merchant_id = merchant_account.get_id();
+
+    let subscription_plan = find_subscription_plan(db, merchant_id, &request.plan_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription_plan)?;
+    when(!subscription_plan.is_active, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Subscriptions cannot be created for an inactive plan".to_owned(),
+        })
+    })?;
+
+    validate_payment_method(
+        &state,
+        &merchant_context,
+        &request.customer_id,
+        &request.payment_method_id,
+    )
+    .await?;
+
+    if let Some(mandate_id) = &request.mandate_id {
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                merchant_id,
+                mandate_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+        when(
+            mandate.customer_id != request.customer_id
+                || mandate.mandate_status != MandateStatus::Active,
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The mandate must be active and belong to the customer".to_owned(),
+                })
+            },
+        )?;
+    }
+
+    let now = date_time::now();
+    // A trial period of 0 days on the request skips the trial period of the plan
+    let trial_end = request
+        .trial_period_days
+        .map(i32::from)
+        .or(subscription_plan.trial_period_days)
+        .filter(|trial_period_days| *trial_period_days > 0)
+        .map(|trial_period_days| {
+            now.saturating_add(time::Duration::days(i64::from(trial_period_days)))
+        });
+    let status = if trial_end.is_some() {
+        SubscriptionStatus::Trialing
+    } else {
+        SubscriptionStatus::Created
+    };
+
+    let subscription_new = storage::SubscriptionNew::new(
+        common_utils::generate_id(consts::ID_LENGTH, SUBSCRIPTION_ID_PREFIX),
+        status.to_string(),
+        None,
+        Some(request.payment_method_id),
+        None,
+        None,
+        None,
+        merchant_id.clone(),
+        request.customer_id,
+        request.metadata,
+        subscription_plan.profile_id.clone(),
+    )
+    .with_plan(
+        subscription_plan.plan_id.clone(),
+        request.mandate_id,
+        now,
+        trial_end.unwrap_or(now),
+        trial_end,
+    );
+
+    let subscription = db
+        .insert_subscription_entry(subscription_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription")?;
+
+    let subscription = match trial_end {
+        Some(trial_end) => {
+            schedule_subscription_renewal(db, &subscription, trial_end).await?;
+            subscription
+        }
+        None => {
+            let (subscription, invoice) = Box::pin(bill_subscription(
+                &state,
+                req_state,
+                &merchant_context,
+                subscription,
+                &subscription_plan,
+            ))
+            .await?;
+            schedule_next_subscription_renewal(&state, subscription, &invoice).await?
+        }
+    };
+
+    trigger_subscription_outgoing_webhook(&state, &merchant_context, &subscription)
+        .await
+        .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+        .ok();
+
+    Ok(ApplicationResponse::Json(get_subscription_response(
+        subscription,
+    )?))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionId,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let subscription = find_subscription(
+        state.store.as_ref(),
+        merchant_context.get_merchant_account().get_id(),
+        &request.subscription_id,
+    )
+    .await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription)?;
+
+    Ok(ApplicationResponse::Json(get_subscription_response(
+        subscription,
+    )?))
+}
+
+/// Changes the plan or the payment method of a subscription. When an active subscription is
+/// changed to a plan with a different price, the difference for the remainder of the current
+/// billing period is added to the next invoice.
+#[instrument(skip_all)]
+pub async fn update_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionUpdateRequestInternal,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let subscription = find_subscription(db, merchant_id, &request.subscription_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription)?;
+    let status = get_subscription_status(&subscription)?;
+    when(status.is_terminal(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Cancelled subscriptions cannot be updated".to_owned(),
+        })
+    })?;
+
+    let mut subscription_update = storage::SubscriptionUpdate::new(None, None);
+
+    if let Some(payment_method_id) = request.request.payment_method_id {
+        validate_payment_method(
+            &state,
+            &merchant_context,
+            &subscription.customer_id,
+            &payment_method_id,
+        )
+        .await?;
+        subscription_update.payment_method_id = Some(payment_method_id);
+        // The mandate of the customer is specific to the previous payment method
+        subscription_update.mandate_id = Some(None);
+    }
+
+    if let Some(plan_id) = request.request.plan_id {
+        let current_plan_id = get_subscription_plan_id(&subscription)?;
+        let current_plan = find_subscription_plan(db, merchant_id, current_plan_id).await?;
+        let new_plan = find_subscription_plan(db, merchant_id, &plan_id).await?;
+
+        when(!new_plan.is_active, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Subscriptions cannot be changed to an inactive plan".to_owned(),
+            })
+        })?;
+        when(
+            new_plan.profile_id != current_plan.profile_id
+                || new_plan.currency != current_plan.currency
+                || new_plan.billing_interval != current_plan.billing_interval
+                || new_plan.billing_interval_count != current_plan.billing_interval_count,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The new plan must have the same business profile, currency and \
+                              billing interval as the current plan"
+                        .to_owned(),
+                })
+            },
+        )?;
+
+        let should_prorate =
+            request.request.prorate.unwrap_or(true) && status == SubscriptionStatus::Active;
+        if should_prorate {
+            if let (Some(period_start), Some(period_end)) = (
+                subscription.current_period_start,
+                subscription.current_period_end,
+            ) {
+                let proration_amount = get_proration_amount(
+                    current_plan.amount,
+                    new_plan.amount,
+                    period_start,
+                    period_end,
+                    date_time::now(),
+                );
+                subscription_update.pending_proration_amount = Some(
+                    subscription.pending_proration_amount.unwrap_or_default() + proration_amount,
+                );
+            }
+        }
+
+        subscription_update.plan_id = Some(new_plan.plan_id);
+    }
+
+    let subscription = db
+        .update_subscription_entry(
+            merchant_id,
+            subscription.subscription_id,
+            subscription_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription")?;
+
+    Ok(ApplicationResponse::Json(get_subscription_response(
+        subscription,
+    )?))
+}
+
+/// Pauses a subscription. Paused subscriptions are not renewed, and the billing periods they are
+/// paused for are not charged.
+#[instrument(skip_all)]
+pub async fn pause_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionId,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let subscription = find_subscription(db, merchant_id, &request.subscription_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription)?;
+    let status = get_subscription_status(&subscription)?;
+    when(
+        !matches!(
+            status,
+            SubscriptionStatus::Active | SubscriptionStatus::Trialing | SubscriptionStatus::PastDue
+        ),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Subscriptions with status `{status}` cannot be paused"),
+            })
+        },
+    )?;
+
+    let subscription_update = storage::SubscriptionUpdate {
+        paused_at: Some(date_time::now()),
+        ..storage::SubscriptionUpdate::new(None, Some(SubscriptionStatus::Paused.to_string()))
+    };
+    let subscription = db
+        .update_subscription_entry(
+            merchant_id,
+            subscription.subscription_id,
+            subscription_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to pause subscription")?;
+
+    trigger_subscription_outgoing_webhook(&state, &merchant_context, &subscription)
+        .await
+        .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+        .ok();
+
+    Ok(ApplicationResponse::Json(get_subscription_response(
+        subscription,
+    )?))
+}
+
+/// Resumes a paused subscription. If the current billing period ended while the subscription was
+/// paused, a new billing period starts when the subscription is resumed.
+#[instrument(skip_all)]
+pub async fn resume_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionId,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let subscription = find_subscription(db, merchant_id, &request.subscription_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription)?;
+    let status = get_subscription_status(&subscription)?;
+    when(status != SubscriptionStatus::Paused, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Subscriptions with status `{status}` cannot be resumed"),
+        })
+    })?;
+
+    let now = date_time::now();
+    let status = if subscription
+        .trial_end
+        .is_some_and(|trial_end| trial_end > now)
+    {
+        SubscriptionStatus::Trialing
+    } else {
+        SubscriptionStatus::Active
+    };
+    let mut subscription_update = storage::SubscriptionUpdate::new(None, Some(status.to_string()));
+    let is_period_elapsed = subscription
+        .current_period_end
+        .map_or(true, |current_period_end| current_period_end <= now);
+    if is_period_elapsed {
+        subscription_update.current_period_start = Some(now);
+        subscription_update.current_period_end = Some(now);
+    }
+
+    let subscription = db
+        .update_subscription_entry(
+            merchant_id,
+            subscription.subscription_id,
+            subscription_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to resume subscription")?;
+
+    let renewal_time = subscription
+        .current_period_end
+        .map_or(now, |current_period_end| current_period_end.max(now));
+    schedule_subscription_renewal(db, &subscription, renewal_time).await?;
+
+    trigger_subscription_outgoing_webhook(&state, &merchant_context, &subscription)
+        .await
+        .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+        .ok();
+
+    Ok(ApplicationResponse::Json(get_subscription_response(
+        subscription,
+    )?))
+}
+
+/// Cancels a subscription, either immediately or at the end of the current billing period.
+/// Subscriptions that have no paid billing period are always cancelled immediately.
+#[instrument(skip_all)]
+pub async fn cancel_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionCancelRequestInternal,
+) -> RouterResponse<subscription_types::SubscriptionResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let subscription = find_subscription(db, merchant_id, &request.subscription_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription)?;
+    let status = get_subscription_status(&subscription)?;
+    when(status.is_terminal(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The subscription has already been cancelled".to_owned(),
+        })
+    })?;
+
+    let cancel_at_period_end = request.request.cancel_at_period_end.unwrap_or(false)
+        && matches!(
+            status,
+            SubscriptionStatus::Active | SubscriptionStatus::Trialing
+        );
+
+    let subscription = if cancel_at_period_end {
+        let subscription_update = storage::SubscriptionUpdate {
+            cancel_at_period_end: Some(true),
+            ..storage::SubscriptionUpdate::new(None, None)
+        };
+        db.update_subscription_entry(
+            merchant_id,
+            subscription.subscription_id,
+            subscription_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription")?
+    } else {
+        let subscription = mark_subscription_as_cancelled(db, subscription).await?;
+        trigger_subscription_outgoing_webhook(&state, &merchant_context, &subscription)
+            .await
+            .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+            .ok();
+        subscription
+    };
+
+    Ok(ApplicationResponse::Json(get_subscription_response(
+        subscription,
+    )?))
+}
+
+#[instrument(skip_all)]
+pub async fn list_subscription_invoices(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    request: subscription_types::SubscriptionId,
+) -> RouterResponse<Vec<subscription_types::SubscriptionInvoiceResponse>> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let subscription = find_subscription(db, merchant_id, &request.subscription_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription)?;
+    let invoices = db
+        .list_subscription_invoices_by_merchant_id_subscription_id(
+            merchant_id,
+            &subscription.subscription_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription invoices")?;
+
+    Ok(ApplicationResponse::Json(
+        invoices
+            .into_iter()
+            .map(|invoice| subscription_types::SubscriptionInvoiceResponse {
+                invoice_id: invoice.invoice_id,
+                subscription_id: invoice.subscription_id,
+                amount: invoice.amount,
+                currency: invoice.currency,
+                status: invoice.status,
+                period_start: invoice.period_start,
+                period_end: invoice.period_end,
+                payment_id: invoice.payment_id,
+                attempt_count: invoice.attempt_count,
+                created: invoice.created_at,
+            })
+            .collect(),
+    ))
+}
+
+/// Generates the invoice for the billing period following the current one, and charges the
+/// customer for it using the saved mandate or payment method. The subscription is moved to the
+/// next billing period if the payment succeeds, and is marked as past due otherwise.
+///
+/// An invoice is generated only once for a billing period, retries of a failed renewal charge the
+/// same invoice. While the payment of the invoice is being processed, the invoice remains pending
+/// and the payment is synced instead of charging the customer again.
+#[instrument(skip_all)]
+pub(crate) async fn bill_subscription(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    subscription_plan: &storage::SubscriptionPlan,
+) -> RouterResult<(storage::Subscription, storage::SubscriptionInvoice)> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let period_start = subscription
+        .current_period_end
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Subscription does not have a billing period")?;
+    let period_end = add_billing_interval(
+        period_start,
+        subscription_plan.billing_interval,
+        subscription_plan.billing_interval_count,
+    )
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to compute the end of the billing period")?;
+
+    // Prorations exceeding the price of the plan are carried forward as a credit
+    let total_amount =
+        subscription_plan.amount + subscription.pending_proration_amount.unwrap_or_default();
+    let (invoice_amount, remaining_credit) = if total_amount > MinorUnit::zero() {
+        (total_amount, MinorUnit::zero())
+    } else {
+        (MinorUnit::zero(), total_amount)
+    };
+
+    let invoice = match db
+        .find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+            merchant_id,
+            &subscription.subscription_id,
+            period_start,
+        )
+        .await
+    {
+        Ok(invoice) => invoice,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = date_time::now();
+            let invoice_new = storage::SubscriptionInvoiceNew {
+                invoice_id: common_utils::generate_id(
+                    consts::ID_LENGTH,
+                    SUBSCRIPTION_INVOICE_ID_PREFIX,
+                ),
+                subscription_id: subscription.subscription_id.clone(),
+                merchant_id: merchant_id.clone(),
+                amount: invoice_amount,
+                currency: subscription_plan.currency,
+                status: if invoice_amount == MinorUnit::zero() {
+                    SubscriptionInvoiceStatus::Paid
+                } else {
+                    SubscriptionInvoiceStatus::Pending
+                },
+                period_start,
+                period_end,
+                payment_id: None,
+                attempt_count: 0,
+                created_at: now,
+                modified_at: now,
+            };
+            db.insert_subscription_invoice(invoice_new)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert subscription invoice")?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find subscription invoice")?,
+    };
+
+    let invoice = match invoice.status {
+        SubscriptionInvoiceStatus::Paid => invoice,
+        SubscriptionInvoiceStatus::Pending
+        | SubscriptionInvoiceStatus::Failed
+        | SubscriptionInvoiceStatus::Void => {
+            let (status, payment_id, attempt_count) = match invoice.payment_id.clone() {
+                Some(payment_id) if invoice.status == SubscriptionInvoiceStatus::Pending => {
+                    let status = Box::pin(sync_subscription_invoice_payment(
+                        state,
+                        req_state,
+                        merchant_context,
+                        &subscription,
+                        payment_id.clone(),
+                    ))
+                    .await;
+                    (status, Some(payment_id), invoice.attempt_count)
+                }
+                _ => {
+                    let attempt_count = invoice.attempt_count.saturating_add(1);
+                    let payment_id = get_subscription_invoice_payment_id(&invoice, attempt_count)?;
+                    let (status, payment_id) = Box::pin(charge_subscription_invoice(
+                        state,
+                        req_state,
+                        merchant_context,
+                        &subscription,
+                        &invoice,
+                        subscription_plan,
+                        payment_id,
+                    ))
+                    .await;
+                    (status, payment_id, attempt_count)
+                }
+            };
+            let invoice_update = storage::SubscriptionInvoiceUpdate::PaymentAttemptUpdate {
+                status,
+                payment_id,
+                attempt_count,
+            };
+            db.update_subscription_invoice_by_merchant_id_invoice_id(
+                merchant_id,
+                &invoice.invoice_id,
+                invoice_update,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update subscription invoice")?
+        }
+    };
+
+    let subscription_update = match invoice.status {
+        SubscriptionInvoiceStatus::Paid => storage::SubscriptionUpdate {
+            current_period_start: Some(period_start),
+            current_period_end: Some(period_end),
+            pending_proration_amount: Some(remaining_credit),
+            ..storage::SubscriptionUpdate::new(None, Some(SubscriptionStatus::Active.to_string()))
+        },
+        // The subscription remains in its current status until the outcome of the payment is known
+        SubscriptionInvoiceStatus::Pending => return Ok((subscription, invoice)),
+        SubscriptionInvoiceStatus::Failed | SubscriptionInvoiceStatus::Void => {
+            storage::SubscriptionUpdate::new(None, Some(SubscriptionStatus::PastDue.to_string()))
+        }
+    };
+    let subscription = db
+        .update_subscription_entry(
+            merchant_id,
+            subscription.subscription_id,
+            subscription_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription after billing")?;
+
+    Ok((subscription, invoice))
+}
+
+/// Returns the ID of the payment for the specified attempt to charge the invoice. The ID is derived
+/// from the invoice, so that a renewal that is retried after failing midway does not charge the
+/// customer again for the same attempt.
+fn get_subscription_invoice_payment_id(
+    invoice: &storage::SubscriptionInvoice,
+    attempt_count: i32,
+) -> RouterResult<id_type::PaymentId> {
+    id_type::PaymentId::try_from(std::borrow::Cow::Owned(format!(
+        "{}_{attempt_count}",
+        invoice.invoice_id
+    )))
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the payment ID of the subscription invoice")
+}
+
+/// Payments that have not reached a terminal status, such as payments being processed by the
+/// connector or awaiting capture, leave the invoice pending until the payment is synced.
+fn get_subscription_invoice_status(payment_status: IntentStatus) -> SubscriptionInvoiceStatus {
+    match payment_status {
+        IntentStatus::Succeeded | IntentStatus::PartiallyCaptured => {
+            SubscriptionInvoiceStatus::Paid
+        }
+        IntentStatus::Processing
+        | IntentStatus::RequiresCapture
+        | IntentStatus::PartiallyCapturedAndCapturable
+        | IntentStatus::PartiallyAuthorizedAndRequiresCapture
+        | IntentStatus::RequiresCustomerAction
+        | IntentStatus::RequiresMerchantAction
+        | IntentStatus::Conflicted => SubscriptionInvoiceStatus::Pending,
+        IntentStatus::Failed
+        | IntentStatus::Cancelled
+        | IntentStatus::CancelledPostCapture
+        | IntentStatus::RequiresPaymentMethod
+        | IntentStatus::RequiresConfirmation
+        | IntentStatus::Expired => SubscriptionInvoiceStatus::Failed,
+    }
+}
+
+fn get_payment_status_from_response(
+    payments_response: ApplicationResponse<api::PaymentsResponse>,
+) -> RouterResult<IntentStatus> {
+    match payments_response {
+        ApplicationResponse::Json(payments_response)
+        | ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            Ok(payments_response.status)
+        }
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response for the payment of subscription invoice"),
+    }
+}
+
+/// Charges the invoice off-session with the specified payment ID, returning the status of the
+/// invoice and the payment that was created, if any. If the payment was already created by an
+/// earlier run of the same attempt, it is synced instead.
+async fn charge_subscription_invoice(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+    invoice: &storage::SubscriptionInvoice,
+    subscription_plan: &storage::SubscriptionPlan,
+    payment_id: id_type::PaymentId,
+) -> (SubscriptionInvoiceStatus, Option<id_type::PaymentId>) {
+    let recurring_details = match (&subscription.mandate_id, &subscription.payment_method_id) {
+        (Some(mandate_id), _) => {
+            api_models::mandates::RecurringDetails::MandateId(mandate_id.clone())
+        }
+        (None, Some(payment_method_id)) => {
+            api_models::mandates::RecurringDetails::PaymentMethodId(payment_method_id.clone())
+        }
+        (None, None) => {
+            logger::error!("Subscription does not have a mandate or payment method to charge");
+            return (SubscriptionInvoiceStatus::Failed, None);
+        }
+    };
+
+    let payments_request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id.clone())),
+        amount: Some(api::Amount::from(invoice.amount)),
+        currency: Some(invoice.currency),
+        customer_id: Some(subscription.customer_id.clone()),
+        confirm: Some(true),
+        off_session: Some(true),
+        recurring_details: Some(recurring_details),
+        description: Some(format!(
+            "{} ({})",
+            subscription_plan.name, invoice.invoice_id
+        )),
+        profile_id: Some(subscription.profile_id.clone()),
+        ..Default::default()
+    };
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state.clone(),
+        merchant_context.clone(),
+        Some(subscription.profile_id.clone()),
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await;
+
+    match payments_response.and_then(get_payment_status_from_response) {
+        Ok(payment_status) => (
+            get_subscription_invoice_status(payment_status),
+            Some(payment_id),
+        ),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicatePayment { .. }
+            ) =>
+        {
+            logger::info!("Payment of subscription invoice already exists, syncing the payment");
+            let status = Box::pin(sync_subscription_invoice_payment(
+                state,
+                req_state,
+                merchant_context,
+                subscription,
+                payment_id.clone(),
+            ))
+            .await;
+            (status, Some(payment_id))
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to charge subscription invoice");
+            (SubscriptionInvoiceStatus::Failed, None)
+        }
+    }
+}
+
+/// Syncs the payment of the invoice with the connector, returning the status of the invoice. The
+/// invoice remains pending if the payment could not be synced.
+async fn sync_subscription_invoice_payment(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+    payment_id: id_type::PaymentId,
+) -> SubscriptionInvoiceStatus {
+    let payments_request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_id),
+        merchant_id: Some(subscription.merchant_id.clone()),
+        force_sync: true,
+        ..Default::default()
+    };
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::PSync>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_context.clone(),
+        Some(subscription.profile_id.clone()),
+        payments::PaymentStatus,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await;
+
+    match payments_response.and_then(get_payment_status_from_response) {
+        Ok(payment_status) => get_subscription_invoice_status(payment_status),
+        Err(error) => {
+            logger::error!(?error, "Failed to sync the payment of subscription invoice");
+            SubscriptionInvoiceStatus::Pending
+        }
+    }
+}
+
+/// Schedules the renewal of the subscription following an attempt to charge its invoice. Paid
+/// subscriptions are renewed at the end of the billing period, pending payments are synced
+/// periodically and failed payments are retried as per the dunning configuration. Subscriptions
+/// whose invoice could not be paid once all retries are exhausted are cancelled.
+pub(crate) async fn schedule_next_subscription_renewal(
+    state: &SessionState,
+    subscription: storage::Subscription,
+    invoice: &storage::SubscriptionInvoice,
+) -> RouterResult<storage::Subscription> {
+    let db = state.store.as_ref();
+
+    let renewal_time = match invoice.status {
+        SubscriptionInvoiceStatus::Paid => subscription.current_period_end,
+        // The payment of the invoice is synced until it reaches a terminal status
+        SubscriptionInvoiceStatus::Pending => {
+            Some(date_time::now().saturating_add(SUBSCRIPTION_INVOICE_PAYMENT_SYNC_INTERVAL))
+        }
+        SubscriptionInvoiceStatus::Failed | SubscriptionInvoiceStatus::Void => {
+            // The first dunning retry follows the first failed attempt
+            get_dunning_retry_schedule_time(
+                db,
+                &subscription.merchant_id,
+                invoice.attempt_count.saturating_sub(1),
+            )
+            .await
+        }
+    };
+
+    match renewal_time {
+        Some(renewal_time) => {
+            schedule_subscription_renewal(db, &subscription, renewal_time).await?;
+            Ok(subscription)
+        }
+        None => {
+            logger::info!(
+                subscription_id = %subscription.subscription_id,
+                "Cancelling subscription as the renewal payment retries are exhausted"
+            );
+            db.update_subscription_invoice_by_merchant_id_invoice_id(
+                &subscription.merchant_id,
+                &invoice.invoice_id,
+                storage::SubscriptionInvoiceUpdate::StatusUpdate {
+                    status: SubscriptionInvoiceStatus::Void,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to void subscription invoice")?;
+            mark_subscription_as_cancelled(db, subscription).await
+        }
+    }
+}
+
+pub(crate) async fn mark_subscription_as_cancelled(
+    db: &dyn StorageInterface,
+    subscription: storage::Subscription,
+) -> RouterResult<storage::Subscription> {
+    let subscription_update = storage::SubscriptionUpdate {
+        cancelled_at: Some(date_time::now()),
+        cancel_at_period_end: Some(false),
+        ..storage::SubscriptionUpdate::new(None, Some(SubscriptionStatus::Cancelled.to_string()))
+    };
+
+    db.update_subscription_entry(
+        &subscription.merchant_id,
+        subscription.subscription_id,
+        subscription_update,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to cancel subscription")
+}
+
+/// Schedules the renewal task of the subscription at the specified time, reusing the existing
+/// task of the subscription if there is one.
+async fn schedule_subscription_renewal(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_id = format!(
+        "{SUBSCRIPTION_RENEWAL_RUNNER}_{SUBSCRIPTION_RENEWAL_NAME}_{}_{}",
+        subscription.merchant_id.get_string_repr(),
+        subscription.subscription_id
+    );
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find subscription renewal task")?;
+
+    match existing_process {
+        Some(process) => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule subscription renewal task"),
+        None => {
+            let tracking_data = SubscriptionRenewalTrackingData {
+                subscription_id: subscription.subscription_id.clone(),
+                merchant_id: subscription.merchant_id.clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                SUBSCRIPTION_RENEWAL_NAME,
+                SUBSCRIPTION_RENEWAL_RUNNER,
+                [SUBSCRIPTION_RENEWAL_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct subscription renewal process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert subscription renewal task")?;
+            Ok(())
+        }
+    }
+}
+
+/// Get the schedule time for the specified retry of a failed subscription renewal payment.
+///
+/// The dunning configuration is read from the `pt_mapping_subscription_dunning` config, in the
+/// same format as the outgoing webhooks retry configuration. The default configuration is used if
+/// the config is not found.
+async fn get_dunning_retry_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    retry_count: i32,
+) -> Option<PrimitiveDateTime> {
+    let key = "pt_mapping_subscription_dunning";
+
+    let result = db
+        .find_config_by_key(key)
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("SubscriptionDunningProcessTrackerMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+    let mapping = result.map_or_else(
+        |error| {
+            if error.current_context().is_db_not_found() {
+                logger::debug!("Subscription dunning config `{key}` not found, ignoring");
+            } else {
+                logger::error!(?error, "Failed to read subscription dunning config `{key}`");
+            }
+            process_data::SubscriptionDunningProcessTrackerMapping::default()
+        },
+        |mapping| {
+            logger::debug!(?mapping, "Using custom subscription dunning config");
+            mapping
+        },
+    );
+
+    let time_delta =
+        scheduler_utils::get_subscription_dunning_schedule_time(mapping, merchant_id, retry_count);
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
+
+/// Triggers the outgoing webhook for the current status of the subscription.
+pub(crate) async fn trigger_subscription_outgoing_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+) -> RouterResult<()> {
+    let subscription_response = get_subscription_response(subscription.clone())?;
+    let Some(event_type) = Option::<EventType>::from(subscription_response.status) else {
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &subscription.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: subscription.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let cloned_state = state.clone();
+    let cloned_merchant_context = merchant_context.clone();
+    let subscription_id = subscription_response.subscription_id.clone();
+    let primary_object_created_at = Some(subscription_response.created);
+    tokio::spawn(
+        async move {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                cloned_merchant_context,
+                business_profile,
+                event_type,
+                diesel_models::enums::EventClass::Subscriptions,
+                subscription_id,
+                diesel_models::enums::EventObjectType::SubscriptionDetails,
+                webhooks::OutgoingWebhookContent::SubscriptionDetails(Box::new(
+                    subscription_response,
+                )),
+                primary_object_created_at,
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
+async fn find_subscription_plan(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    plan_id: &str,
+) -> RouterResult<storage::SubscriptionPlan> {
+    db.find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription plan not found".to_owned(),
+        })
+}
+
+/// Finds a subscription billed by a plan. Subscriptions billed by external billing processors
+/// cannot be managed using the subscription APIs.
+pub(crate) async fn find_subscription(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    let subscription = db
+        .find_by_merchant_id_subscription_id(merchant_id, subscription_id.to_owned())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription not found".to_owned(),
+        })?;
+
+    when(subscription.plan_id.is_none(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The subscription is billed by an external billing processor".to_owned(),
+        })
+    })?;
+
+    Ok(subscription)
+}
+
+pub(crate) async fn find_subscription_plan_of_subscription(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+) -> RouterResult<storage::SubscriptionPlan> {
+    find_subscription_plan(
+        db,
+        &subscription.merchant_id,
+        get_subscription_plan_id(subscription)?,
+    )
+    .await
+}
+
+async fn validate_payment_method(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+    payment_method_id: &str,
+) -> RouterResult<()> {
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    when(&payment_method.customer_id != customer_id, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment method does not belong to the customer".to_owned(),
+        })
+    })
+}
+
+pub(crate) fn get_subscription_status(
+    subscription: &storage::Subscription,
+) -> RouterResult<SubscriptionStatus> {
+    SubscriptionStatus::from_str(&subscription.status)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Invalid subscription status `{}`", subscription.status))
+}
+
+fn get_subscription_plan_id(subscription: &storage::Subscription) -> RouterResult<&str> {
+    subscription
+        .plan_id
+        .as_deref()
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Subscription is not billed by a plan")
+}
+
+pub(crate) fn get_subscription_response(
+    subscription: storage::Subscription,
+) -> RouterResult<subscription_types::SubscriptionResponse> {
+    let status = get_subscription_status(&subscription)?;
+    let plan_id = get_subscription_plan_id(&subscription)?.to_owned();
+
+    Ok(subscription_types::SubscriptionResponse {
+        subscription_id: subscription.subscription_id,
+        merchant_id: subscription.merchant_id,
+        profile_id: subscription.profile_id,
+        customer_id: subscription.customer_id,
+        status,
+        plan_id,
+        payment_method_id: subscription.payment_method_id,
+        mandate_id: subscription.mandate_id,
+        current_period_start: subscription.current_period_start,
+        current_period_end: subscription.current_period_end,
+        trial_end: subscription.trial_end,
+        cancel_at_period_end: subscription.cancel_at_period_end.unwrap_or(false),
+        paused_at: subscription.paused_at,
+        cancelled_at: subscription.cancelled_at,
+        pending_proration_amount: subscription.pending_proration_amount.unwrap_or_default(),
+        metadata: subscription.metadata,
+        created: subscription.created_at,
+    })
+}
+
+fn get_subscription_plan_response(
+    subscription_plan: storage::SubscriptionPlan,
+) -> subscription_types::SubscriptionPlanResponse {
+    subscription_types::SubscriptionPlanResponse {
+        plan_id: subscription_plan.plan_id,
+        merchant_id: subscription_plan.merchant_id,
+        profile_id: subscription_plan.profile_id,
+        name: subscription_plan.name,
+        description: subscription_plan.description,
+        amount: subscription_plan.amount,
+        currency: subscription_plan.currency,
+        interval: subscription_plan.billing_interval,
+        // Stored from `u16` values, the conversions do not fail
+        interval_count: u16::try_from(subscription_plan.billing_interval_count).unwrap_or_default(),
+        trial_period_days: subscription_plan
+            .trial_period_days
+            .and_then(|trial_period_days| u16::try_from(trial_period_days).ok()),
+        is_active: subscription_plan.is_active,
+        metadata: subscription_plan.metadata,
+        created: subscription_plan.created_at,
+    }
+}
+
+/// Returns the end of a billing period of `interval_count` units of `interval` starting at
+/// `period_start`. Monthly and yearly periods starting on a day that does not exist in the
+/// resulting month end on the last day of that month.
+fn add_billing_interval(
+    period_start: PrimitiveDateTime,
+    interval: SubscriptionPlanInterval,
+    interval_count: i32,
+) -> Option<PrimitiveDateTime> {
+    match interval {
+        SubscriptionPlanInterval::Day => {
+            period_start.checked_add(time::Duration::days(i64::from(interval_count)))
+        }
+        SubscriptionPlanInterval::Week => {
+            period_start.checked_add(time::Duration::weeks(i64::from(interval_count)))
+        }
+        SubscriptionPlanInterval::Month => add_months(period_start, interval_count),
+        SubscriptionPlanInterval::Year => add_months(period_start, interval_count.checked_mul(12)?),
+    }
+}
+
+fn add_months(date_time: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let date = date_time.date();
+    let month_index = date
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date.month())) - 1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+
+    time::Date::from_calendar_date(year, month, day)
+        .ok()
+        .map(|date| date.with_time(date_time.time()))
+}
+
+/// Returns the prorated difference between the prices of two plans for the remainder of the
+/// billing period. The amount is negative when the subscription is changed to a cheaper plan.
+fn get_proration_amount(
+    current_amount: MinorUnit,
+    new_amount: MinorUnit,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+) -> MinorUnit {
+    let period_seconds = (period_end - period_start).whole_seconds();
+    if period_seconds <= 0 {
+        return MinorUnit::zero();
+    }
+    let remaining_seconds = (period_end - now).whole_seconds().clamp(0, period_seconds);
+
+    let difference =
+        i128::from(new_amount.get_amount_as_i64()) - i128::from(current_amount.get_amount_as_i64());
+    let proration = difference * i128::from(remaining_seconds) / i128::from(period_seconds);
+
+    // The proration never exceeds the difference between the prices, which fits in an `i64`
+    MinorUnit::new(i64::try_from(proration).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_add_billing_interval() {
+        let period_start = datetime!(2024-01-31 10:00);
+
+        assert_eq!(
+            add_billing_interval(period_start, SubscriptionPlanInterval::Month, 1).unwrap(),
+            datetime!(2024-02-29 10:00)
+        );
+        assert_eq!(
+            add_billing_interval(period_start, SubscriptionPlanInterval::Month, 3).unwrap(),
+            datetime!(2024-04-30 10:00)
+        );
+        assert_eq!(
+            add_billing_interval(period_start, SubscriptionPlanInterval::Month, 11).unwrap(),
+            datetime!(2024-12-31 10:00)
+        );
+        assert_eq!(
+            add_billing_interval(
+                datetime!(2024-02-29 10:00),
+                SubscriptionPlanInterval::Year,
+                1
+            )
+            .unwrap(),
+            datetime!(2025-02-28 10:00)
+        );
+        assert_eq!(
+            add_billing_interval(period_start, SubscriptionPlanInterval::Week, 2).unwrap(),
+            datetime!(2024-02-14 10:00)
+        );
+        assert_eq!(
+            add_billing_interval(period_start, SubscriptionPlanInterval::Day, 1).unwrap(),
+            datetime!(2024-02-01 10:00)
+        );
+    }
+
+    #[test]
+    fn test_proration_amount() {
+        let period_start = datetime!(2024-04-01 00:00);
+        let period_end = datetime!(2024-05-01 00:00);
+        let halfway = datetime!(2024-04-16 00:00);
+
+        assert_eq!(
+            get_proration_amount(
+                MinorUnit::new(1000),
+                MinorUnit::new(3000),
+                period_start,
+                period_end,
+                halfway
+            ),
+            MinorUnit::new(1000)
+        );
+        assert_eq!(
+            get_proration_amount(
+                MinorUnit::new(3000),
+                MinorUnit::new(1000),
+                period_start,
+                period_end,
+                halfway
+            ),
+            MinorUnit::new(-1000)
+        );
+        assert_eq!(
+            get_proration_amount(
+                MinorUnit::new(1000),
+                MinorUnit::new(3000),
+                period_start,
+                period_end,
+                datetime!(2024-05-02 00:00)
+            ),
+            MinorUnit::zero()
+        );
+    }
+}
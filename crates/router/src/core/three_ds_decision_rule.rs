@@ -14,9 +14,10 @@ use crate::{
     core::{
         errors,
         errors::{RouterResponse, StorageErrorExt},
+        payments::routing as payments_routing,
     },
     services,
-    types::transformers::ForeignFrom,
+    types::{domain, storage, transformers::ForeignFrom},
     SessionState,
 };
 
@@ -26,9 +27,29 @@ pub async fn execute_three_ds_decision_rule(
     merchant_context: MerchantContext,
     request: api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
 ) -> RouterResponse<api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteResponse> {
-    let decision = get_three_ds_decision_rule_output(
+    let db = state.store.as_ref();
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &request.routing_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    // The rule is evaluated with the settings of the profile it belongs to
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            &(&state).into(),
+            merchant_context.get_merchant_key_store(),
+            &routing_algorithm.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: routing_algorithm.profile_id.get_string_repr().to_owned(),
+        })?;
+    let decision = evaluate_three_ds_decision_rule(
         &state,
-        merchant_context.get_merchant_account().get_id(),
+        &business_profile,
+        &routing_algorithm,
         request.clone(),
     )
     .await?;
@@ -40,17 +61,30 @@ pub async fn execute_three_ds_decision_rule(
 
 pub async fn get_three_ds_decision_rule_output(
     state: &SessionState,
-    merchant_id: &common_utils::id_type::MerchantId,
+    business_profile: &domain::Profile,
     request: api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
 ) -> errors::RouterResult<common_types::three_ds_decision_rule_engine::ThreeDSDecision> {
     let db = state.store.as_ref();
     // Retrieve the rule from database
     let routing_algorithm = db
-        .find_routing_algorithm_by_algorithm_id_merchant_id(&request.routing_id, merchant_id)
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &request.routing_id,
+            &business_profile.merchant_id,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    evaluate_three_ds_decision_rule(state, business_profile, &routing_algorithm, request).await
+}
+
+async fn evaluate_three_ds_decision_rule(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    routing_algorithm: &storage::RoutingAlgorithm,
+    request: api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
+) -> errors::RouterResult<common_types::three_ds_decision_rule_engine::ThreeDSDecision> {
     let algorithm: Algorithm = routing_algorithm
         .algorithm_data
+        .clone()
         .parse_value("Algorithm")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error parsing program from three_ds_decision rule algorithm")?;
@@ -60,7 +94,15 @@ pub async fn get_three_ds_decision_rule_output(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Error parsing program from three_ds_decision rule algorithm")?;
     // Construct backend input from request
-    let backend_input = dsl_inputs::BackendInput::foreign_from(request.clone());
+    let mut backend_input = dsl_inputs::BackendInput::foreign_from(request.clone());
+    backend_input.payment.amount_in_base_currency =
+        payments_routing::make_dsl_amount_in_base_currency(
+            state,
+            business_profile,
+            &backend_input.payment,
+        )
+        .await;
+    backend_input.time = payments_routing::make_dsl_time_input(business_profile);
     // Initialize interpreter with the rule program
     let interpreter = backend::VirInterpreterBackend::with_program(program)
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -42,6 +42,7 @@ impl ForeignFrom<api_threedsecure::PaymentData> for dsl_inputs::PaymentInput {
     fn foreign_from(request_payment_data: api_threedsecure::PaymentData) -> Self {
         Self {
             amount: request_payment_data.amount,
+            amount_in_base_currency: None,
             currency: request_payment_data.currency,
             authentication_type: None,
             capture_method: None,
@@ -110,6 +111,7 @@ impl ForeignFrom<api_threedsecure::ThreeDsDecisionRuleExecuteRequest> for dsl_in
             acquirer_data: request.acquirer.map(ForeignFrom::foreign_from),
             customer_device_data: request.customer_device.map(ForeignFrom::foreign_from),
             issuer_data: request.issuer.map(ForeignFrom::foreign_from),
+            time: None,
         }
     }
 }
@@ -16,7 +16,10 @@ use error_stack::{report, ResultExt};
 use masking::Secret;
 
 use crate::{
-    core::errors::{StorageErrorExt, UserErrors, UserResponse},
+    core::{
+        audit_log,
+        errors::{StorageErrorExt, UserErrors, UserResponse},
+    },
     db::user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload},
     routes::{app::ReqState, SessionState},
     services::{
@@ -184,6 +187,7 @@ pub async fn update_user_role(
     .change_context(UserErrors::InternalServerError)?;
 
     let mut is_updated = false;
+    let mut previous_role_id = None;
 
     let v2_user_role_to_be_updated = match state
         .global_store
@@ -267,6 +271,7 @@ pub async fn update_user_role(
             .change_context(UserErrors::InternalServerError)?;
 
         is_updated = true;
+        previous_role_id = Some(user_role.role_id);
     }
 
     let v1_user_role_to_be_updated = match state
@@ -351,6 +356,7 @@ pub async fn update_user_role(
             .change_context(UserErrors::InternalServerError)?;
 
         is_updated = true;
+        previous_role_id = Some(user_role.role_id);
     }
 
     if !is_updated {
@@ -358,6 +364,17 @@ pub async fn update_user_role(
             .attach_printable("User with given email is not found in the organization")?;
     }
 
+    audit_log::record_audit_log(
+        &state,
+        Some(&user_from_token.merchant_id),
+        common_enums::AuditLogResourceType::UserRole,
+        user_to_be_updated.get_user_id(),
+        common_enums::AuditLogAction::Update,
+        Some(&serde_json::json!({ "role_id": previous_role_id })),
+        Some(&serde_json::json!({ "role_id": req.role_id })),
+    )
+    .await;
+
     auth::blacklist::insert_user_in_blacklist(&state, user_to_be_updated.get_user_id()).await?;
 
     Ok(ApplicationResponse::StatusOk)
@@ -538,6 +555,7 @@ pub async fn delete_user_role(
     .change_context(UserErrors::InternalServerError)?;
 
     let mut user_role_deleted_flag = false;
+    let mut deleted_role_id = None;
 
     // Find in V2
     let user_role_v2 = match state
@@ -612,6 +630,7 @@ pub async fn delete_user_role(
             .await
             .change_context(UserErrors::InternalServerError)
             .attach_printable("Error while deleting user role")?;
+        deleted_role_id = Some(role_to_be_deleted.role_id);
     }
 
     // Find in V1
@@ -687,6 +706,7 @@ pub async fn delete_user_role(
             .await
             .change_context(UserErrors::InternalServerError)
             .attach_printable("Error while deleting user role")?;
+        deleted_role_id = Some(role_to_be_deleted.role_id);
     }
 
     if !user_role_deleted_flag {
@@ -694,6 +714,17 @@ pub async fn delete_user_role(
             .attach_printable("User is not associated with the merchant");
     }
 
+    audit_log::record_audit_log(
+        &state,
+        Some(&user_from_token.merchant_id),
+        common_enums::AuditLogResourceType::UserRole,
+        user_from_db.get_user_id(),
+        common_enums::AuditLogAction::Delete,
+        Some(&serde_json::json!({ "role_id": deleted_role_id })),
+        None,
+    )
+    .await;
+
     // Check if user has any more role associations
     let remaining_roles = state
         .global_store
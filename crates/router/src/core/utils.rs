@@ -2278,6 +2278,18 @@ impl GetProfileId for diesel_models::routing_algorithm::RoutingProfileMetadata {
     }
 }
 
+impl GetProfileId for storage::Subscription {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
+impl GetProfileId for storage::SubscriptionPlan {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
 impl GetProfileId for domain::Profile {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
         Some(self.get_id())
@@ -2297,6 +2309,13 @@ impl<T, F, R> GetProfileId for (storage::Payouts, T, F, R) {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl GetProfileId for storage::PayoutBatch {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
 /// Filter Objects based on profile ids
 pub(super) fn filter_objects_based_on_profile_id_list<
     T: GetProfileId,
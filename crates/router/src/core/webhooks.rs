@@ -10,8 +10,12 @@ mod outgoing;
 mod outgoing_v2;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 pub mod recovery_incoming;
+#[cfg(feature = "v1")]
+pub mod signing_secret;
 pub mod types;
 pub mod utils;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
@@ -20,7 +20,7 @@ use router_env::{
     tracing::{self, Instrument},
 };
 
-use super::{types, utils, MERCHANT_ID};
+use super::{signing_secret, types, utils, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
@@ -61,21 +61,105 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
     let idempotent_event_id =
         utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    if !state.conf.webhooks.outgoing_enabled {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
             %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
         );
         return Ok(());
     }
 
+    let is_profile_webhook_url_configured =
+        get_webhook_url_from_business_profile(&business_profile)
+            .is_ok_and(|webhook_url| !webhook_url.is_empty());
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to list webhook endpoints"))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|webhook_endpoint| webhook_endpoint.is_event_type_enabled(event_type))
+        .collect::<Vec<_>>();
+
+    if !is_profile_webhook_url_configured && webhook_endpoints.is_empty() {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %idempotent_event_id,
+            "Merchant webhook URL could not be obtained and no webhook endpoint is subscribed to \
+             the event; skipping outgoing webhooks for event"
+        );
+        return Ok(());
+    }
+
+    // The webhook URL of the business profile receives all events, webhook endpoints receive
+    // only the events they are subscribed to
+    let webhook_destinations = is_profile_webhook_url_configured
+        .then_some(None)
+        .into_iter()
+        .chain(webhook_endpoints.into_iter().map(Some));
+
+    let mut result = Ok(());
+    for webhook_endpoint in webhook_destinations {
+        let webhook_endpoint_id = webhook_endpoint
+            .as_ref()
+            .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+
+        if let Err(error) = Box::pin(create_event_and_trigger_outgoing_webhook_for_destination(
+            state.clone(),
+            &merchant_context,
+            business_profile.clone(),
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            webhook_endpoint,
+        ))
+        .await
+        {
+            logger::error!(
+                ?error,
+                ?webhook_endpoint_id,
+                "Failed to create and trigger outgoing webhook"
+            );
+            result = Err(error);
+        }
+    }
+
+    result
+}
+
+/// Creates the event for delivering the webhook to a single destination, which is either the
+/// webhook URL of the business profile (if `webhook_endpoint` is `None`) or a webhook endpoint
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_destination(
+    state: SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: domain::Profile,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    webhook_endpoint: Option<storage::WebhookEndpoint>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let idempotent_event_id = utils::get_idempotent_event_id_for_webhook_endpoint(
+        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt),
+        webhook_endpoint.as_ref(),
+    );
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -88,10 +172,27 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_context, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let webhook_endpoint_signing_secret = match webhook_endpoint.as_ref() {
+        Some(webhook_endpoint) => Some(
+            utils::get_webhook_endpoint_signing_secret(
+                &state,
+                merchant_context.get_merchant_key_store(),
+                webhook_endpoint,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)?,
+        ),
+        None => None,
+    };
+
+    let request_content = get_outgoing_webhook_request(
+        merchant_context,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint_signing_secret.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -140,6 +241,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: webhook_endpoint.map(|webhook_endpoint| webhook_endpoint.endpoint_id),
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -292,11 +394,11 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let (webhook_url, webhook_endpoint) = match (
+        get_webhook_url_for_event(&state, &business_profile, &event).await,
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(webhook_destination), _) => Ok(webhook_destination),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
@@ -319,11 +421,39 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
-    let headers = request_content
+    // The timestamped signature is generated for every delivery attempt, so that retried webhooks
+    // carry a recent timestamp and are signed with the current secrets
+    let signing_secrets = match webhook_endpoint {
+        Some(webhook_endpoint) => vec![
+            utils::get_webhook_endpoint_signing_secret(
+                &state,
+                merchant_key_store,
+                &webhook_endpoint,
+            )
+            .await?,
+        ],
+        None => signing_secret::get_webhook_signing_secrets(&business_profile),
+    };
+    let timestamped_signature = signing_secret::get_timestamped_webhook_signature(
+        request_content.body.peek(),
+        &signing_secrets,
+        common_utils::date_time::now_unix_timestamp(),
+    )?;
+
+    let mut headers: Vec<(String, masking::Maskable<String>)> = request_content
         .headers
         .into_iter()
+        .filter(|(name, _)| {
+            !name.eq_ignore_ascii_case(crate::headers::X_WEBHOOK_TIMESTAMPED_SIGNATURE)
+        })
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
+    if let Some(timestamped_signature) = timestamped_signature {
+        headers.push((
+            crate::headers::X_WEBHOOK_TIMESTAMPED_SIGNATURE.to_string(),
+            timestamped_signature.into(),
+        ));
+    }
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
@@ -637,6 +767,43 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+/// Returns the URL of the webhook endpoint of the event along with the endpoint, or the webhook
+/// URL of the business profile if the event is not associated with a webhook endpoint
+async fn get_webhook_url_for_event(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    event: &domain::Event,
+) -> CustomResult<(String, Option<storage::WebhookEndpoint>), errors::WebhooksFlowError> {
+    let Some(webhook_endpoint_id) = event.webhook_endpoint_id.as_deref() else {
+        return get_webhook_url_from_business_profile(business_profile)
+            .map(|webhook_url| (webhook_url, None));
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(
+            &business_profile.merchant_id,
+            webhook_endpoint_id,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)
+            } else {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointFetchFailed)
+            }
+        })?;
+
+    if webhook_endpoint.status == enums::WebhookEndpointStatus::Disabled {
+        return Err(report!(errors::WebhooksFlowError::WebhookEndpointDisabled))
+            .attach_printable_lazy(|| {
+                format!("Webhook endpoint `{webhook_endpoint_id}` is disabled")
+            });
+    }
+
+    Ok((webhook_endpoint.url.clone(), Some(webhook_endpoint)))
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::Profile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -653,15 +820,19 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Webhooks delivered to a webhook endpoint are signed with the signing secret of the endpoint,
+/// and with the payment response hash key of the business profile otherwise
 pub(crate) fn get_outgoing_webhook_request(
     merchant_context: &domain::MerchantContext,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint_signing_secret: Option<&Secret<String>>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint_signing_secret: Option<&Secret<String>>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -675,7 +846,10 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        let payment_response_hash_key = match webhook_endpoint_signing_secret {
+            Some(signing_secret) => Some(signing_secret.peek().clone()),
+            None => business_profile.payment_response_hash_key.clone(),
+        };
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -715,12 +889,17 @@ pub(crate) fn get_outgoing_webhook_request(
         .get_compatible_connector()
     {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint_signing_secret,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint_signing_secret,
         ),
     }
 }
@@ -1024,6 +1203,17 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.subscription_id.clone(),
+                }
+            }
+            #[cfg(feature = "payouts")]
+            webhooks::OutgoingWebhookContent::PayoutBatchDetails(payout_batch_response) => {
+                Self::PayoutBatch {
+                    batch_id: payout_batch_response.batch_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1068,5 +1258,17 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Subscription { subscription_id } => {
+            OutgoingWebhookEventContent::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::PayoutBatch { batch_id } => {
+            OutgoingWebhookEventContent::PayoutBatch {
+                batch_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
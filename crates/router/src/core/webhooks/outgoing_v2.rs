@@ -115,6 +115,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: None,
     };
 
     let event_insert_result = state
@@ -616,6 +617,17 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.subscription_id.clone(),
+                }
+            }
+            #[cfg(feature = "payouts")]
+            webhooks::OutgoingWebhookContent::PayoutBatchDetails(payout_batch_response) => {
+                Self::PayoutBatch {
+                    batch_id: payout_batch_response.batch_id.clone(),
+                }
+            }
         }
     }
 }
@@ -657,6 +669,14 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 mandate_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::Subscription { subscription_id } => Self::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            },
+            diesel_models::EventMetadata::PayoutBatch { batch_id } => Self::PayoutBatch {
+                batch_id,
+                content: serde_json::Value::Null,
+            },
         }
     }
 }
@@ -0,0 +1,331 @@
+#[cfg(feature = "olap")]
+use api_models::admin as admin_types;
+use common_utils::{crypto::SignMessage, date_time};
+#[cfg(feature = "olap")]
+use common_utils::{id_type, type_name, types::keymanager::Identifier};
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+#[cfg(feature = "olap")]
+use router_env::{instrument, tracing};
+#[cfg(feature = "olap")]
+use time::PrimitiveDateTime;
+
+use super::types;
+#[cfg(feature = "olap")]
+use crate::{
+    consts,
+    core::errors::{RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::metrics,
+    services::ApplicationResponse,
+    types::storage,
+};
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::SessionState,
+    types::domain,
+};
+
+#[cfg(feature = "olap")]
+const WEBHOOK_SIGNING_SECRET_EXPIRY_TAG: &str = "WEBHOOK";
+#[cfg(feature = "olap")]
+const WEBHOOK_SIGNING_SECRET_EXPIRY_NAME: &str = "WEBHOOK_SIGNING_SECRET_EXPIRY";
+#[cfg(feature = "olap")]
+const WEBHOOK_SIGNING_SECRET_EXPIRY_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::WebhookSigningSecretExpiryWorkflow;
+
+/// Signature scheme version of the signatures in the timestamped webhook signature header
+const WEBHOOK_SIGNATURE_VERSION: &str = "v1";
+
+/// Returns the secrets with which webhooks delivered to the webhook URL of the business profile
+/// are signed: the payment response hash key of the profile, followed by the previous secret if it
+/// is still within its grace period
+pub(crate) fn get_webhook_signing_secrets(
+    business_profile: &domain::Profile,
+) -> Vec<Secret<String>> {
+    let is_previous_secret_valid = business_profile
+        .previous_payment_response_hash_key_expires_at
+        .is_some_and(|expires_at| expires_at > date_time::now());
+    let previous_secret = business_profile
+        .previous_payment_response_hash_key
+        .as_ref()
+        .filter(|_| is_previous_secret_valid)
+        .map(|previous_secret| previous_secret.get_inner().clone());
+
+    business_profile
+        .payment_response_hash_key
+        .clone()
+        .map(Secret::new)
+        .into_iter()
+        .chain(previous_secret)
+        .collect()
+}
+
+/// Generates the value of the timestamped webhook signature header, of the form
+/// `t=<timestamp>,v1=<signature>[,v1=<signature>]`, with one signature per signing secret. Each
+/// signature is the hex encoded HMAC-SHA512 of `<timestamp>.<payload>`, so that merchants can
+/// reject replayed webhooks based on the timestamp.
+pub(crate) fn get_timestamped_webhook_signature(
+    payload: &str,
+    signing_secrets: &[Secret<String>],
+    timestamp: i64,
+) -> CustomResult<Option<String>, errors::WebhooksFlowError> {
+    if signing_secrets.is_empty() {
+        return Ok(None);
+    }
+
+    let signature_payload = format!("{timestamp}.{payload}");
+    let signatures = signing_secrets
+        .iter()
+        .map(|signing_secret| {
+            common_utils::crypto::HmacSha512::sign_message(
+                &common_utils::crypto::HmacSha512,
+                signing_secret.peek().as_bytes(),
+                signature_payload.as_bytes(),
+            )
+            .map(|signature| format!("{WEBHOOK_SIGNATURE_VERSION}={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?;
+
+    Ok(Some(format!("t={timestamp},{}", signatures.join(","))))
+}
+
+/// Replaces the webhook signing secret of the business profile, keeping the previous secret valid
+/// for the requested grace period
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn rotate_webhook_signing_secret(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: admin_types::WebhookSigningSecretRotateRequest,
+) -> RouterResponse<admin_types::WebhookSigningSecretRotateResponse> {
+    let grace_period_in_seconds = request
+        .grace_period_in_seconds
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SIGNING_SECRET_GRACE_PERIOD);
+
+    if grace_period_in_seconds > consts::MAX_WEBHOOK_SIGNING_SECRET_GRACE_PERIOD {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`grace_period_in_seconds` must be at most {}",
+                consts::MAX_WEBHOOK_SIGNING_SECRET_GRACE_PERIOD
+            ),
+        }
+        .into());
+    }
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            &merchant_id,
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // Any secret replaced by an earlier rotation is discarded, only the secret being replaced now
+    // remains valid during the grace period
+    let (previous_payment_response_hash_key, previous_secret_expires_at) =
+        match business_profile.payment_response_hash_key.clone() {
+            Some(previous_secret) if grace_period_in_seconds > 0 => {
+                let previous_secret = crypto_operation::<String, masking::WithType>(
+                    key_manager_state,
+                    type_name!(domain::Profile),
+                    CryptoOperation::Encrypt(Secret::new(previous_secret)),
+                    Identifier::Merchant(key_store.merchant_id.clone()),
+                    key_store.key.get_inner().peek(),
+                )
+                .await
+                .and_then(|val| val.try_into_operation())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encrypt previous webhook signing secret")?;
+                let expires_at = date_time::now()
+                    .saturating_add(time::Duration::seconds(i64::from(grace_period_in_seconds)));
+
+                (Some(previous_secret), Some(expires_at))
+            }
+            _ => (None, None),
+        };
+
+    let payment_response_hash_key =
+        common_utils::crypto::generate_cryptographically_secure_random_string(64);
+    db.update_profile_by_profile_id(
+        key_manager_state,
+        &key_store,
+        business_profile,
+        domain::ProfileUpdate::PaymentResponseHashKeyUpdate {
+            payment_response_hash_key: payment_response_hash_key.clone(),
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: previous_secret_expires_at,
+        },
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    if let Some(expires_at) = previous_secret_expires_at {
+        add_webhook_signing_secret_expiry_task(db, &merchant_id, &profile_id, expires_at).await?;
+    }
+
+    Ok(ApplicationResponse::Json(
+        admin_types::WebhookSigningSecretRotateResponse {
+            profile_id,
+            payment_response_hash_key: Secret::new(payment_response_hash_key),
+            previous_secret_expires_at,
+        },
+    ))
+}
+
+#[cfg(feature = "olap")]
+async fn add_webhook_signing_secret_expiry_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    expires_at: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let tracking_data = types::WebhookSigningSecretExpiryTrackingData {
+        merchant_id: merchant_id.clone(),
+        profile_id: profile_id.clone(),
+        expires_at,
+    };
+
+    // A profile can be rotated several times, each rotation schedules its own task
+    let process_tracker_id = format!(
+        "{WEBHOOK_SIGNING_SECRET_EXPIRY_RUNNER}_{WEBHOOK_SIGNING_SECRET_EXPIRY_NAME}_{}_{}",
+        profile_id.get_string_repr(),
+        expires_at.assume_utc().unix_timestamp()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        WEBHOOK_SIGNING_SECRET_EXPIRY_NAME,
+        WEBHOOK_SIGNING_SECRET_EXPIRY_RUNNER,
+        [WEBHOOK_SIGNING_SECRET_EXPIRY_TAG],
+        tracking_data,
+        None,
+        expires_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct webhook signing secret expiry process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting webhook signing secret expiry task to process_tracker: {:?}",
+                profile_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "WebhookSigningSecretExpiry")),
+    );
+
+    Ok(())
+}
+
+/// Deletes the previous webhook signing secret of the profile, unless it was replaced by a later
+/// rotation with a different expiry
+pub(crate) async fn expire_previous_webhook_signing_secret(
+    state: &SessionState,
+    tracking_data: &types::WebhookSigningSecretExpiryTrackingData,
+) -> CustomResult<(), errors::StorageError> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            &tracking_data.merchant_id,
+            &tracking_data.profile_id,
+        )
+        .await?;
+
+    match business_profile.previous_payment_response_hash_key_expires_at {
+        Some(expires_at)
+            if expires_at == tracking_data.expires_at || expires_at <= date_time::now() =>
+        {
+            db.update_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                business_profile,
+                domain::ProfileUpdate::PreviousPaymentResponseHashKeyDelete,
+            )
+            .await?;
+            logger::info!(
+                profile_id = ?tracking_data.profile_id,
+                "Deleted expired previous webhook signing secret"
+            );
+        }
+        Some(_) | None => logger::debug!(
+            profile_id = ?tracking_data.profile_id,
+            "Previous webhook signing secret was already replaced or deleted"
+        ),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_timestamped_webhook_signature_contains_signature_per_secret() {
+        let payload = r#"{"event_id":"evt_123"}"#;
+        let new_secret = Secret::new("new_secret".to_string());
+        let old_secret = Secret::new("old_secret".to_string());
+
+        let signature = get_timestamped_webhook_signature(
+            payload,
+            &[new_secret.clone(), old_secret],
+            1_700_000_000,
+        )
+        .unwrap()
+        .unwrap();
+        let components = signature.split(',').collect::<Vec<_>>();
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[0], "t=1700000000");
+
+        let expected_signature = hex::encode(
+            common_utils::crypto::HmacSha512
+                .sign_message(
+                    new_secret.peek().as_bytes(),
+                    format!("1700000000.{payload}").as_bytes(),
+                )
+                .unwrap(),
+        );
+        assert_eq!(components[1], format!("v1={expected_signature}"));
+        assert_ne!(components[1], components[2]);
+    }
+
+    #[test]
+    fn test_timestamped_webhook_signature_without_secrets() {
+        assert!(get_timestamped_webhook_signature("{}", &[], 1_700_000_000)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -124,3 +124,12 @@ impl WebhookResponse {
         }
     }
 }
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct WebhookSigningSecretExpiryTrackingData {
+    pub(crate) merchant_id: common_utils::id_type::MerchantId,
+    pub(crate) profile_id: common_utils::id_type::ProfileId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub(crate) expires_at: time::PrimitiveDateTime,
+}
@@ -160,6 +160,41 @@ pub(crate) fn get_idempotent_event_id(
     }
 }
 
+/// Events delivered to webhook endpoints are deduplicated per endpoint
+pub(crate) fn get_idempotent_event_id_for_webhook_endpoint(
+    idempotent_event_id: String,
+    webhook_endpoint: Option<&types::storage::WebhookEndpoint>,
+) -> String {
+    match webhook_endpoint {
+        Some(webhook_endpoint) => format!("{idempotent_event_id}_{}", webhook_endpoint.endpoint_id),
+        None => idempotent_event_id,
+    }
+}
+
+/// Decrypts the signing secret of the webhook endpoint, which is stored encrypted with the key of
+/// the merchant
+#[cfg(feature = "v1")]
+pub(crate) async fn get_webhook_endpoint_signing_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: &types::storage::WebhookEndpoint,
+) -> CustomResult<masking::Secret<String>, errors::WebhooksFlowError> {
+    use masking::PeekInterface;
+
+    domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        common_utils::type_name!(types::storage::WebhookEndpoint),
+        domain::types::CryptoOperation::Decrypt(webhook_endpoint.signing_secret.clone()),
+        common_utils::types::keymanager::Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(|signing_secret| signing_secret.into_inner())
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+    .attach_printable("Failed to decrypt webhook endpoint signing secret")
+}
+
 #[inline]
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
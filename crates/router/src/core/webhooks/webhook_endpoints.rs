@@ -0,0 +1,270 @@
+use api_models::webhook_endpoints as webhook_endpoint_types;
+use common_utils::{fp_utils, id_type, type_name, types::keymanager::Identifier};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// Maximum number of webhook endpoints that can be configured for a business profile
+const WEBHOOK_ENDPOINTS_PER_PROFILE_MAX_LIMIT: usize = 16;
+const WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH: usize = 255;
+const WEBHOOK_ENDPOINT_ID_PREFIX: &str = "whep";
+const WEBHOOK_ENDPOINT_SIGNING_SECRET_LENGTH: usize = 64;
+
+fn to_webhook_endpoint_response(
+    webhook_endpoint: storage::WebhookEndpoint,
+    signing_secret: Option<Secret<String>>,
+) -> webhook_endpoint_types::WebhookEndpointResponse {
+    webhook_endpoint_types::WebhookEndpointResponse {
+        endpoint_id: webhook_endpoint.endpoint_id,
+        merchant_id: webhook_endpoint.merchant_id,
+        profile_id: webhook_endpoint.profile_id,
+        url: webhook_endpoint.url,
+        enabled_events: webhook_endpoint.enabled_events.0,
+        status: webhook_endpoint.status,
+        description: webhook_endpoint.description,
+        signing_secret,
+        created: webhook_endpoint.created_at,
+        modified: webhook_endpoint.modified_at,
+    }
+}
+
+fn validate_description(description: Option<&String>) -> RouterResult<()> {
+    fp_utils::when(
+        description
+            .is_some_and(|description| description.len() > WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`description` must be at most {WEBHOOK_ENDPOINT_DESCRIPTION_MAX_LENGTH} characters"
+                ),
+            }
+            .into())
+        },
+    )
+}
+
+async fn validate_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+async fn find_webhook_endpoint(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    endpoint_id: &str,
+) -> RouterResult<storage::WebhookEndpoint> {
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Webhook endpoint `{endpoint_id}` not found"),
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+        .await
+        .to_not_found_response(not_found_error())?;
+
+    // Webhook endpoints are scoped to a business profile
+    fp_utils::when(&webhook_endpoint.profile_id != profile_id, || {
+        Err(not_found_error().into())
+    })?;
+
+    Ok(webhook_endpoint)
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: webhook_endpoint_types::WebhookEndpointCreateRequest,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    validate_description(request.description.as_ref())?;
+    validate_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+
+    let existing_webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(&merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?;
+
+    fp_utils::when(
+        existing_webhook_endpoints.len() >= WEBHOOK_ENDPOINTS_PER_PROFILE_MAX_LIMIT,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "A business profile can have at most {WEBHOOK_ENDPOINTS_PER_PROFILE_MAX_LIMIT} webhook endpoints"
+                ),
+            })
+        },
+    )?;
+
+    let signing_secret = Secret::new(
+        common_utils::crypto::generate_cryptographically_secure_random_string(
+            WEBHOOK_ENDPOINT_SIGNING_SECRET_LENGTH,
+        ),
+    );
+    let encrypted_signing_secret = crypto_operation::<String, masking::WithType>(
+        &(&state).into(),
+        type_name!(storage::WebhookEndpoint),
+        CryptoOperation::Encrypt(signing_secret.clone()),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt webhook endpoint signing secret")?;
+    let now = common_utils::date_time::now();
+    let webhook_endpoint_new = storage::WebhookEndpointNew {
+        endpoint_id: common_utils::generate_id(consts::ID_LENGTH, WEBHOOK_ENDPOINT_ID_PREFIX),
+        merchant_id,
+        profile_id,
+        url: request.url.to_string(),
+        signing_secret: encrypted_signing_secret.into(),
+        enabled_events: storage::WebhookEndpointEventTypes(request.enabled_events),
+        status: request.status.unwrap_or_default(),
+        description: request.description,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .insert_webhook_endpoint(webhook_endpoint_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Webhook endpoint with the generated ID already exists".to_owned(),
+        })?;
+
+    Ok(ApplicationResponse::Json(to_webhook_endpoint_response(
+        webhook_endpoint,
+        Some(signing_secret),
+    )))
+}
+
+#[instrument(skip(state, key_store))]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<Vec<webhook_endpoint_types::WebhookEndpointResponse>> {
+    validate_business_profile(&state, &key_store, &merchant_id, &profile_id).await?;
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(&merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints
+            .into_iter()
+            .map(|webhook_endpoint| to_webhook_endpoint_response(webhook_endpoint, None))
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, &merchant_id, &profile_id, &endpoint_id).await?;
+
+    Ok(ApplicationResponse::Json(to_webhook_endpoint_response(
+        webhook_endpoint,
+        None,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+    request: webhook_endpoint_types::WebhookEndpointUpdateRequest,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    validate_description(request.description.as_ref())?;
+    find_webhook_endpoint(&state, &merchant_id, &profile_id, &endpoint_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            &merchant_id,
+            &endpoint_id,
+            storage::WebhookEndpointUpdate::Update {
+                url: request.url.map(|url| url.to_string()),
+                enabled_events: request
+                    .enabled_events
+                    .map(storage::WebhookEndpointEventTypes),
+                status: request.status,
+                description: request.description,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update webhook endpoint")?;
+
+    Ok(ApplicationResponse::Json(to_webhook_endpoint_response(
+        webhook_endpoint,
+        None,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointDeleteResponse> {
+    find_webhook_endpoint(&state, &merchant_id, &profile_id, &endpoint_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .delete_webhook_endpoint_by_merchant_id_endpoint_id(&merchant_id, &endpoint_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete webhook endpoint")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_types::WebhookEndpointDeleteResponse {
+            endpoint_id: webhook_endpoint.endpoint_id,
+            profile_id: webhook_endpoint.profile_id,
+            deleted: true,
+        },
+    ))
+}
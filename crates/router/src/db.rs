@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -29,17 +30,22 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method_session;
+pub mod payout_batch;
+pub mod payout_batch_item;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
 pub mod subscription;
+pub mod subscription_invoice;
+pub mod subscription_plan;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_utils::id_type;
@@ -145,6 +151,12 @@ pub trait StorageInterface:
     + tokenization::TokenizationInterface
     + callback_mapper::CallbackMapperInterface
     + subscription::SubscriptionInterface
+    + subscription_invoice::SubscriptionInvoiceInterface
+    + subscription_plan::SubscriptionPlanInterface
+    + webhook_endpoint::WebhookEndpointInterface
+    + audit_log::AuditLogInterface
+    + payout_batch::PayoutBatchInterface
+    + payout_batch_item::PayoutBatchItemInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -29,6 +29,12 @@ pub trait BlocklistInterface {
         fingerprint_id: &str,
     ) -> CustomResult<storage::Blocklist, errors::StorageError>;
 
+    async fn find_active_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
     async fn list_blocklist_entries_by_merchant_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -111,6 +117,22 @@ impl BlocklistInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn find_active_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Blocklist::find_active_by_merchant_id_fingerprint_ids(
+            &conn,
+            merchant_id,
+            fingerprint_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -155,6 +177,14 @@ impl BlocklistInterface for MockDb {
     ) -> CustomResult<storage::Blocklist, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_active_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[async_trait::async_trait]
@@ -189,6 +219,20 @@ impl BlocklistInterface for KafkaStore {
             .await
     }
 
+    #[instrument(skip_all)]
+    async fn find_active_blocklist_entries_by_merchant_id_fingerprint_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .find_active_blocklist_entries_by_merchant_id_fingerprint_ids(
+                merchant_id,
+                fingerprint_ids,
+            )
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn list_blocklist_entries_by_merchant_id_data_kind(
         &self,
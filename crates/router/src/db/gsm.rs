@@ -15,6 +15,11 @@ pub trait GsmInterface {
         &self,
         rule: hyperswitch_domain_models::gsm::GatewayStatusMap,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError>;
+    /// Adds all the rules or none of them
+    async fn add_gsm_rules(
+        &self,
+        rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    ) -> CustomResult<(), errors::StorageError>;
     async fn find_gsm_decision(
         &self,
         connector: String,
@@ -31,6 +36,16 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<hyperswitch_domain_models::gsm::GatewayStatusMap, errors::StorageError>;
+    async fn find_all_gsm_rules(
+        &self,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>;
+    /// Lists every rule of the connector and flow, regardless of how the rule is matched
+    async fn find_gsm_rules_by_connector_flow(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>;
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -71,6 +86,23 @@ impl GsmInterface for Store {
             .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
+    #[instrument(skip_all)]
+    async fn add_gsm_rules(
+        &self,
+        rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let gsm_db_records = rules
+            .into_iter()
+            .map(diesel_models::gsm::GatewayStatusMappingNew::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .change_context(errors::StorageError::SerializationFailed)
+            .attach_printable("Failed to convert gsm domain models to diesel models")?;
+        diesel_models::gsm::GatewayStatusMappingNew::batch_insert(gsm_db_records, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn find_gsm_decision(
         &self,
@@ -108,6 +140,45 @@ impl GsmInterface for Store {
             .attach_printable("Failed to convert gsm diesel models to domain models")
     }
 
+    #[instrument(skip_all)]
+    async fn find_all_gsm_rules(
+        &self,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::find_all(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .into_iter()
+            .map(|gsm_db_record| {
+                hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+                    .change_context(errors::StorageError::DeserializationFailed)
+                    .attach_printable("Failed to convert gsm diesel models to domain models")
+            })
+            .collect()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_gsm_rules_by_connector_flow(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::find_all_by_connector_flow(&conn, connector, flow, sub_flow)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .into_iter()
+            .map(|gsm_db_record| {
+                hyperswitch_domain_models::gsm::GatewayStatusMap::try_from(gsm_db_record)
+                    .change_context(errors::StorageError::DeserializationFailed)
+                    .attach_printable("Failed to convert gsm diesel models to domain models")
+            })
+            .collect()
+    }
+
     #[instrument(skip_all)]
     async fn update_gsm_rule(
         &self,
@@ -163,6 +234,13 @@ impl GsmInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn add_gsm_rules(
+        &self,
+        _rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn find_gsm_decision(
         &self,
         _connector: String,
@@ -185,6 +263,23 @@ impl GsmInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_all_gsm_rules(
+        &self,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_gsm_rules_by_connector_flow(
+        &self,
+        _connector: String,
+        _flow: String,
+        _sub_flow: String,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn update_gsm_rule(
         &self,
         _connector: String,
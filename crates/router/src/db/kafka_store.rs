@@ -2525,6 +2525,17 @@ impl PayoutsInterface for KafkaStore {
             .await
     }
 
+    async fn find_payouts_by_merchant_id_payout_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payout_ids: &[id_type::PayoutId],
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::StorageError> {
+        self.diesel_store
+            .find_payouts_by_merchant_id_payout_ids(merchant_id, payout_ids, storage_scheme)
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
@@ -3207,6 +3218,13 @@ impl GsmInterface for KafkaStore {
         self.diesel_store.add_gsm_rule(rule).await
     }
 
+    async fn add_gsm_rules(
+        &self,
+        rules: Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store.add_gsm_rules(rules).await
+    }
+
     async fn find_gsm_decision(
         &self,
         connector: String,
@@ -3233,6 +3251,25 @@ impl GsmInterface for KafkaStore {
             .await
     }
 
+    async fn find_all_gsm_rules(
+        &self,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        self.diesel_store.find_all_gsm_rules().await
+    }
+
+    async fn find_gsm_rules_by_connector_flow(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<hyperswitch_domain_models::gsm::GatewayStatusMap>, errors::StorageError>
+    {
+        self.diesel_store
+            .find_gsm_rules_by_connector_flow(connector, flow, sub_flow)
+            .await
+    }
+
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -0,0 +1,198 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInvoiceInterface {
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice_new: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError>;
+
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInvoiceInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice_new: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_invoice_new
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SubscriptionInvoice::find_by_merchant_id_subscription_id_period_start(
+            &conn,
+            merchant_id,
+            subscription_id,
+            period_start,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::list_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SubscriptionInvoice::update_by_merchant_id_invoice_id(
+            &conn,
+            merchant_id,
+            invoice_id,
+            subscription_invoice_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInvoiceInterface for MockDb {
+    async fn insert_subscription_invoice(
+        &self,
+        _subscription_invoice_new: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _invoice_id: &str,
+        _subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInvoiceInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice_new: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_invoice(subscription_invoice_new)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+                merchant_id,
+                subscription_id,
+                period_start,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_invoices_by_merchant_id_subscription_id(merchant_id, subscription_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_invoice_by_merchant_id_invoice_id(
+                merchant_id,
+                invoice_id,
+                subscription_invoice_update,
+            )
+            .await
+    }
+}
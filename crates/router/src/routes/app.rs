@@ -65,11 +65,14 @@ use super::{
     profiles, relay, user, user_role,
 };
 #[cfg(feature = "v1")]
-use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
+use super::{
+    apple_pay_certificates_migration, audit_log, blocklist, payment_link, webhook_endpoints,
+    webhook_events,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers, payments};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
-use super::{mandates::*, refunds::*};
+use super::{mandates::*, refunds::*, subscription};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -135,6 +138,8 @@ pub struct SessionState {
     pub crm_client: Arc<dyn CrmInterface>,
     pub infra_components: Option<serde_json::Value>,
     pub enhancement: Option<HashMap<String, String>>,
+    /// The user or API key performing the request, recorded in the audit log
+    pub audit_log_actor: Option<crate::core::audit_log::AuditLogActor>,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -543,6 +548,7 @@ impl AppState {
             crm_client: self.crm_client.clone(),
             infra_components: self.infra_components.clone(),
             enhancement: self.enhancement.clone(),
+            audit_log_actor: None,
         })
     }
 
@@ -986,6 +992,18 @@ impl Routing {
                     routing::routing_unlink_config(state, req, payload, None)
                 })),
             )
+            .service(
+                web::resource("/shadow/deactivate")
+                    .route(web::post().to(routing::routing_unlink_shadow_config)),
+            )
+            .service(
+                web::resource("/dsl/import")
+                    .route(web::post().to(routing::routing_import_dsl_config)),
+            )
+            .service(
+                web::resource("/eligibility/explain")
+                    .route(web::post().to(routing::routing_explain_eligibility)),
+            )
             .service(
                 web::resource("/decision")
                     .route(web::put().to(routing::upsert_decision_manager_config))
@@ -1151,6 +1169,22 @@ impl Routing {
                     },
                 )),
             )
+            .service(
+                web::resource("/{algorithm_id}/shadow/activate")
+                    .route(web::post().to(routing::routing_link_shadow_config)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/simulate")
+                    .route(web::post().to(routing::routing_simulate_algorithm)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/dsl")
+                    .route(web::get().to(routing::routing_export_dsl_config)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/rule_hits")
+                    .route(web::get().to(routing::routing_retrieve_rule_hits)),
+            )
             .service(
                 web::resource("/rule/evaluate")
                     .route(web::post().to(routing::evaluate_routing_rule)),
@@ -1315,7 +1349,17 @@ pub struct Payouts;
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/payouts").app_data(web::Data::new(state));
-        route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
+        route = route
+            .service(web::resource("/create").route(web::post().to(payouts_create)))
+            .service(web::resource("/batches").route(web::post().to(payouts_batch_create)))
+            .service(web::resource("/batches/upload").route(web::post().to(payouts_batch_upload)))
+            .service(
+                web::resource("/batches/{batch_id}").route(web::get().to(payouts_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}/results")
+                    .route(web::get().to(payouts_batch_results)),
+            );
 
         #[cfg(feature = "olap")]
         {
@@ -1599,12 +1643,32 @@ impl Blocklist {
                     .route(web::post().to(blocklist::add_entry_to_blocklist))
                     .route(web::delete().to(blocklist::remove_entry_from_blocklist)),
             )
+            .service(
+                web::resource("/bulk")
+                    .route(web::post().to(blocklist::bulk_add_entries_to_blocklist)),
+            )
             .service(
                 web::resource("/toggle").route(web::post().to(blocklist::toggle_blocklist_guard)),
             )
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct AuditLogs;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl AuditLogs {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_logs")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(audit_log::list_audit_logs)))
+            .service(
+                web::resource("/{merchant_id}")
+                    .route(web::get().to(audit_log::list_merchant_audit_logs)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Organization;
 
@@ -1752,6 +1816,13 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/circuit_breaker",
+                    )
+                    .route(web::get().to(connector_circuit_breaker_retrieve))
+                    .route(web::delete().to(connector_circuit_breaker_reset)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1809,6 +1880,48 @@ impl Mandates {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/plans")
+                    .route(web::post().to(subscription::create_subscription_plan))
+                    .route(web::get().to(subscription::list_subscription_plans)),
+            )
+            .service(
+                web::resource("/plans/{plan_id}")
+                    .route(web::get().to(subscription::retrieve_subscription_plan))
+                    .route(web::post().to(subscription::update_subscription_plan)),
+            )
+            .service(web::resource("").route(web::post().to(subscription::create_subscription)))
+            .service(
+                web::resource("/{subscription_id}")
+                    .route(web::get().to(subscription::retrieve_subscription))
+                    .route(web::post().to(subscription::update_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/pause")
+                    .route(web::post().to(subscription::pause_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/resume")
+                    .route(web::post().to(subscription::resume_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(subscription::cancel_subscription)),
+            )
+            .service(
+                web::resource("/{subscription_id}/invoices")
+                    .route(web::get().to(subscription::list_subscription_invoices)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(all(feature = "oltp", feature = "v1"))]
@@ -2284,6 +2397,21 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::resource("/rotate_webhook_signing_secret")
+                        .route(web::post().to(profiles::rotate_webhook_signing_secret)),
+                )
+                .service(
+                    web::resource("/webhook_endpoints")
+                        .route(web::post().to(webhook_endpoints::create_webhook_endpoint))
+                        .route(web::get().to(webhook_endpoints::list_webhook_endpoints)),
+                )
+                .service(
+                    web::resource("/webhook_endpoints/{endpoint_id}")
+                        .route(web::get().to(webhook_endpoints::retrieve_webhook_endpoint))
+                        .route(web::post().to(webhook_endpoints::update_webhook_endpoint))
+                        .route(web::delete().to(webhook_endpoints::delete_webhook_endpoint)),
                 ),
         );
 
@@ -2323,6 +2451,13 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(gsm::get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(gsm::update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(gsm::delete_gsm_rule)))
+            .service(web::resource("/import").route(web::post().to(gsm::import_gsm_rules)))
+            .service(web::resource("/import/csv").route(web::post().to(gsm::import_gsm_rules_csv)))
+            .service(web::resource("/export").route(web::get().to(gsm::export_gsm_rules)))
+            .service(
+                web::resource("/unmapped_errors")
+                    .route(web::post().to(gsm::get_unmapped_gsm_errors)),
+            )
     }
 }
 
@@ -2335,6 +2470,13 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(gsm::get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(gsm::update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(gsm::delete_gsm_rule)))
+            .service(web::resource("/import").route(web::post().to(gsm::import_gsm_rules)))
+            .service(web::resource("/import/csv").route(web::post().to(gsm::import_gsm_rules_csv)))
+            .service(web::resource("/export").route(web::get().to(gsm::export_gsm_rules)))
+            .service(
+                web::resource("/unmapped_errors")
+                    .route(web::post().to(gsm::get_unmapped_gsm_errors)),
+            )
     }
 }
 pub struct Chat;
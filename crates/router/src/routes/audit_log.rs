@@ -0,0 +1,75 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::audit_log as audit_log_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, audit_log},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Lists the audit log entries of all merchants, including changes to resources that are not
+/// owned by a merchant, such as GSM rules
+#[instrument(skip_all, fields(flow = ?Flow::AuditLogList))]
+pub async fn list_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<audit_log_types::AuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::AuditLogList;
+    let payload = audit_log_types::AuditLogListRequestInternal {
+        merchant_id: None,
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            audit_log::list_audit_logs(state, payload.merchant_id, payload.constraints)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AuditLogList))]
+pub async fn list_merchant_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<audit_log_types::AuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::AuditLogList;
+    let merchant_id = path.into_inner();
+    let payload = audit_log_types::AuditLogListRequestInternal {
+        merchant_id: Some(merchant_id.clone()),
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            audit_log::list_audit_logs(state, payload.merchant_id, payload.constraints)
+        },
+        auth::auth_type(
+            &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
+                merchant_id_from_route: merchant_id.clone(),
+                is_admin_auth_allowed: true,
+            },
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAuditLogRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,4 +1,5 @@
-use actix_web::{web, HttpRequest, Responder};
+use actix_multipart::form::MultipartForm;
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
 use api_models::gsm as gsm_api_types;
 use router_env::{instrument, tracing, Flow};
 
@@ -162,3 +163,141 @@ pub async fn delete_gsm_rule(
     ))
     .await
 }
+
+/// Gsm - Import
+///
+/// To import Gsm Rules, either all of them or none
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules imported", body = GsmImportResponse),
+        (status = 400, description = "Invalid Gsm rules")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmImportRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRulesImport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::import_gsm_rules(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Import CSV
+///
+/// To import Gsm Rules from a CSV file with one rule per row, either all of them or none
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules_csv(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<gsm::GsmImportForm>,
+) -> HttpResponse {
+    let flow = Flow::GsmRulesImport;
+    let payload = match gsm::get_gsm_import_request(form) {
+        Ok(payload) => payload,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::import_gsm_rules(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Export
+///
+/// To export all Gsm Rules, as JSON or as a CSV file which can be imported again
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(
+        ("format" = Option<GsmExportFormat>, Query, description = "The format of the exported rules, defaults to json"),
+    ),
+    responses(
+        (status = 200, description = "Gsm rules exported", body = Vec<GsmResponse>),
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesExport))]
+pub async fn export_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<gsm_api_types::GsmExportQuery>,
+) -> impl Responder {
+    let flow = Flow::GsmRulesExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, query, _| gsm::export_gsm_rules(state, query),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Unmapped errors
+///
+/// To list the most frequent errors of failed payment attempts which aren't matched by any Gsm Rule
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    post,
+    path = "/gsm/unmapped_errors",
+    request_body(
+        content = GsmUnmappedErrorsRequest,
+    ),
+    responses(
+        (status = 200, description = "Unmapped errors retrieved", body = GsmUnmappedErrorsResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "List Unmapped Gsm Errors",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmUnmappedErrors))]
+pub async fn get_unmapped_gsm_errors(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmUnmappedErrorsRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmUnmappedErrors;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::unmapped_errors::retrieve_unmapped_gsm_errors(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
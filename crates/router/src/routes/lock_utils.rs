@@ -14,6 +14,7 @@ pub enum ApiIdentifier {
     Ephemeral,
     Health,
     Mandates,
+    Subscriptions,
     PaymentMethods,
     PaymentMethodAuth,
     Payouts,
@@ -60,7 +61,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
             | Flow::MerchantAccountList
-            | Flow::EnablePlatformAccount => Self::MerchantAccount,
+            | Flow::EnablePlatformAccount
+            | Flow::AuditLogList => Self::MerchantAccount,
 
             Flow::OrganizationCreate | Flow::OrganizationRetrieve | Flow::OrganizationUpdate => {
                 Self::Organization
@@ -69,7 +71,14 @@ impl From<Flow> for ApiIdentifier {
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
             | Flow::RoutingUnlinkConfig
+            | Flow::RoutingLinkShadowConfig
+            | Flow::RoutingUnlinkShadowConfig
             | Flow::RoutingRetrieveConfig
+            | Flow::RoutingSimulate
+            | Flow::RoutingImportDslConfig
+            | Flow::RoutingExportDslConfig
+            | Flow::RoutingExplainEligibility
+            | Flow::RoutingRetrieveRuleHits
             | Flow::RoutingRetrieveActiveConfig
             | Flow::RoutingRetrieveDefaultConfig
             | Flow::RoutingRetrieveDictionary
@@ -91,6 +100,7 @@ impl From<Flow> for ApiIdentifier {
             Flow::RetrieveForexFlow => Self::Forex,
 
             Flow::AddToBlocklist => Self::Blocklist,
+            Flow::BulkAddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
@@ -99,7 +109,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::ConnectorCircuitBreakerRetrieve
+            | Flow::ConnectorCircuitBreakerReset => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -119,6 +131,18 @@ impl From<Flow> for ApiIdentifier {
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
             Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
 
+            Flow::SubscriptionPlanCreate
+            | Flow::SubscriptionPlanList
+            | Flow::SubscriptionPlanRetrieve
+            | Flow::SubscriptionPlanUpdate
+            | Flow::SubscriptionCreate
+            | Flow::SubscriptionRetrieve
+            | Flow::SubscriptionUpdate
+            | Flow::SubscriptionPause
+            | Flow::SubscriptionResume
+            | Flow::SubscriptionCancel
+            | Flow::SubscriptionInvoiceList => Self::Subscriptions,
+
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
             | Flow::PaymentMethodsBatchUpdate
@@ -182,7 +206,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
-            | Flow::PayoutLinkInitiate => Self::Payouts,
+            | Flow::PayoutLinkInitiate
+            | Flow::PayoutBatchCreate
+            | Flow::PayoutBatchRetrieve
+            | Flow::PayoutBatchResults => Self::Payouts,
 
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
@@ -200,6 +227,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
             | Flow::RecoveryIncomingWebhookReceive
             | Flow::IncomingNetworkTokenWebhookReceive => Self::Webhooks,
 
@@ -233,7 +265,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::WebhookSigningSecretRotate => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -247,7 +280,10 @@ impl From<Flow> for ApiIdentifier {
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
-            | Flow::GsmRuleDelete => Self::Gsm,
+            | Flow::GsmRuleDelete
+            | Flow::GsmRulesImport
+            | Flow::GsmRulesExport
+            | Flow::GsmUnmappedErrors => Self::Gsm,
 
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
 
@@ -104,6 +104,7 @@ counter_metric!(AUTO_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_DEFERRED_COUNT, GLOBAL_METER);
 
 // Metrics for Payout Auto Retries
 counter_metric!(AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
@@ -132,6 +133,9 @@ counter_metric!(ACCESS_TOKEN_CACHE_MISS, GLOBAL_METER);
 // A counter to indicate the integrity check failures
 counter_metric!(INTEGRITY_CHECK_FAILED, GLOBAL_METER);
 
+// A counter to indicate the payments exceeding the threshold of a velocity rule
+counter_metric!(VELOCITY_RULE_TRIGGERED_COUNT, GLOBAL_METER);
+
 // Network Tokenization metrics
 histogram_metric_f64!(GENERATE_NETWORK_TOKEN_TIME, GLOBAL_METER);
 histogram_metric_f64!(FETCH_NETWORK_TOKEN_TIME, GLOBAL_METER);
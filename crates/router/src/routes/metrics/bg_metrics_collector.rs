@@ -17,6 +17,7 @@ pub fn spawn_metrics_collector(metrics_collection_interval_in_secs: Option<u16>)
         &cache::SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE,
         &cache::CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE,
         &cache::ELIMINATION_BASED_DYNAMIC_ALGORITHM_CACHE,
+        &cache::GSM_CACHE,
     ];
 
     tokio::spawn(async move {
@@ -16,6 +16,7 @@ use crate::{
     self as app,
     core::{
         errors::{self, http_not_implemented},
+        idempotency,
         payments::{self, PaymentRedirectFlow},
     },
     routes::lock_utils,
@@ -50,6 +51,12 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_request(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     if let Err(err) = get_or_generate_payment_id(&mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -82,18 +89,24 @@ pub async fn payments_create(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            authorize_verify_select::<_>(
-                payments::PaymentCreate,
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                header_payload.clone(),
-                req,
-                api::AuthFlow::Client,
+            idempotency::with_idempotency(
+                state.clone(),
+                merchant_id,
+                idempotency_input.clone(),
+                authorize_verify_select::<_>(
+                    payments::PaymentCreate,
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    header_payload.clone(),
+                    req,
+                    api::AuthFlow::Client,
+                ),
             )
         },
         match env::which() {
@@ -901,6 +914,17 @@ pub async fn payments_confirm(
         }
     };
 
+    // The payment id is part of the fingerprint since the key is scoped to the merchant, not
+    // to the payment being confirmed
+    let idempotency_input = match idempotency::IdempotencyInput::from_request(
+        req.headers(),
+        flow.clone(),
+        &(&*path, &payload),
+    ) {
+        Ok(idempotency_input) => idempotency_input,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
     if let Err(err) = helpers::populate_browser_info(&req, &mut payload, &header_payload) {
         return api::log_and_return_error_response(err);
     }
@@ -929,18 +953,24 @@ pub async fn payments_confirm(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            authorize_verify_select::<_>(
-                payments::PaymentConfirm,
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                header_payload.clone(),
-                req,
-                auth_flow,
+            idempotency::with_idempotency(
+                state.clone(),
+                merchant_id,
+                idempotency_input.clone(),
+                authorize_verify_select::<_>(
+                    payments::PaymentConfirm,
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    header_payload.clone(),
+                    req,
+                    auth_flow,
+                ),
             )
         },
         &*auth_type,
@@ -966,6 +996,12 @@ pub async fn payments_capture(
         ..json_payload.into_inner()
     };
 
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_request(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     let locking_action = payload.get_locking_input(flow.clone());
 
     Box::pin(api::server_wrap(
@@ -974,27 +1010,33 @@ pub async fn payments_capture(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            payments::payments_core::<
-                api_types::Capture,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::Capture>,
-            >(
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                payments::PaymentCapture,
-                payload,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
+            idempotency::with_idempotency(
+                state.clone(),
+                merchant_id,
+                idempotency_input.clone(),
+                payments::payments_core::<
+                    api_types::Capture,
+                    payment_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    payments::PaymentData<api_types::Capture>,
+                >(
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    payments::PaymentCapture,
+                    payload,
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    HeaderPayload::default(),
+                ),
             )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
@@ -35,6 +35,7 @@ pub fn populate_browser_info(
             os_type: None,
             os_version: None,
             device_model: None,
+            device_id: None,
             accept_language: None,
         });
 
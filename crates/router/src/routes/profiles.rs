@@ -440,6 +440,51 @@ pub async fn toggle_extended_card_info(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningSecretRotate))]
+pub async fn rotate_webhook_signing_secret(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: Option<web::Json<api_models::admin::WebhookSigningSecretRotateRequest>>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSigningSecretRotate;
+    let (merchant_id, profile_id) = path.into_inner();
+    let payload = json_payload
+        .map(|json_payload| json_payload.into_inner())
+        .unwrap_or_default();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth_data, req, _| {
+            crate::core::webhooks::signing_secret::rotate_webhook_signing_secret(
+                state,
+                auth_data.merchant_account.get_id().to_owned(),
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsList))]
 pub async fn payment_connector_list_profile(
@@ -52,7 +52,7 @@ use serde_json::json;
 use tera::{Context, Error as TeraError, Tera};
 
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::{self, AuthenticateAndFetch, AuthenticationType},
     connector_integration_interface::BoxedConnectorIntegrationInterface,
 };
 use crate::{
@@ -717,6 +717,24 @@ where
 
     request_state.event_context.record_info(auth_type.clone());
 
+    if let AuthenticationType::ApiKey {
+        restrictions: Some(restrictions),
+        ..
+    } = &auth_type
+    {
+        authentication::api_key_restrictions::validate_request(
+            &session_state,
+            flow.get_api_flow(),
+            request,
+            &serialized_request,
+            restrictions,
+        )
+        .switch()?;
+    }
+
+    session_state.audit_log_actor =
+        crate::core::audit_log::AuditLogActor::from_authentication_type(&auth_type);
+
     let merchant_id = auth_type
         .get_merchant_id()
         .cloned()
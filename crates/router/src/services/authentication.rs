@@ -10,6 +10,7 @@ use api_models::payments;
 use api_models::payouts;
 use async_trait::async_trait;
 use common_enums::TokenPurpose;
+use common_types::api_keys::ApiKeyRestrictions;
 use common_utils::{date_time, fp_utils, id_type};
 #[cfg(feature = "v2")]
 use diesel_models::ephemeral_key;
@@ -49,6 +50,7 @@ use crate::{
     utils::OptionExt,
 };
 
+pub mod api_key_restrictions;
 pub mod blacklist;
 pub mod cookies;
 pub mod decision;
@@ -116,6 +118,9 @@ pub enum AuthenticationType {
     ApiKey {
         merchant_id: id_type::MerchantId,
         key_id: id_type::ApiKeyId,
+        /// The restrictions of the API key, validated against each request
+        #[serde(skip)]
+        restrictions: Option<ApiKeyRestrictions>,
     },
     AdminApiKey,
     AdminApiAuthWithMerchantId {
@@ -175,6 +180,7 @@ impl AuthenticationType {
             Self::ApiKey {
                 merchant_id,
                 key_id: _,
+                restrictions: _,
             }
             | Self::AdminApiAuthWithMerchantId { merchant_id }
             | Self::MerchantId { merchant_id }
@@ -526,6 +532,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                restrictions: stored_api_key.restrictions,
             },
         ))
     }
@@ -596,6 +603,8 @@ where
                     field_name: "X-Profile-Id",
                 })
                 .change_context(errors::ApiErrorResponse::Unauthorized)?;
+        let profile_id =
+            api_key_restrictions::get_profile_id(stored_api_key.restrictions.as_ref(), profile_id)?;
 
         let merchant = state
             .store()
@@ -646,6 +655,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                restrictions: stored_api_key.restrictions,
             },
         ))
     }
@@ -802,6 +812,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                restrictions: stored_api_key.restrictions,
             },
         ))
     }
@@ -896,6 +907,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                restrictions: stored_api_key.restrictions,
             },
         ))
     }
@@ -1026,6 +1038,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: platform_merchant.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                restrictions: stored_api_key.restrictions,
             },
         ))
     }
@@ -1111,6 +1124,7 @@ where
                         AuthenticationType::ApiKey {
                             merchant_id: auth.merchant_account.get_id().clone(),
                             key_id,
+                            restrictions: None,
                         },
                     ))
                 }
@@ -1764,6 +1778,7 @@ where
                 AuthenticationType::ApiKey {
                     merchant_id: stored_api_key.merchant_id,
                     key_id: stored_api_key.key_id,
+                    restrictions: stored_api_key.restrictions,
                 },
             ));
         }
@@ -2560,6 +2575,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                restrictions: stored_api_key.restrictions,
             },
         ))
     }
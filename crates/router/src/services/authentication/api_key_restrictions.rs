@@ -0,0 +1,237 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    str::FromStr,
+};
+
+use actix_web::{http::Method, HttpRequest};
+use common_enums::{PermissionScope, Resource};
+use common_types::api_keys::{ApiKeyRestrictions, IpRange};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{logger, Flow};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    headers,
+    routes::{app::SessionStateInfo, lock_utils::ApiIdentifier},
+};
+
+/// Validates a request authenticated using an API key against the restrictions configured on the
+/// API key: the permissions required by the flow, the business profile in the route, the
+/// `X-Profile-Id` header or the request body and the source IP address of the request.
+pub fn validate_request<A>(
+    state: &A,
+    flow: Option<&Flow>,
+    request: &HttpRequest,
+    request_body: &serde_json::Value,
+    restrictions: &ApiKeyRestrictions,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo,
+{
+    let forbidden = || errors::ApiErrorResponse::AccessForbidden {
+        resource: request.path().to_owned(),
+    };
+
+    let source_ip_address =
+        get_source_ip_address(request, &state.conf().api_keys.get_inner().trusted_proxies);
+    if !restrictions.is_ip_allowed(source_ip_address) {
+        return Err(report!(forbidden())).attach_printable_lazy(|| {
+            format!("API key cannot be used from the IP address {source_ip_address:?}")
+        });
+    }
+
+    let is_permitted = match flow.and_then(|flow| get_required_permission(flow, request.method())) {
+        Some((resource, scope)) => restrictions.is_permitted(resource, scope),
+        // Flows that are not mapped to a resource are accessible only to keys without scopes
+        None => restrictions.permissions.is_none(),
+    };
+    if !is_permitted {
+        return Err(report!(forbidden()))
+            .attach_printable_lazy(|| format!("API key is not permitted to perform {flow:?}"));
+    }
+
+    let profile_id_from_route = request.match_info().get("profile_id");
+    let profile_id_from_header = request
+        .headers()
+        .get(headers::X_PROFILE_ID)
+        .and_then(|header_value| header_value.to_str().ok());
+    let profile_id_from_body = request_body
+        .get("profile_id")
+        .and_then(|profile_id| profile_id.as_str());
+    let is_profile_allowed = [
+        profile_id_from_route,
+        profile_id_from_header,
+        profile_id_from_body,
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|profile_id| id_type::ProfileId::from_str(profile_id).ok())
+    .all(|profile_id| restrictions.is_profile_allowed(&profile_id));
+    if !is_profile_allowed {
+        return Err(report!(forbidden()))
+            .attach_printable("API key is not permitted to access the business profile");
+    }
+
+    Ok(())
+}
+
+/// Returns the business profile to be used for a request authenticated using an API key. An API
+/// key restricted to a single business profile defaults to it, an API key restricted to multiple
+/// business profiles requires the `X-Profile-Id` header to be set.
+pub fn get_profile_id(
+    restrictions: Option<&ApiKeyRestrictions>,
+    profile_id: Option<id_type::ProfileId>,
+) -> RouterResult<Option<id_type::ProfileId>> {
+    let Some(restrictions) = restrictions.filter(|restrictions| restrictions.profile_ids.is_some())
+    else {
+        return Ok(profile_id);
+    };
+
+    match profile_id {
+        Some(profile_id) if restrictions.is_profile_allowed(&profile_id) => Ok(Some(profile_id)),
+        Some(_) => Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: headers::X_PROFILE_ID.to_owned(),
+        }))
+        .attach_printable("API key is not permitted to access the business profile"),
+        None => restrictions
+            .get_default_profile_id()
+            .cloned()
+            .map(Some)
+            .ok_or(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: headers::X_PROFILE_ID,
+            }))
+            .attach_printable("API key is restricted to multiple business profiles"),
+    }
+}
+
+/// Returns the IP address of the client that sent the request. The `X-Forwarded-For` header can
+/// be set by any client, so it is honoured only for requests received from a trusted proxy, in
+/// which case the nearest address in the header that is not a trusted proxy is the client.
+fn get_source_ip_address(request: &HttpRequest, trusted_proxies: &[IpRange]) -> Option<IpAddr> {
+    let is_trusted_proxy = |ip_address: &IpAddr| {
+        trusted_proxies
+            .iter()
+            .any(|trusted_proxy| trusted_proxy.contains(ip_address))
+    };
+
+    let peer_ip_address = request.peer_addr()?.ip();
+    if !is_trusted_proxy(&peer_ip_address) {
+        return Some(peer_ip_address);
+    }
+
+    let forwarded_ip_addresses = request
+        .headers()
+        .get_all(headers::X_FORWARDED_FOR)
+        .map(|header_value| header_value.to_str().ok())
+        .collect::<Option<Vec<_>>>()
+        .and_then(|header_values| {
+            header_values
+                .iter()
+                .flat_map(|header_value| header_value.split(','))
+                .map(|ip_address| parse_ip_address(ip_address.trim()))
+                .collect::<Option<Vec<_>>>()
+        });
+
+    match forwarded_ip_addresses {
+        Some(forwarded_ip_addresses) => forwarded_ip_addresses
+            .iter()
+            .rev()
+            .find(|ip_address| !is_trusted_proxy(ip_address))
+            .or(forwarded_ip_addresses.first())
+            .copied()
+            .or(Some(peer_ip_address)),
+        None => {
+            logger::warn!("Failed to parse the X-Forwarded-For header of the request");
+            Some(peer_ip_address)
+        }
+    }
+}
+
+fn parse_ip_address(ip_address: &str) -> Option<IpAddr> {
+    IpAddr::from_str(ip_address)
+        .or_else(|_| SocketAddr::from_str(ip_address).map(|socket| socket.ip()))
+        .ok()
+}
+
+fn get_required_permission(flow: &Flow, method: &Method) -> Option<(Resource, PermissionScope)> {
+    let resource = match ApiIdentifier::from(flow.clone()) {
+        ApiIdentifier::Payments | ApiIdentifier::PaymentLink | ApiIdentifier::Poll => {
+            Resource::Payment
+        }
+        ApiIdentifier::Refunds => Resource::Refund,
+        ApiIdentifier::Payouts => Resource::Payout,
+        ApiIdentifier::Disputes | ApiIdentifier::Files => Resource::Dispute,
+        ApiIdentifier::Mandates | ApiIdentifier::Subscriptions => Resource::Mandate,
+        ApiIdentifier::Customers
+        | ApiIdentifier::PaymentMethods
+        | ApiIdentifier::PaymentMethodSession
+        | ApiIdentifier::Ephemeral => Resource::Customer,
+        ApiIdentifier::MerchantAccount | ApiIdentifier::Profile => Resource::Account,
+        ApiIdentifier::MerchantConnector => Resource::Connector,
+        ApiIdentifier::ApiKeys => Resource::ApiKey,
+        ApiIdentifier::Routing => Resource::Routing,
+        ApiIdentifier::Webhooks => Resource::WebhookEvent,
+        ApiIdentifier::Organization
+        | ApiIdentifier::Configs
+        | ApiIdentifier::Health
+        | ApiIdentifier::PaymentMethodAuth
+        | ApiIdentifier::CardsInfo
+        | ApiIdentifier::Cache
+        | ApiIdentifier::Verification
+        | ApiIdentifier::Blocklist
+        | ApiIdentifier::Forex
+        | ApiIdentifier::RustLockerMigration
+        | ApiIdentifier::Gsm
+        | ApiIdentifier::Role
+        | ApiIdentifier::User
+        | ApiIdentifier::UserRole
+        | ApiIdentifier::ConnectorOnboarding
+        | ApiIdentifier::Recon
+        | ApiIdentifier::AiWorkflow
+        | ApiIdentifier::ApplePayCertificatesMigration
+        | ApiIdentifier::Relay
+        | ApiIdentifier::Documentation
+        | ApiIdentifier::CardNetworkTokenization
+        | ApiIdentifier::Hypersense
+        | ApiIdentifier::ProcessTracker
+        | ApiIdentifier::Authentication
+        | ApiIdentifier::Proxy
+        | ApiIdentifier::ProfileAcquirer
+        | ApiIdentifier::ThreeDsDecisionRule
+        | ApiIdentifier::GenericTokenization => return None,
+    };
+
+    let scope = if matches!(*method, Method::GET | Method::HEAD) || is_read_only_flow(flow) {
+        PermissionScope::Read
+    } else {
+        PermissionScope::Write
+    };
+
+    Some((resource, scope))
+}
+
+/// Flows that only read data, but are served over `POST` endpoints
+fn is_read_only_flow(flow: &Flow) -> bool {
+    matches!(
+        flow,
+        Flow::PaymentsRetrieve
+            | Flow::PaymentsRetrieveForceSync
+            | Flow::PaymentsList
+            | Flow::PaymentsFilters
+            | Flow::PaymentsAggregate
+            | Flow::RefundsRetrieve
+            | Flow::RefundsRetrieveForceSync
+            | Flow::RefundsList
+            | Flow::RefundsFilters
+            | Flow::RefundsAggregate
+            | Flow::PayoutsRetrieve
+            | Flow::PayoutsList
+            | Flow::PayoutsFilter
+            | Flow::DisputesList
+            | Flow::DisputesFilters
+            | Flow::DisputesAggregate
+            | Flow::CustomersList
+            | Flow::MandatesList
+    )
+}
@@ -50,6 +50,7 @@ fn get_group_description(group: PermissionGroup) -> Option<&'static str> {
         PermissionGroup::ReconOpsManage => Some("Manage all reconciliation operations including reports and analytics"),
         PermissionGroup::ThemeView => Some("View Themes"),
         PermissionGroup::ThemeManage => Some("Manage Themes"),
+        PermissionGroup::AuditLogView => Some("View the audit log of configuration and access changes"),
         PermissionGroup::InternalManage => None, // Internal group, no user-facing description
     }
 }
@@ -65,6 +66,7 @@ pub fn get_parent_group_description(group: ParentGroup) -> Option<&'static str>
         ParentGroup::ReconOps => Some("View, manage reconciliation operations like upload and process files, run reconciliation etc"),
         ParentGroup::ReconReports => Some("View, manage reconciliation reports and analytics"),
         ParentGroup::Theme => Some("Manage and view themes for the organization"),
+        ParentGroup::AuditLog => Some("View the audit log of configuration and access changes"),
         ParentGroup::Internal => None, // Internal group, no user-facing description
     }
 }
@@ -24,7 +24,8 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::AccountView
             | Self::ReconOpsView
             | Self::ReconReportsView
-            | Self::ThemeView => PermissionScope::Read,
+            | Self::ThemeView
+            | Self::AuditLogView => PermissionScope::Read,
 
             Self::OperationsManage
             | Self::ConnectorsManage
@@ -57,6 +58,7 @@ impl PermissionGroupExt for PermissionGroup {
             Self::ReconOpsView | Self::ReconOpsManage => ParentGroup::ReconOps,
             Self::ReconReportsView | Self::ReconReportsManage => ParentGroup::ReconReports,
             Self::InternalManage => ParentGroup::Internal,
+            Self::AuditLogView => ParentGroup::AuditLog,
         }
     }
 
@@ -109,6 +111,7 @@ impl PermissionGroupExt for PermissionGroup {
             Self::InternalManage => vec![Self::InternalManage],
             Self::ThemeView => vec![Self::ThemeView, Self::AccountView],
             Self::ThemeManage => vec![Self::ThemeManage, Self::AccountView],
+            Self::AuditLogView => vec![Self::AuditLogView],
         }
     }
 }
@@ -135,6 +138,7 @@ impl ParentGroupExt for ParentGroup {
             Self::ReconReports => RECON_REPORTS.to_vec(),
             Self::Internal => INTERNAL.to_vec(),
             Self::Theme => THEME.to_vec(),
+            Self::AuditLog => AUDIT_LOG.to_vec(),
         }
     }
 
@@ -229,3 +233,5 @@ pub static RECON_REPORTS: [Resource; 4] = [
 ];
 
 pub static THEME: [Resource; 1] = [Resource::Theme];
+
+pub static AUDIT_LOG: [Resource; 1] = [Resource::AuditLog];
@@ -106,6 +106,10 @@ generate_permissions! {
         Theme: {
             scopes: [Read,Write],
             entities: [Organization]
+        },
+        AuditLog: {
+            scopes: [Read],
+            entities: [Merchant]
         }
     ]
 }
@@ -142,6 +146,7 @@ pub fn get_resource_name(resource: Resource, entity_type: EntityType) -> Option<
         (Resource::Account, EntityType::Organization) => Some("Organization Account"),
         (Resource::Account, EntityType::Tenant) => Some("Tenant Account"),
         (Resource::Theme, _) => Some("Themes"),
+        (Resource::AuditLog, _) => Some("Audit Logs"),
         (Resource::InternalConnector, _) => None,
     }
 }
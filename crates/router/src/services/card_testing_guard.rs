@@ -1,13 +1,72 @@
 use std::sync::Arc;
 
+use common_utils::date_time;
 use error_stack::ResultExt;
-use redis_interface::RedisConnectionPool;
+use redis_interface::{RedisConnectionPool, RedisKey};
 
 use crate::{
     core::errors::{ApiErrorResponse, RouterResult},
     routes::app::SessionStateInfo,
 };
 
+/// Evaluates a velocity counter, kept as a sorted set of payments scored by the unix timestamp of
+/// the payment. Counters summing amounts store `{payment}:{amount}` members, along with a running
+/// total of the amounts of the members in a separate key.
+///
+/// KEYS[1]: the velocity counter, KEYS[2]: running total of the counter, only for amount sums
+/// ARGV[1]: current unix timestamp, ARGV[2]: window in seconds, ARGV[3]: member of the payment,
+/// ARGV[4]: weight of the payment, ARGV[5]: `1` if the payment has to be recorded in the counter
+///
+/// The payments which fell out of the window are trimmed first, and their amounts are taken off
+/// the running total. Returns the measure of the payments within the window, with the given
+/// payment counted exactly once.
+const VELOCITY_COUNTER_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local weight = tonumber(ARGV[4])
+local function member_amount(member)
+    return tonumber(string.match(member, ':(%-?%d+)$'))
+end
+local total
+if KEYS[2] then
+    local expired = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', now - window)
+    redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+    local running_total = redis.call('GET', KEYS[2])
+    if running_total and redis.call('EXISTS', KEYS[1]) == 1 then
+        total = tonumber(running_total)
+        for _, member in ipairs(expired) do
+            total = total - member_amount(member)
+        end
+        redis.call('SET', KEYS[2], total, 'KEEPTTL')
+    else
+        -- the running total is rebuilt if it is missing or outlived the counter
+        total = 0
+        for _, member in ipairs(redis.call('ZRANGE', KEYS[1], 0, -1)) do
+            total = total + member_amount(member)
+        end
+        redis.call('SET', KEYS[2], total, 'EX', window)
+    end
+else
+    redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+    total = redis.call('ZCARD', KEYS[1])
+end
+local is_recorded = redis.call('ZSCORE', KEYS[1], ARGV[3])
+if not is_recorded then
+    total = total + weight
+end
+if ARGV[5] == '1' then
+    redis.call('ZADD', KEYS[1], now, ARGV[3])
+    redis.call('EXPIRE', KEYS[1], window)
+    if KEYS[2] then
+        if not is_recorded then
+            redis.call('INCRBY', KEYS[2], weight)
+        end
+        redis.call('EXPIRE', KEYS[2], window)
+    end
+end
+return total
+"#;
+
 fn get_redis_connection<A: SessionStateInfo>(state: &A) -> RouterResult<Arc<RedisConnectionPool>> {
     state
         .store()
@@ -16,63 +75,46 @@ fn get_redis_connection<A: SessionStateInfo>(state: &A) -> RouterResult<Arc<Redi
         .attach_printable("Failed to get redis connection")
 }
 
-pub async fn set_blocked_count_in_cache<A>(
-    state: &A,
-    cache_key: &str,
-    value: i32,
-    expiry: i64,
-) -> RouterResult<()>
-where
-    A: SessionStateInfo + Sync,
-{
-    let redis_conn = get_redis_connection(state)?;
-
-    redis_conn
-        .set_key_with_expiry(&cache_key.into(), value, expiry)
-        .await
-        .change_context(ApiErrorResponse::InternalServerError)
-}
-
-pub async fn get_blocked_count_from_cache<A>(
+/// Atomically trims the velocity counter to its window and returns its measure including the
+/// given payment, recording the payment in the counter if `record` is set. The counter sums the
+/// amounts of the payments if `amount` is given, and counts the payments otherwise.
+pub async fn evaluate_velocity_counter<A>(
     state: &A,
     cache_key: &str,
-) -> RouterResult<Option<i32>>
+    window_in_seconds: u32,
+    payment_reference: &str,
+    amount: Option<i64>,
+    record: bool,
+) -> RouterResult<i64>
 where
     A: SessionStateInfo + Sync,
 {
     let redis_conn = get_redis_connection(state)?;
 
-    let value: Option<i32> = redis_conn
-        .get_key(&cache_key.into())
-        .await
-        .change_context(ApiErrorResponse::InternalServerError)?;
-
-    Ok(value)
-}
-
-pub async fn increment_blocked_count_in_cache<A>(
-    state: &A,
-    cache_key: &str,
-    expiry: i64,
-) -> RouterResult<()>
-where
-    A: SessionStateInfo + Sync,
-{
-    let redis_conn = get_redis_connection(state)?;
-
-    let value: Option<i32> = redis_conn
-        .get_key(&cache_key.into())
-        .await
-        .change_context(ApiErrorResponse::InternalServerError)?;
-
-    let mut incremented_blocked_count: i32 = 1;
-
-    if let Some(actual_value) = value {
-        incremented_blocked_count = actual_value + 1;
-    }
+    // The running total is kept in the hash slot of the counter, as both are used by the script
+    let counter_key = format!("{{{cache_key}}}");
+    let mut keys = vec![RedisKey::from(counter_key.as_str()).tenant_aware_key(&redis_conn)];
+    let (member, weight) = match amount {
+        Some(amount) => {
+            keys.push(RedisKey::from(format!("{counter_key}_TOTAL")).tenant_aware_key(&redis_conn));
+            (format!("{payment_reference}:{amount}"), amount)
+        }
+        None => (payment_reference.to_string(), 1),
+    };
 
     redis_conn
-        .set_key_with_expiry(&cache_key.into(), incremented_blocked_count, expiry)
+        .evaluate_redis_script(
+            VELOCITY_COUNTER_SCRIPT,
+            keys,
+            vec![
+                date_time::now_unix_timestamp().to_string(),
+                window_in_seconds.to_string(),
+                member,
+                weight.to_string(),
+                u8::from(record).to_string(),
+            ],
+        )
         .await
         .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate the velocity counter")
 }
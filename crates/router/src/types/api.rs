@@ -23,6 +23,8 @@ pub mod routing;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(feature = "olap")]
+pub mod webhook_endpoints;
+#[cfg(feature = "olap")]
 pub mod webhook_events;
 pub mod webhooks;
 
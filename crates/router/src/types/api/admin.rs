@@ -235,6 +235,8 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             dispute_polling_interval: item.dispute_polling_interval,
             is_manual_retry_enabled: item.is_manual_retry_enabled,
             always_enable_overcapture: item.always_enable_overcapture,
+            routing_timezone: item.routing_timezone,
+            routing_base_currency: item.routing_base_currency,
         })
     }
 }
@@ -495,5 +497,7 @@ pub async fn create_profile_from_merchant_account(
         dispute_polling_interval: request.dispute_polling_interval,
         is_manual_retry_enabled: request.is_manual_retry_enabled,
         always_enable_overcapture: request.always_enable_overcapture,
+        routing_timezone: request.routing_timezone,
+        routing_base_currency: request.routing_base_currency,
     }))
 }
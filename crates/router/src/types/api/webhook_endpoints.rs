@@ -0,0 +1,5 @@
+pub use api_models::webhook_endpoints::{
+    WebhookEndpointCreateRequest, WebhookEndpointCreateRequestInternal,
+    WebhookEndpointDeleteResponse, WebhookEndpointIdInternal, WebhookEndpointResponse,
+    WebhookEndpointUpdateRequest, WebhookEndpointUpdateRequestInternal,
+};
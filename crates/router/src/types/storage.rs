@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -32,6 +33,8 @@ pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
+pub mod payout_batch_item;
 pub mod payouts;
 pub mod refund;
 #[cfg(feature = "v2")]
@@ -42,10 +45,13 @@ pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
 pub mod subscription;
+pub mod subscription_invoice;
+pub mod subscription_plan;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 pub use diesel_models::{
     process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
@@ -71,12 +77,14 @@ pub use hyperswitch_domain_models::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
+    address::*, api_keys::*, audit_log::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
     capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
     dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
     generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
     merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
-    subscription::*, unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    payout_batch::*, payout_batch_item::*, process_tracker::*, refund::*, reverse_lookup::*,
+    role::*, routing_algorithm::*, subscription::*, subscription_invoice::*, subscription_plan::*,
+    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    webhook_endpoint::*,
 };
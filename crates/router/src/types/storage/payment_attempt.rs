@@ -229,6 +229,8 @@ mod tests {
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_details: Default::default(),
+            flagged_velocity_rules: Default::default(),
+            blocklist_match_details: Default::default(),
         };
 
         let store = state
@@ -323,6 +325,8 @@ mod tests {
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_details: Default::default(),
+            flagged_velocity_rules: Default::default(),
+            blocklist_match_details: Default::default(),
         };
         let store = state
             .stores
@@ -430,6 +434,8 @@ mod tests {
             connector_request_reference_id: Default::default(),
             network_transaction_id: Default::default(),
             network_details: Default::default(),
+            flagged_velocity_rules: Default::default(),
+            blocklist_match_details: Default::default(),
         };
         let store = state
             .stores
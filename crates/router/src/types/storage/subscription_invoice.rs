@@ -0,0 +1,3 @@
+pub use diesel_models::subscription_invoice::{
+    SubscriptionInvoice, SubscriptionInvoiceNew, SubscriptionInvoiceUpdate,
+};
@@ -622,6 +622,7 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            restrictions: api_key.restrictions,
         }
     }
 }
@@ -636,6 +637,7 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            restrictions: api_key.restrictions,
         }
     }
 }
@@ -649,6 +651,7 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            restrictions: api_key.restrictions,
         }
     }
 }
@@ -1630,6 +1633,7 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest>
             error_category: value.error_category,
             feature_data: value.feature_data.unwrap_or(inferred_feature_data),
             feature: value.feature.unwrap_or(api_enums::GsmFeature::Retry),
+            match_type: value.match_type.unwrap_or_default(),
         }
     }
 }
@@ -1647,6 +1651,7 @@ impl ForeignFrom<&gsm_api_types::GsmCreateRequest> for common_types::domain::Gsm
                     clear_pan_possible: value.clear_pan_possible,
                     alternate_network_possible,
                     decision: value.decision,
+                    retry_later_config: None,
                 })
             }
         }
@@ -1666,9 +1671,9 @@ impl
         ),
     ) -> Self {
         let gsm_db_record_inferred_feature = match gsm_db_record.feature_data.get_decision() {
-            api_enums::GsmDecision::Retry | api_enums::GsmDecision::DoDefault => {
-                api_enums::GsmFeature::Retry
-            }
+            api_enums::GsmDecision::Retry
+            | api_enums::GsmDecision::DoDefault
+            | api_enums::GsmDecision::RetryLater => api_enums::GsmFeature::Retry,
         };
 
         let gsm_feature = gsm_update_request
@@ -1695,12 +1700,15 @@ impl
                                 .map(|data| data.clear_pan_possible))
                             .unwrap_or_default(),
                         alternate_network_possible: gsm_db_record_retry_feature_data
+                            .as_ref()
                             .map(|data| data.alternate_network_possible)
                             .unwrap_or_default(),
                         decision: gsm_update_request
                             .decision
                             .or(Some(gsm_db_record.feature_data.get_decision()))
                             .unwrap_or_default(),
+                        retry_later_config: gsm_db_record_retry_feature_data
+                            .and_then(|data| data.retry_later_config),
                     },
                 );
                 (api_enums::GsmFeature::Retry, retry_feature_data)
@@ -1735,6 +1743,43 @@ impl ForeignFrom<hyperswitch_domain_models::gsm::GatewayStatusMap> for gsm_api_t
                 .unwrap_or(false),
             feature_data: Some(value.feature_data),
             feature: value.feature,
+            match_type: value.match_type,
+        }
+    }
+}
+
+impl ForeignFrom<hyperswitch_domain_models::gsm::GatewayStatusMap> for gsm_api_types::GsmCsvRecord {
+    fn foreign_from(value: hyperswitch_domain_models::gsm::GatewayStatusMap) -> Self {
+        let retry_feature_data = value.feature_data.get_retry_feature_data();
+        Self {
+            connector: value.connector,
+            flow: value.flow,
+            sub_flow: value.sub_flow,
+            code: value.code,
+            message: value.message,
+            match_type: Some(value.match_type),
+            status: value.status,
+            router_error: value.router_error,
+            decision: value.feature_data.get_decision(),
+            step_up_possible: retry_feature_data
+                .as_ref()
+                .is_some_and(|data| data.is_step_up_possible()),
+            clear_pan_possible: retry_feature_data
+                .as_ref()
+                .is_some_and(|data| data.is_clear_pan_possible()),
+            alternate_network_possible: retry_feature_data
+                .as_ref()
+                .is_some_and(|data| data.is_alternate_network_possible()),
+            retry_later_initial_delay_in_seconds: retry_feature_data
+                .as_ref()
+                .and_then(|data| data.retry_later_config)
+                .map(|config| config.initial_delay_in_seconds),
+            retry_later_max_retries: retry_feature_data
+                .and_then(|data| data.retry_later_config)
+                .map(|config| config.max_retries),
+            unified_code: value.unified_code,
+            unified_message: value.unified_message,
+            error_category: value.error_category,
         }
     }
 }
@@ -1837,6 +1882,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_class: item.event_class,
             is_delivery_successful: item.is_overall_delivery_successful,
             initial_attempt_id,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             created: item.created_at,
         })
     }
@@ -1948,6 +1994,7 @@ impl ForeignFrom<api_models::admin::CardTestingGuardConfig>
             },
             customer_id_blocking_threshold: item.customer_id_blocking_threshold,
             card_testing_guard_expiry: item.card_testing_guard_expiry,
+            velocity_rules: item.velocity_rules,
         }
     }
 }
@@ -1973,6 +2020,7 @@ impl ForeignFrom<diesel_models::business_profile::CardTestingGuardConfig>
             },
             customer_id_blocking_threshold: item.customer_id_blocking_threshold,
             card_testing_guard_expiry: item.card_testing_guard_expiry,
+            velocity_rules: item.velocity_rules,
         }
     }
 }
@@ -6,13 +6,15 @@ use std::{
 };
 
 use api_models::enums;
-use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
+use common_utils::{
+    date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt, types::MinorUnit,
+};
 use currency_conversion::types::{CurrencyFactors, ExchangeRates};
 use error_stack::ResultExt;
 use masking::PeekInterface;
 use redis_interface::DelReply;
 use router_env::{instrument, tracing};
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use strum::IntoEnumIterator;
 use tokio::sync::RwLock;
 use tracing_futures::Instrument;
@@ -545,3 +547,57 @@ pub async fn convert_currency(
         currency: to_currency.to_string(),
     })
 }
+
+/// Converts an amount in the lowest denomination of `from_currency` to the lowest denomination of
+/// `to_currency`, using the cached forex rates
+#[instrument(skip_all)]
+pub async fn convert_minor_unit_amount(
+    state: &SessionState,
+    amount: MinorUnit,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+) -> CustomResult<MinorUnit, ForexError> {
+    if from_currency == to_currency {
+        return Ok(amount);
+    }
+
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = get_forex_rates(state, forex_api.data_expiration_delay_in_seconds)
+        .await
+        .change_context(ForexError::ApiError)?;
+
+    convert_minor_unit_amount_with_rates(&rates.data, amount, from_currency, to_currency)
+}
+
+/// Converts an amount in the lowest denomination of `from_currency` to the lowest denomination of
+/// `to_currency`, using the provided forex rates
+pub fn convert_minor_unit_amount_with_rates(
+    rates: &ExchangeRates,
+    amount: MinorUnit,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+) -> CustomResult<MinorUnit, ForexError> {
+    if from_currency == to_currency {
+        return Ok(amount);
+    }
+
+    let converted_amount = currency_conversion::conversion::convert(
+        rates,
+        from_currency,
+        to_currency,
+        amount.get_amount_as_i64(),
+    )
+    .change_context(ForexError::ConversionError)
+    .attach_printable("Unable to perform currency conversion")?;
+
+    let minor_unit_multiplier = Decimal::from(10_i64.pow(u32::from(
+        to_currency.number_of_digits_after_decimal_point(),
+    )));
+
+    converted_amount
+        .checked_mul(minor_unit_multiplier)
+        .and_then(|converted_amount| converted_amount.round().to_i64())
+        .map(MinorUnit::new)
+        .ok_or(ForexError::ConversionError)
+        .attach_printable("Converted amount is out of range")
+}
@@ -394,6 +394,8 @@ pub async fn generate_sample_data(
             connector_request_reference_id: None,
             network_transaction_id: None,
             network_details: None,
+            flagged_velocity_rules: None,
+            blocklist_match_details: None,
         };
 
         let refund = if refunds_count < number_of_refunds && !is_failed_payment {
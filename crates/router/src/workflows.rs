@@ -4,10 +4,17 @@ pub mod api_key_expiry;
 pub mod attach_payout_account_workflow;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
+#[cfg(all(feature = "retry", feature = "v1"))]
+pub mod payment_retry_later;
 pub mod payment_sync;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_batch;
 
 pub mod refund_router;
 
+#[cfg(feature = "v1")]
+pub mod subscription_renewal;
+
 pub mod tokenized_data;
 
 pub mod revenue_recovery;
@@ -15,3 +22,6 @@ pub mod revenue_recovery;
 pub mod process_dispute;
 
 pub mod dispute_list;
+
+#[cfg(feature = "v1")]
+pub mod webhook_signing_secret_expiry;
@@ -120,6 +120,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -188,6 +189,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             &merchant_context,
                             outgoing_webhook,
                             &business_profile,
+                            None,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -364,6 +366,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         mandates::MandateId,
         payments::{PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
         refunds::{RefundResponse, RefundsRetrieveRequest},
+        subscription::SubscriptionId,
     };
 
     use crate::{
@@ -372,6 +375,7 @@ async fn get_outgoing_webhook_content_and_event_type(
             mandate::get_mandate,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
+            subscription::retrieve_subscription,
         },
         services::{ApplicationResponse, AuthFlow},
         types::{api::PSync, transformers::ForeignFrom},
@@ -542,6 +546,62 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+        diesel_models::enums::EventClass::Subscriptions => {
+            let request = SubscriptionId {
+                subscription_id: tracking_data.primary_object_id.clone(),
+            };
+
+            let subscription_response = match retrieve_subscription(
+                state,
+                merchant_context.clone(),
+                None,
+                request,
+            )
+            .await?
+            {
+                ApplicationResponse::Json(subscription_response)
+                | ApplicationResponse::JsonWithHeaders((subscription_response, _)) => {
+                    Ok(subscription_response)
+                }
+                ApplicationResponse::StatusOk
+                | ApplicationResponse::TextPlain(_)
+                | ApplicationResponse::JsonForRedirection(_)
+                | ApplicationResponse::Form(_)
+                | ApplicationResponse::GenericLinkForm(_)
+                | ApplicationResponse::PaymentLinkForm(_)
+                | ApplicationResponse::FileData(_) => {
+                    Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    })
+                }
+            }
+            .map(Box::new)?;
+            let event_type: Option<EventType> = subscription_response.status.into();
+            logger::debug!(current_resource_status=%subscription_response.status);
+
+            Ok((
+                OutgoingWebhookContent::SubscriptionDetails(subscription_response),
+                event_type,
+            ))
+        }
+        #[cfg(feature = "payouts")]
+        diesel_models::enums::EventClass::PayoutBatches => {
+            let payout_batch = payouts::batch::find_payout_batch(
+                &*state.store,
+                merchant_context.get_merchant_account().get_id(),
+                &tracking_data.primary_object_id,
+            )
+            .await?;
+            let event_type: Option<EventType> = payout_batch.status.into();
+            logger::debug!(current_resource_status=%payout_batch.status);
+
+            Ok((
+                OutgoingWebhookContent::PayoutBatchDetails(Box::new(
+                    payouts::batch::get_payout_batch_response(payout_batch),
+                )),
+                event_type,
+            ))
+        }
         #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
@@ -0,0 +1,308 @@
+use api_models::{mandates::RecurringDetails, payments::PaymentsRequest};
+use common_utils::{
+    ext_traits::{OptionExt, ValueExt},
+    types::MinorUnit,
+};
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::payments::{self as payment_flows, operations, retry},
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// Recorded on a payment that is failed because its deferred retry couldn't be made
+const RETRY_LATER_FAILED_ERROR_REASON: &str = "Deferred retry of the payment failed";
+
+pub struct PaymentRetryLaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentRetryLaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: retry::PaymentRetryLaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentRetryLaterTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let storage_scheme = merchant_account.storage_scheme;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                merchant_context.get_merchant_key_store(),
+                storage_scheme,
+            )
+            .await?;
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                payment_intent.active_attempt.get_id().as_str(),
+                storage_scheme,
+            )
+            .await?;
+
+        // The payment may have been resolved in the meantime, by a webhook of the connector
+        if payment_intent.status != enums::IntentStatus::Processing
+            || payment_attempt.status != enums::AttemptStatus::Pending
+        {
+            logger::info!(
+                payment_id = ?tracking_data.payment_id,
+                "payment is no longer parked, skipping the deferred retry"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        }
+
+        let recurring_details = match (
+            payment_attempt.mandate_id.clone(),
+            payment_attempt.payment_method_id.clone(),
+        ) {
+            (Some(mandate_id), _) => RecurringDetails::MandateId(mandate_id),
+            (None, Some(payment_method_id)) => RecurringDetails::PaymentMethodId(payment_method_id),
+            (None, None) => return Err(errors::ProcessTrackerError::MissingRequiredField),
+        };
+        let request = PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                tracking_data.payment_id.clone(),
+            )),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            customer_id: payment_intent.customer_id.clone(),
+            confirm: Some(true),
+            off_session: Some(true),
+            recurring_details: Some(recurring_details),
+            ..Default::default()
+        };
+
+        let parked_attempt_id = payment_attempt.attempt_id.clone();
+        let retry_result = async {
+            retry::make_retry_later_attempt(
+                state,
+                &merchant_context,
+                &request,
+                payment_intent,
+                payment_attempt,
+            )
+            .await?;
+
+            // Confirming the payment also triggers the outgoing webhooks, and parks the payment
+            // again if it is declined with the `retry_later` decision
+            Box::pin(payment_flows::payments_core::<
+                api::Authorize,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+                payment_flows::PaymentData<api::Authorize>,
+            >(
+                state.clone(),
+                state.get_req_state(),
+                merchant_context.clone(),
+                None,
+                operations::PaymentConfirm,
+                request,
+                services::AuthFlow::Merchant,
+                payment_flows::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            ))
+            .await
+        }
+        .await;
+
+        if let Err(error) = retry_result {
+            logger::error!(
+                ?error,
+                payment_id = ?tracking_data.payment_id,
+                "deferred retry of the payment failed"
+            );
+            fail_unretried_payment(state, &merchant_context, &tracking_data, &parked_attempt_id)
+                .await?;
+        }
+
+        // The task is rescheduled instead when the payment was parked again
+        let is_rescheduled = db
+            .as_scheduler()
+            .find_process_by_id(&process.id)
+            .await?
+            .is_some_and(|process| process.status != enums::ProcessTrackerStatus::ProcessStarted);
+        if !is_rescheduled {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Fails a payment whose deferred retry errored before the new attempt reached the connector, so
+/// that it isn't left waiting for a confirmation that never comes, and notifies the merchant
+async fn fail_unretried_payment(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    tracking_data: &retry::PaymentRetryLaterTrackingData,
+    parked_attempt_id: &str,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await?;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+        )
+        .await?;
+
+    // Either no new attempt was made, or it failed before being sent to the connector
+    let is_retry_incomplete = payment_attempt.attempt_id == parked_attempt_id
+        || payment_attempt.status == enums::AttemptStatus::Started;
+    if payment_intent.status.is_in_terminal_state() || !is_retry_incomplete {
+        return Ok(());
+    }
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::ErrorUpdate {
+            connector: None,
+            status: enums::AttemptStatus::Failure,
+            error_code: None,
+            error_message: None,
+            error_reason: Some(Some(RETRY_LATER_FAILED_ERROR_REASON.to_string())),
+            amount_capturable: Some(MinorUnit::new(0)),
+            updated_by: storage_scheme.to_string(),
+            unified_code: None,
+            unified_message: None,
+            connector_transaction_id: None,
+            payment_method_data: None,
+            authentication_type: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+            network_details: None,
+        },
+        storage_scheme,
+    )
+    .await?;
+    let feature_metadata = payment_intent
+        .feature_metadata
+        .clone()
+        .map(masking::Secret::new);
+    let payment_intent = db
+        .update_payment_intent(
+            key_manager_state,
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Failed,
+                incremental_authorization_allowed: Some(false),
+                updated_by: storage_scheme.to_string(),
+                feature_metadata,
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await?;
+
+    let (payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::PSync,
+        _,
+        _,
+        _,
+        payment_flows::PaymentData<api::PSync>,
+    >(
+        state,
+        state.get_req_state(),
+        merchant_context,
+        None,
+        operations::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            force_sync: false,
+            ..Default::default()
+        },
+        payment_flows::CallConnectorAction::Avoid,
+        services::AuthFlow::Merchant,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_manager_state, key_store, profile_id)
+        .await?;
+    Box::pin(utils::trigger_payments_webhook(
+        merchant_context.clone(),
+        business_profile,
+        payment_data,
+        customer,
+        state,
+        operations::PaymentStatus,
+    ))
+    .await
+    .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+    .ok();
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use common_enums::SubscriptionStatus;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::subscription::{self as subscription_core, SubscriptionRenewalTrackingData},
+    errors,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct SubscriptionRenewalWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SubscriptionRenewalWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: SubscriptionRenewalTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionRenewalTrackingData")?;
+
+        let db = &*state.store;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let subscription = subscription_core::find_subscription(
+            db,
+            &tracking_data.merchant_id,
+            &tracking_data.subscription_id,
+        )
+        .await?;
+        let status = subscription_core::get_subscription_status(&subscription)?;
+
+        // Paused subscriptions are rescheduled when they are resumed
+        if matches!(
+            status,
+            SubscriptionStatus::Paused | SubscriptionStatus::Cancelled
+        ) {
+            logger::info!(%status, "Skipping renewal of subscription");
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        }
+
+        if subscription.cancel_at_period_end == Some(true) {
+            let subscription =
+                subscription_core::mark_subscription_as_cancelled(db, subscription).await?;
+            subscription_core::trigger_subscription_outgoing_webhook(
+                state,
+                &merchant_context,
+                &subscription,
+            )
+            .await
+            .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+            .ok();
+
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        }
+
+        let subscription_plan =
+            subscription_core::find_subscription_plan_of_subscription(db, &subscription).await?;
+        let (subscription, invoice) = Box::pin(subscription_core::bill_subscription(
+            state,
+            state.get_req_state(),
+            &merchant_context,
+            subscription,
+            &subscription_plan,
+        ))
+        .await?;
+
+        // Reschedules this task, or cancels the subscription if the retries are exhausted
+        let subscription =
+            subscription_core::schedule_next_subscription_renewal(state, subscription, &invoice)
+                .await?;
+        let new_status = subscription_core::get_subscription_status(&subscription)?;
+
+        if new_status != status {
+            subscription_core::trigger_subscription_outgoing_webhook(
+                state,
+                &merchant_context,
+                &subscription,
+            )
+            .await
+            .map_err(|error| logger::warn!(subscription_outgoing_webhook_error=?error))
+            .ok();
+        }
+
+        if new_status == SubscriptionStatus::Cancelled {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
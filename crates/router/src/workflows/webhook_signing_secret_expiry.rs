@@ -0,0 +1,37 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::webhooks::{signing_secret, types::WebhookSigningSecretExpiryTrackingData},
+    errors,
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct WebhookSigningSecretExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookSigningSecretExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: WebhookSigningSecretExpiryTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("WebhookSigningSecretExpiryTrackingData")?;
+
+        signing_secret::expire_previous_webhook_signing_secret(state, &tracking_data).await?;
+
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+}
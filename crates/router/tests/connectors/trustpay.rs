@@ -52,6 +52,7 @@ fn get_default_browser_info() -> BrowserInformation {
         os_type: None,
         os_version: None,
         device_model: None,
+        device_id: None,
         accept_language: Some("en".to_string()),
     }
 }
@@ -1045,6 +1045,7 @@ impl Default for BrowserInfoType {
             java_script_enabled: Some(true),
             ip_address: Some("127.0.0.1".parse().unwrap()),
             device_model: Some("Apple IPHONE 7".to_string()),
+            device_id: None,
             os_type: Some("IOS or ANDROID".to_string()),
             os_version: Some("IOS 14.5".to_string()),
             accept_language: Some("en".to_string()),
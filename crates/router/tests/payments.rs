@@ -471,6 +471,8 @@ async fn payments_create_core() {
         is_overcapture_enabled: None,
         enable_overcapture: None,
         network_details: None,
+        flagged_velocity_rules: None,
+        blocklist_match_details: None,
     };
     let expected_response =
         services::ApplicationResponse::JsonWithHeaders((expected_response, vec![]));
@@ -755,6 +757,8 @@ async fn payments_create_core_adyen_no_redirect() {
             is_overcapture_enabled: None,
             enable_overcapture: None,
             network_details: None,
+            flagged_velocity_rules: None,
+            blocklist_match_details: None,
         },
         vec![],
     ));
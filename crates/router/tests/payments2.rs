@@ -233,6 +233,8 @@ async fn payments_create_core() {
         is_overcapture_enabled: None,
         enable_overcapture: None,
         network_details: None,
+        flagged_velocity_rules: None,
+        blocklist_match_details: None,
     };
 
     let expected_response =
@@ -525,6 +527,8 @@ async fn payments_create_core_adyen_no_redirect() {
             is_overcapture_enabled: None,
             enable_overcapture: None,
             network_details: None,
+            flagged_velocity_rules: None,
+            blocklist_match_details: None,
         },
         vec![],
     ));
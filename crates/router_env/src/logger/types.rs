@@ -82,6 +82,10 @@ pub enum Flow {
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Merchant Connector circuit breaker retrieve flow.
+    ConnectorCircuitBreakerRetrieve,
+    /// Merchant Connector circuit breaker reset flow.
+    ConnectorCircuitBreakerReset,
     /// Merchant Transfer Keys
     MerchantTransferKey,
     /// ConfigKey create flow.
@@ -114,6 +118,28 @@ pub enum Flow {
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Subscription plan create flow.
+    SubscriptionPlanCreate,
+    /// Subscription plan list flow.
+    SubscriptionPlanList,
+    /// Subscription plan retrieve flow.
+    SubscriptionPlanRetrieve,
+    /// Subscription plan update flow.
+    SubscriptionPlanUpdate,
+    /// Subscription create flow.
+    SubscriptionCreate,
+    /// Subscription retrieve flow.
+    SubscriptionRetrieve,
+    /// Subscription update flow.
+    SubscriptionUpdate,
+    /// Subscription pause flow.
+    SubscriptionPause,
+    /// Subscription resume flow.
+    SubscriptionResume,
+    /// Subscription cancel flow.
+    SubscriptionCancel,
+    /// Subscription invoice list flow.
+    SubscriptionInvoiceList,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
@@ -213,6 +239,15 @@ pub enum Flow {
     PayoutsAccounts,
     /// Payout link initiate flow
     PayoutLinkInitiate,
+    #[cfg(feature = "payouts")]
+    /// Payout batch create flow
+    PayoutBatchCreate,
+    #[cfg(feature = "payouts")]
+    /// Payout batch retrieve flow
+    PayoutBatchRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payout batch results download flow
+    PayoutBatchResults,
     /// Payments Redirect flow
     PaymentsRedirect,
     /// Payemnts Complete Authorize Flow
@@ -247,8 +282,22 @@ pub enum Flow {
     RoutingLinkConfig,
     /// Routing link config
     RoutingUnlinkConfig,
+    /// Routing link shadow config
+    RoutingLinkShadowConfig,
+    /// Routing unlink shadow config
+    RoutingUnlinkShadowConfig,
     /// Routing retrieve config
     RoutingRetrieveConfig,
+    /// Routing simulate algorithm
+    RoutingSimulate,
+    /// Routing import config from DSL
+    RoutingImportDslConfig,
+    /// Routing export config as DSL
+    RoutingExportDslConfig,
+    /// Routing explain connector eligibility
+    RoutingExplainEligibility,
+    /// Routing retrieve rule hits
+    RoutingRetrieveRuleHits,
     /// Routing retrieve active config
     RoutingRetrieveActiveConfig,
     /// Routing retrieve default config
@@ -271,6 +320,8 @@ pub enum Flow {
     UpdateDynamicRoutingConfigs,
     /// Add record to blocklist
     AddToBlocklist,
+    /// Add records to blocklist in bulk
+    BulkAddToBlocklist,
     /// Delete record from blocklist
     DeleteFromBlocklist,
     /// List entries from blocklist
@@ -293,6 +344,8 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// List entries of the administrative audit log
+    AuditLogList,
     /// Dispute Retrieve flow
     DisputesRetrieve,
     /// Dispute List flow
@@ -355,6 +408,12 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Gsm Rules Import flow
+    GsmRulesImport,
+    /// Gsm Rules Export flow
+    GsmRulesExport,
+    /// Gsm Unmapped Errors flow
+    GsmUnmappedErrors,
     /// Get data from embedded flow
     GetDataFromHyperswitchAiFlow,
     /// User Sign Up
@@ -551,12 +610,24 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Create a webhook endpoint for a business profile
+    WebhookEndpointCreate,
+    /// List webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Retrieve a webhook endpoint
+    WebhookEndpointRetrieve,
+    /// Update a webhook endpoint
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint
+    WebhookEndpointDelete,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
     ToggleConnectorAgnosticMit,
+    /// Rotate the secret with which outgoing webhooks of a profile are signed
+    WebhookSigningSecretRotate,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
     /// Manually update the refund details like status, error code, error message etc.
@@ -653,8 +724,17 @@ pub enum Flow {
 }
 
 /// Trait for providing generic behaviour to flow metric
-pub trait FlowMetric: ToString + std::fmt::Debug + Clone {}
-impl FlowMetric for Flow {}
+pub trait FlowMetric: ToString + std::fmt::Debug + Clone {
+    /// Returns the API flow, if the flow metric corresponds to one.
+    fn get_api_flow(&self) -> Option<&Flow> {
+        None
+    }
+}
+impl FlowMetric for Flow {
+    fn get_api_flow(&self) -> Option<&Flow> {
+        Some(self)
+    }
+}
 
 /// Category of log event.
 #[derive(Debug)]
@@ -118,3 +118,33 @@ impl Default for RevenueRecoveryPaymentProcessTrackerMapping {
         }
     }
 }
+
+/// Configuration for retrying failed subscription renewal payments.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscriptionDunningProcessTrackerMapping {
+    /// Default (fallback) retry configuration used when no merchant-specific retry configuration
+    /// exists.
+    pub default_mapping: RetryMapping,
+
+    /// Merchant-specific retry configuration.
+    pub custom_merchant_mapping: HashMap<common_utils::id_type::MerchantId, RetryMapping>,
+}
+
+impl Default for SubscriptionDunningProcessTrackerMapping {
+    fn default() -> Self {
+        Self {
+            default_mapping: RetryMapping {
+                // 1st retry happens 1 day after the renewal payment failed
+                start_after: 60 * 60 * 24,
+
+                frequencies: vec![
+                    // 2nd, 3rd and 4th retries happen at intervals of 1 day each
+                    (60 * 60 * 24, 3),
+                    // 5th and 6th retries happen at intervals of 3 days each
+                    (60 * 60 * 24 * 3, 2),
+                ],
+            },
+            custom_merchant_mapping: HashMap::new(),
+        }
+    }
+}
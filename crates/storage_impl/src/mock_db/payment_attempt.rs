@@ -241,6 +241,8 @@ impl PaymentAttemptInterface for MockDb {
             network_transaction_id: payment_attempt.network_transaction_id,
             is_overcapture_enabled: None,
             network_details: payment_attempt.network_details,
+            flagged_velocity_rules: payment_attempt.flagged_velocity_rules,
+            blocklist_match_details: payment_attempt.blocklist_match_details,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
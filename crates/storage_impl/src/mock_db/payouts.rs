@@ -50,6 +50,16 @@ impl PayoutsInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn find_payouts_by_merchant_id_payout_ids(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payout_ids: &[common_utils::id_type::PayoutId],
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<Payouts>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
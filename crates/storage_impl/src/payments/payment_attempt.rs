@@ -695,6 +695,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     network_transaction_id: payment_attempt.network_transaction_id.clone(),
                     is_overcapture_enabled: None,
                     network_details: payment_attempt.network_details.clone(),
+                    flagged_velocity_rules: payment_attempt.flagged_velocity_rules.clone(),
+                    blocklist_match_details: payment_attempt.blocklist_match_details.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1906,6 +1908,8 @@ impl DataModelExt for PaymentAttempt {
             network_transaction_id: self.network_transaction_id,
             is_overcapture_enabled: self.is_overcapture_enabled,
             network_details: self.network_details,
+            flagged_velocity_rules: self.flagged_velocity_rules,
+            blocklist_match_details: self.blocklist_match_details,
         }
     }
 
@@ -2002,6 +2006,8 @@ impl DataModelExt for PaymentAttempt {
             network_transaction_id: storage_model.network_transaction_id,
             is_overcapture_enabled: storage_model.is_overcapture_enabled,
             network_details: storage_model.network_details,
+            flagged_velocity_rules: storage_model.flagged_velocity_rules,
+            blocklist_match_details: storage_model.blocklist_match_details,
         }
     }
 }
@@ -2095,6 +2101,8 @@ impl DataModelExt for PaymentAttemptNew {
             connector_request_reference_id: self.connector_request_reference_id,
             network_transaction_id: self.network_transaction_id,
             network_details: self.network_details,
+            flagged_velocity_rules: self.flagged_velocity_rules,
+            blocklist_match_details: self.blocklist_match_details,
         }
     }
 
@@ -2181,6 +2189,8 @@ impl DataModelExt for PaymentAttemptNew {
             connector_request_reference_id: storage_model.connector_request_reference_id,
             network_transaction_id: storage_model.network_transaction_id,
             network_details: storage_model.network_details,
+            flagged_velocity_rules: storage_model.flagged_velocity_rules,
+            blocklist_match_details: storage_model.blocklist_match_details,
         }
     }
 }
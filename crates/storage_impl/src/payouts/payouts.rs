@@ -303,6 +303,20 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         .map(|payout| payout.map(Payouts::from_storage_model))
     }
 
+    /// Only looks up the database, a payout that is not drained yet is rejected when it is
+    /// inserted with the same payout id
+    #[instrument(skip_all)]
+    async fn find_payouts_by_merchant_id_payout_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_ids: &[common_utils::id_type::PayoutId],
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        self.router_store
+            .find_payouts_by_merchant_id_payout_ids(merchant_id, payout_ids, storage_scheme)
+            .await
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -458,6 +472,28 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn find_payouts_by_merchant_id_payout_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_ids: &[common_utils::id_type::PayoutId],
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayouts::find_by_merchant_id_payout_ids(&conn, merchant_id, payout_ids.to_vec())
+            .await
+            .map(|payouts| {
+                payouts
+                    .into_iter()
+                    .map(Payouts::from_storage_model)
+                    .collect()
+            })
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(*er.current_context());
+                er.change_context(new_err)
+            })
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -106,6 +106,10 @@ pub static CONTRACT_BASED_DYNAMIC_ALGORITHM_CACHE: LazyLock<Cache> = LazyLock::n
     )
 });
 
+/// GSM rules Cache
+pub static GSM_CACHE: LazyLock<Cache> =
+    LazyLock::new(|| Cache::new("GSM_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
     fn as_any(&self) -> &dyn Any;
@@ -129,6 +133,7 @@ pub enum CacheKind<'a> {
     EliminationBasedDynamicRoutingCache(Cow<'a, str>),
     ContractBasedDynamicRoutingCache(Cow<'a, str>),
     PmFiltersCGraph(Cow<'a, str>),
+    Gsm(Cow<'a, str>),
     All(Cow<'a, str>),
 }
 
@@ -145,6 +150,7 @@ impl CacheKind<'_> {
             | CacheKind::EliminationBasedDynamicRoutingCache(key)
             | CacheKind::ContractBasedDynamicRoutingCache(key)
             | CacheKind::PmFiltersCGraph(key)
+            | CacheKind::Gsm(key)
             | CacheKind::All(key) => key,
         }
     }
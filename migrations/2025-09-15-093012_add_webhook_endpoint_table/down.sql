@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+-- Fails if events delivered to webhook endpoints have idempotent event IDs longer than 64 characters
+ALTER TABLE events ALTER COLUMN idempotent_event_id TYPE VARCHAR(64);
+
+ALTER TABLE events DROP COLUMN IF EXISTS webhook_endpoint_id;
+
+DROP INDEX IF EXISTS webhook_endpoint_profile_id_index;
+
+DROP TABLE IF EXISTS webhook_endpoint;
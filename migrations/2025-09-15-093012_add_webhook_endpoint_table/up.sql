@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS webhook_endpoint (
+    endpoint_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    url TEXT NOT NULL,
+    signing_secret BYTEA NOT NULL,
+    enabled_events JSONB NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    description VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, endpoint_id)
+);
+
+CREATE INDEX IF NOT EXISTS webhook_endpoint_profile_id_index ON webhook_endpoint (profile_id);
+
+ALTER TABLE events ADD COLUMN IF NOT EXISTS webhook_endpoint_id VARCHAR(64) DEFAULT NULL;
+
+-- Idempotent event IDs of events delivered to webhook endpoints are suffixed with the endpoint ID
+ALTER TABLE events ALTER COLUMN idempotent_event_id TYPE VARCHAR(128);
@@ -0,0 +1,19 @@
+-- This file should undo anything in `up.sql`
+-- Values added to the "EventClass", "EventObjectType" and "EventType" enums are not removed, as
+-- Postgres does not support removing values from an enum type
+ALTER TABLE subscription
+    DROP COLUMN IF EXISTS plan_id,
+    DROP COLUMN IF EXISTS mandate_id,
+    DROP COLUMN IF EXISTS current_period_start,
+    DROP COLUMN IF EXISTS current_period_end,
+    DROP COLUMN IF EXISTS trial_end,
+    DROP COLUMN IF EXISTS cancel_at_period_end,
+    DROP COLUMN IF EXISTS paused_at,
+    DROP COLUMN IF EXISTS cancelled_at,
+    DROP COLUMN IF EXISTS pending_proration_amount;
+
+DROP INDEX IF EXISTS subscription_invoice_subscription_id_period_start_index;
+
+DROP TABLE IF EXISTS subscription_invoice;
+
+DROP TABLE IF EXISTS subscription_plan;
@@ -0,0 +1,58 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS subscription_plan (
+    plan_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description VARCHAR(255),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(32) NOT NULL,
+    billing_interval_count INTEGER NOT NULL,
+    trial_period_days INTEGER,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (plan_id, merchant_id)
+);
+
+CREATE TABLE IF NOT EXISTS subscription_invoice (
+    invoice_id VARCHAR(64) NOT NULL,
+    subscription_id VARCHAR(128) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    period_start TIMESTAMP NOT NULL,
+    period_end TIMESTAMP NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (invoice_id, merchant_id)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS subscription_invoice_subscription_id_period_start_index
+    ON subscription_invoice (merchant_id, subscription_id, period_start);
+
+ALTER TABLE subscription
+    ADD COLUMN IF NOT EXISTS plan_id VARCHAR(64),
+    ADD COLUMN IF NOT EXISTS mandate_id VARCHAR(64),
+    ADD COLUMN IF NOT EXISTS current_period_start TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS current_period_end TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS trial_end TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS cancel_at_period_end BOOLEAN,
+    ADD COLUMN IF NOT EXISTS paused_at TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS cancelled_at TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS pending_proration_amount BIGINT;
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_active';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_trialing';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_paused';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_past_due';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_cancelled';
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+-- Values added to the "EventClass", "EventObjectType" and "EventType" enums are not removed, as
+-- Postgres does not support removing values from an enum type
+DROP INDEX IF EXISTS payout_batch_item_status_index;
+
+DROP TABLE IF EXISTS payout_batch_item;
+
+DROP TABLE IF EXISTS payout_batch;
@@ -0,0 +1,38 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_batch (
+    batch_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    total_count INTEGER NOT NULL,
+    succeeded_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    max_concurrency INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (batch_id, merchant_id)
+);
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    batch_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    row_number INTEGER NOT NULL,
+    payout_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    payout_status "PayoutStatus",
+    error_code VARCHAR(255),
+    error_message TEXT,
+    request JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (batch_id, merchant_id, row_number)
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_item_status_index
+    ON payout_batch_item (merchant_id, batch_id, status);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payout_batches';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payout_batch_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_batch_completed';
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE gateway_status_map
+ADD COLUMN IF NOT EXISTS match_type VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+-- Values added to the "BlocklistDataKind" enum are not removed, as Postgres does not support
+-- removing values from an enum type
+ALTER TABLE blocklist
+DROP COLUMN IF EXISTS reason,
+DROP COLUMN IF EXISTS expires_at;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'customer_email';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_address';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'phone_number';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'device_fingerprint';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'billing_country';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'customer_id';
+
+ALTER TABLE blocklist
+ALTER COLUMN fingerprint_id TYPE VARCHAR(255),
+ADD COLUMN IF NOT EXISTS reason VARCHAR(255) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP DEFAULT NULL;
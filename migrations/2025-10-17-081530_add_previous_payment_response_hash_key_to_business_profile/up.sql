@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS previous_payment_response_hash_key BYTEA,
+ADD COLUMN IF NOT EXISTS previous_payment_response_hash_key_expires_at TIMESTAMP;